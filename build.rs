@@ -1,9 +1,34 @@
 fn main() {
-    if cfg!(target_os = "windows") {
+    // Build scripts run on the host, the target is only known through the
+    // environment when cross compiling (e.g building Windows from Linux)
+    let target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    let target_arch = std::env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+
+    if target_os == "windows" {
         let mut res = winres::WindowsResource::new();
 
         res.set_manifest(include_str!("./Manifest.xml"));
         res.set_icon("src/resources/icon.ico");
+
+        // Distinguishes the builds in the file properties dialog
+        res.set(
+            "FileDescription",
+            &format!(
+                "Pocket Relay Plugin Installer ({})",
+                arch_name(&target_arch)
+            ),
+        );
+
         res.compile().unwrap();
     }
 }
+
+/// Name of the `target_arch` as used by Windows
+fn arch_name(target_arch: &str) -> &str {
+    match target_arch {
+        "x86_64" => "x64",
+        "aarch64" => "ARM64",
+        "x86" => "x86",
+        other => other,
+    }
+}
@@ -0,0 +1,150 @@
+//! Module for managing all the ASI plugins within the game plugin directory
+
+use crate::{
+    pe::read_file_version,
+    plugin::{read_plugin_version, PLUGIN_DIR, PLUGIN_NAME},
+    process::ensure_game_not_running,
+};
+use anyhow::Context;
+use log::debug;
+use std::path::{Path, PathBuf};
+
+/// File extension for ASI plugins
+pub const ASI_EXTENSION: &str = ".asi";
+/// Extension appended to disabled ASI plugins
+pub const DISABLED_EXTENSION: &str = ".disabled";
+
+/// Details about an ASI plugin within the plugin directory
+#[derive(Debug, Clone)]
+pub struct AsiPlugin {
+    /// Plugin file name (Without the disabled extension)
+    pub name: String,
+    /// Current path to the plugin file
+    pub path: PathBuf,
+    /// Size of the plugin file in bytes
+    pub size: u64,
+    /// Version of the plugin if known
+    pub version: Option<String>,
+    /// Whether the plugin is enabled
+    pub enabled: bool,
+}
+
+/// Lists all the enabled and disabled ASI plugins in the plugin
+/// directory of the game
+pub async fn list_asi_plugins(game_path: PathBuf) -> anyhow::Result<Vec<AsiPlugin>> {
+    let asi_path = game_path.join(PLUGIN_DIR);
+
+    // Plugin directory hasn't been created yet
+    if !asi_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut entries = tokio::fs::read_dir(&asi_path)
+        .await
+        .context("failed to read plugin directory")?;
+
+    let mut plugins = Vec::new();
+
+    while let Some(entry) = entries
+        .next_entry()
+        .await
+        .context("failed to read plugin directory entry")?
+    {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let lower_name = file_name.to_lowercase();
+
+        let (name, enabled) = if lower_name.ends_with(ASI_EXTENSION) {
+            (file_name, true)
+        } else if lower_name.ends_with(&format!("{ASI_EXTENSION}{DISABLED_EXTENSION}")) {
+            let name = file_name[..file_name.len() - DISABLED_EXTENSION.len()].to_string();
+            (name, false)
+        } else {
+            continue;
+        };
+
+        let path = entry.path();
+        let metadata = entry
+            .metadata()
+            .await
+            .context("failed to read plugin metadata")?;
+
+        if !metadata.is_file() {
+            continue;
+        }
+
+        let version = if name.eq_ignore_ascii_case(PLUGIN_NAME) {
+            read_plugin_version(&game_path).await
+        } else {
+            None
+        };
+
+        // Fallback to the version resource embedded in the plugin
+        let version = match version {
+            Some(value) => Some(value),
+            None => tokio::fs::read(&path)
+                .await
+                .ok()
+                .and_then(|bytes| read_file_version(&bytes)),
+        };
+
+        plugins.push(AsiPlugin {
+            name,
+            path,
+            size: metadata.len(),
+            version,
+            enabled,
+        });
+    }
+
+    plugins.sort_by_key(|plugin| plugin.name.to_lowercase());
+
+    Ok(plugins)
+}
+
+/// Enables or disables the ASI plugin at the provided path by
+/// renaming the plugin file
+pub async fn set_asi_plugin_enabled(
+    game_path: PathBuf,
+    plugin: AsiPlugin,
+    enabled: bool,
+) -> anyhow::Result<()> {
+    if plugin.enabled == enabled {
+        return Ok(());
+    }
+
+    ensure_game_not_running(&game_path).await?;
+
+    let target_path = asi_plugin_path(&plugin.path, &plugin.name, enabled);
+
+    tokio::fs::rename(&plugin.path, &target_path)
+        .await
+        .context("failed to rename plugin")?;
+
+    debug!("set plugin {} enabled: {enabled}", plugin.name);
+
+    Ok(())
+}
+
+/// Deletes the ASI plugin file
+pub async fn delete_asi_plugin(game_path: PathBuf, plugin: AsiPlugin) -> anyhow::Result<()> {
+    ensure_game_not_running(&game_path).await?;
+
+    tokio::fs::remove_file(&plugin.path)
+        .await
+        .context("failed to delete plugin")?;
+
+    debug!("deleted plugin {}", plugin.name);
+
+    Ok(())
+}
+
+/// Determines the path for a plugin based on whether its enabled
+fn asi_plugin_path(path: &Path, name: &str, enabled: bool) -> PathBuf {
+    let file_name = if enabled {
+        name.to_string()
+    } else {
+        format!("{name}{DISABLED_EXTENSION}")
+    };
+
+    path.with_file_name(file_name)
+}
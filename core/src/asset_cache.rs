@@ -0,0 +1,269 @@
+//! Module for caching downloaded plugin assets on disk, so reinstalling a
+//! version or installing it to another copy of the game doesn't download
+//! it again. Assets are stored by their release tag and SHA-256 hash and
+//! are checked against the hash before being reused
+
+use crate::fs_util::atomic_write;
+use anyhow::Context;
+use log::{debug, error};
+use std::path::{Path, PathBuf};
+
+/// Name of the folder within the cache folder storing assets
+const ASSETS_FOLDER: &str = "assets";
+
+/// Length of a hex encoded SHA-256 hash
+const HASH_LENGTH: usize = 64;
+
+/// Number of files and total size of the asset cache
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheUsage {
+    /// Number of cached assets
+    pub files: usize,
+    /// Total size of the cached assets in bytes
+    pub bytes: u64,
+}
+
+/// Determines the folder storing cached assets within the `cache_dir`
+pub fn asset_cache_dir(cache_dir: &Path) -> PathBuf {
+    cache_dir.join(ASSETS_FOLDER)
+}
+
+/// Replaces the characters of a release tag that aren't safe to use
+/// within a file name
+fn sanitize_tag(tag: &str) -> String {
+    tag.chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '.' | '_' | '+' => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+/// Determines the file name for the `asset_name` asset of the release
+/// with the `tag` having the `hash`
+fn cache_file_name(tag: &str, hash: &str, asset_name: &str) -> String {
+    format!("{}-{hash}-{asset_name}", sanitize_tag(tag))
+}
+
+/// Extracts the hash from a cached asset `file_name` when it belongs to
+/// the `asset_name` asset of the release with the `tag`
+fn parse_cache_file_name<'a>(file_name: &'a str, tag: &str, asset_name: &str) -> Option<&'a str> {
+    let hash = file_name
+        .strip_prefix(&sanitize_tag(tag))?
+        .strip_prefix('-')?
+        .strip_suffix(asset_name)?
+        .strip_suffix('-')?;
+
+    if hash.len() != HASH_LENGTH || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    Some(hash)
+}
+
+/// Finds the cached contents of the `asset_name` asset of the release with
+/// the `tag` within the `dir`. When the `expected` hash is known only an
+/// asset with that hash is used. Assets that no longer match their hash
+/// are removed
+pub async fn find_cached_asset(
+    dir: &Path,
+    tag: &str,
+    asset_name: &str,
+    expected: Option<&str>,
+) -> Option<Vec<u8>> {
+    let mut entries = tokio::fs::read_dir(dir).await.ok()?;
+
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let file_name = entry.file_name();
+        let hash = match file_name
+            .to_str()
+            .and_then(|name| parse_cache_file_name(name, tag, asset_name))
+        {
+            Some(value) => value,
+            None => continue,
+        };
+
+        if expected.is_some_and(|expected| !expected.eq_ignore_ascii_case(hash)) {
+            continue;
+        }
+
+        let bytes = match tokio::fs::read(entry.path()).await {
+            Ok(value) => value,
+            Err(err) => {
+                debug!("failed to read cached asset {file_name:?}: {err}");
+                continue;
+            }
+        };
+
+        if !sha256::digest(&bytes).eq_ignore_ascii_case(hash) {
+            debug!("removing corrupted cached asset {file_name:?}");
+            _ = tokio::fs::remove_file(entry.path()).await;
+            continue;
+        }
+
+        debug!("using cached asset {file_name:?}");
+        return Some(bytes);
+    }
+
+    None
+}
+
+/// Saves the verified `bytes` of the `asset_name` asset of the release with
+/// the `tag` to the cache `dir`, failures are logged as the cache is only
+/// an optimization
+pub async fn store_asset(dir: &Path, tag: &str, asset_name: &str, bytes: &[u8]) {
+    if let Err(err) = try_store_asset(dir, tag, asset_name, bytes).await {
+        error!("failed to cache downloaded asset: {err:#}");
+    }
+}
+
+async fn try_store_asset(
+    dir: &Path,
+    tag: &str,
+    asset_name: &str,
+    bytes: &[u8],
+) -> anyhow::Result<()> {
+    tokio::fs::create_dir_all(dir)
+        .await
+        .context("failed to create asset cache directory")?;
+
+    let hash = sha256::digest(bytes);
+    let path = dir.join(cache_file_name(tag, &hash, asset_name));
+    atomic_write(&path, bytes).await?;
+
+    debug!("cached asset {}", path.display());
+
+    Ok(())
+}
+
+/// Determines the number of files and total size of the cache `dir`
+pub async fn cache_usage(dir: &Path) -> CacheUsage {
+    let mut usage = CacheUsage::default();
+
+    let mut entries = match tokio::fs::read_dir(dir).await {
+        Ok(value) => value,
+        Err(_) => return usage,
+    };
+
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        if let Ok(metadata) = entry.metadata().await {
+            if metadata.is_file() {
+                usage.files += 1;
+                usage.bytes += metadata.len();
+            }
+        }
+    }
+
+    usage
+}
+
+/// Removes all the cached assets from the cache `dir`
+pub async fn clear_cache(dir: &Path) -> anyhow::Result<()> {
+    match tokio::fs::remove_dir_all(dir).await {
+        Ok(()) => {}
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err).context("failed to remove asset cache"),
+    }
+
+    debug!("cleared asset cache");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        cache_file_name, cache_usage, clear_cache, find_cached_asset, parse_cache_file_name,
+        store_asset, CacheUsage,
+    };
+    use crate::game_fs::test_util::TempDir;
+
+    /// Cache file names include the hash and can be parsed back
+    #[test]
+    fn test_cache_file_name() {
+        let hash = "a".repeat(64);
+        let file_name = cache_file_name("v1.0.0/beta", &hash, "binkw32.dll");
+
+        assert_eq!(file_name, format!("v1.0.0_beta-{hash}-binkw32.dll"));
+        assert_eq!(
+            parse_cache_file_name(&file_name, "v1.0.0/beta", "binkw32.dll"),
+            Some(hash.as_str())
+        );
+        assert_eq!(
+            parse_cache_file_name(&file_name, "v1.0.0", "binkw32.dll"),
+            None
+        );
+        assert_eq!(
+            parse_cache_file_name(&file_name, "v1.0.0/beta", "other.dll"),
+            None
+        );
+    }
+
+    /// Stored assets are found again by their tag and hash
+    #[tokio::test]
+    async fn test_store_and_find() {
+        let dir = TempDir::new();
+        let bytes = b"plugin".to_vec();
+        let hash = sha256::digest(&bytes);
+
+        store_asset(dir.path(), "v1.0.0", "plugin.dll", &bytes).await;
+
+        assert_eq!(
+            find_cached_asset(dir.path(), "v1.0.0", "plugin.dll", None).await,
+            Some(bytes.clone())
+        );
+        assert_eq!(
+            find_cached_asset(dir.path(), "v1.0.0", "plugin.dll", Some(&hash)).await,
+            Some(bytes)
+        );
+        assert_eq!(
+            find_cached_asset(dir.path(), "v1.0.0", "plugin.dll", Some(&"b".repeat(64))).await,
+            None
+        );
+        assert_eq!(
+            find_cached_asset(dir.path(), "v2.0.0", "plugin.dll", None).await,
+            None
+        );
+    }
+
+    /// Cached assets that no longer match their hash are removed
+    #[tokio::test]
+    async fn test_corrupted_asset() {
+        let dir = TempDir::new();
+        let bytes = b"plugin".to_vec();
+        let path = dir.path().join(cache_file_name(
+            "v1.0.0",
+            &sha256::digest(&bytes),
+            "plugin.dll",
+        ));
+
+        store_asset(dir.path(), "v1.0.0", "plugin.dll", &bytes).await;
+        std::fs::write(&path, b"corrupted").unwrap();
+
+        assert_eq!(
+            find_cached_asset(dir.path(), "v1.0.0", "plugin.dll", None).await,
+            None
+        );
+        assert!(!path.exists());
+    }
+
+    /// Usage includes every cached asset and is empty once cleared
+    #[tokio::test]
+    async fn test_usage_and_clear() {
+        let dir = TempDir::new();
+        let cache_dir = dir.path().join("assets");
+
+        store_asset(&cache_dir, "v1.0.0", "plugin.dll", b"one").await;
+        store_asset(&cache_dir, "v2.0.0", "plugin.dll", b"three").await;
+
+        assert_eq!(
+            cache_usage(&cache_dir).await,
+            CacheUsage { files: 2, bytes: 8 }
+        );
+
+        clear_cache(&cache_dir).await.unwrap();
+        clear_cache(&cache_dir).await.unwrap();
+
+        assert_eq!(cache_usage(&cache_dir).await, CacheUsage::default());
+    }
+}
@@ -0,0 +1,476 @@
+//! Module for helpers related to patching the game using the binkw32 DLL
+
+use std::path::{Path, PathBuf};
+
+use crate::{
+    fs_util::{atomic_write, resolve_file_name},
+    game_fs::{GameFs, TokioFs},
+    hashes::{embedded_manifest, HashKind, HashManifest},
+    plan::{plan_delete, plan_write, ChangeKind, PlannedChange},
+    preflight::check_patch_paths,
+    process::ensure_game_not_running,
+    profile::Game,
+    progress::{Progress, ProgressStep},
+};
+use anyhow::{bail, Context};
+use log::debug;
+use sha256::{digest, try_async_digest};
+
+/// Unpatched binkw32.dll
+const BINK_UNPATCHED: &[u8] = include_bytes!("./resources/binkw23.dll");
+/// Patched binkw32.dll
+const BINK_PATCHED: &[u8] = include_bytes!("./resources/binkw32.dll");
+
+/// Expected SHA256 hash of the embedded unpatched binkw32.dll
+const BINK_UNPATCHED_HASH: &str =
+    "a4ddcf8d78eac388cbc85155ef37a251a77f50de79d0b975ab9bb65bd0375698";
+/// Expected size in bytes of the embedded unpatched binkw32.dll
+const BINK_UNPATCHED_SIZE: usize = 181016;
+/// Expected SHA256 hash of the embedded patched binkw32.dll
+const BINK_PATCHED_HASH: &str = "db3c0b8d1993b890c7f45b668ff9e408ca91395e8c8b810c346d128fcb5f6793";
+/// Expected size in bytes of the embedded patched binkw32.dll
+const BINK_PATCHED_SIZE: usize = 113152;
+
+/// Name of the backup of the original binkw32.dll
+pub const BINKW32_BACKUP_NAME: &str = "binkw32.dll.bak";
+
+/// Files for patching Mass Effect 3
+pub const MASS_EFFECT_3_PATCH: PatchFiles = PatchFiles {
+    unpatched: BINK_UNPATCHED,
+    unpatched_size: BINK_UNPATCHED_SIZE,
+    unpatched_hash: BINK_UNPATCHED_HASH,
+    patched: BINK_PATCHED,
+    patched_size: BINK_PATCHED_SIZE,
+    patched_hash: BINK_PATCHED_HASH,
+};
+
+/// Embedded DLLs for patching a game through its binkw32.dll, the other
+/// known binkw32.dll hashes are listed in the [crate::hashes] manifest
+pub struct PatchFiles {
+    /// Unpatched binkw32.dll, written as binkw23.dll
+    pub unpatched: &'static [u8],
+    /// Expected size in bytes of the unpatched binkw32.dll
+    pub unpatched_size: usize,
+    /// Expected SHA256 hash of the unpatched binkw32.dll
+    pub unpatched_hash: &'static str,
+    /// Patched binkw32.dll
+    pub patched: &'static [u8],
+    /// Expected size in bytes of the patched binkw32.dll
+    pub patched_size: usize,
+    /// Expected SHA256 hash of the patched binkw32.dll
+    pub patched_hash: &'static str,
+}
+
+/// State of the binkw32.dll within the game folder
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatchState {
+    /// Official unpatched binkw32.dll
+    Unpatched,
+    /// Known patched binkw32.dll
+    Patched,
+    /// Unknown binkw32.dll, either a third-party patch or a corrupted
+    /// file. Contains the SHA256 hash of the file
+    UnknownDll(String),
+}
+
+/// Checks that the DLLs embedded for the `game` match their expected size
+/// and hash. A mismatch means the installer executable is corrupted or was
+/// modified (e.g by an antivirus) and the DLLs must not be written to the game
+pub fn verify_embedded_resources(game: Game) -> anyhow::Result<()> {
+    let patch = game.profile().patch;
+    let resources = [
+        (
+            "binkw23.dll",
+            patch.unpatched,
+            patch.unpatched_size,
+            patch.unpatched_hash,
+        ),
+        (
+            "binkw32.dll",
+            patch.patched,
+            patch.patched_size,
+            patch.patched_hash,
+        ),
+    ];
+
+    for (name, bytes, size, hash) in resources {
+        if bytes.len() != size {
+            bail!(
+                "embedded {name} is {} bytes, expected {size} bytes",
+                bytes.len()
+            );
+        }
+
+        if digest(bytes) != hash {
+            bail!("embedded {name} does not match the expected hash");
+        }
+    }
+
+    debug!("verified embedded resources");
+
+    Ok(())
+}
+
+/// Obtains the SHA256 hash of the binkw32.dll at the provided game path
+pub async fn binkw32_hash(game_path: &Path) -> anyhow::Result<String> {
+    let binkw32_path = resolve_file_name(game_path, "binkw32.dll").await;
+
+    try_async_digest(binkw32_path)
+        .await
+        .context("failed to get binkw32.dll hash")
+}
+
+/// Determines the state of the binkw32.dll of the `game` at the provided
+/// game path by comparing its hash against the embedded known hashes
+pub async fn patch_state(game: Game, game_path: &Path) -> anyhow::Result<PatchState> {
+    patch_state_with(&TokioFs, embedded_manifest(), game, game_path).await
+}
+
+/// [patch_state] reading the binkw32.dll through the `fs` and comparing
+/// its hash against the `manifest`
+pub async fn patch_state_with(
+    fs: &impl GameFs,
+    manifest: &HashManifest,
+    game: Game,
+    game_path: &Path,
+) -> anyhow::Result<PatchState> {
+    let binkw32_path = resolve_file_name(game_path, "binkw32.dll").await;
+    let digest = fs
+        .read(&binkw32_path)
+        .await
+        .map(digest)
+        .context("failed to get binkw32.dll hash")?;
+
+    let state = match manifest.classify(game, &digest) {
+        Some(HashKind::Official) => PatchState::Unpatched,
+        Some(HashKind::Patched) => PatchState::Patched,
+        None => PatchState::UnknownDll(digest),
+    };
+
+    debug!("binkw32 state is: {state:?}");
+
+    Ok(state)
+}
+
+/// Writes an unpatched version of the binkw32.dll to binkw23.dll and
+/// overwrites the binkw32.dll with a patched version. The existing
+/// binkw32.dll is backed up so it can be restored when removing the patch.
+/// Each step is reported to `on_progress`
+pub async fn apply_patch(
+    game: Game,
+    game_path: PathBuf,
+    on_progress: impl FnMut(Progress),
+) -> anyhow::Result<()> {
+    apply_patch_with(&TokioFs, embedded_manifest(), game, game_path, on_progress).await
+}
+
+/// [apply_patch] writing the patch files through the `fs`, the result is
+/// verified against the `manifest`
+pub async fn apply_patch_with(
+    fs: &impl GameFs,
+    manifest: &HashManifest,
+    game: Game,
+    game_path: PathBuf,
+    mut on_progress: impl FnMut(Progress),
+) -> anyhow::Result<()> {
+    on_progress(Progress::step(ProgressStep::Checking));
+
+    let binkw32_path = resolve_file_name(&game_path, "binkw32.dll").await;
+    let binkw23_path = resolve_file_name(&game_path, "binkw23.dll").await;
+    let backup_path = game_path.join(BINKW32_BACKUP_NAME);
+    let patch = game.profile().patch;
+
+    verify_embedded_resources(game).context("installer files are damaged")?;
+    ensure_game_not_running(&game_path).await?;
+    check_patch_paths(game, &game_path).await?;
+
+    on_progress(Progress::step(ProgressStep::Writing));
+
+    // Backup the existing binkw32.dll unless its already the patched version
+    if fs.is_file(&binkw32_path) {
+        let existing = fs
+            .read(&binkw32_path)
+            .await
+            .context("failed to read existing binkw32.dll")?;
+
+        if existing != patch.patched {
+            fs.write(&backup_path, &existing)
+                .await
+                .context("failed to backup binkw32.dll")?;
+
+            debug!("backed up binkw32.dll");
+        }
+    }
+
+    fs.write(&binkw32_path, patch.patched)
+        .await
+        .context("failed to write patch")?;
+    fs.write(&binkw23_path, patch.unpatched)
+        .await
+        .context("failed to write unpatched")?;
+
+    on_progress(Progress::step(ProgressStep::Verifying));
+
+    verify_patch_with(fs, manifest, game, &game_path)
+        .await
+        .context("patch failed verification")?;
+
+    Ok(())
+}
+
+/// Verifies the patch was written correctly by hashing the
+/// binkw32.dll and binkw23.dll files in the game folder
+pub async fn verify_patch(game: Game, game_path: &Path) -> anyhow::Result<()> {
+    verify_patch_with(&TokioFs, embedded_manifest(), game, game_path).await
+}
+
+/// [verify_patch] reading the patch files through the `fs`
+async fn verify_patch_with(
+    fs: &impl GameFs,
+    manifest: &HashManifest,
+    game: Game,
+    game_path: &Path,
+) -> anyhow::Result<()> {
+    match patch_state_with(fs, manifest, game, game_path).await? {
+        PatchState::Patched => {}
+        state => bail!("binkw32.dll is not the patched version ({state:?})"),
+    }
+
+    let binkw23_path = resolve_file_name(game_path, "binkw23.dll").await;
+    let binkw23_hash = fs
+        .read(&binkw23_path)
+        .await
+        .map(digest)
+        .context("failed to get binkw23.dll hash")?;
+
+    if binkw23_hash != game.profile().patch.unpatched_hash {
+        bail!("binkw23.dll does not match the unpatched version");
+    }
+
+    debug!("verified patch");
+
+    Ok(())
+}
+
+/// Plans the changes that [apply_patch] will make without making them
+pub async fn plan_apply_patch(
+    game: Game,
+    game_path: PathBuf,
+) -> anyhow::Result<Vec<PlannedChange>> {
+    let binkw32_path = resolve_file_name(&game_path, "binkw32.dll").await;
+    let patch = game.profile().patch;
+
+    // Existing binkw32.dll is backed up unless its already the patched version
+    let mut backup = None;
+    if binkw32_path.exists() {
+        let existing = tokio::fs::read(&binkw32_path)
+            .await
+            .context("failed to read existing binkw32.dll")?;
+
+        if existing != patch.patched {
+            backup = Some(PathBuf::from(BINKW32_BACKUP_NAME));
+        }
+    }
+
+    Ok(vec![
+        plan_write(
+            &game_path,
+            "binkw32.dll",
+            patch.patched.len() as u64,
+            backup,
+        ),
+        plan_write(
+            &game_path,
+            "binkw23.dll",
+            patch.unpatched.len() as u64,
+            None,
+        ),
+    ])
+}
+
+/// Plans the changes that [remove_patch] will make without making them
+pub async fn plan_remove_patch(
+    game: Game,
+    game_path: PathBuf,
+) -> anyhow::Result<Vec<PlannedChange>> {
+    let mut changes = Vec::new();
+
+    if game_path.join(BINKW32_BACKUP_NAME).exists() {
+        changes.push(PlannedChange {
+            path: PathBuf::from("binkw32.dll"),
+            kind: ChangeKind::Restore(PathBuf::from(BINKW32_BACKUP_NAME)),
+        });
+    } else {
+        changes.push(plan_write(
+            &game_path,
+            "binkw32.dll",
+            game.profile().patch.unpatched.len() as u64,
+            None,
+        ));
+    }
+
+    changes.extend(plan_delete(&game_path, "binkw23.dll"));
+
+    Ok(changes)
+}
+
+/// Restores the backed up binkw32.dll and removes the old binkw23.dll, the
+/// embedded unpatched binkw32.dll is used when there is no backup
+pub async fn remove_patch(game: Game, game_path: PathBuf) -> anyhow::Result<()> {
+    let binkw32_path = resolve_file_name(&game_path, "binkw32.dll").await;
+    let binkw23_path = resolve_file_name(&game_path, "binkw23.dll").await;
+    let backup_path = game_path.join(BINKW32_BACKUP_NAME);
+
+    ensure_game_not_running(&game_path).await?;
+
+    if backup_path.exists() {
+        // Moving the backup into place replaces the patch atomically
+        tokio::fs::rename(&backup_path, &binkw32_path)
+            .await
+            .context("failed to restore binkw32.dll backup")?;
+
+        debug!("restored binkw32.dll backup");
+    } else {
+        verify_embedded_resources(game).context("installer files are damaged")?;
+
+        atomic_write(binkw32_path, game.profile().patch.unpatched)
+            .await
+            .context("failed to write unpatched")?;
+    }
+
+    if binkw23_path.exists() {
+        tokio::fs::remove_file(binkw23_path)
+            .await
+            .context("failed to remove patched")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{apply_patch_with, patch_state_with, PatchState, BINKW32_BACKUP_NAME};
+    use crate::{
+        game_fs::{
+            test_util::{FailingFs, TempDir},
+            TokioFs,
+        },
+        hashes::embedded_manifest,
+        profile::Game,
+    };
+
+    /// Game the tests patch
+    const GAME: Game = Game::MassEffect3;
+
+    /// Applying the patch over the official binkw32.dll backs it up and
+    /// writes both patch files
+    #[tokio::test]
+    async fn test_apply_patch() {
+        let game = TempDir::new();
+        let patch = GAME.profile().patch;
+        std::fs::write(game.path().join("binkw32.dll"), patch.unpatched).unwrap();
+
+        assert!(matches!(
+            patch_state_with(&TokioFs, embedded_manifest(), GAME, game.path())
+                .await
+                .unwrap(),
+            PatchState::Unpatched
+        ));
+
+        apply_patch_with(
+            &TokioFs,
+            embedded_manifest(),
+            GAME,
+            game.path().to_path_buf(),
+            |_| {},
+        )
+        .await
+        .unwrap();
+
+        assert!(matches!(
+            patch_state_with(&TokioFs, embedded_manifest(), GAME, game.path())
+                .await
+                .unwrap(),
+            PatchState::Patched
+        ));
+        assert_eq!(
+            std::fs::read(game.path().join("binkw23.dll")).unwrap(),
+            patch.unpatched
+        );
+        assert_eq!(
+            std::fs::read(game.path().join(BINKW32_BACKUP_NAME)).unwrap(),
+            patch.unpatched
+        );
+    }
+
+    /// Game files named with a different case are patched in place
+    #[tokio::test]
+    async fn test_apply_patch_case_insensitive() {
+        let game = TempDir::new();
+        std::fs::write(
+            game.path().join("BINKW32.DLL"),
+            GAME.profile().patch.unpatched,
+        )
+        .unwrap();
+
+        apply_patch_with(
+            &TokioFs,
+            embedded_manifest(),
+            GAME,
+            game.path().to_path_buf(),
+            |_| {},
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            std::fs::read(game.path().join("BINKW32.DLL")).unwrap(),
+            GAME.profile().patch.patched
+        );
+    }
+
+    /// Failing to write the patch leaves the existing binkw32.dll in place
+    #[tokio::test]
+    async fn test_apply_patch_write_failure() {
+        let game = TempDir::new();
+        let patch = GAME.profile().patch;
+        std::fs::write(game.path().join("binkw32.dll"), patch.unpatched).unwrap();
+
+        let fs = FailingFs {
+            file_name: "binkw32.dll",
+        };
+        let result = apply_patch_with(
+            &fs,
+            embedded_manifest(),
+            GAME,
+            game.path().to_path_buf(),
+            |_| {},
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            std::fs::read(game.path().join("binkw32.dll")).unwrap(),
+            patch.unpatched
+        );
+        assert!(!game.path().join("binkw23.dll").exists());
+    }
+
+    /// Unknown DLLs are reported with their hash and missing DLLs fail
+    #[tokio::test]
+    async fn test_patch_state_unknown() {
+        let game = TempDir::new();
+
+        assert!(
+            patch_state_with(&TokioFs, embedded_manifest(), GAME, game.path())
+                .await
+                .is_err()
+        );
+
+        std::fs::write(game.path().join("binkw32.dll"), b"modified").unwrap();
+
+        assert!(matches!(
+            patch_state_with(&TokioFs, embedded_manifest(), GAME, game.path()).await.unwrap(),
+            PatchState::UnknownDll(hash) if hash == sha256::digest(b"modified")
+        ));
+    }
+}
@@ -0,0 +1,16 @@
+//! Module for reading little endian values from binary file formats
+//! (e.g PE files and Windows shortcuts)
+
+/// Reads a little endian u16 at the `offset`
+pub fn read_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(
+        bytes.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+/// Reads a little endian u32 at the `offset`
+pub fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(
+        bytes.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
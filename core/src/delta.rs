@@ -0,0 +1,197 @@
+//! Module for applying delta updates to the plugin, releases can publish
+//! a binary diff from a previous version so slow connections only need to
+//! download the changes instead of the whole plugin.
+//!
+//! Delta assets are named "<asset name>.from-<previous tag>.bsdiff.gz" and
+//! contain a gzip compressed patch in the format produced by the `bsdiff`
+//! crate: a sequence of control entries each made up of the number of bytes
+//! to add to the old file, the number of extra bytes to copy and how far to
+//! seek within the old file, followed by the bytes for the entry
+
+use anyhow::{bail, Context};
+use flate2::read::GzDecoder;
+use std::io::Read;
+
+/// Suffix of the delta asset names after the previous tag
+const DELTA_SUFFIX: &str = ".bsdiff.gz";
+
+/// Largest plugin a delta is allowed to produce, guards against corrupted
+/// patches causing huge allocations
+const MAX_OUTPUT_SIZE: usize = 64 * 1024 * 1024;
+
+/// Determines the name of the delta asset updating the `asset_name` asset
+/// from the release with the `from_tag`
+pub fn delta_asset_name(asset_name: &str, from_tag: &str) -> String {
+    format!("{asset_name}.from-{from_tag}{DELTA_SUFFIX}")
+}
+
+/// Reads a patch integer, stored as little endian with the sign in the
+/// highest bit rather than as two's complement
+fn read_offset(bytes: &[u8]) -> i64 {
+    let mut buf = [0; 8];
+    buf.copy_from_slice(bytes);
+
+    let value = u64::from_le_bytes(buf);
+    let magnitude = (value & !(1 << 63)) as i64;
+
+    if value & (1 << 63) == 0 {
+        magnitude
+    } else {
+        -magnitude
+    }
+}
+
+/// Reads a patch length, lengths can't be negative
+fn read_length(bytes: &[u8]) -> anyhow::Result<usize> {
+    let value = read_offset(bytes);
+    if value < 0 {
+        bail!("negative length in patch");
+    }
+
+    usize::try_from(value).context("length in patch is too large")
+}
+
+/// Applies the gzip compressed `patch` to the `old` plugin producing the
+/// new plugin
+pub fn apply_delta(old: &[u8], patch: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let mut patch_bytes = Vec::new();
+    GzDecoder::new(patch)
+        .read_to_end(&mut patch_bytes)
+        .context("failed to decompress patch")?;
+
+    apply_patch(old, &patch_bytes)
+}
+
+/// Applies the uncompressed `patch` to the `old` file
+fn apply_patch(old: &[u8], patch: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let mut new = Vec::new();
+    let mut old_pos: i64 = 0;
+    let mut rest = patch;
+
+    while !rest.is_empty() {
+        if rest.len() < 24 {
+            bail!("truncated patch control entry");
+        }
+
+        let (control, data) = rest.split_at(24);
+        let add_len = read_length(&control[0..8])?;
+        let copy_len = read_length(&control[8..16])?;
+        let seek = read_offset(&control[16..24]);
+
+        let entry_len = add_len
+            .checked_add(copy_len)
+            .context("patch entry is too large")?;
+        if data.len() < entry_len {
+            bail!("truncated patch data");
+        }
+        if new.len() + entry_len > MAX_OUTPUT_SIZE {
+            bail!("patch produces a file that is too large");
+        }
+
+        let (add, data) = data.split_at(add_len);
+        let (extra, data) = data.split_at(copy_len);
+
+        // Added bytes are the difference from the old file
+        let start = usize::try_from(old_pos)
+            .ok()
+            .filter(|start| {
+                start
+                    .checked_add(add_len)
+                    .is_some_and(|end| end <= old.len())
+            })
+            .context("patch reads outside the old file")?;
+        new.extend(
+            add.iter()
+                .zip(&old[start..start + add_len])
+                .map(|(diff, old)| diff.wrapping_add(*old)),
+        );
+        new.extend_from_slice(extra);
+
+        old_pos = old_pos
+            .checked_add(add_len as i64)
+            .and_then(|pos| pos.checked_add(seek))
+            .context("patch seeks outside the old file")?;
+        rest = data;
+    }
+
+    Ok(new)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{apply_delta, apply_patch, delta_asset_name};
+    use flate2::{write::GzEncoder, Compression};
+    use std::io::Write;
+
+    /// Encodes a patch integer with the sign in the highest bit
+    fn offset(value: i64) -> [u8; 8] {
+        let magnitude = value.unsigned_abs();
+        let value = if value < 0 {
+            magnitude | (1 << 63)
+        } else {
+            magnitude
+        };
+        value.to_le_bytes()
+    }
+
+    /// Creates a patch control entry followed by its data
+    fn entry(add: &[u8], extra: &[u8], seek: i64) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend(offset(add.len() as i64));
+        bytes.extend(offset(extra.len() as i64));
+        bytes.extend(offset(seek));
+        bytes.extend(add);
+        bytes.extend(extra);
+        bytes
+    }
+
+    /// Delta asset names include the previous tag
+    #[test]
+    fn test_delta_asset_name() {
+        assert_eq!(
+            delta_asset_name("pocket-relay-plugin.asi", "v1.0.0"),
+            "pocket-relay-plugin.asi.from-v1.0.0.bsdiff.gz"
+        );
+    }
+
+    /// Patches add to the old bytes, insert extra bytes and seek within
+    /// the old file
+    #[test]
+    fn test_apply_patch() {
+        let old = b"hello world";
+
+        let mut patch = entry(&[0, 0, 0, 0, 0], b", big ", 1);
+        patch.extend(entry(&[0, 0, 0, 0, 1], b"!", 0));
+
+        assert_eq!(apply_patch(old, &patch).unwrap(), b"hello, big worle!");
+    }
+
+    /// Compressed patches are decompressed before being applied
+    #[test]
+    fn test_apply_delta() {
+        let old = b"plugin v1";
+        let patch = entry(&[0, 0, 0, 0, 0, 0, 0, 0, 1], &[], 0);
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&patch).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        assert_eq!(apply_delta(old, &compressed).unwrap(), b"plugin v2");
+        assert!(apply_delta(old, &patch).is_err());
+    }
+
+    /// Patches reading past the old file or cut short are rejected
+    #[test]
+    fn test_invalid_patch() {
+        let old = b"short";
+
+        assert!(apply_patch(old, &entry(&[0; 10], &[], 0)).is_err());
+        assert!(apply_patch(old, &entry(&[0; 2], &[], -5)[..]).is_ok());
+        assert!(apply_patch(
+            old,
+            &[entry(&[0; 2], &[], -5), entry(&[0], &[], 0)].concat()
+        )
+        .is_err());
+        assert!(apply_patch(old, &entry(&[0; 2], b"extra", 0)[..30]).is_err());
+    }
+}
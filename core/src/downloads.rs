@@ -0,0 +1,303 @@
+//! Module for the download manager, queues downloads so only a limited
+//! number run at once and tracks the progress of each download so they
+//! can be listed and cancelled from the downloads panel
+
+use crate::github::DownloadProgress;
+use anyhow::anyhow;
+use futures_util::{stream, Stream};
+use log::debug;
+use std::{
+    collections::HashMap,
+    future::Future,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU32, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use tokio::sync::{watch, Notify, Semaphore};
+
+/// Maximum number of downloads that run at the same time
+const MAX_CONCURRENT_DOWNLOADS: usize = 2;
+
+/// Minimum time between updates to the downloads list, progress is
+/// reported for every chunk which is far more often than needed
+const UPDATE_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Unique ID for a download
+pub type DownloadId = u64;
+
+/// Download tracked by the download manager
+#[derive(Debug, Clone)]
+pub struct DownloadItem {
+    /// Unique ID of the download
+    pub id: DownloadId,
+    /// Name of the file being downloaded
+    pub name: String,
+    /// Progress of the download once started
+    pub progress: Option<DownloadProgress>,
+    /// Current status of the download
+    pub status: DownloadStatus,
+}
+
+/// Status of a download
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DownloadStatus {
+    /// Waiting for another download to finish
+    Queued,
+    /// Download is in progress
+    Downloading,
+    /// Download completed successfully
+    Finished,
+    /// Download failed, contains the error message
+    Failed(String),
+    /// Download was cancelled by the user
+    Cancelled,
+}
+
+impl DownloadStatus {
+    /// Whether the download has stopped and won't change again
+    pub fn is_done(&self) -> bool {
+        matches!(
+            self,
+            DownloadStatus::Finished | DownloadStatus::Failed(_) | DownloadStatus::Cancelled
+        )
+    }
+}
+
+/// Queue of downloads shared between the installer operations, cloning
+/// the manager shares the same queue
+#[derive(Clone)]
+pub struct DownloadManager {
+    inner: Arc<DownloadManagerInner>,
+}
+
+struct DownloadManagerInner {
+    /// Permits for the downloads that can run at once
+    permits: Semaphore,
+    /// Current downloads, changes are published to [DownloadManager::changes]
+    items: watch::Sender<Vec<DownloadItem>>,
+    /// Signals for cancelling each of the downloads that haven't finished
+    cancels: Mutex<HashMap<DownloadId, Arc<Notify>>>,
+    /// ID to use for the next download
+    next_id: AtomicU64,
+    /// Maximum download speed in KiB/s, zero for unlimited
+    speed_limit: AtomicU32,
+    /// Folder to stage downloads in, none for the system temp folder
+    download_dir: Mutex<Option<PathBuf>>,
+    /// Folder to keep downloaded assets in for reuse, none when disabled
+    cache_dir: Mutex<Option<PathBuf>>,
+}
+
+impl Default for DownloadManager {
+    fn default() -> Self {
+        Self {
+            inner: Arc::new(DownloadManagerInner {
+                permits: Semaphore::new(MAX_CONCURRENT_DOWNLOADS),
+                items: watch::Sender::new(Vec::new()),
+                cancels: Mutex::new(HashMap::new()),
+                next_id: AtomicU64::new(0),
+                speed_limit: AtomicU32::new(0),
+                download_dir: Mutex::new(None),
+                cache_dir: Mutex::new(None),
+            }),
+        }
+    }
+}
+
+/// Guard that stops tracking a download when its dropped, downloads that
+/// are dropped before they finish are marked as cancelled
+struct DownloadGuard {
+    manager: DownloadManager,
+    id: DownloadId,
+}
+
+impl Drop for DownloadGuard {
+    fn drop(&mut self) {
+        if let Ok(mut cancels) = self.manager.inner.cancels.lock() {
+            cancels.remove(&self.id);
+        }
+
+        self.manager.update(self.id, |item| {
+            if !item.status.is_done() {
+                item.status = DownloadStatus::Cancelled;
+            }
+        });
+    }
+}
+
+/// Reports the progress of a download to the download manager along with
+/// the progress callback of the operation that started the download
+pub struct DownloadReporter<P> {
+    manager: DownloadManager,
+    id: DownloadId,
+    on_progress: P,
+}
+
+impl<P: FnMut(DownloadProgress)> DownloadReporter<P> {
+    /// Reports the current `progress` of the download
+    pub fn report(&mut self, progress: DownloadProgress) {
+        self.manager
+            .update(self.id, |item| item.progress = Some(progress));
+        (self.on_progress)(progress);
+    }
+}
+
+impl DownloadManager {
+    /// Adds a download named `name` to the queue, once there is room for the
+    /// download the `download` function is called with a reporter for its
+    /// progress, progress is also passed along to `on_progress`.
+    ///
+    /// The download fails if it's cancelled before it finishes
+    pub async fn run<T, P, F, Fut>(
+        &self,
+        name: String,
+        on_progress: P,
+        download: F,
+    ) -> anyhow::Result<T>
+    where
+        P: FnMut(DownloadProgress),
+        F: FnOnce(DownloadReporter<P>) -> Fut,
+        Fut: Future<Output = anyhow::Result<T>>,
+    {
+        let id = self.inner.next_id.fetch_add(1, Ordering::Relaxed);
+        let cancel = Arc::new(Notify::new());
+
+        if let Ok(mut cancels) = self.inner.cancels.lock() {
+            cancels.insert(id, cancel.clone());
+        }
+        self.inner.items.send_modify(|items| {
+            items.push(DownloadItem {
+                id,
+                name: name.clone(),
+                progress: None,
+                status: DownloadStatus::Queued,
+            })
+        });
+
+        // Marks the download as cancelled if the operation is dropped part way
+        let _guard = DownloadGuard {
+            manager: self.clone(),
+            id,
+        };
+
+        let reporter = DownloadReporter {
+            manager: self.clone(),
+            id,
+            on_progress,
+        };
+
+        let result = tokio::select! {
+            result = async {
+                let _permit = self.inner.permits.acquire().await?;
+                self.update(id, |item| item.status = DownloadStatus::Downloading);
+                download(reporter).await
+            } => result.map_err(|err| (err, false)),
+            _ = cancel.notified() => Err((anyhow!("download was cancelled"), true)),
+        };
+
+        let status = match &result {
+            Ok(_) => DownloadStatus::Finished,
+            Err((_, true)) => DownloadStatus::Cancelled,
+            Err((err, false)) => DownloadStatus::Failed(format!("{err:#}")),
+        };
+
+        debug!("download {name} finished: {status:?}");
+
+        self.update(id, |item| item.status = status);
+
+        result.map_err(|(err, _)| err)
+    }
+
+    /// Sets the maximum download speed in KiB/s, none for unlimited. Only
+    /// applies to downloads started afterwards
+    pub fn set_speed_limit(&self, limit: Option<u32>) {
+        self.inner
+            .speed_limit
+            .store(limit.unwrap_or_default(), Ordering::Relaxed);
+    }
+
+    /// Maximum download speed in KiB/s, none for unlimited
+    pub fn speed_limit(&self) -> Option<u32> {
+        Some(self.inner.speed_limit.load(Ordering::Relaxed)).filter(|limit| *limit > 0)
+    }
+
+    /// Sets the folder to stage downloads in, none for the system temp folder
+    pub fn set_download_dir(&self, download_dir: Option<PathBuf>) {
+        if let Ok(mut value) = self.inner.download_dir.lock() {
+            *value = download_dir;
+        }
+    }
+
+    /// Folder to stage downloads in, none for the system temp folder
+    pub fn download_dir(&self) -> Option<PathBuf> {
+        self.inner
+            .download_dir
+            .lock()
+            .ok()
+            .and_then(|value| value.clone())
+    }
+
+    /// Sets the folder to keep downloaded assets in for reuse, none to
+    /// disable reusing downloads
+    pub fn set_cache_dir(&self, cache_dir: Option<PathBuf>) {
+        if let Ok(mut value) = self.inner.cache_dir.lock() {
+            *value = cache_dir;
+        }
+    }
+
+    /// Folder to keep downloaded assets in for reuse, none when disabled
+    pub fn cache_dir(&self) -> Option<PathBuf> {
+        self.inner
+            .cache_dir
+            .lock()
+            .ok()
+            .and_then(|value| value.clone())
+    }
+
+    /// Cancels the download with the matching `id` if it hasn't finished
+    pub fn cancel(&self, id: DownloadId) {
+        if let Some(cancel) = self
+            .inner
+            .cancels
+            .lock()
+            .ok()
+            .and_then(|cancels| cancels.get(&id).cloned())
+        {
+            cancel.notify_one();
+        }
+    }
+
+    /// Removes the downloads that have stopped from the list
+    pub fn clear_done(&self) {
+        self.inner
+            .items
+            .send_modify(|items| items.retain(|item| !item.status.is_done()));
+    }
+
+    /// Creates a stream of the current downloads, producing the list of
+    /// downloads whenever it changes
+    pub fn changes(&self) -> impl Stream<Item = Vec<DownloadItem>> {
+        let rx = self.inner.items.subscribe();
+
+        stream::unfold(rx, |mut rx| async move {
+            rx.changed().await.ok()?;
+
+            // Combine the changes from the progress of each chunk
+            tokio::time::sleep(UPDATE_INTERVAL).await;
+
+            let items = rx.borrow_and_update().clone();
+            Some((items, rx))
+        })
+    }
+
+    /// Updates the download with the matching `id` using the `update` function
+    fn update(&self, id: DownloadId, update: impl FnOnce(&mut DownloadItem)) {
+        self.inner.items.send_modify(|items| {
+            if let Some(item) = items.iter_mut().find(|item| item.id == id) {
+                update(item);
+            }
+        });
+    }
+}
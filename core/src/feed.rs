@@ -0,0 +1,102 @@
+//! Module for loading plugin releases from release feeds, feeds allow
+//! following builds published outside of GitHub releases (e.g nightly
+//! builds from a CI artifacts server) as their own release channel
+
+use crate::github::{decode_json, GitHubRelease};
+use anyhow::{bail, Context};
+use log::debug;
+use reqwest::header;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Time to wait for a release feed to respond
+const FEED_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Feed providing plugin releases for a release channel
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReleaseFeed {
+    /// Name of the release channel the feed provides (e.g "Nightly")
+    pub name: String,
+    /// URL of the feed, responds with a JSON list of releases in the same
+    /// format as the GitHub releases API
+    pub url: String,
+}
+
+/// Obtains the releases listed by the release `feed`
+pub async fn get_feed_releases(
+    http_client: &reqwest::Client,
+    feed: &ReleaseFeed,
+) -> anyhow::Result<Vec<GitHubRelease>> {
+    let bytes = http_client
+        .get(&feed.url)
+        .header(header::ACCEPT, "application/json")
+        .timeout(FEED_REQUEST_TIMEOUT)
+        .send()
+        .await
+        .with_context(|| format!("failed to request release feed {}", feed.name))?
+        .error_for_status()
+        .with_context(|| format!("release feed {} responded with an error", feed.name))?
+        .bytes()
+        .await
+        .with_context(|| format!("failed to read release feed {}", feed.name))?;
+
+    let releases: Vec<GitHubRelease> = decode_json(&bytes)
+        .with_context(|| format!("release feed {} responded with invalid releases", feed.name))?;
+
+    debug!(
+        "release feed {} listed {} releases",
+        feed.name,
+        releases.len()
+    );
+
+    Ok(releases)
+}
+
+/// Parses the release feeds from the comma separated list of "name=url"
+/// entries used by the settings screen
+pub fn parse_feeds(value: &str) -> anyhow::Result<Vec<ReleaseFeed>> {
+    let mut feeds: Vec<ReleaseFeed> = Vec::new();
+
+    for entry in value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+    {
+        let (name, url) = match entry.split_once('=') {
+            Some((name, url)) => (name.trim(), url.trim()),
+            None => bail!("release feed \"{entry}\" is missing its URL"),
+        };
+
+        if name.is_empty() {
+            bail!("release feed \"{entry}\" is missing its name");
+        }
+
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            bail!("release feed {name} must have an http:// or https:// URL");
+        }
+
+        if feeds
+            .iter()
+            .any(|feed| feed.name.eq_ignore_ascii_case(name))
+        {
+            bail!("release feed {name} is listed more than once");
+        }
+
+        feeds.push(ReleaseFeed {
+            name: name.to_string(),
+            url: url.to_string(),
+        });
+    }
+
+    Ok(feeds)
+}
+
+/// Formats the release `feeds` as the comma separated list of "name=url"
+/// entries used by the settings screen
+pub fn format_feeds(feeds: &[ReleaseFeed]) -> String {
+    feeds
+        .iter()
+        .map(|feed| format!("{}={}", feed.name, feed.url))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
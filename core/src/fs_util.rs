@@ -0,0 +1,156 @@
+//! Module for filesystem helpers shared by the code that modifies
+//! the game folder
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, PoisonError},
+};
+use tokio::io::AsyncWriteExt;
+
+/// Extension appended to the file name of temporary files
+const TEMP_EXTENSION: &str = ".tmp";
+
+/// Locks for the files currently being written by [atomic_write]
+static WRITE_LOCKS: Mutex<BTreeMap<PathBuf, Arc<tokio::sync::Mutex<()>>>> =
+    Mutex::new(BTreeMap::new());
+
+/// Writes the `contents` to the file at `path` atomically by writing to a
+/// temporary file in the same directory then renaming it over the file, so
+/// the file is never left partially written if the write is interrupted.
+///
+/// Writes to the same file share its temporary file so they take turns
+/// (e.g the config being saved again before the previous save finished)
+pub async fn atomic_write(
+    path: impl AsRef<Path>,
+    contents: impl AsRef<[u8]>,
+) -> std::io::Result<()> {
+    let path = path.as_ref();
+    let temp_path = temp_path(path);
+
+    let lock = write_lock(path);
+    let _guard = lock.lock().await;
+
+    let result = async {
+        let mut file = tokio::fs::File::create(&temp_path).await?;
+        file.write_all(contents.as_ref()).await?;
+        // Ensure the contents are on disk before replacing the file
+        file.sync_all().await?;
+        drop(file);
+
+        tokio::fs::rename(&temp_path, path).await
+    }
+    .await;
+
+    // Don't leave the temporary file behind on failure
+    if result.is_err() {
+        _ = tokio::fs::remove_file(&temp_path).await;
+    }
+
+    result
+}
+
+/// Provides the lock for writing the file at `path`
+fn write_lock(path: &Path) -> Arc<tokio::sync::Mutex<()>> {
+    let mut locks = WRITE_LOCKS.lock().unwrap_or_else(PoisonError::into_inner);
+
+    // Locks that no write is holding or waiting on are no longer needed
+    locks.retain(|_, lock| Arc::strong_count(lock) > 1);
+
+    locks.entry(path.to_path_buf()).or_default().clone()
+}
+
+/// Resolves the path of the file named `name` within `dir` ignoring the
+/// casing of the name, case sensitive file systems (e.g Proton on Linux)
+/// can have game files named "BINKW32.DLL". Uses the provided `name`
+/// when no existing file matches
+pub async fn resolve_file_name(dir: &Path, name: &str) -> PathBuf {
+    let path = dir.join(name);
+    if path.exists() {
+        return path;
+    }
+
+    if let Ok(mut entries) = tokio::fs::read_dir(dir).await {
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            if entry.file_name().eq_ignore_ascii_case(name) {
+                return entry.path();
+            }
+        }
+    }
+
+    path
+}
+
+/// Determines the path of the temporary file used when writing
+/// the file at `path`
+pub fn temp_path(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(TEMP_EXTENSION);
+    path.with_file_name(file_name)
+}
+
+/// Number of units byte sizes are scaled through (B, KB, MB and GB)
+pub const BYTE_UNIT_COUNT: usize = 4;
+
+/// Scales a number of bytes to the largest unit there is at least one of,
+/// provides the scaled value and the index of the unit from 0 for bytes
+/// through to 3 for gigabytes
+pub fn scale_bytes(bytes: u64) -> (f64, usize) {
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < BYTE_UNIT_COUNT - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    (value, unit)
+}
+
+/// Formats a number of bytes as a human readable size for log and error
+/// messages
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; BYTE_UNIT_COUNT] = ["B", "KB", "MB", "GB"];
+
+    match scale_bytes(bytes) {
+        (_, 0) => format!("{bytes} {}", UNITS[0]),
+        (value, unit) => format!("{value:.1} {}", UNITS[unit]),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{atomic_write, format_bytes, scale_bytes};
+    use crate::game_fs::test_util::TempDir;
+
+    /// Sizes are scaled to the largest unit with at least one of it
+    #[test]
+    fn test_scale_bytes() {
+        assert_eq!(scale_bytes(512), (512.0, 0));
+        assert_eq!(scale_bytes(1536), (1.5, 1));
+        assert_eq!(scale_bytes(5 * 1024 * 1024 * 1024 * 1024), (5120.0, 3));
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+    }
+
+    /// Overlapping writes to the same file all succeed and leave the
+    /// contents of one of the writes
+    #[tokio::test]
+    async fn test_atomic_write_overlapping() {
+        let dir = TempDir::new();
+        let path = dir.path().join("config.json");
+
+        let contents: Vec<Vec<u8>> = (0..16u8).map(|value| vec![value; 256 * 1024]).collect();
+        let writes = contents.iter().map(|contents| {
+            let path = path.clone();
+            let contents = contents.clone();
+            tokio::spawn(async move { atomic_write(path, contents).await })
+        });
+
+        for write in writes.collect::<Vec<_>>() {
+            write.await.unwrap().unwrap();
+        }
+
+        let written = std::fs::read(&path).unwrap();
+        assert!(contents.contains(&written));
+    }
+}
@@ -0,0 +1,139 @@
+//! Module for the filesystem access used when changing the game folder,
+//! the install steps go through [GameFs] so they can be tested against
+//! temporary folders and filesystems that fail on purpose
+
+use crate::fs_util::atomic_write;
+use std::{future::Future, io, path::Path};
+
+/// Filesystem operations used to read and change the game folder
+pub trait GameFs: Sync {
+    /// Reads the contents of the file at `path`
+    fn read(&self, path: &Path) -> impl Future<Output = io::Result<Vec<u8>>> + Send;
+
+    /// Writes the `contents` to the file at `path`, the file must never be
+    /// left partially written
+    fn write(&self, path: &Path, contents: &[u8]) -> impl Future<Output = io::Result<()>> + Send;
+
+    /// Removes the file at `path`
+    fn remove_file(&self, path: &Path) -> impl Future<Output = io::Result<()>> + Send;
+
+    /// Creates the folder at `path` along with any missing parents
+    fn create_dir_all(&self, path: &Path) -> impl Future<Output = io::Result<()>> + Send;
+
+    /// Whether a file exists at `path`
+    fn is_file(&self, path: &Path) -> bool;
+}
+
+/// Filesystem of the system, used outside of tests
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioFs;
+
+impl GameFs for TokioFs {
+    async fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        tokio::fs::read(path).await
+    }
+
+    async fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        atomic_write(path, contents).await
+    }
+
+    async fn remove_file(&self, path: &Path) -> io::Result<()> {
+        tokio::fs::remove_file(path).await
+    }
+
+    async fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        tokio::fs::create_dir_all(path).await
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        path.is_file()
+    }
+}
+
+/// Helpers for tests that change a game folder
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util {
+    use super::{GameFs, TokioFs};
+    use std::{
+        io,
+        path::{Path, PathBuf},
+        sync::atomic::{AtomicU32, Ordering},
+    };
+
+    /// Counter making the names of temporary folders unique within a run
+    static NEXT_TEMP_ID: AtomicU32 = AtomicU32::new(0);
+
+    /// Temporary folder that is removed when dropped
+    pub struct TempDir {
+        path: PathBuf,
+    }
+
+    #[allow(clippy::new_without_default)]
+    impl TempDir {
+        /// Creates a new empty temporary folder
+        pub fn new() -> Self {
+            let id = NEXT_TEMP_ID.fetch_add(1, Ordering::Relaxed);
+            let path = std::env::temp_dir().join(format!(
+                "pocket-relay-installer-test-{}-{id}",
+                std::process::id()
+            ));
+
+            _ = std::fs::remove_dir_all(&path);
+            std::fs::create_dir_all(&path).expect("failed to create temp dir");
+
+            Self { path }
+        }
+
+        /// Path to the folder
+        pub fn path(&self) -> &Path {
+            &self.path
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            _ = std::fs::remove_dir_all(&self.path);
+        }
+    }
+
+    /// Filesystem that fails to write any file with the `file_name`, other
+    /// operations go to the system filesystem
+    pub struct FailingFs {
+        /// Name of the files that can't be written
+        pub file_name: &'static str,
+    }
+
+    impl FailingFs {
+        /// Whether writes to `path` fail
+        fn fails(&self, path: &Path) -> bool {
+            path.file_name()
+                .is_some_and(|name| name.eq_ignore_ascii_case(self.file_name))
+        }
+    }
+
+    impl GameFs for FailingFs {
+        async fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+            TokioFs.read(path).await
+        }
+
+        async fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+            if self.fails(path) {
+                return Err(io::ErrorKind::PermissionDenied.into());
+            }
+
+            TokioFs.write(path, contents).await
+        }
+
+        async fn remove_file(&self, path: &Path) -> io::Result<()> {
+            TokioFs.remove_file(path).await
+        }
+
+        async fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+            TokioFs.create_dir_all(path).await
+        }
+
+        fn is_file(&self, path: &Path) -> bool {
+            TokioFs.is_file(path)
+        }
+    }
+}
@@ -0,0 +1,945 @@
+//! Code for assisting with the updating process
+
+use crate::{
+    mirror::{mirror_urls, RequestFailure, SourceErrors},
+    release_cache::{read_cached_releases, write_cached_releases, CachedReleases},
+    throttle::throttle,
+};
+use anyhow::{bail, Context};
+use bytes::{Bytes, BytesMut};
+use futures_util::StreamExt;
+use log::{debug, warn};
+use reqwest::{header, StatusCode};
+use semver::Version;
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+use std::{
+    fmt::Display,
+    future::Future,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use tokio::io::AsyncWriteExt;
+
+/// Structure for the required portions of github releases. Only the tag
+/// is required, the other fields fall back to empty values when they are
+/// missing or null so changes to the API don't prevent installing
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[allow(unused)]
+pub struct GitHubRelease {
+    /// The URL for viewing the release in the browser, empty if unknown
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub html_url: String,
+    /// The release tag / version
+    pub tag_name: String,
+    /// The name of the release (Usually the same as tag_name)
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub name: String,
+    /// The date & time the release was published, empty for releases
+    /// that haven't been published
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub published_at: String,
+    /// Whether the version is a pre-release
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub prerelease: bool,
+    /// The release notes (Markdown)
+    #[serde(default)]
+    pub body: Option<String>,
+    /// The release assets
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub assets: Vec<GitHubReleaseAsset>,
+}
+
+/// Represents an asset from github releases that can be downloaded
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GitHubReleaseAsset {
+    /// The name of the file
+    pub name: String,
+    /// URL for downloading the file
+    pub browser_download_url: String,
+    /// Size of the file in bytes
+    pub size: u64,
+    /// Digest of the file contents in the "algorithm:hex" format
+    /// (e.g "sha256:abc...") only present on newer releases
+    #[serde(default)]
+    pub digest: Option<String>,
+}
+
+/// Verifies the downloaded `bytes` match the size and digest GitHub
+/// provided for the `asset`
+pub fn verify_asset(asset: &GitHubReleaseAsset, bytes: &[u8]) -> anyhow::Result<()> {
+    let length = bytes.len() as u64;
+    if length != asset.size {
+        bail!(
+            "size mismatch (expected {} bytes, downloaded {} bytes)",
+            asset.size,
+            length
+        );
+    }
+
+    let digest = match &asset.digest {
+        Some(value) => value,
+        // Older releases don't provide a digest
+        None => return Ok(()),
+    };
+
+    let expected = match digest.split_once(':') {
+        Some(("sha256", value)) => value,
+        _ => {
+            debug!("skipping unsupported asset digest: {digest}");
+            return Ok(());
+        }
+    };
+
+    let actual = sha256::digest(bytes);
+    if !actual.eq_ignore_ascii_case(expected) {
+        bail!("digest mismatch (expected {expected}, downloaded {actual})");
+    }
+
+    debug!("verified asset digest: {actual}");
+
+    Ok(())
+}
+
+/// Deserializes a value that may be null as the default value
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Maximum number of characters of a response included in a [DecodeError]
+const DECODE_SNIPPET_LENGTH: usize = 200;
+
+/// Error from decoding a JSON response, includes the start of the
+/// response so unexpected responses (e.g an error page from a proxy or a
+/// change to the API) can be identified from the error
+#[derive(Debug)]
+pub struct DecodeError {
+    /// Error from decoding the response
+    error: serde_json::Error,
+    /// Start of the response with the whitespace collapsed
+    snippet: String,
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.snippet.is_empty() {
+            return write!(f, "invalid response ({}), response was empty", self.error);
+        }
+
+        write!(
+            f,
+            "invalid response ({}), response started with: {}",
+            self.error, self.snippet
+        )
+    }
+}
+
+impl std::error::Error for DecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Decodes the JSON response `bytes`, the error includes the start of the
+/// response when they can't be decoded
+pub fn decode_json<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, DecodeError> {
+    serde_json::from_slice(bytes).map_err(|error| DecodeError {
+        error,
+        snippet: response_snippet(bytes),
+    })
+}
+
+/// Creates a single line snippet from the start of the response `bytes`
+fn response_snippet(bytes: &[u8]) -> String {
+    // Characters are at most 4 bytes so this covers the snippet length
+    let start = &bytes[..bytes.len().min(DECODE_SNIPPET_LENGTH * 4)];
+    let mut snippet = String::from_utf8_lossy(start)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    match snippet.char_indices().nth(DECODE_SNIPPET_LENGTH) {
+        Some((index, _)) => {
+            snippet.truncate(index);
+            snippet.push_str("...");
+        }
+        None if start.len() < bytes.len() => snippet.push_str("..."),
+        None => {}
+    }
+
+    snippet
+}
+
+/// Progress of an asset download
+#[derive(Debug, Clone, Copy)]
+pub struct DownloadProgress {
+    /// Number of bytes downloaded so far
+    pub downloaded: u64,
+    /// Total number of bytes if the server provided a length
+    pub total: Option<u64>,
+}
+
+impl DownloadProgress {
+    /// Fraction of the download that has completed between 0 and 1, none
+    /// when the total length is unknown
+    pub fn fraction(&self) -> Option<f32> {
+        let total = self.total.filter(|total| *total > 0)?;
+        Some((self.downloaded as f64 / total as f64).min(1.0) as f32)
+    }
+}
+
+/// Parses a version from a release tag (e.g "v0.1.0")
+pub fn parse_tag_version(tag: &str) -> Option<Version> {
+    Version::parse(tag.trim_start_matches('v')).ok()
+}
+
+/// Options for requests to the GitHub API
+#[derive(Debug, Default, Clone)]
+pub struct ApiOptions {
+    /// Number of times to retry transient failures
+    pub max_retries: u32,
+    /// Personal access token to authenticate with, raises the rate limit
+    pub token: Option<String>,
+    /// Folder to cache responses in, none to not cache responses
+    pub cache_dir: Option<PathBuf>,
+    /// Maximum number of pages of releases to request
+    pub max_release_pages: usize,
+}
+
+/// Delay before the first retry, doubled after each attempt
+const INITIAL_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// Errors that can occur when making a GitHub API request
+#[derive(Debug)]
+enum RequestError {
+    /// The GitHub API rate limit was exceeded, contains the unix
+    /// timestamp of when the limit resets if known
+    RateLimited(Option<u64>),
+    /// The server responded with an error status
+    Status(StatusCode),
+    /// The request failed
+    Request(reqwest::Error),
+    /// The response couldn't be decoded
+    Decode(DecodeError),
+}
+
+impl RequestError {
+    /// Whether the error is likely to be temporary and the request
+    /// is worth retrying
+    fn is_transient(&self) -> bool {
+        match self {
+            RequestError::RateLimited(_) => false,
+            RequestError::Status(status) => status.is_server_error(),
+            RequestError::Request(err) => err.is_timeout() || err.is_connect(),
+            RequestError::Decode(_) => false,
+        }
+    }
+
+    /// Common cause of the error, if known
+    fn failure(&self) -> Option<RequestFailure> {
+        match self {
+            RequestError::RateLimited(_) => Some(RequestFailure::RateLimited),
+            // GitHub responds with forbidden for some rate limits without
+            // including the remaining rate limit header
+            RequestError::Status(StatusCode::FORBIDDEN) => Some(RequestFailure::RateLimited),
+            RequestError::Status(_) => None,
+            RequestError::Request(err) => RequestFailure::from_reqwest(err),
+            RequestError::Decode(_) => None,
+        }
+    }
+}
+
+impl Display for RequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RequestError::RateLimited(Some(reset)) => {
+                let seconds = reset % 86400;
+                write!(
+                    f,
+                    "GitHub rate limit exceeded, resets at {:02}:{:02} UTC",
+                    seconds / 3600,
+                    (seconds % 3600) / 60
+                )
+            }
+            RequestError::RateLimited(None) => f.write_str("GitHub rate limit exceeded"),
+            RequestError::Status(status) => write!(f, "server responded with {status}"),
+            RequestError::Request(err) => err.fmt(f),
+            RequestError::Decode(err) => err.fmt(f),
+        }
+    }
+}
+
+impl From<reqwest::Error> for RequestError {
+    fn from(value: reqwest::Error) -> Self {
+        RequestError::Request(value)
+    }
+}
+
+/// Response to a JSON request
+enum JsonResponse<T> {
+    /// Response value along with its ETag and the URL of the next page from
+    /// the Link header if the server provided them
+    Modified {
+        value: T,
+        etag: Option<String>,
+        next: Option<String>,
+    },
+    /// Value hasn't changed since the ETag provided with the request
+    NotModified,
+}
+
+/// Time to wait for a GitHub API request to complete
+const API_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Requests JSON from the provided `url`, detecting GitHub rate limiting.
+/// The `token` is attached as the authorization when provided, when an
+/// `etag` is provided the request is only answered if the value changed
+async fn request_json<T: DeserializeOwned>(
+    http_client: &reqwest::Client,
+    url: &str,
+    token: Option<&str>,
+    etag: Option<&str>,
+) -> Result<JsonResponse<T>, RequestError> {
+    let mut request = http_client
+        .get(url)
+        .header(header::ACCEPT, "application/json")
+        .timeout(API_REQUEST_TIMEOUT);
+
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+
+    if let Some(etag) = etag {
+        request = request.header(header::IF_NONE_MATCH, etag);
+    }
+
+    let response = request.send().await?;
+
+    let status = response.status();
+    if status == StatusCode::NOT_MODIFIED && etag.is_some() {
+        return Ok(JsonResponse::NotModified);
+    }
+
+    if status.is_success() {
+        let etag = response
+            .headers()
+            .get(header::ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let next = response
+            .headers()
+            .get(header::LINK)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_next_link);
+        let bytes = response.bytes().await?;
+        let value = decode_json(&bytes).map_err(RequestError::Decode)?;
+        return Ok(JsonResponse::Modified { value, etag, next });
+    }
+
+    let headers = response.headers();
+    let rate_limited = (status == StatusCode::FORBIDDEN || status == StatusCode::TOO_MANY_REQUESTS)
+        && headers
+            .get("x-ratelimit-remaining")
+            .is_some_and(|value| value.as_bytes() == b"0");
+
+    if rate_limited {
+        let reset = headers
+            .get("x-ratelimit-reset")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok());
+        return Err(RequestError::RateLimited(reset));
+    }
+
+    Err(RequestError::Status(status))
+}
+
+/// Parses the URL of the next page from a Link header `value`
+/// (e.g `<https://api.github.com/...&page=2>; rel="next", <...>; rel="last"`)
+fn parse_next_link(value: &str) -> Option<String> {
+    value.split(',').find_map(|link| {
+        let mut parts = link.split(';');
+        let url = parts.next()?.trim().strip_prefix('<')?.strip_suffix('>')?;
+
+        parts
+            .any(|param| param.trim() == "rel=\"next\"")
+            .then(|| url.to_string())
+    })
+}
+
+/// Requests JSON from the provided `url`, retrying transient failures up
+/// to `max_retries` times with an exponential backoff
+async fn request_json_retry<T: DeserializeOwned>(
+    http_client: &reqwest::Client,
+    url: &str,
+    token: Option<&str>,
+    etag: Option<&str>,
+    max_retries: u32,
+) -> Result<JsonResponse<T>, RequestError> {
+    let mut delay = INITIAL_RETRY_DELAY;
+    let mut attempt = 0;
+
+    loop {
+        match request_json(http_client, url, token, etag).await {
+            Err(err) if attempt < max_retries && err.is_transient() => {
+                attempt += 1;
+                warn!("request to {url} failed, retrying ({attempt}/{max_retries}): {err}");
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Requests JSON from the provided `url`, falling back to each of the
+/// `mirrors` if the request fails.
+///
+/// The GitHub `token` is only sent to GitHub itself and never to the mirrors
+async fn get_json<T: DeserializeOwned>(
+    http_client: &reqwest::Client,
+    url: &str,
+    mirrors: &[String],
+    options: &ApiOptions,
+) -> Result<T, SourceErrors> {
+    match get_json_conditional(http_client, url, mirrors, options, None).await? {
+        JsonResponse::Modified { value, .. } => Ok(value),
+        // Only sent in response to requests with an ETag
+        JsonResponse::NotModified => Err(SourceErrors::default()),
+    }
+}
+
+/// Requests JSON from the provided `url` like [get_json], when an `etag`
+/// is provided the value is only returned if it changed
+async fn get_json_conditional<T: DeserializeOwned>(
+    http_client: &reqwest::Client,
+    url: &str,
+    mirrors: &[String],
+    options: &ApiOptions,
+    etag: Option<&str>,
+) -> Result<JsonResponse<T>, SourceErrors> {
+    let mut errors = SourceErrors::default();
+    let original_url = url;
+
+    for url in mirror_urls(url, mirrors) {
+        debug!("{url}");
+
+        let token = options.token.as_deref().filter(|_| url == original_url);
+
+        match request_json_retry(http_client, &url, token, etag, options.max_retries).await {
+            Ok(value) => {
+                errors.succeeded(&url);
+                return Ok(value);
+            }
+            Err(err) => {
+                let failure = err.failure();
+                errors.push(&url, err, failure);
+            }
+        }
+    }
+
+    Err(errors)
+}
+
+/// Attempts to obtain the latest release from github
+///
+/// ## Arguments
+/// * `http_client` - The HTTP client to make the request with
+/// * `repository`  - The repository to get the latest release for (e.g "PocketRelay/Client")
+/// * `mirrors`     - Mirrors to fallback to when GitHub is unreachable
+/// * `options`     - Options for the GitHub API requests
+pub async fn get_latest_release(
+    http_client: &reqwest::Client,
+    repository: &str,
+    mirrors: &[String],
+    options: &ApiOptions,
+) -> Result<GitHubRelease, SourceErrors> {
+    let url = format!(
+        "https://api.github.com/repos/{}/releases/latest",
+        repository
+    );
+
+    get_json(http_client, &url, mirrors, options).await
+}
+
+/// Number of releases to request per page
+const RELEASES_PER_PAGE: usize = 100;
+
+/// Prefix of the GitHub API URLs, next page links are only followed
+/// within the API
+const GITHUB_API_URL: &str = "https://api.github.com/";
+
+/// Releases of a repository
+#[derive(Debug, Clone, Default)]
+pub struct ReleaseList {
+    /// Releases from the requested pages
+    pub releases: Vec<GitHubRelease>,
+    /// Whether there are older releases beyond the page limit
+    pub has_more: bool,
+}
+
+/// Creates the URL for a `page` of releases from the `repository`
+fn releases_url(repository: &str, page: usize) -> String {
+    format!(
+        "https://api.github.com/repos/{}/releases?per_page={}&page={}",
+        repository, RELEASES_PER_PAGE, page
+    )
+}
+
+/// Attempts to obtain all the releases from github by following the
+/// next page links, up to the page limit from the `options`.
+///
+/// The releases are cached on disk, the cached releases are used when the
+/// first page hasn't changed since it was cached or when GitHub can't be
+/// reached or is rate limiting requests
+///
+/// ## Arguments
+/// * `http_client` - The HTTP client to make the request with
+/// * `repository`  - The repository to get the releases for (e.g "PocketRelay/Client")
+/// * `mirrors`     - Mirrors to fallback to when GitHub is unreachable
+/// * `options`     - Options for the GitHub API requests
+pub async fn get_all_releases(
+    http_client: &reqwest::Client,
+    repository: &str,
+    mirrors: &[String],
+    options: &ApiOptions,
+) -> Result<ReleaseList, SourceErrors> {
+    let cache_dir = options.cache_dir.as_deref();
+    let max_pages = options.max_release_pages.max(1);
+    let cached = match cache_dir {
+        Some(cache_dir) => read_cached_releases(cache_dir, repository).await,
+        None => None,
+    };
+
+    // Cache is missing pages when the page limit was raised
+    let etag = cached
+        .as_ref()
+        .filter(|cached| !cached.has_more || cached.pages >= max_pages)
+        .and_then(|cached| cached.etag.as_deref());
+
+    let result = fetch_all_releases(http_client, repository, mirrors, options, etag).await;
+
+    match (result, cached) {
+        (Ok(FetchedReleases::Modified { cached }), _) => {
+            if let Some(cache_dir) = cache_dir {
+                write_cached_releases(cache_dir, repository, &cached).await;
+            }
+            Ok(cached.into())
+        }
+        (Ok(FetchedReleases::NotModified), Some(cached)) => {
+            debug!("releases for {repository} not modified, using cache");
+            Ok(cached.into())
+        }
+        // Only sent in response to requests with the cached ETag
+        (Ok(FetchedReleases::NotModified), None) => Err(SourceErrors::default()),
+        // Offline or rate limited, the cached releases are better than nothing
+        (Err(err), Some(cached)) if err.failure().is_some() => {
+            warn!("using cached releases for {repository}: {err}");
+            Ok(cached.into())
+        }
+        (Err(err), _) => Err(err),
+    }
+}
+
+impl From<CachedReleases> for ReleaseList {
+    fn from(value: CachedReleases) -> Self {
+        ReleaseList {
+            releases: value.releases,
+            has_more: value.has_more,
+        }
+    }
+}
+
+/// Result of requesting all the releases
+enum FetchedReleases {
+    /// Releases to cache along with the ETag of the first page
+    Modified { cached: CachedReleases },
+    /// First page hasn't changed since the provided ETag
+    NotModified,
+}
+
+/// Requests each page of releases by following the next page links, the
+/// first page is requested with the `etag` so the remaining pages are
+/// skipped when it hasn't changed
+async fn fetch_all_releases(
+    http_client: &reqwest::Client,
+    repository: &str,
+    mirrors: &[String],
+    options: &ApiOptions,
+    etag: Option<&str>,
+) -> Result<FetchedReleases, SourceErrors> {
+    let max_pages = options.max_release_pages.max(1);
+    let mut url = releases_url(repository, 1);
+    let mut etag = etag;
+    let mut cached = CachedReleases {
+        etag: None,
+        releases: Vec::new(),
+        pages: 0,
+        has_more: false,
+    };
+
+    loop {
+        let (releases, next): (Vec<GitHubRelease>, _) =
+            match get_json_conditional(http_client, &url, mirrors, options, etag).await? {
+                JsonResponse::Modified { value, etag, next } => {
+                    if cached.pages == 0 {
+                        cached.etag = etag;
+                    }
+                    (value, next)
+                }
+                JsonResponse::NotModified => return Ok(FetchedReleases::NotModified),
+            };
+
+        // Only the first page is requested with the ETag
+        etag = None;
+        cached.pages += 1;
+
+        // Mirrors may not forward the Link header, full pages are assumed
+        // to be followed by another page
+        let next = match next {
+            Some(next) if next.starts_with(GITHUB_API_URL) => Some(next),
+            _ if releases.len() >= RELEASES_PER_PAGE => {
+                Some(releases_url(repository, cached.pages + 1))
+            }
+            _ => None,
+        };
+
+        cached.releases.extend(releases);
+
+        match next {
+            Some(_) if cached.pages >= max_pages => {
+                debug!("stopped listing releases for {repository} at {max_pages} pages");
+                cached.has_more = true;
+                break;
+            }
+            Some(next) => url = next,
+            None => break,
+        }
+    }
+
+    Ok(FetchedReleases::Modified { cached })
+}
+
+/// Downloads the provided github release asset returning the
+/// downloaded bytes
+///
+/// ## Arguments
+/// * `http_client` - The HTTP client to make the request with
+/// * `asset`       - The asset to download
+/// * `mirrors`     - Mirrors to fallback to when GitHub is unreachable
+/// * `limit`       - Maximum download speed in KiB/s, none for unlimited
+/// * `on_progress` - Callback invoked with the progress after each received chunk
+pub async fn download_latest_release(
+    http_client: &reqwest::Client,
+    asset: &GitHubReleaseAsset,
+    mirrors: &[String],
+    limit: Option<u32>,
+    mut on_progress: impl FnMut(DownloadProgress),
+) -> Result<Bytes, SourceErrors> {
+    let mut errors = SourceErrors::default();
+
+    for url in mirror_urls(&asset.browser_download_url, mirrors) {
+        debug!("{url}");
+
+        match download_url(http_client, &url, limit, &mut on_progress).await {
+            Ok(value) => {
+                errors.succeeded(&url);
+                return Ok(value);
+            }
+            Err(err) => {
+                let failure = RequestFailure::from_reqwest(&err);
+                errors.push(&url, err, failure);
+            }
+        }
+    }
+
+    Err(errors)
+}
+
+/// Downloads the contents of the provided `url` reporting the
+/// progress to `on_progress`
+async fn download_url(
+    http_client: &reqwest::Client,
+    url: &str,
+    limit: Option<u32>,
+    on_progress: &mut impl FnMut(DownloadProgress),
+) -> Result<Bytes, reqwest::Error> {
+    let response = http_client.get(url).send().await?.error_for_status()?;
+
+    let total = response.content_length();
+    let mut buffer = BytesMut::with_capacity(total.unwrap_or_default() as usize);
+
+    on_progress(DownloadProgress {
+        downloaded: 0,
+        total,
+    });
+
+    let mut chunks = throttle(response.bytes_stream(), limit);
+
+    while let Some(chunk) = chunks.next().await {
+        let chunk = chunk?;
+        buffer.extend_from_slice(&chunk);
+
+        on_progress(DownloadProgress {
+            downloaded: buffer.len() as u64,
+            total,
+        });
+    }
+
+    Ok(buffer.freeze())
+}
+
+/// Number of times to attempt resuming an interrupted download from
+/// each source before moving on to the next source
+const DOWNLOAD_ATTEMPTS: usize = 3;
+
+/// Downloads the provided github release asset to the file at `path`.
+///
+/// Existing partial contents of the file are resumed using range requests
+/// when the server supports them, interrupted downloads are resumed up to
+/// [DOWNLOAD_ATTEMPTS] times per source.
+///
+/// ## Arguments
+/// * `http_client` - The HTTP client to make the request with
+/// * `asset`       - The asset to download
+/// * `mirrors`     - Mirrors to fallback to when GitHub is unreachable
+/// * `path`        - Path to download the asset to
+/// * `limit`       - Maximum download speed in KiB/s, none for unlimited
+/// * `on_progress` - Callback invoked with the progress after each received chunk
+pub async fn download_release_asset(
+    http_client: &reqwest::Client,
+    asset: &GitHubReleaseAsset,
+    mirrors: &[String],
+    path: &Path,
+    limit: Option<u32>,
+    mut on_progress: impl FnMut(DownloadProgress),
+) -> Result<(), SourceErrors> {
+    let mut errors = SourceErrors::default();
+
+    for url in mirror_urls(&asset.browser_download_url, mirrors) {
+        debug!("{url}");
+
+        for attempt in 1..=DOWNLOAD_ATTEMPTS {
+            match download_url_to_file(http_client, &url, asset.size, path, limit, &mut on_progress)
+                .await
+            {
+                Ok(()) => {
+                    errors.succeeded(&url);
+                    return Ok(());
+                }
+                Err(err) => {
+                    warn!(
+                        "download attempt {attempt}/{DOWNLOAD_ATTEMPTS} from {url} failed: {err:#}"
+                    );
+
+                    if attempt == DOWNLOAD_ATTEMPTS {
+                        let failure = RequestFailure::from_anyhow(&err);
+                        errors.push(&url, format!("{err:#}"), failure);
+                    }
+                }
+            }
+        }
+    }
+
+    Err(errors)
+}
+
+/// Downloads the contents of the provided `url` to the file at `path`,
+/// resuming from the existing contents of the file
+async fn download_url_to_file(
+    http_client: &reqwest::Client,
+    url: &str,
+    size: u64,
+    path: &Path,
+    limit: Option<u32>,
+    on_progress: &mut impl FnMut(DownloadProgress),
+) -> anyhow::Result<()> {
+    let mut downloaded = match tokio::fs::metadata(path).await {
+        Ok(metadata) => metadata.len(),
+        Err(_) => 0,
+    };
+
+    // Partial file is already complete
+    if downloaded == size {
+        debug!("download already complete: {}", path.display());
+        on_progress(DownloadProgress {
+            downloaded,
+            total: Some(size),
+        });
+        return Ok(());
+    }
+
+    // Partial file is invalid, start over
+    if downloaded > size {
+        downloaded = 0;
+    }
+
+    let mut request = http_client.get(url);
+    if downloaded > 0 {
+        debug!("resuming download from {downloaded} bytes");
+        request = request.header(header::RANGE, format!("bytes={downloaded}-"));
+    }
+
+    let response = request.send().await?.error_for_status()?;
+
+    // Server ignored the range request and is sending the whole file
+    if response.status() != StatusCode::PARTIAL_CONTENT {
+        downloaded = 0;
+    }
+
+    let total = response
+        .content_length()
+        .map(|length| length + downloaded)
+        .or(Some(size));
+
+    let mut file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(downloaded > 0)
+        .truncate(downloaded == 0)
+        .open(path)
+        .await
+        .context("failed to open download file")?;
+
+    on_progress(DownloadProgress { downloaded, total });
+
+    let mut chunks = throttle(response.bytes_stream(), limit);
+
+    while let Some(chunk) = chunks.next().await {
+        let chunk = chunk?;
+        file.write_all(&chunk)
+            .await
+            .context("failed to write download file")?;
+        downloaded += chunk.len() as u64;
+
+        on_progress(DownloadProgress { downloaded, total });
+    }
+
+    file.flush()
+        .await
+        .context("failed to write download file")?;
+
+    Ok(())
+}
+
+/// Source of the release assets installed into the game, allows installs
+/// to be tested without contacting GitHub
+pub trait ReleaseProvider: Sync {
+    /// Downloads the `asset` to the file at `path` resuming any existing
+    /// contents, `limit` is the maximum speed in KiB/s and the progress
+    /// is reported to `on_progress`
+    fn download_asset(
+        &self,
+        asset: &GitHubReleaseAsset,
+        path: &Path,
+        limit: Option<u32>,
+        on_progress: impl FnMut(DownloadProgress),
+    ) -> impl Future<Output = Result<(), SourceErrors>>;
+
+    /// Downloads the contents of a small `asset` (e.g a checksums file)
+    fn fetch_asset(
+        &self,
+        asset: &GitHubReleaseAsset,
+    ) -> impl Future<Output = Result<Bytes, SourceErrors>> + Send;
+}
+
+/// Release assets downloaded from GitHub, falling back to the `mirrors`
+/// when GitHub is unreachable
+pub struct GitHubReleases {
+    /// The HTTP client to make the requests with
+    pub http_client: reqwest::Client,
+    /// Mirrors to fallback to when GitHub is unreachable
+    pub mirrors: Vec<String>,
+}
+
+impl ReleaseProvider for GitHubReleases {
+    async fn download_asset(
+        &self,
+        asset: &GitHubReleaseAsset,
+        path: &Path,
+        limit: Option<u32>,
+        on_progress: impl FnMut(DownloadProgress),
+    ) -> Result<(), SourceErrors> {
+        download_release_asset(
+            &self.http_client,
+            asset,
+            &self.mirrors,
+            path,
+            limit,
+            on_progress,
+        )
+        .await
+    }
+
+    async fn fetch_asset(&self, asset: &GitHubReleaseAsset) -> Result<Bytes, SourceErrors> {
+        download_latest_release(&self.http_client, asset, &self.mirrors, None, |_| {}).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{decode_json, parse_next_link, GitHubRelease};
+
+    /// Next page URL is read from the Link header
+    #[test]
+    fn test_parse_next_link() {
+        let value = "<https://api.github.com/repositories/1/releases?per_page=100&page=2>; \
+            rel=\"next\", <https://api.github.com/repositories/1/releases?per_page=100&page=4>; \
+            rel=\"last\"";
+
+        assert_eq!(
+            parse_next_link(value).as_deref(),
+            Some("https://api.github.com/repositories/1/releases?per_page=100&page=2")
+        );
+
+        let last_page = "<https://api.github.com/repositories/1/releases?page=1>; rel=\"first\", \
+            <https://api.github.com/repositories/1/releases?page=3>; rel=\"prev\"";
+        assert_eq!(parse_next_link(last_page), None);
+    }
+
+    /// Missing and null fields are treated as empty, unknown fields are ignored
+    #[test]
+    fn test_decode_release_tolerant() {
+        let release: GitHubRelease = decode_json(
+            br#"{
+                "tag_name": "v1.0.0",
+                "name": null,
+                "published_at": null,
+                "assets": [{
+                    "name": "pocket-relay-plugin.asi",
+                    "browser_download_url": "https://example.invalid/plugin.asi",
+                    "size": 10,
+                    "new_field": {}
+                }],
+                "new_field": [1, 2, 3]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(release.tag_name, "v1.0.0");
+        assert_eq!(release.name, "");
+        assert_eq!(release.published_at, "");
+        assert!(!release.prerelease);
+        assert_eq!(release.assets.len(), 1);
+    }
+
+    /// Errors include the start of the response on a single line
+    #[test]
+    fn test_decode_error_snippet() {
+        let err = decode_json::<GitHubRelease>(b"<html>\n  <body>Blocked</body>\n</html>")
+            .unwrap_err()
+            .to_string();
+        assert!(err.ends_with("response started with: <html> <body>Blocked</body> </html>"));
+
+        let long = "x".repeat(1000);
+        let err = decode_json::<GitHubRelease>(long.as_bytes())
+            .unwrap_err()
+            .to_string();
+        assert!(err.ends_with(&format!("{}...", "x".repeat(200))));
+
+        let err = decode_json::<GitHubRelease>(b"").unwrap_err().to_string();
+        assert!(err.ends_with("response was empty"));
+    }
+}
@@ -0,0 +1,107 @@
+//! Module for the known binkw32.dll hashes used to identify the state of the
+//! patch. The hashes are listed in a manifest embedded in the installer, new
+//! game patches and regional builds are recognized by adding their hashes to
+//! resources/known-hashes.json
+
+use crate::profile::Game;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+/// Copy of the manifest embedded in the installer
+const EMBEDDED_HASHES: &str = include_str!("./resources/known-hashes.json");
+
+/// Manifest listing the known binkw32.dll hashes of each game
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HashManifest {
+    /// Version of the manifest, increased whenever hashes are added
+    pub version: u32,
+    /// Known hashes of each game
+    pub games: Vec<GameHashes>,
+}
+
+/// Known binkw32.dll hashes of a game
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameHashes {
+    /// Game the hashes belong to
+    pub game: Game,
+    /// Hashes of the official binkw32.dll files
+    #[serde(default)]
+    pub official: Vec<KnownHash>,
+    /// Hashes of the known patched binkw32.dll files
+    #[serde(default)]
+    pub patched: Vec<KnownHash>,
+}
+
+/// Known binkw32.dll hash
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KnownHash {
+    /// SHA256 hash of the file
+    pub sha256: String,
+    /// Where the file comes from (e.g "Official binkw32.dll")
+    #[serde(default)]
+    pub description: String,
+}
+
+/// Kind of binkw32.dll a known hash belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashKind {
+    /// Official unpatched binkw32.dll
+    Official,
+    /// Patched binkw32.dll
+    Patched,
+}
+
+impl HashManifest {
+    /// Finds the kind of binkw32.dll the `hash` belongs to for the `game`
+    pub fn classify(&self, game: Game, hash: &str) -> Option<HashKind> {
+        let contains = |hashes: &[KnownHash]| {
+            hashes
+                .iter()
+                .any(|known| known.sha256.eq_ignore_ascii_case(hash))
+        };
+
+        self.games
+            .iter()
+            .filter(|hashes| hashes.game == game)
+            .find_map(|hashes| {
+                if contains(&hashes.official) {
+                    Some(HashKind::Official)
+                } else if contains(&hashes.patched) {
+                    Some(HashKind::Patched)
+                } else {
+                    None
+                }
+            })
+    }
+}
+
+/// Manifest embedded in the installer
+pub fn embedded_manifest() -> &'static HashManifest {
+    static EMBEDDED: OnceLock<HashManifest> = OnceLock::new();
+    EMBEDDED.get_or_init(|| {
+        serde_json::from_str(EMBEDDED_HASHES).expect("embedded known hashes manifest is invalid")
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{embedded_manifest, HashKind};
+    use crate::profile::Game;
+
+    /// Embedded manifest knows the hashes of the embedded DLLs
+    #[test]
+    fn test_embedded_manifest() {
+        let manifest = embedded_manifest();
+        let patch = Game::MassEffect3.profile().patch;
+
+        assert_eq!(
+            manifest.classify(Game::MassEffect3, patch.unpatched_hash),
+            Some(HashKind::Official)
+        );
+        assert_eq!(
+            manifest.classify(Game::MassEffect3, &patch.patched_hash.to_uppercase()),
+            Some(HashKind::Patched)
+        );
+        assert_eq!(manifest.classify(Game::MassEffect3, "unknown"), None);
+    }
+}
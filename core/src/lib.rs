@@ -0,0 +1,40 @@
+//! Install and patch logic for the Pocket Relay client plugin, shared by
+//! the installer and other tools (e.g mod managers) that install the plugin
+//! or patch the game. The game being worked with and the folders to cache
+//! data in are passed to the functions using them, so tools embedding the
+//! library don't share any state with the installer.
+//!
+//! - [bink] applies and removes the binkw32.dll patch that allows loading ASI plugins
+//! - [plugin] finds plugin releases and installs or removes the plugin
+//! - [github] obtains releases and downloads assets from GitHub releases
+//! - [progress] reports the current step of installing or patching
+//! - [self_test] checks the whole install works against a synthetic game folder
+#![warn(unused_crate_dependencies)]
+
+// The crate depends on itself to enable the test helpers for the
+// integration tests, the unit tests don't use it
+#[cfg(test)]
+use pocket_relay_installer_core as _;
+
+pub mod asi;
+pub mod asset_cache;
+pub mod bink;
+pub mod bytes;
+pub mod delta;
+pub mod downloads;
+pub mod feed;
+pub mod fs_util;
+pub mod game_fs;
+pub mod github;
+pub mod hashes;
+pub mod mirror;
+pub mod pe;
+pub mod plan;
+pub mod plugin;
+pub mod preflight;
+pub mod process;
+pub mod profile;
+pub mod progress;
+pub mod release_cache;
+pub mod self_test;
+pub mod throttle;
@@ -0,0 +1,108 @@
+//! Module for falling back to mirror download sources when GitHub
+//! is unreachable
+
+use log::{debug, warn};
+use std::fmt::Display;
+
+/// Placeholder within a mirror that is replaced with the original URL
+const URL_PLACEHOLDER: &str = "{url}";
+
+/// Common causes of a failed request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestFailure {
+    /// The server could not be reached or didn't respond in time
+    Unreachable,
+    /// The GitHub API rate limit was exceeded
+    RateLimited,
+}
+
+impl RequestFailure {
+    /// Determines the failure from a `reqwest` error
+    pub fn from_reqwest(err: &reqwest::Error) -> Option<RequestFailure> {
+        if err.is_connect() || err.is_timeout() {
+            Some(RequestFailure::Unreachable)
+        } else {
+            None
+        }
+    }
+
+    /// Determines the failure from any of the causes within `err`
+    pub fn from_anyhow(err: &anyhow::Error) -> Option<RequestFailure> {
+        err.chain()
+            .filter_map(|cause| cause.downcast_ref::<reqwest::Error>())
+            .find_map(RequestFailure::from_reqwest)
+    }
+}
+
+/// Creates the list of URLs to attempt for the provided `url`, the
+/// original URL followed by the URL for each of the `mirrors`.
+///
+/// Mirrors containing a "{url}" placeholder have the placeholder replaced
+/// with the original URL, otherwise the mirror is used as a prefix
+/// (e.g "https://mirror.example.com/" for "https://mirror.example.com/https://api.github.com/...")
+pub fn mirror_urls(url: &str, mirrors: &[String]) -> Vec<String> {
+    let mut urls = Vec::with_capacity(mirrors.len() + 1);
+    urls.push(url.to_string());
+
+    for mirror in mirrors {
+        let mirror = mirror.trim();
+        if mirror.is_empty() {
+            continue;
+        }
+
+        if mirror.contains(URL_PLACEHOLDER) {
+            urls.push(mirror.replace(URL_PLACEHOLDER, url));
+        } else {
+            urls.push(format!("{mirror}{url}"));
+        }
+    }
+
+    urls
+}
+
+/// Collection of errors from each of the attempted sources
+#[derive(Debug, Default)]
+pub struct SourceErrors {
+    errors: Vec<(String, String, Option<RequestFailure>)>,
+}
+
+impl SourceErrors {
+    /// Records that the source at `url` failed with `err`, the `failure`
+    /// is the common cause of the error when known
+    pub fn push(&mut self, url: &str, err: impl Display, failure: Option<RequestFailure>) {
+        warn!("request to {url} failed: {err}");
+        self.errors
+            .push((url.to_string(), err.to_string(), failure));
+    }
+
+    /// Common cause of the failure, only known when every source
+    /// failed for the same reason
+    pub fn failure(&self) -> Option<RequestFailure> {
+        let (_, _, first) = self.errors.first()?;
+        let first = (*first)?;
+
+        self.errors
+            .iter()
+            .all(|(_, _, failure)| *failure == Some(first))
+            .then_some(first)
+    }
+
+    /// Records a successful request, logging the previously failed sources
+    pub fn succeeded(&self, url: &str) {
+        if !self.errors.is_empty() {
+            debug!("request succeeded using fallback source {url}");
+        }
+    }
+}
+
+impl Display for SourceErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("all download sources failed")?;
+        for (url, err, _) in &self.errors {
+            write!(f, "\n- {url}: {err}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for SourceErrors {}
@@ -0,0 +1,138 @@
+//! Module for reading details from Portable Executable (PE) files
+
+use crate::bytes::{read_u16, read_u32};
+
+/// Machine type of 32-bit x86 executables
+pub const MACHINE_I386: u16 = 0x14C;
+/// Machine type of 64-bit x86 executables
+pub const MACHINE_AMD64: u16 = 0x8664;
+
+/// Signature of the fixed file info structure within a PE version resource
+const VS_FIXEDFILEINFO_SIGNATURE: [u8; 4] = 0xFEEF04BDu32.to_le_bytes();
+
+/// File header characteristic flag set for DLL files
+const CHARACTERISTIC_DLL: u16 = 0x2000;
+
+/// Optional header magic for 32-bit PE files
+const OPTIONAL_MAGIC_PE32: u16 = 0x10B;
+/// Optional header magic for 64-bit PE files
+const OPTIONAL_MAGIC_PE32_PLUS: u16 = 0x20B;
+
+/// Size of the PE signature and file header that precede the optional header
+const FILE_HEADER_END: usize = 24;
+/// Size of each entry in the section table
+const SECTION_HEADER_SIZE: usize = 40;
+
+/// Finds the offset of the PE header, none if the bytes are not a PE file
+fn pe_offset(bytes: &[u8]) -> Option<usize> {
+    if !bytes.starts_with(b"MZ") {
+        return None;
+    }
+
+    // Offset of the PE header is stored in the DOS header
+    let pe_offset = read_u32(bytes, 0x3C)? as usize;
+
+    if bytes.get(pe_offset..pe_offset + 4)? != b"PE\0\0" {
+        return None;
+    }
+
+    Some(pe_offset)
+}
+
+/// Reads the machine type from the PE header, none if the bytes
+/// are not a PE file
+pub fn read_machine(bytes: &[u8]) -> Option<u16> {
+    read_u16(bytes, pe_offset(bytes)? + 4)
+}
+
+/// Checks whether the PE file is a DLL
+pub fn is_dll(bytes: &[u8]) -> bool {
+    pe_offset(bytes)
+        .and_then(|offset| read_u16(bytes, offset + 22))
+        .is_some_and(|characteristics| characteristics & CHARACTERISTIC_DLL != 0)
+}
+
+/// Reads the address of the entry point (DllMain for DLLs) from the
+/// optional header, zero when the file has no entry point
+pub fn read_entry_point(bytes: &[u8]) -> Option<u32> {
+    read_u32(bytes, pe_offset(bytes)? + FILE_HEADER_END + 16)
+}
+
+/// Reads the names of the functions exported by the PE file
+pub fn read_export_names(bytes: &[u8]) -> Option<Vec<String>> {
+    let pe_offset = pe_offset(bytes)?;
+    let optional_offset = pe_offset + FILE_HEADER_END;
+
+    // Data directories start at a different offset for 64-bit files
+    let directories_offset = match read_u16(bytes, optional_offset)? {
+        OPTIONAL_MAGIC_PE32 => optional_offset + 96,
+        OPTIONAL_MAGIC_PE32_PLUS => optional_offset + 112,
+        _ => return None,
+    };
+
+    // Export directory is the first data directory
+    let export_rva = read_u32(bytes, directories_offset)?;
+    if export_rva == 0 {
+        return Some(Vec::new());
+    }
+
+    let section_count = read_u16(bytes, pe_offset + 6)? as usize;
+    let optional_size = read_u16(bytes, pe_offset + 20)? as usize;
+    let sections_offset = optional_offset + optional_size;
+
+    // Maps a relative virtual address to an offset within the file
+    let rva_to_offset = |rva: u32| -> Option<usize> {
+        (0..section_count).find_map(|index| {
+            let section = sections_offset + index * SECTION_HEADER_SIZE;
+            let virtual_size = read_u32(bytes, section + 8)?;
+            let virtual_address = read_u32(bytes, section + 12)?;
+            let raw_offset = read_u32(bytes, section + 20)?;
+
+            (rva >= virtual_address && rva < virtual_address + virtual_size)
+                .then(|| (rva - virtual_address + raw_offset) as usize)
+        })
+    };
+
+    let export_offset = rva_to_offset(export_rva)?;
+    let name_count = read_u32(bytes, export_offset + 24)? as usize;
+    let names_offset = rva_to_offset(read_u32(bytes, export_offset + 32)?)?;
+
+    (0..name_count)
+        .map(|index| {
+            let name_offset = rva_to_offset(read_u32(bytes, names_offset + index * 4)?)?;
+            let name = bytes.get(name_offset..)?;
+            let end = name.iter().position(|byte| *byte == 0)?;
+            Some(String::from_utf8_lossy(&name[..end]).to_string())
+        })
+        .collect()
+}
+
+/// Reads the file version from the version resource of a PE file
+/// (e.g "1.2.0.0"), none if the file has no version resource
+pub fn read_file_version(bytes: &[u8]) -> Option<String> {
+    let offset = bytes
+        .windows(VS_FIXEDFILEINFO_SIGNATURE.len())
+        .position(|window| window == VS_FIXEDFILEINFO_SIGNATURE)?;
+
+    // File version follows the signature and structure version
+    let version_ms = read_u32(bytes, offset + 8)?;
+    let version_ls = read_u32(bytes, offset + 12)?;
+
+    Some(format!(
+        "{}.{}.{}.{}",
+        version_ms >> 16,
+        version_ms & 0xFFFF,
+        version_ls >> 16,
+        version_ls & 0xFFFF
+    ))
+}
+
+/// Checks whether the `value` is present as a UTF-16 string within
+/// the file, used for finding version resource strings
+pub fn contains_utf16_string(bytes: &[u8], value: &str) -> bool {
+    let needle: Vec<u8> = value.encode_utf16().flat_map(u16::to_le_bytes).collect();
+
+    bytes
+        .windows(needle.len())
+        .any(|window| window == needle.as_slice())
+}
@@ -0,0 +1,59 @@
+//! Module for describing the file changes an action will make without
+//! making them, allowing the changes to be previewed before applying
+
+use std::path::{Path, PathBuf};
+
+/// Change that will be made to a file within the game directory
+#[derive(Debug, Clone)]
+pub struct PlannedChange {
+    /// Path of the file relative to the game directory
+    pub path: PathBuf,
+    /// The change that will be made
+    pub kind: ChangeKind,
+}
+
+/// Kinds of changes that can be made to a file
+#[derive(Debug, Clone)]
+pub enum ChangeKind {
+    /// File will be created with the provided size in bytes
+    Create(u64),
+    /// File will be overwritten with the provided size in bytes, the
+    /// existing file is backed up to the `backup` path if present
+    Overwrite { size: u64, backup: Option<PathBuf> },
+    /// File will be restored from the provided backup path
+    Restore(PathBuf),
+    /// File will be deleted
+    Delete,
+}
+
+/// Plans writing `size` bytes to the file at `path` relative to the
+/// `game_path`, the existing file is backed up to `backup` if present
+pub fn plan_write(
+    game_path: &Path,
+    path: impl Into<PathBuf>,
+    size: u64,
+    backup: Option<PathBuf>,
+) -> PlannedChange {
+    let path = path.into();
+    let kind = if game_path.join(&path).exists() {
+        ChangeKind::Overwrite { size, backup }
+    } else {
+        ChangeKind::Create(size)
+    };
+
+    PlannedChange { path, kind }
+}
+
+/// Plans deleting the file at `path` relative to the `game_path`, none
+/// if the file doesn't exist
+pub fn plan_delete(game_path: &Path, path: impl Into<PathBuf>) -> Option<PlannedChange> {
+    let path = path.into();
+    if !game_path.join(&path).exists() {
+        return None;
+    }
+
+    Some(PlannedChange {
+        path,
+        kind: ChangeKind::Delete,
+    })
+}
@@ -0,0 +1,1406 @@
+//! Module for helpers related to finding plugin releases and applying/removing the plugin
+//! from the game
+
+use crate::{
+    asi::DISABLED_EXTENSION,
+    asset_cache::{find_cached_asset, store_asset},
+    delta::{apply_delta, delta_asset_name},
+    downloads::DownloadManager,
+    feed::{get_feed_releases, ReleaseFeed},
+    fs_util::{atomic_write, temp_path},
+    game_fs::{GameFs, TokioFs},
+    github::{
+        get_all_releases, get_latest_release, verify_asset, ApiOptions, DownloadProgress,
+        GitHubRelease, GitHubReleaseAsset, GitHubReleases, ReleaseList, ReleaseProvider,
+    },
+    pe::{is_dll, read_entry_point, read_export_names, read_machine, MACHINE_I386},
+    plan::{plan_delete, plan_write, PlannedChange},
+    preflight::{check_disk_space, check_install_paths},
+    process::{ensure_game_not_running, is_file_in_use_error, GameRunningError},
+    progress::{Progress, ProgressStep},
+};
+use anyhow::{bail, Context};
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use std::{
+    io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// The default GitHub repository to use for releases
+pub const DEFAULT_GITHUB_REPOSITORY: &str = "PocketRelay/PocketRelayClientPlugin";
+/// Default GitHub asset name for the plugin file
+pub const DEFAULT_ASSET_NAME: &str = "pocket-relay-plugin.asi";
+
+/// Source to obtain plugin releases from, allows installing community
+/// forks or test builds of the plugin from another repository
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PluginSource {
+    /// The GitHub repository to use for releases (e.g "PocketRelay/PocketRelayClientPlugin")
+    pub repository: String,
+    /// GitHub asset name for the plugin file
+    pub asset_name: String,
+}
+
+impl PluginSource {
+    /// Whether this is the official plugin source
+    pub fn is_default(&self) -> bool {
+        *self == PluginSource::default()
+    }
+
+    /// Finds the plugin assets within the provided `release`, a release may
+    /// include multiple builds of the plugin (e.g a debug build)
+    pub fn find_assets<'a>(&self, release: &'a GitHubRelease) -> Vec<&'a GitHubReleaseAsset> {
+        release
+            .assets
+            .iter()
+            .filter(|asset| asset.name == self.asset_name || is_plugin_asset_name(&asset.name))
+            .collect()
+    }
+
+    /// Selects the plugin asset to install from the provided `release`. Uses
+    /// the `preferred` asset when the release includes it, otherwise the
+    /// configured asset name, otherwise the first asset that isn't a debug build
+    pub fn select_asset<'a>(
+        &self,
+        release: &'a GitHubRelease,
+        preferred: Option<&str>,
+    ) -> Option<&'a GitHubReleaseAsset> {
+        let assets = self.find_assets(release);
+
+        let find_named = |name: &str| assets.iter().find(|asset| asset.name == name).copied();
+
+        preferred
+            .and_then(find_named)
+            .or_else(|| find_named(&self.asset_name))
+            .or_else(|| {
+                assets
+                    .iter()
+                    .find(|asset| !asset.name.to_ascii_lowercase().contains("debug"))
+                    .copied()
+            })
+            .or_else(|| assets.first().copied())
+    }
+}
+
+/// Checks whether the asset `name` is an ASI plugin file
+fn is_plugin_asset_name(name: &str) -> bool {
+    Path::new(name)
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("asi"))
+}
+
+/// Finds the asset named `asset_name` within the provided `release`
+pub fn find_named_asset<'a>(
+    release: &'a GitHubRelease,
+    asset_name: &str,
+) -> anyhow::Result<&'a GitHubReleaseAsset> {
+    release
+        .assets
+        .iter()
+        .find(|asset| asset.name == asset_name)
+        .with_context(|| format!("missing plugin asset file {asset_name}"))
+}
+
+impl Default for PluginSource {
+    fn default() -> Self {
+        Self {
+            repository: DEFAULT_GITHUB_REPOSITORY.to_string(),
+            asset_name: DEFAULT_ASSET_NAME.to_string(),
+        }
+    }
+}
+
+/// Checks that `repository` is in the "owner/name" form used by GitHub
+pub fn is_valid_repository(repository: &str) -> bool {
+    let valid_part = |part: &str| {
+        !part.is_empty()
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    };
+
+    matches!(repository.split_once('/'), Some((owner, name)) if valid_part(owner) && valid_part(name))
+}
+
+/// Checks that `asset_name` is a plain file name, the name is used
+/// for the download staging file
+pub fn is_valid_asset_name(asset_name: &str) -> bool {
+    !asset_name.is_empty() && asset_name != ".." && !asset_name.contains(['/', '\\'])
+}
+
+/// Name of the plugin directory
+pub const PLUGIN_DIR: &str = "ASI";
+
+/// Name of the plugin file
+pub const PLUGIN_NAME: &str = "pocket-relay-plugin.asi";
+
+/// State of the plugin within the game folder
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PluginState {
+    /// Plugin is not installed
+    #[default]
+    Absent,
+    /// Plugin is installed and loaded by the game
+    Installed,
+    /// Plugin is installed but renamed so the game doesn't load it
+    Disabled,
+}
+
+impl PluginState {
+    /// Whether the plugin is installed, enabled or not
+    pub fn is_present(&self) -> bool {
+        !matches!(self, PluginState::Absent)
+    }
+}
+
+/// Exported function ASI loaders call after loading a plugin
+const ASI_ENTRY_POINT: &str = "InitializeASI";
+
+/// Name of the directory within the system temp directory that plugin
+/// downloads are staged in
+const DOWNLOAD_STAGING_DIR: &str = "pocket-relay-plugin-installer";
+
+/// Name of the file storing the installed plugin version, written
+/// alongside the plugin file
+pub const PLUGIN_VERSION_NAME: &str = "pocket-relay-plugin.version";
+
+/// Names of the release assets listing the checksums of every asset
+const CHECKSUMS_ASSET_NAMES: [&str; 2] = ["SHA256SUMS", "SHA256SUMS.txt"];
+
+/// Extension of the release asset containing the checksum of a single asset
+const CHECKSUM_EXTENSION: &str = ".sha256";
+
+/// Result of checking a downloaded plugin against the release checksums
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumStatus {
+    /// Plugin matched the checksum provided by the release
+    Verified,
+    /// Release doesn't provide a checksum for the plugin
+    Unavailable,
+}
+
+/// Time to wait after installing the plugin before checking it's still
+/// present, antivirus software usually quarantines new files within seconds
+const QUARANTINE_CHECK_DELAY: Duration = Duration::from_secs(5);
+
+/// Obtains all the releases of the plugin that include the plugin asset,
+/// sorted from newest to oldest, up to the release page limit.
+///
+/// The latest stable release is requested alongside the list of releases,
+/// when only the list fails the latest stable release is still provided
+pub async fn get_plugin_releases(
+    http_client: reqwest::Client,
+    mirrors: Vec<String>,
+    options: ApiOptions,
+    source: PluginSource,
+) -> anyhow::Result<ReleaseList> {
+    // Request the list of releases and the latest release at the same time
+    let (all_result, latest_result) = tokio::join!(
+        get_all_releases(&http_client, &source.repository, &mirrors, &options),
+        get_latest_release(&http_client, &source.repository, &mirrors, &options)
+    );
+
+    let mut list = match (all_result, latest_result) {
+        (Ok(list), _) => list,
+        (Err(err), Ok(latest)) => {
+            warn!("failed to list plugin releases, using the latest release only: {err}");
+            ReleaseList {
+                releases: vec![latest],
+                has_more: false,
+            }
+        }
+        (Err(err), Err(_)) => {
+            return Err(err).with_context(|| {
+                format!(
+                    "failed finding plugin client versions from {}",
+                    source.repository
+                )
+            })
+        }
+    };
+
+    retain_installable(&mut list.releases, &source);
+
+    Ok(list)
+}
+
+/// Obtains all the releases of the plugin from the release `feed` that
+/// include the plugin asset, sorted from newest to oldest
+pub async fn get_plugin_feed_releases(
+    http_client: reqwest::Client,
+    feed: ReleaseFeed,
+    source: PluginSource,
+) -> anyhow::Result<Vec<GitHubRelease>> {
+    let mut releases = get_feed_releases(&http_client, &feed).await?;
+
+    retain_installable(&mut releases, &source);
+
+    Ok(releases)
+}
+
+/// Retains only the `releases` that include a plugin asset from the
+/// `source`, sorting them from newest to oldest
+fn retain_installable(releases: &mut Vec<GitHubRelease>, source: &PluginSource) {
+    releases.retain(|release| !source.find_assets(release).is_empty());
+
+    // Sort on the published_at descending
+    releases.sort_by(|a, b| a.published_at.cmp(&b.published_at).reverse());
+}
+
+/// Applies the plugin from the `asset_name` asset of the provided `release`,
+/// downloads the plugin through the `downloads` manager and saves it to the
+/// plugin directory. Each step, including the download progress, is
+/// reported to `on_progress`.
+///
+/// The plugin is checked against the release checksums file when the
+/// release provides one. A previous download of the same version is
+/// reused when the `downloads` manager has a cache folder, otherwise only
+/// the changes are downloaded when the release provides a delta from the
+/// installed version
+pub async fn apply_plugin(
+    http_client: reqwest::Client,
+    downloads: DownloadManager,
+    mirrors: Vec<String>,
+    game_path: PathBuf,
+    release: GitHubRelease,
+    asset_name: String,
+    on_progress: impl FnMut(Progress),
+) -> anyhow::Result<ChecksumStatus> {
+    let provider = GitHubReleases {
+        http_client,
+        mirrors,
+    };
+
+    apply_plugin_with(
+        &provider,
+        &TokioFs,
+        downloads,
+        game_path,
+        release,
+        asset_name,
+        on_progress,
+    )
+    .await
+}
+
+/// [apply_plugin] downloading the plugin from the `provider` and writing
+/// it to the game folder through the `fs`
+pub async fn apply_plugin_with(
+    provider: &impl ReleaseProvider,
+    fs: &impl GameFs,
+    downloads: DownloadManager,
+    game_path: PathBuf,
+    release: GitHubRelease,
+    asset_name: String,
+    mut on_progress: impl FnMut(Progress),
+) -> anyhow::Result<ChecksumStatus> {
+    on_progress(Progress::step(ProgressStep::Checking));
+
+    // Find the asset for the plugin file
+    let asset = find_named_asset(&release, &asset_name)?;
+
+    // Check the game folder before spending time on the download
+    check_install_paths(&game_path).await?;
+    check_disk_space(
+        &game_path,
+        &staging_dir(downloads.download_dir()),
+        asset.size,
+        asset.size,
+    )
+    .await?;
+
+    let expected_checksum = get_release_checksum(provider, &release, &asset.name)
+        .await
+        .context("failed to obtain release checksums")?;
+
+    // Reuse a previous download of the same version when it still passes the checks
+    let cache_dir = downloads.cache_dir();
+    let cached = match &cache_dir {
+        Some(dir) => {
+            let expected = expected_checksum.as_deref();
+            find_cached_asset(dir, &release.tag_name, &asset.name, expected)
+                .await
+                .filter(|bytes| verify_asset(asset, bytes).is_ok())
+        }
+        None => None,
+    };
+
+    let (bytes, checksum_status) = match cached {
+        Some(bytes) => {
+            on_progress(Progress::step(ProgressStep::Verifying));
+
+            let checksum_status = verify_checksum(expected_checksum.as_deref(), &bytes)?;
+            (bytes, checksum_status)
+        }
+        None => {
+            let delta = download_plugin_delta(
+                provider,
+                fs,
+                &downloads,
+                &game_path,
+                &release,
+                asset,
+                expected_checksum.as_deref(),
+                &mut on_progress,
+            )
+            .await;
+
+            let (bytes, checksum_status) = match delta {
+                Some(value) => value,
+                None => {
+                    download_plugin(
+                        provider,
+                        &downloads,
+                        &release,
+                        asset,
+                        expected_checksum.as_deref(),
+                        &mut on_progress,
+                    )
+                    .await?
+                }
+            };
+
+            if let Some(dir) = &cache_dir {
+                store_asset(dir, &release.tag_name, &asset.name, &bytes).await;
+            }
+
+            (bytes, checksum_status)
+        }
+    };
+
+    on_progress(Progress::step(ProgressStep::Writing));
+
+    write_plugin(fs, &game_path, &bytes, Some(&release.tag_name)).await?;
+
+    debug!("applied plugin");
+
+    Ok(checksum_status)
+}
+
+/// Downloads the `asset` of the `release` through the `downloads` manager
+/// and verifies it against the `expected_checksum`, provides the contents
+/// of the plugin along with the checksum status
+async fn download_plugin(
+    provider: &impl ReleaseProvider,
+    downloads: &DownloadManager,
+    release: &GitHubRelease,
+    asset: &GitHubReleaseAsset,
+    expected_checksum: Option<&str>,
+    mut on_progress: impl FnMut(Progress),
+) -> anyhow::Result<(Vec<u8>, ChecksumStatus)> {
+    let bytes = download_release_asset(provider, downloads, release, asset, |progress| {
+        on_progress(Progress::download(progress))
+    })
+    .await
+    .context("failed to download client plugin")?;
+
+    on_progress(Progress::step(ProgressStep::Verifying));
+
+    // Ensure the download wasn't truncated or corrupted
+    let checksum_status = verify_asset(asset, &bytes)
+        .and_then(|_| verify_checksum(expected_checksum, &bytes))
+        .context("downloaded plugin failed verification")?;
+
+    Ok((bytes, checksum_status))
+}
+
+/// Updates the plugin installed in the `game_path` to the `asset` of the
+/// `release` using the delta the release provides from the installed
+/// version. None when the release has no matching delta or the delta
+/// couldn't be applied, in which case the full plugin is downloaded instead
+#[allow(clippy::too_many_arguments)]
+async fn download_plugin_delta(
+    provider: &impl ReleaseProvider,
+    fs: &impl GameFs,
+    downloads: &DownloadManager,
+    game_path: &Path,
+    release: &GitHubRelease,
+    asset: &GitHubReleaseAsset,
+    expected_checksum: Option<&str>,
+    mut on_progress: impl FnMut(Progress),
+) -> Option<(Vec<u8>, ChecksumStatus)> {
+    let installed_tag = read_plugin_version_with(fs, game_path).await?;
+    if installed_tag == release.tag_name {
+        return None;
+    }
+
+    let delta_name = delta_asset_name(&asset.name, &installed_tag);
+    let delta_asset = release
+        .assets
+        .iter()
+        .find(|asset| asset.name.eq_ignore_ascii_case(&delta_name))?;
+
+    let plugin_path = game_path.join(PLUGIN_DIR).join(PLUGIN_NAME);
+    let old = fs.read(&plugin_path).await.ok()?;
+
+    let result = async {
+        let patch = download_release_asset(provider, downloads, release, delta_asset, |progress| {
+            on_progress(Progress::download(progress))
+        })
+        .await
+        .context("failed to download delta")?;
+
+        on_progress(Progress::step(ProgressStep::Verifying));
+
+        let bytes = apply_delta(&old, &patch).context("failed to apply delta")?;
+
+        // The delta only produces the plugin when applied to the expected version
+        let checksum_status = verify_asset(asset, &bytes)
+            .and_then(|_| verify_checksum(expected_checksum, &bytes))
+            .context("delta produced an invalid plugin")?;
+
+        anyhow::Ok((bytes, checksum_status))
+    }
+    .await;
+
+    match result {
+        Ok(value) => {
+            debug!(
+                "updated plugin from {installed_tag} using {} byte delta",
+                delta_asset.size
+            );
+            Some(value)
+        }
+        Err(err) => {
+            warn!("falling back to full plugin download: {err:#}");
+            None
+        }
+    }
+}
+
+/// Downloads the `asset` of the `release` through the `downloads` manager
+/// to a staging file, resuming any previous download, then reads it back
+async fn download_release_asset(
+    provider: &impl ReleaseProvider,
+    downloads: &DownloadManager,
+    release: &GitHubRelease,
+    asset: &GitHubReleaseAsset,
+    on_progress: impl FnMut(DownloadProgress),
+) -> anyhow::Result<Vec<u8>> {
+    let staging_path =
+        download_staging_path(downloads.download_dir(), &release.tag_name, &asset.name).await?;
+    let download_name = format!("{} ({})", asset.name, release.tag_name);
+    downloads
+        .run(download_name, on_progress, |mut reporter| async {
+            provider
+                .download_asset(
+                    asset,
+                    &staging_path,
+                    downloads.speed_limit(),
+                    move |progress| reporter.report(progress),
+                )
+                .await?;
+            Ok(())
+        })
+        .await?;
+
+    let bytes = tokio::fs::read(&staging_path)
+        .await
+        .context("failed to read download")?;
+
+    // Downloads are read into memory so the staging file is no longer needed
+    if let Err(err) = tokio::fs::remove_file(&staging_path).await {
+        debug!("failed to remove download: {err}");
+    }
+
+    Ok(bytes)
+}
+
+/// Obtains the checksum the `release` provides for the `asset_name` asset
+/// from its checksums file, none when the release doesn't provide one
+async fn get_release_checksum(
+    provider: &impl ReleaseProvider,
+    release: &GitHubRelease,
+    asset_name: &str,
+) -> anyhow::Result<Option<String>> {
+    let single_name = format!("{asset_name}{CHECKSUM_EXTENSION}");
+
+    // Prefer the checksum for the single asset over the checksums list
+    let checksum_asset = release
+        .assets
+        .iter()
+        .find(|asset| asset.name.eq_ignore_ascii_case(&single_name))
+        .or_else(|| {
+            release.assets.iter().find(|asset| {
+                CHECKSUMS_ASSET_NAMES
+                    .iter()
+                    .any(|name| asset.name.eq_ignore_ascii_case(name))
+            })
+        });
+
+    let checksum_asset = match checksum_asset {
+        Some(value) => value,
+        None => {
+            debug!("release {} has no checksums file", release.tag_name);
+            return Ok(None);
+        }
+    };
+
+    let bytes = provider
+        .fetch_asset(checksum_asset)
+        .await
+        .with_context(|| format!("failed to download {}", checksum_asset.name))?;
+    let contents = String::from_utf8_lossy(&bytes);
+
+    let checksum = parse_checksum(&contents, asset_name);
+    if checksum.is_none() {
+        warn!(
+            "{} doesn't include a checksum for {asset_name}",
+            checksum_asset.name
+        );
+    }
+
+    Ok(checksum)
+}
+
+/// Finds the checksum for the `asset_name` within the `contents` of a
+/// checksums file. Lines are in the "<sha256>  <file name>" format used by
+/// sha256sum, files for a single asset may contain only the checksum
+fn parse_checksum(contents: &str, asset_name: &str) -> Option<String> {
+    contents.lines().find_map(|line| {
+        let mut parts = line.split_whitespace();
+        let checksum = parts.next()?;
+
+        if checksum.len() != 64 || !checksum.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        match parts.next() {
+            // Binary mode files are prefixed with '*'
+            Some(name) if name.trim_start_matches('*') != asset_name => None,
+            _ => Some(checksum.to_ascii_lowercase()),
+        }
+    })
+}
+
+/// Verifies the downloaded `bytes` match the `expected` checksum from the
+/// release checksums file
+fn verify_checksum(expected: Option<&str>, bytes: &[u8]) -> anyhow::Result<ChecksumStatus> {
+    let expected = match expected {
+        Some(value) => value,
+        None => return Ok(ChecksumStatus::Unavailable),
+    };
+
+    let actual = sha256::digest(bytes);
+    if !actual.eq_ignore_ascii_case(expected) {
+        bail!("checksum mismatch (expected {expected}, downloaded {actual})");
+    }
+
+    debug!("verified plugin checksum: {actual}");
+
+    Ok(ChecksumStatus::Verified)
+}
+
+/// Determines the folder downloads are staged in, the `download_dir` or
+/// a folder within the system temp folder
+pub fn staging_dir(download_dir: Option<PathBuf>) -> PathBuf {
+    download_dir.unwrap_or_else(|| std::env::temp_dir().join(DOWNLOAD_STAGING_DIR))
+}
+
+/// Determines the path to stage the download of the plugin `version` at
+/// within the `download_dir` or the system temp folder, creating the
+/// staging directory if its missing
+async fn download_staging_path(
+    download_dir: Option<PathBuf>,
+    version: &str,
+    asset_name: &str,
+) -> anyhow::Result<PathBuf> {
+    let staging_dir = staging_dir(download_dir);
+
+    tokio::fs::create_dir_all(&staging_dir)
+        .await
+        .context("failed to create download directory")?;
+
+    Ok(staging_dir.join(format!("{version}-{asset_name}.part")))
+}
+
+/// Applies the plugin from a local plugin file at `file_path`, copying
+/// it into the plugin directory without any network access
+pub async fn apply_plugin_file(game_path: PathBuf, file_path: PathBuf) -> anyhow::Result<()> {
+    check_install_paths(&game_path).await?;
+
+    let bytes = tokio::fs::read(&file_path)
+        .await
+        .context("failed to read plugin file")?;
+
+    // Nothing is downloaded for a local file
+    check_disk_space(&game_path, &file_path, 0, bytes.len() as u64).await?;
+
+    // Version of a local plugin file is unknown
+    write_plugin(&TokioFs, &game_path, &bytes, None).await?;
+
+    debug!("applied plugin from file: {}", file_path.display());
+
+    Ok(())
+}
+
+/// Plans the changes that [apply_plugin] will make for the `asset_name`
+/// asset of the `release` without making them
+pub async fn plan_apply_plugin(
+    game_path: PathBuf,
+    release: GitHubRelease,
+    asset_name: String,
+) -> anyhow::Result<Vec<PlannedChange>> {
+    let asset = find_named_asset(&release, &asset_name)?;
+
+    Ok(plan_write_plugin(
+        &game_path,
+        asset.size,
+        Some(&release.tag_name),
+    ))
+}
+
+/// Plans the changes that [apply_plugin_file] will make for the plugin
+/// file at `file_path` without making them
+pub async fn plan_apply_plugin_file(
+    game_path: PathBuf,
+    file_path: PathBuf,
+) -> anyhow::Result<Vec<PlannedChange>> {
+    let metadata = tokio::fs::metadata(&file_path)
+        .await
+        .context("failed to read plugin file")?;
+
+    Ok(plan_write_plugin(&game_path, metadata.len(), None))
+}
+
+/// Plans the changes that [remove_plugin] will make without making them
+pub async fn plan_remove_plugin(game_path: PathBuf) -> anyhow::Result<Vec<PlannedChange>> {
+    Ok(plugin_file_paths()
+        .into_iter()
+        .filter_map(|path| plan_delete(&game_path, path))
+        .collect())
+}
+
+/// Plans the changes that [write_plugin] will make for a plugin of
+/// `size` bytes
+fn plan_write_plugin(game_path: &Path, size: u64, version: Option<&str>) -> Vec<PlannedChange> {
+    let asi_path = Path::new(PLUGIN_DIR);
+    let version_path = asi_path.join(PLUGIN_VERSION_NAME);
+
+    let mut changes = vec![plan_write(
+        game_path,
+        asi_path.join(PLUGIN_NAME),
+        size,
+        None,
+    )];
+
+    changes.extend(plan_delete(
+        game_path,
+        asi_path.join(format!("{PLUGIN_NAME}{DISABLED_EXTENSION}")),
+    ));
+
+    match version {
+        Some(version) => changes.push(plan_write(
+            game_path,
+            version_path,
+            version.len() as u64,
+            None,
+        )),
+        None => changes.extend(plan_delete(game_path, version_path)),
+    }
+
+    changes
+}
+
+/// Writes the plugin `bytes` to the plugin file, creating the
+/// plugins directory if its missing. Writes the `version` to the
+/// plugin version file when known
+async fn write_plugin(
+    fs: &impl GameFs,
+    game_path: &Path,
+    bytes: &[u8],
+    version: Option<&str>,
+) -> anyhow::Result<()> {
+    let asi_path = game_path.join(PLUGIN_DIR);
+    let plugin_path = asi_path.join(PLUGIN_NAME);
+    let version_path = asi_path.join(PLUGIN_VERSION_NAME);
+
+    ensure_game_not_running(game_path).await?;
+
+    // Fresh installs won't have the plugins directory yet
+    fs.create_dir_all(&asi_path)
+        .await
+        .with_context(|| format!("failed to create plugins directory {}", asi_path.display()))?;
+
+    // Save the plugin to the plugins directory
+    fs.write(&plugin_path, bytes)
+        .await
+        .context("saving plugin file")?;
+
+    verify_plugin(fs, &plugin_path, bytes)
+        .await
+        .context("plugin failed verification")?;
+
+    // Installing replaces a disabled copy of the plugin
+    let disabled_path = asi_path.join(format!("{PLUGIN_NAME}{DISABLED_EXTENSION}"));
+    if fs.is_file(&disabled_path) {
+        fs.remove_file(&disabled_path)
+            .await
+            .context("failed to remove disabled plugin file")?;
+    }
+
+    match version {
+        Some(version) => fs
+            .write(&version_path, version.as_bytes())
+            .await
+            .context("saving plugin version file")?,
+        None => remove_version_file(fs, &version_path).await?,
+    }
+
+    Ok(())
+}
+
+/// Removes the plugin version file if it exists
+async fn remove_version_file(fs: &impl GameFs, version_path: &Path) -> anyhow::Result<()> {
+    if fs.is_file(version_path) {
+        fs.remove_file(version_path)
+            .await
+            .context("failed to remove plugin version file")?;
+    }
+    Ok(())
+}
+
+/// Reads the version of the plugin installed in the game directory from the
+/// plugin version file, none if the version is unknown
+pub async fn read_plugin_version(game_path: &Path) -> Option<String> {
+    read_plugin_version_with(&TokioFs, game_path).await
+}
+
+/// [read_plugin_version] reading the version file through the `fs`
+pub async fn read_plugin_version_with(fs: &impl GameFs, game_path: &Path) -> Option<String> {
+    let version_path = game_path.join(PLUGIN_DIR).join(PLUGIN_VERSION_NAME);
+    let version = fs.read(&version_path).await.ok()?;
+    let version = String::from_utf8_lossy(&version);
+    let version = version.trim();
+
+    if version.is_empty() {
+        return None;
+    }
+
+    Some(version.to_string())
+}
+
+/// Verifies the plugin file at `plugin_path` was written correctly by
+/// comparing its hash with the `expected` bytes and checking that it is
+/// a plugin the ASI loader can load
+async fn verify_plugin(
+    fs: &impl GameFs,
+    plugin_path: &Path,
+    expected: &[u8],
+) -> anyhow::Result<()> {
+    let bytes = fs
+        .read(plugin_path)
+        .await
+        .context("failed to read plugin file")?;
+
+    let actual = sha256::digest(&bytes);
+    if actual != sha256::digest(expected) {
+        bail!("plugin file does not match the installed plugin");
+    }
+
+    check_plugin_loadable(&bytes)?;
+
+    debug!("verified plugin file: {actual}");
+
+    Ok(())
+}
+
+/// Verifies the plugin installed in the game folder at `game_path` is a
+/// plugin the ASI loader can load
+pub async fn verify_installed_plugin(game_path: &Path) -> anyhow::Result<()> {
+    let plugin_path = game_path.join(PLUGIN_DIR).join(PLUGIN_NAME);
+    let bytes = tokio::fs::read(plugin_path)
+        .await
+        .context("failed to read plugin file")?;
+
+    check_plugin_loadable(&bytes)
+}
+
+/// Checks the plugin `bytes` are a plugin the ASI loader can load
+fn check_plugin_loadable(bytes: &[u8]) -> anyhow::Result<()> {
+    // The game is 32-bit so the plugin must be a 32-bit DLL
+    if read_machine(bytes) != Some(MACHINE_I386) || !is_dll(bytes) {
+        bail!("plugin file is not a 32-bit DLL");
+    }
+
+    // The loader runs DllMain when loading the plugin and calls the
+    // ASI entry point when the plugin exports one
+    let has_entry_point = read_entry_point(bytes).is_some_and(|address| address != 0);
+    let has_asi_export = read_export_names(bytes)
+        .unwrap_or_default()
+        .iter()
+        .any(|name| name == ASI_ENTRY_POINT);
+
+    if !has_entry_point && !has_asi_export {
+        bail!("plugin file has no entry point");
+    }
+
+    Ok(())
+}
+
+/// Watchdog run after the plugin is installed, waits a moment then checks
+/// the plugin file in the game folder at `game_path` still exists and is
+/// readable. Antivirus software commonly flags the plugin as a false
+/// positive and quarantines it shortly after its written.
+///
+/// Returns whether the plugin is still present
+pub async fn check_plugin_quarantine(game_path: PathBuf) -> bool {
+    tokio::time::sleep(QUARANTINE_CHECK_DELAY).await;
+
+    let plugin_path = game_path.join(PLUGIN_DIR).join(PLUGIN_NAME);
+
+    match tokio::fs::read(&plugin_path).await {
+        Ok(bytes) if !bytes.is_empty() => true,
+        Ok(_) => {
+            warn!("plugin was emptied after install, likely quarantined");
+            false
+        }
+        Err(err) => {
+            warn!("plugin is unreadable after install, likely quarantined: {err}");
+            false
+        }
+    }
+}
+
+/// Removes the plugin from the game directory, whether its enabled or not,
+/// along with any temporary files left behind by an interrupted install.
+/// Files that are already missing are skipped so removing a partially
+/// removed plugin succeeds
+pub async fn remove_plugin(game_path: PathBuf) -> anyhow::Result<()> {
+    remove_plugin_with(&TokioFs, game_path).await
+}
+
+/// [remove_plugin] removing the plugin files through the `fs`
+pub async fn remove_plugin_with(fs: &impl GameFs, game_path: PathBuf) -> anyhow::Result<()> {
+    ensure_game_not_running(&game_path).await?;
+
+    for path in plugin_file_paths() {
+        remove_file_if_present(fs, &game_path.join(path)).await?;
+    }
+
+    debug!("removed plugin");
+
+    Ok(())
+}
+
+/// Paths of the plugin files relative to the game folder, includes the
+/// disabled copy and temporary files left behind by interrupted writes.
+/// The plugin comes before its version file so a failed removal doesn't
+/// leave the plugin without its version
+fn plugin_file_paths() -> Vec<PathBuf> {
+    let asi_path = Path::new(PLUGIN_DIR);
+    let disabled_name = format!("{PLUGIN_NAME}{DISABLED_EXTENSION}");
+
+    [PLUGIN_NAME, &disabled_name, PLUGIN_VERSION_NAME]
+        .into_iter()
+        .flat_map(|name| {
+            let path = asi_path.join(name);
+            let temp = temp_path(&path);
+            [path, temp]
+        })
+        .collect()
+}
+
+/// Removes the file at `path` through the `fs` when its present, files
+/// that are in use by another process are reported as the game running
+async fn remove_file_if_present(fs: &impl GameFs, path: &Path) -> anyhow::Result<()> {
+    match fs.remove_file(path).await {
+        Ok(()) => {
+            debug!("removed {}", path.display());
+            Ok(())
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(err) if is_file_in_use_error(&err) => Err(GameRunningError.into()),
+        Err(err) => Err(err).with_context(|| format!("failed to remove {}", path.display())),
+    }
+}
+
+/// Reads the state of the plugin within the game directory through the `fs`
+pub fn read_plugin_state(fs: &impl GameFs, game_path: &Path) -> PluginState {
+    let asi_path = game_path.join(PLUGIN_DIR);
+
+    if fs.is_file(&asi_path.join(PLUGIN_NAME)) {
+        PluginState::Installed
+    } else if fs.is_file(&asi_path.join(format!("{PLUGIN_NAME}{DISABLED_EXTENSION}"))) {
+        PluginState::Disabled
+    } else {
+        PluginState::Absent
+    }
+}
+
+/// Enables or disables the installed plugin by renaming the plugin file,
+/// the game only loads plugins with the ASI extension so a disabled plugin
+/// can be switched back without downloading it again
+pub async fn set_plugin_enabled(game_path: PathBuf, enabled: bool) -> anyhow::Result<()> {
+    let asi_path = game_path.join(PLUGIN_DIR);
+    let plugin_path = asi_path.join(PLUGIN_NAME);
+    let disabled_path = asi_path.join(format!("{PLUGIN_NAME}{DISABLED_EXTENSION}"));
+
+    ensure_game_not_running(&game_path).await?;
+
+    let (from, to) = match enabled {
+        true => (disabled_path, plugin_path),
+        false => (plugin_path, disabled_path),
+    };
+
+    tokio::fs::rename(&from, &to)
+        .await
+        .with_context(|| format!("failed to rename plugin {}", from.display()))?;
+
+    debug!("set pocket relay plugin enabled: {enabled}");
+
+    Ok(())
+}
+
+/// Copy of the plugin files taken before they are replaced, allows the
+/// previous plugin to be restored if an install fails part way
+#[derive(Debug, Clone)]
+pub struct PluginSnapshot {
+    /// Contents of the plugin file if one was installed
+    plugin: Option<Vec<u8>>,
+    /// Contents of the plugin version file if one was present
+    version: Option<Vec<u8>>,
+}
+
+/// Takes a snapshot of the plugin files in the game directory
+pub async fn snapshot_plugin(game_path: &Path) -> PluginSnapshot {
+    let asi_path = game_path.join(PLUGIN_DIR);
+
+    PluginSnapshot {
+        plugin: tokio::fs::read(asi_path.join(PLUGIN_NAME)).await.ok(),
+        version: tokio::fs::read(asi_path.join(PLUGIN_VERSION_NAME))
+            .await
+            .ok(),
+    }
+}
+
+/// Restores the plugin files in the game directory to the state of the
+/// `snapshot`, files that didn't exist in the snapshot are removed
+pub async fn restore_plugin(game_path: &Path, snapshot: PluginSnapshot) -> anyhow::Result<()> {
+    let asi_path = game_path.join(PLUGIN_DIR);
+    let plugin_path = asi_path.join(PLUGIN_NAME);
+    let version_path = asi_path.join(PLUGIN_VERSION_NAME);
+
+    match snapshot.plugin {
+        Some(bytes) => atomic_write(&plugin_path, bytes)
+            .await
+            .context("failed to restore plugin file")?,
+        None => {
+            if plugin_path.exists() {
+                tokio::fs::remove_file(&plugin_path)
+                    .await
+                    .context("failed to remove plugin file")?;
+            }
+        }
+    }
+
+    match snapshot.version {
+        Some(bytes) => atomic_write(&version_path, bytes)
+            .await
+            .context("failed to restore plugin version file")?,
+        None => remove_version_file(&TokioFs, &version_path).await?,
+    }
+
+    debug!("restored plugin snapshot");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        apply_plugin_with, read_plugin_state, read_plugin_version_with, remove_plugin_with,
+        ChecksumStatus, PluginState, DEFAULT_ASSET_NAME, PLUGIN_DIR, PLUGIN_NAME,
+        PLUGIN_VERSION_NAME,
+    };
+    use crate::{
+        downloads::DownloadManager,
+        game_fs::{
+            test_util::{FailingFs, TempDir},
+            GameFs, TokioFs,
+        },
+        github::{DownloadProgress, GitHubRelease, GitHubReleaseAsset, ReleaseProvider},
+        mirror::SourceErrors,
+        profile::Game,
+        progress::{Progress, ProgressStep},
+    };
+    use bytes::Bytes;
+    use flate2::{write::GzEncoder, Compression};
+    use std::{io::Write, path::Path};
+
+    /// Release assets served from memory
+    struct FakeReleases {
+        /// Name and contents of each asset
+        assets: Vec<(String, Vec<u8>)>,
+    }
+
+    impl FakeReleases {
+        /// Finds the contents of the `asset`
+        fn find(&self, asset: &GitHubReleaseAsset) -> Result<&[u8], SourceErrors> {
+            match self.assets.iter().find(|(name, _)| *name == asset.name) {
+                Some((_, contents)) => Ok(contents),
+                None => {
+                    let mut errors = SourceErrors::default();
+                    errors.push(&asset.browser_download_url, "404 Not Found", None);
+                    Err(errors)
+                }
+            }
+        }
+    }
+
+    impl ReleaseProvider for FakeReleases {
+        async fn download_asset(
+            &self,
+            asset: &GitHubReleaseAsset,
+            path: &Path,
+            _limit: Option<u32>,
+            mut on_progress: impl FnMut(DownloadProgress),
+        ) -> Result<(), SourceErrors> {
+            let contents = self.find(asset)?;
+            std::fs::write(path, contents).expect("failed to write download");
+
+            on_progress(DownloadProgress {
+                downloaded: contents.len() as u64,
+                total: Some(contents.len() as u64),
+            });
+
+            Ok(())
+        }
+
+        async fn fetch_asset(&self, asset: &GitHubReleaseAsset) -> Result<Bytes, SourceErrors> {
+            self.find(asset).map(Bytes::copy_from_slice)
+        }
+    }
+
+    /// Plugin served by the fake releases, the patched binkw32.dll is a
+    /// 32-bit DLL with an entry point so it passes the plugin checks
+    fn plugin_bytes() -> Vec<u8> {
+        Game::MassEffect3.profile().patch.patched.to_vec()
+    }
+
+    /// Creates a release of the `plugin` along with the provider serving
+    /// its assets, the release includes the `checksums` file when provided
+    fn create_release(plugin: Vec<u8>, checksums: Option<String>) -> (GitHubRelease, FakeReleases) {
+        let mut assets = vec![serde_json::json!({
+            "name": DEFAULT_ASSET_NAME,
+            "browser_download_url": format!("https://example.invalid/{DEFAULT_ASSET_NAME}"),
+            "size": plugin.len(),
+        })];
+        let mut contents = vec![(DEFAULT_ASSET_NAME.to_string(), plugin)];
+
+        if let Some(checksums) = checksums {
+            assets.push(serde_json::json!({
+                "name": "SHA256SUMS",
+                "browser_download_url": "https://example.invalid/SHA256SUMS",
+                "size": checksums.len(),
+            }));
+            contents.push(("SHA256SUMS".to_string(), checksums.into_bytes()));
+        }
+
+        let release = serde_json::from_value(serde_json::json!({
+            "html_url": "https://example.invalid/releases/tag/v1.0.0",
+            "tag_name": "v1.0.0",
+            "name": "v1.0.0",
+            "published_at": "2024-01-01T00:00:00Z",
+            "prerelease": false,
+            "assets": assets,
+        }))
+        .unwrap();
+
+        (release, FakeReleases { assets: contents })
+    }
+
+    /// Applies the plugin from the `release` to the `game` folder
+    async fn apply(
+        provider: &FakeReleases,
+        fs: &impl GameFs,
+        game: &TempDir,
+        release: GitHubRelease,
+    ) -> anyhow::Result<ChecksumStatus> {
+        let downloads = DownloadManager::default();
+        downloads.set_download_dir(Some(game.path().join("downloads")));
+
+        apply_plugin_with(
+            provider,
+            fs,
+            downloads,
+            game.path().to_path_buf(),
+            release,
+            DEFAULT_ASSET_NAME.to_string(),
+            |_| {},
+        )
+        .await
+    }
+
+    /// Plugin is installed along with its version and checked against the
+    /// release checksums
+    #[tokio::test]
+    async fn test_apply_plugin() {
+        let game = TempDir::new();
+        let plugin = plugin_bytes();
+        let checksums = format!("{}  {DEFAULT_ASSET_NAME}\n", sha256::digest(&plugin));
+        let (release, provider) = create_release(plugin.clone(), Some(checksums));
+
+        let status = apply(&provider, &TokioFs, &game, release).await.unwrap();
+
+        assert_eq!(status, ChecksumStatus::Verified);
+        assert_eq!(
+            std::fs::read(game.path().join(PLUGIN_DIR).join(PLUGIN_NAME)).unwrap(),
+            plugin
+        );
+        assert_eq!(
+            read_plugin_version_with(&TokioFs, game.path())
+                .await
+                .as_deref(),
+            Some("v1.0.0")
+        );
+        assert_eq!(
+            read_plugin_state(&TokioFs, game.path()),
+            PluginState::Installed
+        );
+    }
+
+    /// Each step of installing is reported in order, including the
+    /// download progress
+    #[tokio::test]
+    async fn test_apply_plugin_progress() {
+        let game = TempDir::new();
+        let plugin = plugin_bytes();
+        let size = plugin.len() as u64;
+        let (release, provider) = create_release(plugin, None);
+
+        let downloads = DownloadManager::default();
+        downloads.set_download_dir(Some(game.path().join("downloads")));
+
+        let mut steps = Vec::new();
+        apply_plugin_with(
+            &provider,
+            &TokioFs,
+            downloads,
+            game.path().to_path_buf(),
+            release,
+            DEFAULT_ASSET_NAME.to_string(),
+            |progress| steps.push(progress),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            steps,
+            [
+                Progress::step(ProgressStep::Checking),
+                Progress::download(DownloadProgress {
+                    downloaded: size,
+                    total: Some(size),
+                }),
+                Progress::step(ProgressStep::Verifying),
+                Progress::step(ProgressStep::Writing),
+            ]
+        );
+    }
+
+    /// Releases without checksums still install
+    #[tokio::test]
+    async fn test_apply_plugin_without_checksums() {
+        let game = TempDir::new();
+        let (release, provider) = create_release(plugin_bytes(), None);
+
+        let status = apply(&provider, &TokioFs, &game, release).await.unwrap();
+
+        assert_eq!(status, ChecksumStatus::Unavailable);
+    }
+
+    /// Downloads not matching the release checksums aren't installed
+    #[tokio::test]
+    async fn test_apply_plugin_checksum_mismatch() {
+        let game = TempDir::new();
+        let checksums = format!("{}  {DEFAULT_ASSET_NAME}\n", sha256::digest(b"other"));
+        let (release, provider) = create_release(plugin_bytes(), Some(checksums));
+
+        assert!(apply(&provider, &TokioFs, &game, release).await.is_err());
+        assert_eq!(
+            read_plugin_state(&TokioFs, game.path()),
+            PluginState::Absent
+        );
+    }
+
+    /// Files that aren't 32-bit DLLs are rejected before being installed
+    #[tokio::test]
+    async fn test_apply_plugin_not_dll() {
+        let game = TempDir::new();
+        let (release, provider) = create_release(b"not a plugin".to_vec(), None);
+
+        assert!(apply(&provider, &TokioFs, &game, release).await.is_err());
+        assert!(!game
+            .path()
+            .join(PLUGIN_DIR)
+            .join(PLUGIN_VERSION_NAME)
+            .exists());
+    }
+
+    /// Failing to download the plugin doesn't change the game folder
+    #[tokio::test]
+    async fn test_apply_plugin_download_failure() {
+        let game = TempDir::new();
+        let (release, mut provider) = create_release(plugin_bytes(), None);
+        provider.assets.clear();
+
+        assert!(apply(&provider, &TokioFs, &game, release).await.is_err());
+        assert!(!game.path().join(PLUGIN_DIR).exists());
+    }
+
+    /// Plugin downloaded for one game is reused for another without
+    /// downloading it again
+    #[tokio::test]
+    async fn test_apply_plugin_cached() {
+        let cache = TempDir::new();
+        let plugin = plugin_bytes();
+        let checksums = format!("{}  {DEFAULT_ASSET_NAME}\n", sha256::digest(&plugin));
+        let (release, mut provider) = create_release(plugin.clone(), Some(checksums));
+
+        let downloads = DownloadManager::default();
+        downloads.set_cache_dir(Some(cache.path().join("assets")));
+
+        let first = TempDir::new();
+        apply_plugin_with(
+            &provider,
+            &TokioFs,
+            downloads.clone(),
+            first.path().to_path_buf(),
+            release.clone(),
+            DEFAULT_ASSET_NAME.to_string(),
+            |_| {},
+        )
+        .await
+        .unwrap();
+
+        // Only the checksums remain available to download
+        provider
+            .assets
+            .retain(|(name, _)| name != DEFAULT_ASSET_NAME);
+
+        let second = TempDir::new();
+        let status = apply_plugin_with(
+            &provider,
+            &TokioFs,
+            downloads,
+            second.path().to_path_buf(),
+            release,
+            DEFAULT_ASSET_NAME.to_string(),
+            |_| {},
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(status, ChecksumStatus::Verified);
+        assert_eq!(
+            std::fs::read(second.path().join(PLUGIN_DIR).join(PLUGIN_NAME)).unwrap(),
+            plugin
+        );
+    }
+
+    /// Installed plugin is updated using the delta from its version
+    /// without downloading the full plugin
+    #[tokio::test]
+    async fn test_apply_plugin_delta() {
+        let game = TempDir::new();
+        let plugin = plugin_bytes();
+        let checksums = format!("{}  {DEFAULT_ASSET_NAME}\n", sha256::digest(&plugin));
+        let (mut release, mut provider) = create_release(plugin.clone(), Some(checksums));
+
+        // Previous version differs from the new plugin in its first byte
+        let mut old = plugin.clone();
+        old[0] = old[0].wrapping_add(1);
+        let asi_path = game.path().join(PLUGIN_DIR);
+        std::fs::create_dir_all(&asi_path).unwrap();
+        std::fs::write(asi_path.join(PLUGIN_NAME), &old).unwrap();
+        std::fs::write(asi_path.join(PLUGIN_VERSION_NAME), "v0.9.0").unwrap();
+
+        let mut patch = Vec::new();
+        patch.extend((plugin.len() as u64).to_le_bytes());
+        patch.extend(0u64.to_le_bytes());
+        patch.extend(0u64.to_le_bytes());
+        patch.extend(
+            plugin
+                .iter()
+                .zip(&old)
+                .map(|(new, old)| new.wrapping_sub(*old)),
+        );
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&patch).unwrap();
+        let delta = encoder.finish().unwrap();
+
+        let delta_name = format!("{DEFAULT_ASSET_NAME}.from-v0.9.0.bsdiff.gz");
+        release.assets.push(
+            serde_json::from_value(serde_json::json!({
+                "name": delta_name,
+                "browser_download_url": format!("https://example.invalid/{delta_name}"),
+                "size": delta.len(),
+            }))
+            .unwrap(),
+        );
+
+        // Only the checksums and delta are available to download
+        provider
+            .assets
+            .retain(|(name, _)| name != DEFAULT_ASSET_NAME);
+        provider.assets.push((delta_name, delta));
+
+        let status = apply(&provider, &TokioFs, &game, release).await.unwrap();
+
+        assert_eq!(status, ChecksumStatus::Verified);
+        assert_eq!(std::fs::read(asi_path.join(PLUGIN_NAME)).unwrap(), plugin);
+    }
+
+    /// Failing to write the plugin is reported and no version is recorded
+    #[tokio::test]
+    async fn test_apply_plugin_write_failure() {
+        let game = TempDir::new();
+        let (release, provider) = create_release(plugin_bytes(), None);
+        let fs = FailingFs {
+            file_name: PLUGIN_NAME,
+        };
+
+        assert!(apply(&provider, &fs, &game, release).await.is_err());
+        assert_eq!(read_plugin_state(&fs, game.path()), PluginState::Absent);
+        assert_eq!(read_plugin_version_with(&fs, game.path()).await, None);
+    }
+
+    /// Removing a partially removed plugin removes the remaining files,
+    /// including temporary files, and removing again succeeds
+    #[tokio::test]
+    async fn test_remove_plugin_partial() {
+        let game = TempDir::new();
+        let asi_path = game.path().join(PLUGIN_DIR);
+        std::fs::create_dir_all(&asi_path).unwrap();
+
+        let files = [
+            format!("{PLUGIN_NAME}.tmp"),
+            format!("{PLUGIN_NAME}.disabled"),
+            PLUGIN_VERSION_NAME.to_string(),
+        ];
+        for file in &files {
+            std::fs::write(asi_path.join(file), b"plugin").unwrap();
+        }
+
+        remove_plugin_with(&TokioFs, game.path().to_path_buf())
+            .await
+            .unwrap();
+
+        for file in &files {
+            assert!(!asi_path.join(file).exists(), "{file} was not removed");
+        }
+
+        remove_plugin_with(&TokioFs, game.path().to_path_buf())
+            .await
+            .unwrap();
+        assert_eq!(
+            read_plugin_state(&TokioFs, game.path()),
+            PluginState::Absent
+        );
+    }
+}
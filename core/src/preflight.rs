@@ -0,0 +1,221 @@
+//! Module for checking the paths required for an install before making
+//! any changes, so all the problems can be reported together instead of
+//! failing partway through with a confusing filesystem error
+
+use crate::{
+    fs_util::format_bytes,
+    plugin::{PLUGIN_DIR, PLUGIN_NAME},
+    profile::Game,
+};
+use anyhow::bail;
+use log::debug;
+use std::path::{Path, PathBuf};
+use sysinfo::Disks;
+
+/// Name of the temporary file used to probe write access
+const WRITE_PROBE_NAME: &str = ".pocket-relay-write-test";
+
+/// Free space kept spare on top of the files being written, files are
+/// written to a temporary file before replacing the existing file
+const FREE_SPACE_MARGIN: u64 = 1024 * 1024;
+
+/// Checks that the game folder at `game_path` is in a state the plugin
+/// can be installed into, reporting every problem found
+pub async fn check_install_paths(game_path: &Path) -> anyhow::Result<()> {
+    let mut problems: Vec<String> = Vec::new();
+
+    // Nothing else can be checked without the game folder
+    if !game_path.is_dir() {
+        bail!("Game folder {} does not exist", game_path.display());
+    }
+
+    // The plugin directory is created when missing, but a file with the
+    // same name prevents it from being created
+    let asi_path = game_path.join(PLUGIN_DIR);
+    if asi_path.exists() && !asi_path.is_dir() {
+        problems.push(format!(
+            "{} is a file, it must be a folder for plugins to be installed",
+            asi_path.display()
+        ));
+    }
+
+    let plugin_path = asi_path.join(PLUGIN_NAME);
+    if plugin_path.is_dir() {
+        problems.push(format!(
+            "{} is a folder, remove it so the plugin can be installed",
+            plugin_path.display()
+        ));
+    }
+
+    problems.extend(check_game_writable(game_path).await);
+
+    if !problems.is_empty() {
+        bail!(format_problems(&problems));
+    }
+
+    debug!("pre-flight checks passed for {}", game_path.display());
+
+    Ok(())
+}
+
+/// Checks that the folder at `game_path` can be patched for the `game`, the
+/// patch files and the backup of the existing binkw32.dll must fit on the drive
+pub async fn check_patch_paths(game: Game, game_path: &Path) -> anyhow::Result<()> {
+    if !game_path.is_dir() {
+        bail!("Game folder {} does not exist", game_path.display());
+    }
+
+    let mut problems: Vec<String> = check_game_writable(game_path).await.into_iter().collect();
+
+    if problems.is_empty() {
+        let patch = game.profile().patch;
+        let patch_size = (patch.patched.len() + patch.unpatched.len() * 2) as u64;
+        problems.extend(check_free_space(&[(game_path.to_path_buf(), patch_size)]).await);
+    }
+
+    if !problems.is_empty() {
+        bail!(format_problems(&problems));
+    }
+
+    Ok(())
+}
+
+/// Checks there is enough free space to download `download_size` bytes into
+/// the `download_dir` then write `install_size` bytes to the game folder at
+/// `game_path`, the sizes are combined when both are on the same drive
+pub async fn check_disk_space(
+    game_path: &Path,
+    download_dir: &Path,
+    download_size: u64,
+    install_size: u64,
+) -> anyhow::Result<()> {
+    let problems = check_free_space(&[
+        (download_dir.to_path_buf(), download_size),
+        (game_path.to_path_buf(), install_size),
+    ])
+    .await;
+
+    if !problems.is_empty() {
+        bail!(format_problems(&problems));
+    }
+
+    Ok(())
+}
+
+/// Checks that files can be written to the game folder at `game_path`,
+/// returning the problem when they can't
+async fn check_game_writable(game_path: &Path) -> Option<String> {
+    let err = probe_write(game_path).await.err()?;
+
+    debug!("game folder is not writable: {err}");
+
+    Some(match is_read_only_error(&err) {
+        true => format!(
+            "Game folder {} is on a read-only drive (such as a mounted disc image), \
+            install the game to a writable drive",
+            game_path.display()
+        ),
+        false => format!(
+            "Game folder {} is not writable, try running as administrator",
+            game_path.display()
+        ),
+    })
+}
+
+/// Checks each drive has enough free space for the files that will be
+/// written to it, `writes` are pairs of a folder and the bytes written to
+/// that folder. Drives that can't be found are skipped
+async fn check_free_space(writes: &[(PathBuf, u64)]) -> Vec<String> {
+    let writes = writes.to_vec();
+
+    tokio::task::spawn_blocking(move || {
+        let disks = Disks::new_with_refreshed_list();
+
+        // Bytes required on each of the drives, keyed by mount point
+        let mut required: Vec<(&sysinfo::Disk, u64)> = Vec::new();
+
+        for (path, size) in writes {
+            let disk = match find_disk(&disks, &path) {
+                Some(value) => value,
+                None => {
+                    debug!("unable to find drive for {}", path.display());
+                    continue;
+                }
+            };
+
+            match required
+                .iter_mut()
+                .find(|(existing, _)| existing.mount_point() == disk.mount_point())
+            {
+                Some((_, total)) => *total += size,
+                None => required.push((disk, size)),
+            }
+        }
+
+        required
+            .into_iter()
+            .filter(|(disk, size)| disk.available_space() < size + FREE_SPACE_MARGIN)
+            .map(|(disk, size)| {
+                format!(
+                    "Not enough free space on {}, {} is needed but only {} is available",
+                    disk.mount_point().display(),
+                    format_bytes(size + FREE_SPACE_MARGIN),
+                    format_bytes(disk.available_space())
+                )
+            })
+            .collect()
+    })
+    .await
+    .unwrap_or_default()
+}
+
+/// Attempts to create and remove a temporary file within the folder at
+/// `path`, returning the error when the file couldn't be created
+pub async fn probe_write(path: &Path) -> std::io::Result<()> {
+    let probe_path = path.join(WRITE_PROBE_NAME);
+
+    tokio::fs::write(&probe_path, []).await?;
+    _ = tokio::fs::remove_file(&probe_path).await;
+
+    Ok(())
+}
+
+/// Checks whether the `err` from writing a file was caused by the drive
+/// being read-only (e.g a mounted disc image)
+pub fn is_read_only_error(err: &std::io::Error) -> bool {
+    /// Windows error code for writing to write protected media
+    const ERROR_WRITE_PROTECT: i32 = 19;
+    /// Unix error code for writing to a read-only filesystem
+    const EROFS: i32 = 30;
+
+    match err.raw_os_error() {
+        Some(code) if cfg!(target_os = "windows") => code == ERROR_WRITE_PROTECT,
+        Some(code) => code == EROFS,
+        None => false,
+    }
+}
+
+/// Finds the disk containing the `path`, the disk mounted at the longest
+/// matching path is used as drives can be mounted within other drives.
+/// Folders that don't exist yet are matched using their closest parent
+fn find_disk<'a>(disks: &'a Disks, path: &Path) -> Option<&'a sysinfo::Disk> {
+    // Not canonicalized as Windows canonical paths have a prefix the mount
+    // points don't
+    let path = path.ancestors().find(|path| path.exists()).unwrap_or(path);
+
+    disks
+        .list()
+        .iter()
+        .filter(|disk| path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())
+}
+
+/// Formats the list of `problems` into a single message
+fn format_problems(problems: &[String]) -> String {
+    let mut message = String::from("Cannot install to the game folder:");
+    for problem in problems {
+        message.push_str("\n- ");
+        message.push_str(problem);
+    }
+    message
+}
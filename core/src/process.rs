@@ -0,0 +1,106 @@
+//! Module for detecting whether the game is currently running
+
+use crate::profile::Game;
+use log::debug;
+use std::{ffi::OsStr, fmt::Display, io, path::Path};
+use sysinfo::{ProcessRefreshKind, System, UpdateKind};
+use tokio::task::spawn_blocking;
+
+/// Checks if the executable of any of the supported games within the
+/// provided `game_path` is currently running.
+///
+/// This function performs blocking IO and should be run on a blocking thread
+pub fn is_game_running(game_path: &Path) -> bool {
+    let mut system = System::new();
+    system
+        .refresh_processes_specifics(ProcessRefreshKind::new().with_exe(UpdateKind::OnlyIfNotSet));
+
+    system.processes().values().any(|process| {
+        let exe_name = match find_exe_name(OsStr::new(process.name())) {
+            Some(value) => value,
+            None => return false,
+        };
+        let exe_path = game_path.join(exe_name);
+
+        let running = match process.exe() {
+            // Process is running from the selected game folder
+            Some(path) if is_exe_file(path) => same_file(path, &exe_path),
+            // Process path is unavailable or the game is being run through a
+            // compatibility layer (Wine/Proton), assume its the same game
+            _ => true,
+        };
+
+        debug!(
+            "found game process {} (running: {running}) at {:?}",
+            process.pid(),
+            process.exe()
+        );
+
+        running
+    })
+}
+
+/// Finds the name of the game executable matching the `name`
+fn find_exe_name(name: &OsStr) -> Option<&'static str> {
+    Game::ALL
+        .into_iter()
+        .map(|game| game.profile().exe_name)
+        .find(|exe_name| name.eq_ignore_ascii_case(exe_name))
+}
+
+/// Checks if the provided path is a game executable path
+fn is_exe_file(path: &Path) -> bool {
+    path.file_name().and_then(find_exe_name).is_some()
+}
+
+/// Checks if both paths refer to the same file
+fn same_file(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+/// Error for when the game files can't be modified because the
+/// game is currently running
+#[derive(Debug)]
+pub struct GameRunningError;
+
+impl Display for GameRunningError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("the game is currently running")
+    }
+}
+
+impl std::error::Error for GameRunningError {}
+
+/// Checks whether the `err` from changing a game file was caused by the
+/// file being open in another process, Windows doesn't allow files loaded
+/// by the running game to be replaced or removed
+pub fn is_file_in_use_error(err: &io::Error) -> bool {
+    /// Windows error code for a file opened by another process
+    const ERROR_SHARING_VIOLATION: i32 = 32;
+    /// Windows error code for a file locked by another process
+    const ERROR_LOCK_VIOLATION: i32 = 33;
+
+    cfg!(target_os = "windows")
+        && matches!(
+            err.raw_os_error(),
+            Some(ERROR_SHARING_VIOLATION | ERROR_LOCK_VIOLATION)
+        )
+}
+
+/// Ensures the game within `game_path` is not running, returns a
+/// [GameRunningError] if the game is running
+pub async fn ensure_game_not_running(game_path: &Path) -> anyhow::Result<()> {
+    let game_path = game_path.to_path_buf();
+    let running = spawn_blocking(move || is_game_running(&game_path))
+        .await
+        .unwrap_or_default();
+
+    if running {
+        return Err(GameRunningError.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+//! Module for the profiles describing each of the supported games, the game
+//! specific details (executable, store folders, patch files) are read from
+//! the profile of the selected game so another game or variant of a game can
+//! be supported by adding a profile
+
+use crate::{
+    bink::{PatchFiles, MASS_EFFECT_3_PATCH},
+    pe::MACHINE_I386,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// Profile for the original release of Mass Effect 3
+const MASS_EFFECT_3: GameProfile = GameProfile {
+    name: "Mass Effect 3",
+    install_folder: "Mass Effect 3",
+    exe_name: "MassEffect3.exe",
+    exe_dir: &["Binaries", "Win32"],
+    steam_app_id: "1238020",
+    product_name: "Mass Effect 3",
+    major_version: "1",
+    launcher_exe: "MassEffectLauncher.exe",
+    machine: MACHINE_I386,
+    patch: &MASS_EFFECT_3_PATCH,
+};
+
+/// Games the installer supports
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Game {
+    /// Mass Effect 3 (original release)
+    #[default]
+    #[serde(rename = "me3")]
+    MassEffect3,
+}
+
+impl Game {
+    /// All the supported games
+    pub const ALL: [Game; 1] = [Game::MassEffect3];
+
+    /// Profile describing the game
+    pub fn profile(&self) -> &'static GameProfile {
+        match self {
+            Game::MassEffect3 => &MASS_EFFECT_3,
+        }
+    }
+}
+
+impl Display for Game {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.profile().name)
+    }
+}
+
+/// Details of a supported game
+pub struct GameProfile {
+    /// Name of the game shown to the user
+    pub name: &'static str,
+    /// Name of the install folder used by the stores
+    pub install_folder: &'static str,
+    /// Name of the game executable
+    pub exe_name: &'static str,
+    /// Path to the folder containing the executable relative to the
+    /// install folder
+    pub exe_dir: &'static [&'static str],
+    /// Steam app ID of the game
+    pub steam_app_id: &'static str,
+    /// Product name within the version resource of the game executable
+    pub product_name: &'static str,
+    /// Major file version of the game executable
+    pub major_version: &'static str,
+    /// Name of the launcher executable that is commonly picked by mistake
+    pub launcher_exe: &'static str,
+    /// Machine type of the game executable
+    pub machine: u16,
+    /// Files used to patch the game
+    pub patch: &'static PatchFiles,
+}
@@ -0,0 +1,88 @@
+//! Module for the progress reported by operations that take multiple steps
+//! (e.g installing the plugin), letting the user interface show the current
+//! step rather than only whether the operation is still running
+
+use crate::github::DownloadProgress;
+
+/// Step of an operation that changes the game files
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressStep {
+    /// Checking the game folder before making any changes
+    Checking,
+    /// Downloading files
+    Downloading,
+    /// Verifying downloaded or written files
+    Verifying,
+    /// Writing files to the game folder
+    Writing,
+}
+
+/// Progress of an operation that changes the game files
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    /// Current step of the operation
+    pub step: ProgressStep,
+    /// Number of bytes the step has processed so far
+    pub bytes: u64,
+    /// Total number of bytes the step will process when known
+    pub total: Option<u64>,
+}
+
+impl Progress {
+    /// Creates the progress for the start of the `step`
+    pub fn step(step: ProgressStep) -> Self {
+        Self {
+            step,
+            bytes: 0,
+            total: None,
+        }
+    }
+
+    /// Creates the progress for a download from its download `progress`
+    pub fn download(progress: DownloadProgress) -> Self {
+        Self {
+            step: ProgressStep::Downloading,
+            bytes: progress.downloaded,
+            total: progress.total,
+        }
+    }
+
+    /// Fraction of the step that has completed between 0 and 1, none
+    /// when the total is unknown
+    pub fn fraction(&self) -> Option<f32> {
+        let total = self.total.filter(|total| *total > 0)?;
+        Some((self.bytes as f64 / total as f64).min(1.0) as f32)
+    }
+
+    /// Percentage of the step that has completed, none when the total
+    /// is unknown
+    pub fn percent(&self) -> Option<u8> {
+        self.fraction()
+            .map(|fraction| (fraction * 100.0).floor() as u8)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Progress, ProgressStep};
+    use crate::github::DownloadProgress;
+
+    /// Download progress is reported as a percentage once the total is known
+    #[test]
+    fn test_download_percent() {
+        let progress = Progress::download(DownloadProgress {
+            downloaded: 43,
+            total: Some(100),
+        });
+        assert_eq!(progress.step, ProgressStep::Downloading);
+        assert_eq!(progress.percent(), Some(43));
+
+        let progress = Progress::download(DownloadProgress {
+            downloaded: 43,
+            total: None,
+        });
+        assert_eq!(progress.percent(), None);
+
+        assert_eq!(Progress::step(ProgressStep::Writing).percent(), None);
+    }
+}
@@ -0,0 +1,78 @@
+//! Module for caching the release lists of repositories on disk, so
+//! reopening the installer doesn't use up the GitHub API rate limit and
+//! releases that were already seen are available offline
+
+use crate::{fs_util::atomic_write, github::GitHubRelease};
+use anyhow::Context;
+use log::{debug, error};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Name of the folder within the cache folder storing release lists
+const RELEASES_FOLDER: &str = "releases";
+
+/// Release list of a repository saved from a previous request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedReleases {
+    /// ETag of the first page of releases, used to check if the list changed
+    pub etag: Option<String>,
+    /// All the releases of the repository
+    pub releases: Vec<GitHubRelease>,
+    /// Number of pages of releases that were requested
+    #[serde(default)]
+    pub pages: usize,
+    /// Whether there were more pages than were requested
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+/// Determines the path to the cache file for the `repository` within the
+/// `cache_dir`
+fn cache_path(cache_dir: &Path, repository: &str) -> PathBuf {
+    let file_name = format!("{}.json", repository.replace('/', "_"));
+    cache_dir.join(RELEASES_FOLDER).join(file_name)
+}
+
+/// Reads the cached release list for the `repository`, none if the list
+/// was never cached or the cache is invalid
+pub async fn read_cached_releases(cache_dir: &Path, repository: &str) -> Option<CachedReleases> {
+    let path = cache_path(cache_dir, repository);
+    let contents = tokio::fs::read(&path).await.ok()?;
+
+    match serde_json::from_slice(&contents) {
+        Ok(value) => Some(value),
+        Err(err) => {
+            debug!("ignoring invalid release cache {}: {err}", path.display());
+            None
+        }
+    }
+}
+
+/// Saves the release list for the `repository` to the cache, failures are
+/// logged as the cache is only an optimization
+pub async fn write_cached_releases(cache_dir: &Path, repository: &str, cached: &CachedReleases) {
+    if let Err(err) = try_write_cached_releases(cache_dir, repository, cached).await {
+        error!("failed to write release cache: {err:#}");
+    }
+}
+
+async fn try_write_cached_releases(
+    cache_dir: &Path,
+    repository: &str,
+    cached: &CachedReleases,
+) -> anyhow::Result<()> {
+    let path = cache_path(cache_dir, repository);
+
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .context("failed to create cache directory")?;
+    }
+
+    let contents = serde_json::to_vec(cached).context("failed to serialize releases")?;
+    atomic_write(&path, contents).await?;
+
+    debug!("cached releases for {repository}");
+
+    Ok(())
+}
@@ -0,0 +1,212 @@
+//! Module for the end-to-end self test, builds a synthetic game folder and
+//! runs through detecting, patching, installing, verifying and uninstalling
+//! against it. Used by the tests and to let users check their environment
+//! (e.g antivirus software or permissions) doesn't break installs
+
+use crate::{
+    bink::{apply_patch, patch_state, remove_patch, verify_patch, PatchState},
+    game_fs::TokioFs,
+    plugin::{
+        apply_plugin_file, read_plugin_state, remove_plugin, verify_installed_plugin, PluginState,
+    },
+    profile::Game,
+};
+use anyhow::{bail, Context};
+use log::{debug, error};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// Name of the plugin file the self test installs from
+const TEST_PLUGIN_NAME: &str = "self-test-plugin.asi";
+
+/// Step of the self test
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SelfTestStep {
+    /// Create the synthetic game folder
+    CreateGame,
+    /// Detect the game folder is unpatched
+    Detect,
+    /// Apply the patch
+    Patch,
+    /// Install the plugin from a local file
+    Install,
+    /// Verify the patch and plugin
+    Verify,
+    /// Remove the plugin and patch
+    Uninstall,
+}
+
+impl SelfTestStep {
+    /// All the steps in the order they are run
+    pub const ALL: [SelfTestStep; 6] = [
+        SelfTestStep::CreateGame,
+        SelfTestStep::Detect,
+        SelfTestStep::Patch,
+        SelfTestStep::Install,
+        SelfTestStep::Verify,
+        SelfTestStep::Uninstall,
+    ];
+
+    /// Description of the step
+    pub fn description(&self) -> &'static str {
+        match self {
+            SelfTestStep::CreateGame => "Create test game folder",
+            SelfTestStep::Detect => "Detect game",
+            SelfTestStep::Patch => "Apply patch",
+            SelfTestStep::Install => "Install plugin",
+            SelfTestStep::Verify => "Verify patch and plugin",
+            SelfTestStep::Uninstall => "Uninstall plugin and patch",
+        }
+    }
+}
+
+/// Result of a step of the self test
+#[derive(Debug, Clone, Serialize)]
+pub struct SelfTestResult {
+    /// Step that was run
+    pub step: SelfTestStep,
+    /// Whether the step passed, steps after a failed step are skipped
+    pub status: SelfTestStatus,
+    /// Error when the step failed
+    pub error: Option<String>,
+}
+
+/// Status of a step of the self test
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SelfTestStatus {
+    /// Step passed
+    Passed,
+    /// Step failed
+    Failed,
+    /// Step wasn't run as an earlier step failed
+    Skipped,
+}
+
+/// Runs the self test for the `game` within the `dir` folder, the folder is
+/// created and should be empty. Provides the result of each step
+pub async fn run_self_test(game: Game, dir: &Path) -> Vec<SelfTestResult> {
+    let game_path = dir.join("game");
+    let mut results = Vec::with_capacity(SelfTestStep::ALL.len());
+    let mut failed = false;
+
+    for step in SelfTestStep::ALL {
+        if failed {
+            results.push(SelfTestResult {
+                step,
+                status: SelfTestStatus::Skipped,
+                error: None,
+            });
+            continue;
+        }
+
+        let result = match step {
+            SelfTestStep::CreateGame => create_test_game(game, dir, &game_path).await,
+            SelfTestStep::Detect => detect(game, &game_path).await,
+            SelfTestStep::Patch => apply_patch(game, game_path.clone(), |_| {}).await,
+            SelfTestStep::Install => install(dir, &game_path).await,
+            SelfTestStep::Verify => verify(game, &game_path).await,
+            SelfTestStep::Uninstall => uninstall(game, &game_path).await,
+        };
+
+        let result = match result {
+            Ok(()) => {
+                debug!("self test step passed: {step:?}");
+                SelfTestResult {
+                    step,
+                    status: SelfTestStatus::Passed,
+                    error: None,
+                }
+            }
+            Err(err) => {
+                error!("self test step failed: {step:?}: {err:?}");
+                failed = true;
+                SelfTestResult {
+                    step,
+                    status: SelfTestStatus::Failed,
+                    error: Some(format!("{err:#}")),
+                }
+            }
+        };
+
+        results.push(result);
+    }
+
+    results
+}
+
+/// Creates the synthetic folder for the `game` at `game_path` with a
+/// placeholder game executable and the official binkw32.dll, along with
+/// the plugin file to install within the `dir`
+async fn create_test_game(game: Game, dir: &Path, game_path: &Path) -> anyhow::Result<()> {
+    let profile = game.profile();
+
+    tokio::fs::create_dir_all(game_path)
+        .await
+        .context("failed to create game folder")?;
+    tokio::fs::write(game_path.join(profile.exe_name), b"MZ")
+        .await
+        .context("failed to create game executable")?;
+    tokio::fs::write(game_path.join("binkw32.dll"), profile.patch.unpatched)
+        .await
+        .context("failed to create binkw32.dll")?;
+
+    // The patched binkw32.dll is a 32-bit DLL with an entry point so it
+    // passes the checks for a loadable plugin
+    tokio::fs::write(test_plugin_path(dir), profile.patch.patched)
+        .await
+        .context("failed to create plugin file")?;
+
+    Ok(())
+}
+
+/// Path to the plugin file to install within the `dir`
+fn test_plugin_path(dir: &Path) -> PathBuf {
+    dir.join(TEST_PLUGIN_NAME)
+}
+
+/// Checks the game folder at `game_path` is detected as an unpatched `game`
+async fn detect(game: Game, game_path: &Path) -> anyhow::Result<()> {
+    let exe_name = game.profile().exe_name;
+    if !game_path.join(exe_name).is_file() {
+        bail!("{exe_name} is missing");
+    }
+
+    match patch_state(game, game_path).await? {
+        PatchState::Unpatched => Ok(()),
+        state => bail!("expected the game to be unpatched, found {state:?}"),
+    }
+}
+
+/// Installs the plugin file from the `dir` to the game folder at `game_path`
+async fn install(dir: &Path, game_path: &Path) -> anyhow::Result<()> {
+    apply_plugin_file(game_path.to_path_buf(), test_plugin_path(dir)).await
+}
+
+/// Verifies the patch and plugin in the game folder at `game_path`
+async fn verify(game: Game, game_path: &Path) -> anyhow::Result<()> {
+    verify_patch(game, game_path).await?;
+    verify_installed_plugin(game_path).await?;
+
+    match patch_state(game, game_path).await? {
+        PatchState::Patched => Ok(()),
+        state => bail!("expected the game to be patched, found {state:?}"),
+    }
+}
+
+/// Removes the plugin and patch from the game folder at `game_path` then
+/// checks the game was restored
+async fn uninstall(game: Game, game_path: &Path) -> anyhow::Result<()> {
+    remove_plugin(game_path.to_path_buf()).await?;
+    remove_patch(game, game_path.to_path_buf()).await?;
+
+    if read_plugin_state(&TokioFs, game_path) != PluginState::Absent {
+        bail!("plugin is still installed");
+    }
+
+    match patch_state(game, game_path).await? {
+        PatchState::Unpatched => Ok(()),
+        state => bail!("expected the original binkw32.dll to be restored, found {state:?}"),
+    }
+}
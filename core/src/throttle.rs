@@ -0,0 +1,85 @@
+//! Module for limiting the download speed, so the installer doesn't
+//! saturate metered or shared connections
+
+use bytes::Bytes;
+use futures_util::Stream;
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::time::{sleep_until, Instant, Sleep};
+
+/// Number of bytes in a KiB, download limits are configured in KiB/s
+const KIB: u64 = 1024;
+
+/// Stream of downloaded chunks that delays reading further chunks while
+/// the average download speed is above the limit
+pub struct Throttled<S> {
+    /// Stream of downloaded chunks
+    inner: S,
+    /// Maximum download speed in bytes per second, none for unlimited
+    limit: Option<u64>,
+    /// When the first chunk was requested
+    started: Option<Instant>,
+    /// Total number of bytes read from the stream
+    downloaded: u64,
+    /// Delay before the next chunk can be read
+    delay: Option<Pin<Box<Sleep>>>,
+}
+
+/// Wraps the `stream` limiting it to `limit` KiB/s, none for unlimited
+pub fn throttle<S>(stream: S, limit: Option<u32>) -> Throttled<S> {
+    Throttled {
+        inner: stream,
+        limit: limit.map(|limit| limit as u64 * KIB),
+        started: None,
+        downloaded: 0,
+        delay: None,
+    }
+}
+
+impl<S, E> Stream for Throttled<S>
+where
+    S: Stream<Item = Result<Bytes, E>> + Unpin,
+{
+    type Item = Result<Bytes, E>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        if let Some(delay) = &mut this.delay {
+            if delay.as_mut().poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+            this.delay = None;
+        }
+
+        let limit = match this.limit {
+            Some(value) => value,
+            None => return Pin::new(&mut this.inner).poll_next(cx),
+        };
+
+        let started = *this.started.get_or_insert_with(Instant::now);
+
+        let item = match Pin::new(&mut this.inner).poll_next(cx) {
+            Poll::Ready(value) => value,
+            Poll::Pending => return Poll::Pending,
+        };
+
+        if let Some(Ok(chunk)) = &item {
+            this.downloaded += chunk.len() as u64;
+
+            // Time the downloaded bytes should have taken at the limit
+            let expected = Duration::from_secs_f64(this.downloaded as f64 / limit as f64);
+            let deadline = started + expected;
+
+            if deadline > Instant::now() {
+                this.delay = Some(Box::pin(sleep_until(deadline)));
+            }
+        }
+
+        Poll::Ready(item)
+    }
+}
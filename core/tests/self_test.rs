@@ -0,0 +1,71 @@
+//! End-to-end tests installing to a synthetic game folder through the
+//! public API of the library
+
+use pocket_relay_installer_core::{
+    bink::{apply_patch, patch_state, PatchState},
+    game_fs::test_util::TempDir,
+    profile::Game,
+    self_test::{run_self_test, SelfTestStatus, SelfTestStep},
+};
+
+/// Every step of the self test passes in a writable folder
+#[tokio::test]
+async fn test_self_test_passes() {
+    let dir = TempDir::new();
+
+    let results = run_self_test(Game::MassEffect3, dir.path()).await;
+
+    let steps: Vec<SelfTestStep> = results.iter().map(|result| result.step).collect();
+    assert_eq!(steps, SelfTestStep::ALL);
+
+    for result in results {
+        assert_eq!(
+            result.status,
+            SelfTestStatus::Passed,
+            "{:?} failed: {:?}",
+            result.step,
+            result.error
+        );
+    }
+}
+
+/// Steps after a failed step are skipped
+#[tokio::test]
+async fn test_self_test_skips_after_failure() {
+    let dir = TempDir::new();
+
+    // A file in place of the game folder can't be created
+    std::fs::write(dir.path().join("game"), b"").unwrap();
+
+    let results = run_self_test(Game::MassEffect3, dir.path()).await;
+
+    assert_eq!(results[0].step, SelfTestStep::CreateGame);
+    assert_eq!(results[0].status, SelfTestStatus::Failed);
+    assert!(results[0].error.is_some());
+    assert!(results[1..]
+        .iter()
+        .all(|result| result.status == SelfTestStatus::Skipped));
+}
+
+/// Patching a game that was already patched keeps it patched
+#[tokio::test]
+async fn test_patch_twice() {
+    let dir = TempDir::new();
+    let results = run_self_test(Game::MassEffect3, dir.path()).await;
+    assert!(results
+        .iter()
+        .all(|result| result.status == SelfTestStatus::Passed));
+
+    let game_path = dir.path().join("game");
+    apply_patch(Game::MassEffect3, game_path.clone(), |_| {})
+        .await
+        .unwrap();
+    apply_patch(Game::MassEffect3, game_path.clone(), |_| {})
+        .await
+        .unwrap();
+
+    assert_eq!(
+        patch_state(Game::MassEffect3, &game_path).await.unwrap(),
+        PatchState::Patched
+    );
+}
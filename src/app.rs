@@ -1,21 +1,89 @@
 use crate::{
-    bink::{apply_patch, is_patched, remove_patch},
-    github::GitHubRelease,
+    asi::{delete_asi_plugin, list_asi_plugins, set_asi_plugin_enabled, AsiPlugin},
+    asset_cache::{asset_cache_dir, cache_usage, clear_cache, CacheUsage},
+    backup::restore_plugin_backup,
+    bink::{
+        apply_patch, patch_state_with, plan_apply_patch, plan_remove_patch, remove_patch,
+        verify_embedded_resources, PatchState,
+    },
+    compat::{scan_compatibility, Finding, Severity},
+    config::{portable_dir, save_config, Config, ProxyConfig, ReleaseChannel},
+    crash::set_app_state,
+    deep_link::{parse_link, register_link_handler},
+    details::{read_game_details, GameDetails},
+    diagnostics::collect_diagnostics,
+    downloads::{DownloadId, DownloadItem, DownloadManager, DownloadStatus},
+    elevation::{check_folder_access, relaunch_elevated, FolderAccess},
+    error::describe_error,
+    explorer::open_folder,
+    feed::{format_feeds, parse_feeds, ReleaseFeed},
+    format::{format_bytes, format_time, format_timestamp},
+    game_fs::{GameFs, TokioFs},
+    github::{parse_tag_version, ApiOptions, DownloadProgress, GitHubRelease},
+    hashes::embedded_manifest,
+    help::{load_help, HelpDocument, HelpSource},
+    history::{load_history, record_change, undo_change, HistoryAction, HistoryEntry},
+    http::{create_http_client, parse_proxy},
+    i18n::{set_language, tr, tr_args, Language},
+    install::{install_all, InstallStep},
+    launch::launch_game,
+    leftovers::{remove_leftovers, scan_leftovers, Leftover, LeftoverKind},
+    loader::{check_readiness, detect_asi_loaders, AsiLoader, Readiness},
+    locate::{default_picker_dir, find_game_paths, resolve_picked_path},
+    logging::recent_logs,
+    network::{run_network_checks, CheckOutcome, CheckResult, NetworkCheck},
+    notifications::notify,
+    plan::{ChangeKind, PlannedChange},
     plugin::{
-        apply_plugin, get_latest_beta_plugin_release, get_latest_plugin_release, remove_plugin,
+        apply_plugin, apply_plugin_file, check_plugin_quarantine, get_plugin_feed_releases,
+        get_plugin_releases, is_valid_asset_name, is_valid_repository, plan_apply_plugin,
+        plan_apply_plugin_file, plan_remove_plugin, read_plugin_state, read_plugin_version_with,
+        remove_plugin, set_plugin_enabled, ChecksumStatus, PluginSource, PluginState,
+        DEFAULT_ASSET_NAME, DEFAULT_GITHUB_REPOSITORY, PLUGIN_DIR, PLUGIN_NAME,
+    },
+    plugin_config::{read_plugin_config, write_plugin_config, PluginConfig},
+    plugin_log::{
+        export_plugin_log, read_plugin_log, PluginLogLevel, PluginLogLine, PLUGIN_LOG_NAME,
     },
+    process::GameRunningError,
+    profile::{game, profile, set_game, Game},
+    progress::{Progress, ProgressStep},
+    proton::{apply_launch_options, LAUNCH_OPTIONS},
+    report::{create_report, diff_reports, read_report, write_report, ReportDifference},
+    server::{check_compatibility, get_server_version, ServerCompatibility},
+    shortcut::SHORTCUT_EXTENSION,
+    state::{
+        ActionError, AlterPatchState, AlterPluginState, PatchStatus, PluginAction, PluginStatus,
+    },
+    stats::{send_usage_report, UsageOperation, UsageOutcome, UsageReport},
+    store::{detect_store, StoreKind},
+    theme::{create_theme, muted_text, parse_accent_color, ThemeMode},
+    tray::{tray_events, TrayAction, TrayEvent},
+    uninstall::uninstall_all,
+    update::{apply_update, check_for_update, required_installer_version},
+    validate::validate_game_exe,
+    watch::watch_game_folder,
 };
 use anyhow::Context;
+use futures_util::future::join_all;
 use iced::{
-    theme::Palette,
-    widget::{button, column, combo_box, container, row, scrollable, text, Button, Column, Text},
+    futures::SinkExt,
+    keyboard::{self, key::Named},
+    task,
+    widget::{
+        button, checkbox, column, combo_box, container, focus_next, focus_previous, markdown,
+        pick_list, progress_bar, radio, row, scrollable, slider, text, text_input, tooltip, Button,
+        Column, Text, Tooltip,
+    },
     window::{self, get_latest, icon, resize},
-    Color, Length, Size, Task,
+    Length, Size, Subscription, Task, Theme,
 };
 use log::{debug, error};
+use semver::Version;
 use std::{
     fmt::Display,
     path::{Path, PathBuf},
+    time::Duration,
 };
 use tokio::task::spawn_blocking;
 
@@ -25,28 +93,117 @@ pub const WINDOW_TITLE: &str =
 /// Window icon bytes
 pub const ICON_BYTES: &[u8] = include_bytes!("./resources/icon.ico");
 
-/// The window size
+/// The window size at 100% interface scale
 const WINDOW_SIZE: Size<f32> = Size::new(500.0, 140.0);
 const EXPANDED_WINDOW_SIZE: Size<f32> = Size::new(500.0, 300.0);
-const DARK_TEXT: Color = Color::from_rgb(0.4, 0.4, 0.4);
+/// Width of the installations sidebar
+const SIDEBAR_WIDTH: f32 = 200.0;
 const SPACING: u16 = 10;
 
+/// Interval between checks for a newer plugin release while the plugin
+/// is installed
+const PLUGIN_UPDATE_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Default file name for exported installation reports
+const REPORT_FILE_NAME: &str = "pocket-relay-report.json";
+
+/// Interval between reads of the plugin log while the log viewer is open
+const PLUGIN_LOG_REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Highest download speed limit selectable in the settings in KiB/s
+const MAX_DOWNLOAD_LIMIT: u32 = 10240;
+/// Step between selectable download speed limits in KiB/s
+const DOWNLOAD_LIMIT_STEP: u32 = 256;
+
+/// Largest number of release pages selectable in the settings
+const MAX_RELEASE_PAGES: u32 = 30;
+
+/// Smallest and largest interface scales selectable in the settings in percent
+const MIN_UI_SCALE_PERCENT: u32 = 75;
+const MAX_UI_SCALE_PERCENT: u32 = 200;
+/// Step between selectable interface scales in percent
+const UI_SCALE_STEP: u32 = 25;
+
 /// Initializes the user interface
 ///
 /// ## Arguments
-/// * `config` - The client config to use
-/// * `client` - The HTTP client to use
-pub fn init() {
-    iced::application(WINDOW_TITLE, App::update, App::view)
+/// * `config` - The installer config to use
+pub fn init(config: Config, launch_path: Option<PathBuf>, launch_link: Option<String>) {
+    // Sizes are in logical pixels so they already follow the display scale
+    let ui_scale = config.ui_scale();
+
+    iced::application(WINDOW_TITLE, App::update_tracked, App::view)
         .window(window::Settings {
             icon: icon::from_file_data(ICON_BYTES, None).ok(),
-            size: WINDOW_SIZE,
-            resizable: false,
+            size: WINDOW_SIZE * ui_scale,
+            min_size: Some(WINDOW_SIZE * ui_scale),
+            resizable: true,
+            // Closing may hide the window to the tray instead
+            exit_on_close_request: false,
 
             ..window::Settings::default()
         })
-        .theme(|_| iced::Theme::Dark)
-        .run_with(|| (Default::default(), plugin_details_task()))
+        .theme(|app: &App| app.theme.clone())
+        .scale_factor(|app: &App| app.config.ui_scale() as f64)
+        .subscription(App::subscription)
+        .run_with(move || {
+            let http_client = create_http_client(&config.proxy).unwrap_or_else(|err| {
+                error!("failed to create http client from proxy config: {err:?}");
+                create_http_client(&ProxyConfig::System).expect("failed to create http client")
+            });
+
+            let mut tasks = vec![
+                detect_game_paths_task(),
+                installer_update_task(http_client.clone(), &config),
+            ];
+
+            // Load straight into the game the installer was opened with,
+            // otherwise the previously used game path
+            match (launch_path, config.game_path.clone()) {
+                (Some(launch_path), _) => tasks.push(launch_game_state_task(launch_path)),
+                (None, Some(game_path)) => tasks.push(saved_game_state_task(game_path)),
+                (None, None) => {}
+            }
+
+            let accent_color = config.accent_color.as_deref().and_then(parse_accent_color);
+
+            let downloads = DownloadManager::default();
+            downloads.set_speed_limit(config.download_limit);
+            downloads.set_download_dir(config.download_dir());
+            downloads.set_cache_dir(config.asset_cache_dir());
+
+            // Check the embedded DLLs before they can be written to the game
+            let resources_error = verify_embedded_resources(game()).err().map(|err| {
+                error!("embedded resources failed verification: {err:#}");
+                format!("{err:#}")
+            });
+
+            // Server from the link the installer was opened with is filled
+            // in once the plugin config of the game is loaded
+            let (link_server, link_error) = match launch_link.as_deref().map(parse_link) {
+                Some(Ok(link)) => (link.server, None),
+                Some(Err(err)) => {
+                    error!("failed to open link: {err:#}");
+                    (None, Some(format!("{err:#}")))
+                }
+                None => (None, None),
+            };
+
+            let mut app = App {
+                theme: create_theme(config.theme, accent_color),
+                downloads,
+                resources_error,
+                link_server,
+                link_error,
+                config,
+                http_client,
+                ..Default::default()
+            };
+
+            tasks.push(app.load_plugin_details().map(AppMessage::PluginDetails));
+
+            (app, Task::batch(tasks))
+        })
         .expect("failed to start");
 }
 
@@ -56,8 +213,161 @@ struct App {
 
     /// Status for the remote plugin details
     plugin_details_state: PluginDetailsState,
+
+    /// Handle for the in-flight load of the plugin details, aborted when
+    /// the details are loaded again
+    plugin_details_handle: Option<task::Handle>,
+
+    /// Game executable paths that were automatically detected
+    detected_paths: Vec<PathBuf>,
+
+    /// Persisted installer settings
+    config: Config,
+
+    /// Status of updating the installer itself
+    installer_update_state: InstallerUpdateState,
+
+    /// HTTP client for making requests, configured with the proxy settings
+    http_client: reqwest::Client,
+
+    /// Queue for the downloads made by the installer
+    downloads: DownloadManager,
+
+    /// Downloads shown in the downloads panel
+    download_items: Vec<DownloadItem>,
+
+    /// Error from verifying the DLLs embedded in the installer, the
+    /// patch can't be applied when present
+    resources_error: Option<String>,
+
+    /// Server address from the link the installer was opened with, shown
+    /// until its saved to the plugin config or dismissed
+    link_server: Option<String>,
+    /// Error from parsing the link the installer was opened with
+    link_error: Option<String>,
+
+    /// Theme created from the theme settings
+    theme: Theme,
+
+    /// State of the settings screen while its open
+    settings: Option<SettingsState>,
+
+    /// State of the help screen while its open
+    help: Option<HelpState>,
+
+    /// State of the history screen while its open
+    history: Option<HistoryState>,
+
+    /// Error from the last attempt to switch installations
+    installation_error: Option<String>,
+
+    /// Whether the log panel is expanded
+    show_logs: bool,
+
+    /// Whether the diagnostics were copied to the clipboard
+    diagnostics_copied: bool,
+
+    /// State of the network diagnostics
+    network_checks: NetworkChecksState,
+
+    /// State of adding the DLL overrides to the Steam launch options
+    proton_state: ProtonState,
+
+    /// Whether the window is in the background, notifications are shown
+    /// when long operations finish while unfocused
+    unfocused: bool,
+
+    /// Whether the tray icon was created, the window is only hidden to the
+    /// tray while it exists
+    tray_ready: bool,
+}
+
+/// State for the history screen
+pub struct HistoryState {
+    /// Recorded changes to the game, newest first. None while loading
+    entries: Option<Result<Vec<HistoryEntry>, String>>,
+    /// Whether the most recent change is being undone
+    undoing: bool,
+    /// Error from the last attempt to undo a change
+    undo_error: Option<String>,
+}
+
+/// State for the help screen
+pub struct HelpState {
+    /// Loaded troubleshooting guide and where it was loaded from, none
+    /// while loading
+    guide: Option<(HelpDocument, HelpSource)>,
+    /// Index of the expanded topic
+    expanded: Option<usize>,
+}
+
+/// State for the settings screen
+pub struct SettingsState {
+    /// Selected proxy mode
+    proxy_mode: ProxyMode,
+    /// Manual proxy URL input
+    proxy_url: String,
+    /// Mirrors input, comma separated
+    mirrors: String,
+    /// GitHub personal access token input
+    github_token: String,
+    /// Selected user interface language
+    language: Language,
+    /// Selected game to install to
+    game: Game,
+    /// Selected theme mode
+    theme_mode: ThemeMode,
+    /// Accent color input, empty to use the theme color
+    accent_color: String,
+    /// Plugin repository input, empty to use the default repository
+    plugin_repository: String,
+    /// Plugin asset name input, empty to use the default asset name
+    plugin_asset_name: String,
+    /// Download speed limit slider value in KiB/s, zero for unlimited
+    download_limit: u32,
+    /// Release page limit slider value
+    max_release_pages: u32,
+    /// Selected default plugin release channel
+    release_channel: ReleaseChannel,
+    /// Release feeds input, comma separated "name=url" entries
+    release_feeds: String,
+    /// Cache folder input, empty to use the system folders
+    cache_dir: String,
+    /// Whether to keep downloaded plugins for reinstalling
+    cache_downloads: bool,
+    /// Size of the download cache, none while its being checked
+    cache_usage: Option<CacheUsage>,
+    /// Whether the download cache is being cleared
+    clearing_cache: bool,
+    /// Whether the installer is being registered to open links
+    registering_links: bool,
+    /// Whether the installer was registered to open links
+    registered_links: bool,
+    /// Interface scale slider value in percent
+    ui_scale: u32,
+    /// Whether to offer applying the patch again when the store removes it
+    detect_patch_reverts: bool,
+    /// Whether closing the window hides it to the tray
+    tray_mode: bool,
+    /// Whether sending anonymous usage statistics is enabled
+    usage_stats: bool,
+    /// Usage statistics endpoint input
+    usage_stats_endpoint: String,
+    /// Example of a usage report exactly as it would be sent
+    usage_stats_preview: String,
+    /// Error from validating the settings
+    error: Option<String>,
+}
+
+/// Proxy mode options for the settings screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProxyMode {
+    System,
+    Disabled,
+    Manual,
 }
 
+#[allow(clippy::large_enum_variant)]
 pub enum AppState {
     /// Initial state, no game has been picked yet
     Initial(AppStateInitial),
@@ -80,626 +390,6709 @@ pub struct AppStateInitial {
 }
 
 pub struct AppStateActive {
-    /// Whether the game is patched
-    patched: bool,
+    /// State of the game patch and the status of changing it
+    patch: PatchStatus,
 
-    /// Whether the plugin is installed
-    plugin: bool,
+    /// State of the plugin and the status of changing it
+    plugin: PluginStatus,
 
     /// Selected game folder path
     path: PathBuf,
 
-    /// Current status of adding/removing a plugin
-    alter_plugin_state: AlterPluginState,
+    /// Store the game was installed from
+    store: StoreKind,
 
-    /// Current status of adding/removing the patch
-    alter_patch_state: AlterPatchState,
-}
+    /// ASI loaders other than the patch
+    loaders: Vec<AsiLoader>,
 
-#[derive(Debug, Clone)]
-enum PatchMessage {
-    /// Applies the patch to the game
-    Add,
-    /// Remove the patch from the game
-    Remove,
+    /// Current state of the ASI plugins list
+    asi_plugins_state: AsiPluginsState,
 
-    /// Result of applying the patch to the game
-    Added(Result<(), String>),
-    /// Result of removing the patch from the game
-    Removed(Result<(), String>),
-}
+    /// Current state of the compatibility scan
+    compat_state: CompatState,
 
-#[derive(Debug, Clone)]
-enum PluginMessage {
-    /// Adds the plugin to the game
-    Add,
-    /// Remove the plugin from the game
-    Remove,
-    /// Select a different plugin version type
-    SelectType(ReleaseType),
+    /// Current state of the leftover files scan
+    leftovers_state: LeftoversState,
 
-    /// Result of adding the plugin to the game
-    Added(Result<(), String>),
-    /// Result of removing the plugin from the game
-    Removed(Result<(), String>),
-}
+    /// Current state of exporting or comparing an installation report
+    report_state: ReportState,
 
-#[derive(Debug, Clone)]
-#[allow(clippy::large_enum_variant)]
-enum AppMessage {
-    /// Messages related to picking the game
-    Game(GameMessage),
+    /// Whether the plugin log viewer is open
+    show_plugin_log: bool,
 
-    /// Messages related to patching the game
-    Patch(PatchMessage),
+    /// Current state of the plugin log viewer
+    plugin_log_state: PluginLogState,
 
-    /// Messages related to adding/removing the plugin
-    Plugin(PluginMessage),
+    /// Result of the last copy or export of the plugin log
+    plugin_log_notice: Option<PluginLogNotice>,
 
-    /// Messages related to loading the plugin details
-    PluginDetails(PluginDetailsMessage),
-}
+    /// Error from the last ASI plugin change
+    asi_plugins_error: Option<String>,
 
-#[derive(Debug, Clone)]
-enum GameMessage {
-    /// Trigger the popup to allow the user to pick the game path
-    PickGamePath,
-    // Result of picking a game path
-    PickedGameResult(Result<Option<GameState>, String>),
-    /// Clears the active game path
-    ClearGamePath,
-}
+    /// Path of the ASI plugin the user is confirming to delete
+    asi_delete_confirm: Option<PathBuf>,
 
-#[derive(Debug, Clone)]
-enum PluginDetailsMessage {
-    /// Result of adding the plugin to the game
-    Loaded(Result<PluginDetails, String>),
-}
+    /// Current state of the plugin config editor
+    plugin_config_state: PluginConfigState,
 
-/// Current state for the plugin details (Remote state from github)
-#[derive(Default)]
-#[allow(clippy::large_enum_variant)]
-pub enum PluginDetailsState {
-    /// Loading details about the plugin
-    #[default]
-    Loading,
+    /// Loaded plugin config
+    plugin_config: PluginConfig,
 
-    /// Failed to load details about the plugin
-    Error(String),
+    /// Server connection URL input
+    connection_url: String,
 
-    /// Ready
-    Ready(PluginDetails),
+    /// Current state of previewing the changes for an action
+    preview_state: PreviewState,
+
+    /// Current status of installing both the patch and plugin
+    install_all_state: InstallAllState,
+
+    /// Results of installing to every known installation, empty until
+    /// a batch install is started
+    batch_results: Vec<BatchResult>,
+
+    /// Current status of uninstalling everything
+    uninstall_state: UninstallState,
+
+    /// Whether to remove the empty plugin directory when uninstalling
+    remove_plugin_dir: bool,
+
+    /// Whether to back up the plugin config and logs when uninstalling
+    backup_plugin_files: bool,
+
+    /// Access the installer has to the game folder, none until checked
+    folder_access: Option<FolderAccess>,
+
+    /// Whether the installer is waiting on the UAC prompt to restart
+    /// as administrator
+    relaunching: bool,
+    /// Error from the last attempt to restart as administrator
+    elevation_error: Option<String>,
+
+    /// Warning when the game executable doesn't look like the game
+    exe_warning: Option<String>,
+
+    /// Whether the store restored the original binkw32.dll over the patch
+    patch_reverted: bool,
+
+    /// Detailed information about the game files
+    details: GameDetails,
+
+    /// Whether the advanced details panel is expanded
+    show_details: bool,
+
+    /// Error from the last attempt to launch the game
+    launch_error: Option<String>,
+
+    /// Whether the plugin is added once the plugin details load
+    plugin_add_queued: bool,
+
+    /// Error from the last attempt to open a folder in the file explorer
+    open_folder_error: Option<String>,
+
+    /// Result of checking the last installed plugin against the release
+    /// checksums, none when the plugin wasn't installed from a release
+    checksum_status: Option<ChecksumStatus>,
+
+    /// Version of the server the plugin is configured to connect to,
+    /// none when not configured or the server couldn't be reached
+    server_version: Option<Version>,
+
+    /// Handles for the in-flight tasks of this game, the tasks are aborted
+    /// when the game is changed so their results aren't applied to another game
+    tasks: Vec<task::Handle>,
 }
 
-#[derive(Debug, Clone)]
-pub struct PluginDetails {
-    /// State for the release type combobox
-    release_type_state: combo_box::State<ReleaseType>,
-    /// Selected release type
-    selected: ReleaseType,
+impl AppStateActive {
+    /// Tracks the `task` so it is aborted when this game state is dropped
+    fn track<T: 'static>(&mut self, task: Task<T>) -> Task<T> {
+        let (task, handle) = task.abortable();
+        self.tasks.push(handle.abort_on_drop());
+        task
+    }
+
+    /// Whether an operation that modifies the game files is in progress,
+    /// the game can't be changed until the operation finishes
+    fn is_busy(&self) -> bool {
+        self.patch.is_busy()
+            || self.plugin.is_busy()
+            || matches!(self.install_all_state, InstallAllState::Running(_))
+            || matches!(self.uninstall_state, UninstallState::Loading)
+            || matches!(self.leftovers_state, LeftoversState::Removing)
+            || self
+                .batch_results
+                .iter()
+                .any(|result| matches!(result.status, BatchStatus::Pending | BatchStatus::Running))
+            || matches!(self.plugin_config_state, PluginConfigState::Saving)
+    }
 }
 
-/// Current state for the plugin add process
+/// Current state for uninstalling everything
 #[derive(Default)]
-pub enum AlterPatchState {
-    /// Initial state, patch has not been added or removed yet
+pub enum UninstallState {
+    /// Initial state, nothing has been uninstalled yet
     #[default]
     Initial,
 
-    /// Loading state, patch is being applied/removed
+    /// Waiting for the user to confirm uninstalling
+    Confirming,
+
+    /// Everything is being uninstalled
     Loading,
 
-    /// Patch was added/removed successfully
-    Success,
+    /// Uninstalled successfully, contains a description of each change
+    Success(Vec<String>),
+
+    /// Can't uninstall while the game is running
+    GameRunning,
 
-    /// Failed to add/remove the patch
+    /// Failed to uninstall
     Error(String),
 }
 
-/// Current state for the plugin add process
+/// Current state for installing both the patch and plugin
 #[derive(Default)]
-pub enum AlterPluginState {
-    /// Initial state, plugin has not been added yet
+pub enum InstallAllState {
+    /// Initial state, nothing has been installed yet
     #[default]
     Initial,
 
-    /// Loading state, plugin asset is being downloaded
-    Loading,
+    /// Patch and plugin are being installed
+    Running(InstallStep),
 
-    /// Plugin was added successfully
+    /// Patch and plugin were installed successfully
     Success,
 
-    /// Failed to add the plugin
+    /// Can't install while the game is running
+    GameRunning,
+
+    /// Failed to install the patch and plugin
     Error(String),
 }
 
-#[derive(Debug, Clone)]
-struct GameState {
-    patched: bool,
-    plugin: bool,
+/// Result of installing to one of the installations in a batch install
+pub struct BatchResult {
+    /// Game folder of the installation
     path: PathBuf,
-}
 
-#[derive(Debug, Clone)]
-pub enum ReleaseType {
-    Stable(GitHubRelease),
-    Beta(GitHubRelease),
+    /// Status of installing to the installation
+    status: BatchStatus,
 }
 
-impl Display for ReleaseType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ReleaseType::Stable(release) => write!(f, "Stable ({})", release.tag_name),
-            ReleaseType::Beta(release) => write!(f, "Beta ({})", release.tag_name),
-        }
-    }
-}
+/// Status of installing to one of the installations in a batch install
+pub enum BatchStatus {
+    /// Waiting for the installations before it
+    Pending,
 
-/// Reads the current patch and plugin state from the provided
-/// game path
-async fn read_game_state(exe_path: &Path) -> anyhow::Result<GameState> {
-    let parent = exe_path.parent().context("missing game folder")?;
-    let asi_path = parent.join("ASI");
+    /// Patch and plugin are being installed
+    Running,
 
-    let plugin_path = asi_path.join("pocket-relay-plugin.asi");
-    let is_patched = is_patched(parent)
-        .await
-        .context("failed to check game patched state")?;
+    /// Patch and plugin were installed successfully
+    Installed(ChecksumStatus),
 
-    let plugin = plugin_path.exists() && plugin_path.is_file();
+    /// Skipped as the game is running
+    GameRunning,
 
-    Ok(GameState {
-        path: parent.to_path_buf(),
-        patched: is_patched,
-        plugin,
-    })
+    /// Failed to install the patch and plugin
+    Failed(String),
 }
 
-/// Obtains the plugin details for the current available releases
-async fn get_plugin_details() -> anyhow::Result<PluginDetails> {
-    let release = get_latest_plugin_release().await?;
-    let beta_release = get_latest_beta_plugin_release().await?;
-
-    let mut options = Vec::new();
-
-    options.push(ReleaseType::Stable(release));
-    if let Some(beta_release) = beta_release {
-        options.push(ReleaseType::Beta(beta_release));
-    }
+/// Current state of previewing the changes for an action
+#[derive(Default)]
+enum PreviewState {
+    /// No action is being previewed
+    #[default]
+    None,
 
-    let selected = options
-        .first()
-        .cloned()
-        .context("no release versions found")?;
+    /// Planning the changes for an action
+    Planning,
 
-    let release_type_state = combo_box::State::<ReleaseType>::new(options);
+    /// Changes are planned and waiting for confirmation
+    Ready(PendingAction, Vec<PlannedChange>),
 
-    Ok(PluginDetails {
-        release_type_state,
-        selected,
-    })
+    /// Failed to plan the changes
+    Error(String),
 }
 
-/// Creates a task that will load and update the plugin details
-fn plugin_details_task() -> Task<AppMessage> {
-    Task::perform(get_plugin_details(), map_error_string)
-        .map(PluginDetailsMessage::Loaded)
-        .map(AppMessage::PluginDetails)
+/// Action waiting for its changes to be confirmed
+#[derive(Debug, Clone)]
+enum PendingAction {
+    /// Patch action
+    Patch(PatchMessage),
+    /// Plugin action
+    Plugin(PluginMessage),
+    /// Install everything action
+    InstallAll,
 }
 
-async fn pick_game_state() -> anyhow::Result<Option<GameState>> {
-    // Spawn new thread for the native file picker dialog
-    let path = spawn_blocking(|| {
-        native_dialog::FileDialog::new()
-            .add_filter("MassEffect3.exe", &["exe"])
-            .set_filename("MassEffect3.exe")
-            .set_title("Choose game executable")
-            .show_open_single_file()
-            .context("failed to pick file")
-    })
-    .await
-    .context("failed to join native thread")?
-    .context("failed to pick file")?;
+/// Current state of the network diagnostics
+#[derive(Default)]
+pub enum NetworkChecksState {
+    /// Network diagnostics haven't been run
+    #[default]
+    Initial,
 
-    let path = match path {
-        Some(path) => path,
-        None => return Ok(None),
-    };
+    /// Checks are running, contains the results of the finished checks
+    Running(Vec<CheckResult>),
 
-    // Read the state from the chosen path
-    let game_state = read_game_state(path.as_ref()).await?;
-    Ok(Some(game_state))
+    /// All the checks finished
+    Finished(Vec<CheckResult>),
 }
 
-impl App {
-    /// View entry point for the app
-    fn view(&self) -> iced::Element<'_, AppMessage> {
-        match &self.state {
-            AppState::Initial(state) => return self.view_initial(state),
-            AppState::Active(state) => self.view_active(state),
-        }
-    }
+/// Current state of adding the DLL overrides to the Steam launch options
+#[derive(Default)]
+pub enum ProtonState {
+    /// Launch options haven't been changed
+    #[default]
+    Initial,
 
-    /// View for the app when its in the initial state
-    fn view_initial<'a>(&'a self, state: &'a AppStateInitial) -> iced::Element<'a, AppMessage> {
-        let target_text: Text = text(
-            "Please click the button below to choose your game path. \
-            When the file picker opens navigate to the folder containing \
-            MassEffect3.exe and pick that file",
-        )
-        .color(DARK_TEXT);
+    /// Launch options are being changed
+    Applying,
 
-        let pick_button: Button<_> = button("Choose game path")
-            .on_press(AppMessage::Game(GameMessage::PickGamePath))
-            .padding(10);
+    /// Launch options were changed for the number of Steam users
+    Applied(usize),
 
-        let mut content: Column<_> = column![target_text, pick_button].spacing(10);
+    /// Launch options were copied to the clipboard
+    Copied,
 
-        if let Some(err) = &state.pick_file_error {
-            content = content
-                .push(text(format!("failed to pick file: {err}")).color(Palette::DARK.danger));
-        }
+    /// Failed to change the launch options
+    Error(String),
+}
 
-        container(content)
-            .width(Length::Fill)
-            .height(Length::Fill)
-            .padding(SPACING)
-            .into()
-    }
+/// Current state of the plugin config editor
+#[derive(Default)]
+pub enum PluginConfigState {
+    /// Loading the plugin config
+    #[default]
+    Loading,
 
-    /// View for the app when its in the active state
-    fn view_active<'a>(&'a self, state: &'a AppStateActive) -> iced::Element<'a, AppMessage> {
-        let back_button: Button<_> = button("Back")
-            .on_press(AppMessage::Game(GameMessage::ClearGamePath))
-            .padding(10);
+    /// Plugin config is loaded and can be edited
+    Ready,
 
-        // Section for applying and removing the patch
-        let patch_section = Self::view_patch_section(state);
+    /// Plugin config is being saved
+    Saving,
 
-        // Section for applying and removing the plugin
-        let plugin_section = Self::view_plugin_section(state, &self.plugin_details_state);
+    /// Plugin config was saved successfully
+    Saved,
 
-        let content: Column<_> = column![back_button, patch_section, plugin_section].spacing(10);
+    /// Failed to load or save the plugin config
+    Error(String),
+}
 
-        container(scrollable(content))
-            .width(Length::Fill)
-            .height(Length::Fill)
-            .padding(SPACING)
-            .into()
-    }
+/// Current state of scanning the game folder for conflicting mods
+#[derive(Default)]
+pub enum CompatState {
+    /// Scanning the game folder
+    #[default]
+    Scanning,
 
-    /// View for the patch game section
-    fn view_patch_section(state: &AppStateActive) -> Column<'_, AppMessage> {
-        match (state.patched, &state.alter_patch_state) {
-            // Patch is installed, we are in the initial state
-            (true, AlterPatchState::Initial) => Self::view_patch_installed(),
+    /// Failed to scan the game folder
+    Error(String),
 
-            // Patch is not installed, we are in the initial state
-            (false, AlterPatchState::Initial) => Self::view_patch_not_installed(),
+    /// Scan finished, contains the problems that were found
+    Ready(Vec<Finding>),
+}
 
-            // Patch is installed, we are uninstalling
-            (true, AlterPatchState::Loading) => Self::view_patch_uninstalling(),
+/// Current state of the leftover files scan
+#[derive(Default)]
+pub enum LeftoversState {
+    /// Scanning the game folder
+    #[default]
+    Scanning,
 
-            // Patch is not installed, we are installing
-            (false, AlterPatchState::Loading) => Self::view_patch_installing(),
+    /// Scan finished, contains the leftover files that were found
+    Ready(Vec<Leftover>),
 
-            // Patch was installed
-            (true, AlterPatchState::Success) => Self::view_patch_add_success(),
+    /// Leftover files are being cleaned up
+    Removing,
 
-            // Patch was uninstalled
-            (false, AlterPatchState::Success) => Self::view_patch_remove_success(),
+    /// Cleaned up successfully, contains a description of each change
+    Removed(Vec<String>),
 
-            // Error occurred while uninstalling
-            (true, AlterPatchState::Error(err)) => Self::view_patch_uninstall_error(err),
+    /// Can't clean up while the game is running
+    GameRunning,
 
-            // Error occurred while installing
-            (false, AlterPatchState::Error(err)) => Self::view_patch_install_error(err),
-        }
-    }
+    /// Failed to clean up the leftover files
+    Error(String),
+}
 
-    fn view_patch_installed() -> Column<'static, AppMessage> {
-        let patch_text: Text = text("Your game is patched").color(DARK_TEXT);
-        let remove_patch_button: Button<_> = button("Remove Patch")
-            .on_press(AppMessage::Patch(PatchMessage::Remove))
-            .padding(10);
+/// Current state of exporting or comparing an installation report
+#[derive(Default)]
+pub enum ReportState {
+    /// No report has been exported or compared
+    #[default]
+    Initial,
+
+    /// Report is being exported or compared
+    Loading,
+
+    /// Report was exported to the path
+    Exported(PathBuf),
+
+    /// Report was compared, contains the differences from the current installation
+    Compared(Vec<ReportDifference>),
+
+    /// Failed to export or compare the report
+    Error(String),
+}
+
+/// Current state of the plugin log viewer
+#[derive(Default)]
+pub enum PluginLogState {
+    /// Log hasn't been read
+    #[default]
+    Initial,
+
+    /// Log is being read
+    Loading,
+
+    /// Plugin hasn't written a log yet
+    Missing,
+
+    /// Log was read, contains the last lines of the log
+    Loaded(Vec<PluginLogLine>),
+
+    /// Failed to read the log
+    Error(String),
+}
+
+/// Result of the last copy or export of the plugin log
+pub enum PluginLogNotice {
+    /// Log was copied to the clipboard
+    Copied,
+
+    /// Log was exported to the path
+    Exported(PathBuf),
+
+    /// Failed to export the log
+    Error(String),
+}
+
+/// Current state for the list of ASI plugins
+#[derive(Default)]
+pub enum AsiPluginsState {
+    /// Loading the list of plugins
+    #[default]
+    Loading,
+
+    /// Failed to load the list of plugins
+    Error(String),
+
+    /// Plugins have been loaded
+    Ready(Vec<AsiPlugin>),
+}
+
+#[derive(Debug, Clone)]
+enum PatchMessage {
+    /// Applies the patch to the game
+    Add,
+    /// Remove the patch from the game
+    Remove,
+
+    /// Progress update while applying the patch
+    Progress(Progress),
+    /// Result of applying the patch to the game
+    Added(Result<(), ActionError>),
+    /// Result of removing the patch from the game
+    Removed(Result<(), ActionError>),
+    /// Dismiss the notice about the store restoring the original binkw32.dll
+    DismissReverted,
+}
+
+#[derive(Debug, Clone)]
+enum PluginMessage {
+    /// Adds the plugin to the game, queued until the plugin details load
+    Add,
+    /// Stop waiting for the plugin details to add the plugin
+    CancelQueuedAdd,
+    /// Updates the installed plugin to the latest release
+    Update,
+    /// Pick a local plugin file to add to the game
+    AddFromFile,
+    /// Result of picking a local plugin file
+    PickedFile(Result<Option<PathBuf>, String>),
+    /// Remove the plugin from the game
+    Remove,
+    /// Enable or disable the installed plugin without removing it
+    SetEnabled(bool),
+    /// Select a different plugin version type
+    SelectType(ReleaseType),
+    /// Select a different plugin file from the releases with multiple
+    SelectAsset(String),
+    /// Expand or collapse the release notes for the selected release
+    ToggleReleaseNotes,
+    /// Progress update while installing the plugin
+    Progress(Progress),
+    /// Result of checking the plugin against the release checksums
+    ChecksumChecked(ChecksumStatus),
+
+    /// Result of adding the plugin to the game, contains the
+    /// installed version if known
+    Added(Result<Option<String>, ActionError>),
+    /// Result of removing the plugin from the game
+    Removed(Result<(), ActionError>),
+    /// Result of enabling or disabling the plugin
+    EnabledChanged(Result<(), ActionError>),
+    /// Result of checking the plugin in the game folder wasn't removed by
+    /// an antivirus after it was installed, contains whether its present
+    QuarantineChecked(PathBuf, bool),
+}
+
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+enum AppMessage {
+    /// Messages related to picking the game
+    Game(GameMessage),
+
+    /// Messages related to patching the game
+    Patch(PatchMessage),
+
+    /// Messages related to adding/removing the plugin
+    Plugin(PluginMessage),
+
+    /// Messages related to loading the plugin details
+    PluginDetails(PluginDetailsMessage),
+
+    /// Messages related to updating the installer
+    InstallerUpdate(InstallerUpdateMessage),
+
+    /// Messages related to the settings screen
+    Settings(SettingsMessage),
+
+    /// Messages related to the help screen
+    Help(HelpMessage),
+
+    /// Messages related to the history screen
+    History(HistoryMessage),
+
+    /// Messages related to managing the ASI plugins
+    Asi(AsiMessage),
+
+    /// Messages related to the compatibility scan
+    Compat(CompatMessage),
+
+    /// Messages related to the leftover files scan
+    Leftovers(LeftoversMessage),
+
+    /// Messages related to installation reports
+    Report(ReportMessage),
+
+    /// Messages related to the advanced details panel
+    Details(DetailsMessage),
+
+    /// Messages related to the plugin log viewer
+    PluginLog(PluginLogMessage),
+
+    /// Messages related to the downloads panel
+    Downloads(DownloadsMessage),
+
+    /// Messages related to editing the plugin config
+    PluginConfig(PluginConfigMessage),
+
+    /// Messages related to previewing changes
+    Preview(PreviewMessage),
+
+    /// Messages related to installing both the patch and plugin
+    InstallAll(InstallAllMessage),
+
+    /// Messages related to installing to every installation
+    Batch(BatchMessage),
+
+    /// Messages related to uninstalling everything
+    Uninstall(UninstallMessage),
+
+    /// Messages related to administrator access
+    Elevation(ElevationMessage),
+
+    /// Messages related to the logs and diagnostics
+    Logs(LogsMessage),
+
+    /// Messages related to running the game under Proton
+    Proton(ProtonMessage),
+
+    /// Open a link in the system browser
+    OpenLink(String),
+
+    /// Window gained or lost focus, true when focused
+    FocusChanged(bool),
+
+    /// Reload the state of the active game, sent when the game files
+    /// change, the window regains focus or the user asks to refresh
+    RefreshGame,
+
+    /// Dismiss the notice about the link the installer was opened with
+    DismissLink,
+
+    /// Launch the game so the plugin can be tried out
+    LaunchGame,
+
+    /// Open a folder of the active game in the file explorer
+    OpenFolder(PathBuf),
+
+    /// Copy an error message to the clipboard
+    CopyError(String),
+
+    /// Keyboard shortcut was pressed
+    Shortcut(Shortcut),
+
+    /// Window was asked to close
+    CloseRequested(window::Id),
+
+    /// Event from the tray icon
+    Tray(TrayEvent),
+}
+
+/// Keyboard shortcuts for moving between inputs and the primary actions
+#[derive(Debug, Clone, Copy)]
+enum Shortcut {
+    /// Focus the next input (Tab)
+    FocusNext,
+    /// Focus the previous input (Shift+Tab)
+    FocusPrevious,
+    /// Apply the patch (Alt+P)
+    ApplyPatch,
+    /// Add the plugin (Alt+A)
+    AddPlugin,
+    /// Cancel the current prompt or go back to the previous screen (Escape or Alt+B)
+    Back,
+}
+
+#[derive(Debug, Clone)]
+enum AsiMessage {
+    /// Reload the list of ASI plugins
+    Refresh,
+    /// Result of loading the list of ASI plugins
+    Loaded(Result<Vec<AsiPlugin>, String>),
+    /// Enable or disable a plugin
+    SetEnabled(AsiPlugin, bool),
+    /// Ask the user to confirm deleting a plugin
+    StartDelete(AsiPlugin),
+    /// Cancel deleting a plugin
+    CancelDelete,
+    /// Delete a plugin once confirmed
+    Delete(AsiPlugin),
+    /// Result of changing a plugin
+    Changed(Result<(), ActionError>),
+}
+
+#[derive(Debug, Clone)]
+enum CompatMessage {
+    /// Scan the game folder for conflicting mods
+    Scan,
+    /// Result of scanning the game folder
+    Scanned(Result<Vec<Finding>, String>),
+}
+
+#[derive(Debug, Clone)]
+enum LeftoversMessage {
+    /// Scan the game folder for leftover files
+    Scan,
+    /// Result of scanning the game folder
+    Scanned(Vec<Leftover>),
+    /// Clean up the leftover files that were found
+    Remove,
+    /// Result of cleaning up, contains a description of each change
+    Removed(Result<Vec<String>, ActionError>),
+}
+
+#[derive(Debug, Clone)]
+enum ReportMessage {
+    /// Pick a file and export the installation report to it
+    Export,
+    /// Result of exporting the report, none if no file was picked
+    Exported(Result<Option<PathBuf>, String>),
+    /// Pick a report file and compare it against the current installation
+    Compare,
+    /// Result of comparing the report, none if no file was picked
+    Compared(Result<Option<Vec<ReportDifference>>, String>),
+}
+
+#[derive(Debug, Clone)]
+enum DownloadsMessage {
+    /// Downloads list changed
+    Changed(Vec<DownloadItem>),
+    /// Cancel the download with the matching ID
+    Cancel(DownloadId),
+    /// Remove the downloads that have stopped from the list
+    ClearDone,
+}
+
+#[derive(Debug, Clone)]
+enum PluginLogMessage {
+    /// Open or close the plugin log viewer
+    Toggle,
+    /// Read the plugin log again
+    Refresh,
+    /// Result of reading the plugin log, none when there is no log
+    Loaded(Result<Option<Vec<PluginLogLine>>, String>),
+    /// Copy the read plugin log to the clipboard
+    Copy,
+    /// Pick a file and export the plugin log to it
+    Export,
+    /// Result of exporting the plugin log, none if no file was picked
+    Exported(Result<Option<PathBuf>, String>),
+}
+
+#[derive(Debug, Clone)]
+enum DetailsMessage {
+    /// Expand or collapse the advanced details panel
+    Toggle,
+    /// Read the details of the game files again
+    Refresh,
+    /// Result of reading the details of the game files
+    Loaded(GameDetails),
+}
+
+#[derive(Debug, Clone)]
+enum LogsMessage {
+    /// Expand or collapse the log panel
+    Toggle,
+    /// Collect the diagnostics and copy them to the clipboard
+    CopyDiagnostics,
+    /// Collected diagnostics report
+    CollectedDiagnostics(String),
+    /// Run the network diagnostics
+    RunNetworkChecks,
+    /// Result of one of the network checks
+    NetworkChecked(CheckResult),
+    /// All the network checks finished
+    NetworkChecksFinished,
+}
+
+#[derive(Debug, Clone)]
+enum ProtonMessage {
+    /// Add the DLL overrides to the Steam launch options
+    Apply,
+    /// Result of changing the launch options
+    Applied(Result<usize, String>),
+    /// Copy the launch options to the clipboard
+    Copy,
+}
+
+#[derive(Debug, Clone)]
+enum ElevationMessage {
+    /// Check the access to the game folder again
+    Check,
+    /// Result of checking the access to the game folder
+    Checked(FolderAccess),
+    /// Restart the installer as administrator
+    Relaunch,
+    /// Result of starting the installer as administrator
+    Relaunched(Result<(), String>),
+}
+
+#[derive(Debug, Clone)]
+enum UninstallMessage {
+    /// Set whether to remove the empty plugin directory
+    SetRemovePluginDir(bool),
+    /// Set whether to back up the plugin config and logs
+    SetBackupPluginFiles(bool),
+    /// Ask the user to confirm uninstalling
+    Start,
+    /// Uninstall everything after confirming
+    Confirm,
+    /// Cancel uninstalling
+    Cancel,
+    /// Result of uninstalling, contains a description of each change
+    Finished(Result<Vec<String>, ActionError>),
+}
+
+#[derive(Debug, Clone)]
+enum BatchMessage {
+    /// Apply the patch and install the selected plugin release to every
+    /// known installation
+    Start,
+    /// Started installing to the installation at the index
+    Started(usize),
+    /// Result of installing to the installation at the index
+    Finished(usize, Result<ChecksumStatus, ActionError>),
+}
+
+#[derive(Debug, Clone)]
+enum InstallAllMessage {
+    /// Apply the patch and install the selected plugin release
+    Start,
+    /// Progress update while installing
+    Progress(InstallStep),
+    /// Result of checking the plugin against the release checksums
+    ChecksumChecked(ChecksumStatus),
+    /// Result of installing, contains the installed plugin version
+    Finished(Result<Option<String>, ActionError>),
+}
+
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+enum PreviewMessage {
+    /// Enable or disable previewing changes before applying them
+    SetEnabled(bool),
+    /// Result of planning the changes for an action
+    Planned(PendingAction, Result<Vec<PlannedChange>, String>),
+    /// Apply the previewed changes
+    Confirm,
+    /// Cancel the previewed action
+    Cancel,
+}
+
+#[derive(Debug, Clone)]
+enum PluginConfigMessage {
+    /// Reload the plugin config
+    Refresh,
+    /// Result of loading the plugin config
+    Loaded(Result<PluginConfig, String>),
+    /// Server connection URL input changed
+    SetConnectionUrl(String),
+    /// Save the plugin config
+    Save,
+    /// Result of saving the plugin config
+    Saved(Result<(), String>),
+    /// Result of checking the version of the configured server
+    ServerChecked(Result<Version, String>),
+}
+
+#[derive(Debug, Clone)]
+enum HistoryMessage {
+    /// Open the history screen
+    Open,
+    /// Close the history screen
+    Close,
+    /// Result of loading the recorded changes
+    Loaded(Result<Vec<HistoryEntry>, String>),
+    /// Undo the most recent change with the ID
+    Undo(String),
+    /// Result of undoing the most recent change
+    Undone(Result<(), ActionError>),
+}
+
+#[derive(Debug, Clone)]
+enum HelpMessage {
+    /// Open the help screen
+    Open,
+    /// Close the help screen
+    Close,
+    /// Load the troubleshooting guide again
+    Refresh,
+    /// Result of loading the troubleshooting guide
+    Loaded(HelpDocument, HelpSource),
+    /// Expand or collapse the topic at the index
+    ToggleTopic(usize),
+}
+
+#[derive(Debug, Clone)]
+enum SettingsMessage {
+    /// Open the settings screen
+    Open,
+    /// Close the settings screen without saving
+    Close,
+    /// Select a different proxy mode
+    SetProxyMode(ProxyMode),
+    /// Manual proxy URL input changed
+    SetProxyUrl(String),
+    /// Mirrors input changed
+    SetMirrors(String),
+    /// GitHub token input changed
+    SetGitHubToken(String),
+    /// Select a different user interface language
+    SetLanguage(Language),
+    /// Select a different game to install to
+    SetGame(Game),
+    /// Select a different theme mode
+    SetThemeMode(ThemeMode),
+    /// Accent color input changed
+    SetAccentColor(String),
+    /// Plugin repository input changed
+    SetPluginRepository(String),
+    /// Plugin asset name input changed
+    SetPluginAssetName(String),
+    /// Reset the plugin source inputs to the official plugin
+    ResetPluginSource,
+    /// Download speed limit slider changed
+    SetDownloadLimit(u32),
+    /// Set the maximum number of release pages to request
+    SetMaxReleasePages(u32),
+    /// Set the interface scale in percent
+    SetUiScale(u32),
+    /// Select a different default release channel
+    SetReleaseChannel(ReleaseChannel),
+    /// Set the release feeds input
+    SetReleaseFeeds(String),
+    /// Cache folder input changed
+    SetCacheDir(String),
+    /// Set whether to keep downloaded plugins for reinstalling
+    SetCacheDownloads(bool),
+    /// Size of the download cache was checked
+    LoadedCacheUsage(CacheUsage),
+    /// Remove the downloaded plugins from the download cache
+    ClearDownloadCache,
+    /// Result of clearing the download cache
+    ClearedDownloadCache(Result<(), String>),
+    /// Register the installer to open pocket-relay-installer:// links
+    RegisterLinkHandler,
+    /// Result of registering the installer to open links
+    RegisteredLinkHandler(Result<(), String>),
+    /// Set whether to offer applying the patch again when the store removes it
+    SetDetectPatchReverts(bool),
+    /// Set whether closing the window hides it to the tray
+    SetTrayMode(bool),
+    /// Set whether to send anonymous usage statistics
+    SetUsageStats(bool),
+    /// Usage statistics endpoint input changed
+    SetUsageStatsEndpoint(String),
+    /// Pick the cache folder using a folder picker
+    PickCacheDir,
+    /// Result of picking the cache folder
+    PickedCacheDir(Result<Option<PathBuf>, String>),
+    /// Save the settings and close the settings screen
+    Save,
+}
+
+#[derive(Debug, Clone)]
+enum GameMessage {
+    /// Trigger the popup to allow the user to pick the game path
+    PickGamePath,
+    /// Trigger the popup to allow the user to pick the game folder
+    PickGameFolder,
+    // Result of picking a game path
+    PickedGameResult(Result<Option<GameState>, String>),
+    /// Result of automatically detecting game paths
+    DetectedGamePaths(Vec<PathBuf>),
+    /// Use a game path that was automatically detected
+    SelectGamePath(PathBuf),
+    /// Result of loading the game path saved in the config
+    LoadedSavedGame(Option<GameState>),
+    /// Switch to a different known installation
+    SwitchInstallation(PathBuf),
+    /// Result of switching to a different installation
+    SwitchedInstallation(Result<GameState, String>),
+    /// Forget a known installation
+    RemoveInstallation(PathBuf),
+    /// Clears the active game path
+    ClearGamePath,
+    /// Read the patch and plugin state of the active game again
+    Refresh,
+    /// Result of reading the state of the active game again
+    Refreshed(Result<GameState, String>),
+}
+
+#[derive(Debug, Clone)]
+enum PluginDetailsMessage {
+    /// Result of adding the plugin to the game
+    Loaded(Result<PluginDetails, String>),
+    /// Load the plugin details again, replacing any load in progress
+    Retry,
+    /// Check for a newer plugin release in the background
+    Refresh,
+    /// Result of checking for a newer plugin release
+    Refreshed(Result<PluginDetails, String>),
+}
+
+#[derive(Debug, Clone)]
+enum InstallerUpdateMessage {
+    /// Result of checking for a newer installer release
+    Checked(Result<Option<GitHubRelease>, String>),
+    /// Download and apply the installer update
+    Update,
+    /// Result of applying the installer update
+    Updated(Result<(), String>),
+}
+
+/// Current state for updating the installer
+#[derive(Default)]
+pub enum InstallerUpdateState {
+    /// No update is available (Or its still being checked)
+    #[default]
+    None,
+
+    /// A newer installer release is available
+    Available(GitHubRelease),
+
+    /// Update is being downloaded and applied
+    Updating,
+
+    /// Failed to apply the update
+    Error(String),
+}
+
+/// Current state for the plugin details (Remote state from github)
+#[derive(Default)]
+#[allow(clippy::large_enum_variant)]
+pub enum PluginDetailsState {
+    /// Loading details about the plugin
+    #[default]
+    Loading,
+
+    /// Failed to load details about the plugin
+    Error(String),
+
+    /// Ready
+    Ready(PluginDetails),
+}
+
+#[derive(Debug, Clone)]
+pub struct PluginDetails {
+    /// State for the release type combobox
+    release_type_state: combo_box::State<ReleaseType>,
+    /// Selected release type
+    selected: ReleaseType,
+    /// Latest stable release
+    latest: GitHubRelease,
+    /// Parsed release notes for the selected release
+    release_notes: Vec<markdown::Item>,
+    /// Whether the release notes panel is expanded
+    show_release_notes: bool,
+    /// Source the releases were loaded from
+    source: PluginSource,
+    /// Whether older GitHub releases weren't listed due to the page limit
+    has_more_releases: bool,
+    /// Name of the plugin file picked by the user, none for the default
+    selected_asset: Option<String>,
+}
+
+impl PluginDetails {
+    /// Name of the plugin file to install from the `release`
+    fn asset_name(&self, release: &GitHubRelease) -> Option<String> {
+        self.source
+            .select_asset(release, self.selected_asset.as_deref())
+            .map(|asset| asset.name.clone())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct GameState {
+    patch_state: PatchState,
+    plugin: PluginState,
+    plugin_version: Option<String>,
+    path: PathBuf,
+    /// Warning when the executable doesn't look like the game
+    warning: Option<String>,
+    /// Detailed information about the game files
+    details: GameDetails,
+    /// Store the game was installed from
+    store: StoreKind,
+    /// ASI loaders other than the patch
+    loaders: Vec<AsiLoader>,
+}
+
+/// Release that can be selected for installing along with the release
+/// channel it belongs to
+#[derive(Debug, Clone)]
+pub struct ReleaseType {
+    /// The release channel the release belongs to
+    channel: ReleaseChannel,
+    /// The release
+    release: GitHubRelease,
+}
+
+impl Display for ReleaseType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let tag_name = &self.release.tag_name;
+
+        match &self.channel {
+            ReleaseChannel::Stable => f.write_str(&tr_args("Stable ({})", &[tag_name]))?,
+            ReleaseChannel::Beta => f.write_str(&tr_args("Beta ({})", &[tag_name]))?,
+            ReleaseChannel::Feed(name) => write!(f, "{name} ({tag_name})")?,
+        }
+
+        match required_installer_version(&self.release) {
+            Some(version) => write!(f, " - {}", tr_args("requires installer {}", &[&version])),
+            None => Ok(()),
+        }
+    }
+}
+
+impl ReleaseType {
+    /// The release this release type is for
+    fn release(&self) -> &GitHubRelease {
+        &self.release
+    }
+
+    /// The release channel this release type belongs to
+    fn channel(&self) -> ReleaseChannel {
+        self.channel.clone()
+    }
+}
+
+impl Display for ReleaseChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReleaseChannel::Stable => f.write_str(tr("Stable")),
+            ReleaseChannel::Beta => f.write_str(tr("Beta")),
+            ReleaseChannel::Feed(name) => f.write_str(name),
+        }
+    }
+}
+
+/// Reads the current patch and plugin state from the provided
+/// game path
+async fn read_game_state(exe_path: &Path) -> anyhow::Result<GameState> {
+    read_game_state_with(&TokioFs, exe_path).await
+}
+
+/// [read_game_state] reading the game files through the `fs`
+async fn read_game_state_with(fs: &impl GameFs, exe_path: &Path) -> anyhow::Result<GameState> {
+    let parent = exe_path.parent().context("missing game folder")?;
+
+    // Ensure the executable is actually the game
+    let warning = validate_game_exe(exe_path).await?;
+
+    let patch_state = patch_state_with(fs, embedded_manifest(), game(), parent)
+        .await
+        .context("failed to check game patched state")?;
+
+    let plugin = read_plugin_state(fs, parent);
+    let plugin_version = match plugin.is_present() {
+        true => read_plugin_version_with(fs, parent).await,
+        false => None,
+    };
+
+    let details = read_game_details(parent.to_path_buf()).await;
+    let store = detect_store(parent);
+    let loaders = detect_asi_loaders(parent, &patch_state).await;
+
+    Ok(GameState {
+        path: parent.to_path_buf(),
+        patch_state,
+        plugin,
+        plugin_version,
+        warning,
+        details,
+        store,
+        loaders,
+    })
+}
+
+/// Obtains the plugin details for the current available releases from
+/// GitHub and the release `feeds`, selecting the release from the `channel`
+/// when its available
+async fn get_plugin_details(
+    http_client: reqwest::Client,
+    mirrors: Vec<String>,
+    options: ApiOptions,
+    channel: ReleaseChannel,
+    feeds: Vec<ReleaseFeed>,
+    source: PluginSource,
+) -> anyhow::Result<PluginDetails> {
+    let (releases, feed_releases) = tokio::join!(
+        get_plugin_releases(http_client.clone(), mirrors, options, source.clone()),
+        join_all(feeds.into_iter().map(|feed| {
+            let http_client = http_client.clone();
+            let source = source.clone();
+            async move {
+                let name = feed.name.clone();
+                (
+                    name,
+                    get_plugin_feed_releases(http_client, feed, source).await,
+                )
+            }
+        }))
+    );
+
+    let releases = releases?;
+    let has_more_releases = releases.has_more;
+
+    let mut options: Vec<ReleaseType> = releases
+        .releases
+        .into_iter()
+        .map(|release| ReleaseType {
+            channel: match release.prerelease {
+                true => ReleaseChannel::Beta,
+                false => ReleaseChannel::Stable,
+            },
+            release,
+        })
+        .collect();
+
+    // Unreachable feeds are left out so the GitHub releases can still be used
+    for (name, result) in feed_releases {
+        match result {
+            Ok(releases) => options.extend(releases.into_iter().map(|release| ReleaseType {
+                channel: ReleaseChannel::Feed(name.clone()),
+                release,
+            })),
+            Err(err) => error!("failed to load release feed {name}: {err:#}"),
+        }
+    }
+
+    // Releases requiring a newer installer are listed but not picked
+    let installable =
+        |option: &&ReleaseType| required_installer_version(option.release()).is_none();
+
+    // Newest release from the preferred channel
+    let selected = options
+        .iter()
+        .filter(installable)
+        .find(|option| option.channel == channel)
+        .or_else(|| options.iter().find(installable))
+        .or_else(|| options.first())
+        .cloned()
+        .context("no release versions found")?;
+
+    // Newest stable release this installer can install
+    let latest = options
+        .iter()
+        .filter(installable)
+        .find(|option| option.channel == ReleaseChannel::Stable)
+        .unwrap_or(&selected)
+        .release()
+        .clone();
+
+    let release_notes = parse_release_notes(selected.release());
+    let release_type_state = combo_box::State::<ReleaseType>::new(options);
+
+    Ok(PluginDetails {
+        release_type_state,
+        selected,
+        latest,
+        release_notes,
+        show_release_notes: false,
+        source,
+        selected_asset: None,
+        has_more_releases,
+    })
+}
+
+/// Parses the markdown release notes for the provided release
+fn parse_release_notes(release: &GitHubRelease) -> Vec<markdown::Item> {
+    match &release.body {
+        Some(body) => markdown::parse(body).collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Creates a task that will load and update the plugin details
+fn plugin_details_task(
+    http_client: reqwest::Client,
+    config: &Config,
+) -> Task<PluginDetailsMessage> {
+    Task::perform(
+        get_plugin_details(
+            http_client,
+            config.mirrors.clone(),
+            config.api_options(),
+            config.release_channel.clone(),
+            config.release_feeds.clone(),
+            config.plugin_source.clone(),
+        ),
+        map_error_string,
+    )
+    .map(PluginDetailsMessage::Loaded)
+}
+
+/// Creates a task that will check the version of the server at the
+/// `connection_url`
+fn server_version_task(
+    http_client: reqwest::Client,
+    connection_url: String,
+) -> Task<PluginConfigMessage> {
+    Task::perform(
+        get_server_version(http_client, connection_url),
+        map_error_string,
+    )
+    .map(PluginConfigMessage::ServerChecked)
+}
+
+/// Creates a task that will check for a newer version of the installer
+fn installer_update_task(http_client: reqwest::Client, config: &Config) -> Task<AppMessage> {
+    Task::perform(
+        check_for_update(http_client, config.mirrors.clone(), config.api_options()),
+        map_error_string,
+    )
+    .map(InstallerUpdateMessage::Checked)
+    .map(AppMessage::InstallerUpdate)
+}
+
+/// Creates a task that will search for installed copies of the game
+fn detect_game_paths_task() -> Task<AppMessage> {
+    Task::perform(
+        async { spawn_blocking(find_game_paths).await.unwrap_or_default() },
+        GameMessage::DetectedGamePaths,
+    )
+    .map(AppMessage::Game)
+}
+
+/// Creates a task that will load the game state for the game
+/// folder that was saved in the config
+fn saved_game_state_task(game_path: PathBuf) -> Task<AppMessage> {
+    Task::perform(
+        async move {
+            match read_folder_game_state(game_path).await {
+                Ok(value) => Some(value),
+                Err(err) => {
+                    error!("failed to load saved game path: {err:?}");
+                    None
+                }
+            }
+        },
+        GameMessage::LoadedSavedGame,
+    )
+    .map(AppMessage::Game)
+}
+
+/// Creates a task reading the state of the game at the `path` the installer
+/// was opened with
+fn launch_game_state_task(path: PathBuf) -> Task<AppMessage> {
+    Task::perform(
+        async move { read_picked_game_state(path).await.map(Some) },
+        map_error_string,
+    )
+    .map(GameMessage::PickedGameResult)
+    .map(AppMessage::Game)
+}
+
+/// Reads the current patch and plugin state from the game folder
+/// containing the game executable
+async fn read_folder_game_state(game_path: PathBuf) -> anyhow::Result<GameState> {
+    let exe_name = profile().exe_name;
+    let exe_path = game_path.join(exe_name);
+
+    // Folder no longer contains the game
+    if !exe_path.is_file() {
+        anyhow::bail!("{} is missing {exe_name}", game_path.display());
+    }
+
+    read_game_state(&exe_path).await
+}
+
+/// Creates a short name for an installation from its game folder,
+/// (e.g "common/Mass Effect 3" for a Steam install)
+fn installation_name(game_path: &Path) -> String {
+    // Game folder path is the "Binaries/Win32" folder within the install
+    let install_path = game_path.ancestors().nth(2).unwrap_or(game_path);
+
+    let mut names = install_path
+        .iter()
+        .rev()
+        .take(2)
+        .map(|part| part.to_string_lossy())
+        .collect::<Vec<_>>();
+    names.reverse();
+    names.join("/")
+}
+
+/// Creates a task that saves the provided config in the background
+fn save_config_task<T>(config: Config) -> Task<T>
+where
+    T: Send + 'static,
+{
+    Task::future(async move {
+        if let Err(err) = save_config(config).await {
+            error!("failed to save config: {err:?}");
+        }
+    })
+    .discard()
+}
+
+/// Opens a native file picker for choosing a local plugin file
+async fn pick_plugin_file() -> anyhow::Result<Option<PathBuf>> {
+    spawn_blocking(|| {
+        native_dialog::FileDialog::new()
+            .add_filter("ASI Plugin", &["asi"])
+            .set_filename(PLUGIN_NAME)
+            .set_title("Choose plugin file")
+            .show_open_single_file()
+            .context("failed to pick file")
+    })
+    .await
+    .context("failed to join native thread")?
+}
+
+async fn pick_cache_dir() -> anyhow::Result<Option<PathBuf>> {
+    spawn_blocking(|| {
+        native_dialog::FileDialog::new()
+            .set_title("Choose cache folder")
+            .show_open_single_dir()
+            .context("failed to pick folder")
+    })
+    .await
+    .context("failed to join native thread")?
+}
+
+async fn pick_report_save_path() -> anyhow::Result<Option<PathBuf>> {
+    spawn_blocking(|| {
+        native_dialog::FileDialog::new()
+            .add_filter("JSON Report", &["json"])
+            .add_filter("Text Report", &["txt"])
+            .set_filename(REPORT_FILE_NAME)
+            .set_title("Export report")
+            .show_save_single_file()
+            .context("failed to pick file")
+    })
+    .await
+    .context("failed to join native thread")?
+}
+
+async fn pick_plugin_log_save_path() -> anyhow::Result<Option<PathBuf>> {
+    spawn_blocking(|| {
+        native_dialog::FileDialog::new()
+            .add_filter("Log File", &["log"])
+            .set_filename(PLUGIN_LOG_NAME)
+            .set_title("Export plugin log")
+            .show_save_single_file()
+            .context("failed to pick file")
+    })
+    .await
+    .context("failed to join native thread")?
+}
+
+async fn pick_report_file() -> anyhow::Result<Option<PathBuf>> {
+    spawn_blocking(|| {
+        native_dialog::FileDialog::new()
+            .add_filter("JSON Report", &["json"])
+            .set_title("Choose report to compare")
+            .show_open_single_file()
+            .context("failed to pick file")
+    })
+    .await
+    .context("failed to join native thread")?
+}
+
+/// Shows a picker for the game executable, a shortcut to it, or a game
+/// folder when `pick_folder` is set. Reads the state of the picked game
+async fn pick_game_state(pick_folder: bool) -> anyhow::Result<Option<GameState>> {
+    // Spawn new thread for the native file picker dialog
+    let path = spawn_blocking(move || {
+        let location = default_picker_dir();
+        let mut dialog = native_dialog::FileDialog::new();
+        if let Some(location) = &location {
+            dialog = dialog.set_location(location);
+        }
+
+        if pick_folder {
+            return dialog
+                .set_title("Choose game folder")
+                .show_open_single_dir()
+                .context("failed to pick folder");
+        }
+
+        dialog
+            .add_filter("MassEffect3.exe", &["exe"])
+            .add_filter("Shortcut", &[SHORTCUT_EXTENSION])
+            .set_filename("MassEffect3.exe")
+            .set_title("Choose game executable")
+            .show_open_single_file()
+            .context("failed to pick file")
+    })
+    .await
+    .context("failed to join native thread")??;
+
+    match path {
+        Some(path) => read_picked_game_state(path).await.map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Reads the state of the game at the picked `path`, shortcuts and folders
+/// are resolved to the game executable
+async fn read_picked_game_state(path: PathBuf) -> anyhow::Result<GameState> {
+    let exe_path = spawn_blocking(move || resolve_picked_path(path))
+        .await
+        .context("failed to join native thread")??;
+
+    read_game_state(&exe_path).await
+}
+
+impl App {
+    /// View entry point for the app
+    fn view(&self) -> iced::Element<'_, AppMessage> {
+        if let Some(settings) = &self.settings {
+            return self.view_settings(settings);
+        }
+
+        if let Some(help) = &self.help {
+            return Self::view_help(help);
+        }
+
+        if let Some(history) = &self.history {
+            return self.view_history(history);
+        }
+
+        match &self.state {
+            AppState::Initial(state) => self.view_initial(state),
+            AppState::Active(state) => self.view_active(state),
+        }
+    }
+
+    /// View for the app when its in the initial state
+    fn view_initial<'a>(&'a self, state: &'a AppStateInitial) -> iced::Element<'a, AppMessage> {
+        let target_text: Text = text(tr(
+            "Please click the button below to choose your game path. \
+            When the file picker opens navigate to the folder containing \
+            MassEffect3.exe and pick that file",
+        ))
+        .style(muted_text);
+
+        let pick_button: Button<_> = button(tr("Choose game path"))
+            .on_press(AppMessage::Game(GameMessage::PickGamePath))
+            .padding(10);
+
+        let pick_folder_button: Button<_> = button(tr("Choose folder"))
+            .on_press(AppMessage::Game(GameMessage::PickGameFolder))
+            .padding(10);
+
+        let settings_button: Button<_> = button(tr("Settings"))
+            .on_press(AppMessage::Settings(SettingsMessage::Open))
+            .padding(10);
+
+        let help_button: Button<_> = button(tr("Help"))
+            .on_press(AppMessage::Help(HelpMessage::Open))
+            .padding(10);
+
+        let mut content: Column<_> = Column::new().spacing(10);
+
+        if let Some(resources_section) = self.view_resources_error() {
+            content = content.push(resources_section);
+        }
+
+        if let Some(link_section) = self.view_link_section() {
+            content = content.push(link_section);
+        }
+
+        if let Some(update_section) = self.view_installer_update() {
+            content = content.push(update_section);
+        }
+
+        if let Some(downloads_section) = self.view_downloads_section() {
+            content = content.push(downloads_section);
+        }
+
+        content = content.push(target_text).push(
+            row![
+                pick_button,
+                pick_folder_button,
+                settings_button,
+                help_button
+            ]
+            .spacing(10),
+        );
+
+        if let Some(err) = &state.pick_file_error {
+            content =
+                content.push(text(tr_args("failed to pick file: {}", &[err])).style(text::danger));
+        }
+
+        if !self.detected_paths.is_empty() {
+            let detected_text: Text =
+                text(tr("Or choose one of the following detected game installs:"))
+                    .style(muted_text);
+            content = content.push(detected_text);
+
+            for path in &self.detected_paths {
+                let path_button: Button<_> = button(text(path.display().to_string()))
+                    .on_press(AppMessage::Game(GameMessage::SelectGamePath(path.clone())))
+                    .width(Length::Fill)
+                    .padding(10);
+                content = content.push(path_button);
+            }
+        }
+
+        container(scrollable(content))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(SPACING)
+            .into()
+    }
+
+    /// View for the app when its in the active state
+    fn view_active<'a>(&'a self, state: &'a AppStateActive) -> iced::Element<'a, AppMessage> {
+        // Game can't be changed while its files are being modified
+        let back_button: Button<_> = button(tr("Back"))
+            .on_press_maybe(
+                (!state.is_busy()).then_some(AppMessage::Game(GameMessage::ClearGamePath)),
+            )
+            .padding(10);
+
+        let settings_button: Button<_> = button(tr("Settings"))
+            .on_press(AppMessage::Settings(SettingsMessage::Open))
+            .padding(10);
+
+        let help_button: Button<_> = button(tr("Help"))
+            .on_press(AppMessage::Help(HelpMessage::Open))
+            .padding(10);
+
+        let history_button: Button<_> = button(tr("History"))
+            .on_press(AppMessage::History(HistoryMessage::Open))
+            .padding(10);
+
+        let refresh_button: Button<_> = button(tr("Refresh"))
+            .on_press_maybe((!state.is_busy()).then_some(AppMessage::RefreshGame))
+            .padding(10);
+
+        // Section for applying and removing the patch
+        let patch_section = Self::view_patch_section(state);
+
+        // Section for applying and removing the plugin
+        let plugin_section =
+            Self::view_plugin_section(state, &self.plugin_details_state, &self.theme);
+
+        let preview_checkbox = checkbox(tr("Preview changes"), self.config.preview_changes)
+            .on_toggle(|value| AppMessage::Preview(PreviewMessage::SetEnabled(value)));
+
+        let mut content: Column<_> = Column::new().spacing(10);
+
+        if let Some(resources_section) = self.view_resources_error() {
+            content = content.push(resources_section);
+        }
+
+        if let Some(link_section) = self.view_link_section() {
+            content = content.push(link_section);
+        }
+
+        if let Some(update_section) = self.view_installer_update() {
+            content = content.push(update_section);
+        }
+
+        if let Some(downloads_section) = self.view_downloads_section() {
+            content = content.push(downloads_section);
+        }
+
+        if let Some(update_banner) =
+            Self::view_plugin_update_banner(state, &self.plugin_details_state)
+        {
+            content = content.push(update_banner);
+        }
+
+        if let Some(warning) = &state.exe_warning {
+            content = content.push(text(warning).style(text::danger));
+        }
+
+        if let Some(warning) = state.store.patch_warning() {
+            content = content.push(text(warning).style(muted_text));
+        }
+
+        content = content.push(Self::view_readiness(state));
+
+        // Make it clear when the plugin isn't from the official repository
+        let plugin_source = &self.config.plugin_source;
+        if !plugin_source.is_default() {
+            content = content.push(
+                text(tr_args(
+                    "Using plugin releases from {} ({})",
+                    &[&plugin_source.repository, &plugin_source.asset_name],
+                ))
+                .style(text::primary),
+            );
+        }
+
+        if let Some(elevation_section) = Self::view_elevation_section(state) {
+            content = content.push(elevation_section);
+        }
+
+        if let Some(reverted_section) = Self::view_patch_reverted_section(state) {
+            content = content.push(reverted_section);
+        }
+
+        if let Some(preview_section) = Self::view_preview_section(state) {
+            content = content.push(preview_section);
+        }
+
+        // Section for installing both the patch and plugin together
+        let install_all_section = Self::view_install_all_section(state, &self.plugin_details_state);
+
+        // Section for installing to every known installation
+        let batch_section = self.view_batch_section(state);
+
+        // Section for configuring the server the plugin connects to
+        let plugin_config_section = Self::view_plugin_config_section(state);
+
+        // Section for managing all the ASI plugins
+        let asi_section = Self::view_asi_section(state);
+
+        // Section for the problems found by the compatibility scan
+        let compat_section = Self::view_compat_section(state);
+
+        // Section for cleaning up files left behind by previous installs
+        let leftovers_section = Self::view_leftovers_section(state);
+
+        // Section for exporting and comparing installation reports
+        let report_section = Self::view_report_section(state);
+
+        // Section for verifying the game files
+        let details_section = Self::view_details_section(state);
+
+        // Section for reading the log written by the plugin
+        let plugin_log_section = Self::view_plugin_log_section(state);
+
+        // Section for removing everything from the game
+        let uninstall_section = Self::view_uninstall_section(state);
+
+        // Section for running the game under Proton
+        let proton_section = self.view_proton_section(state.store);
+
+        // Section for viewing the logs
+        let logs_section = self.view_logs_section();
+
+        content = content
+            .push(
+                row![
+                    with_shortcut(back_button, "Esc"),
+                    settings_button,
+                    help_button,
+                    history_button,
+                    refresh_button,
+                    preview_checkbox
+                ]
+                .spacing(10)
+                .align_y(iced::Alignment::Center),
+            )
+            .push(Self::view_folders_section(state))
+            .push_maybe(install_all_section)
+            .push_maybe(batch_section)
+            .push(patch_section)
+            .push(plugin_section)
+            .push(plugin_config_section)
+            .push_maybe(proton_section)
+            .push(compat_section)
+            .push(leftovers_section)
+            .push(asi_section)
+            .push(uninstall_section)
+            .push(report_section)
+            .push(details_section)
+            .push(plugin_log_section)
+            .push(logs_section);
+
+        let content = container(scrollable(content))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(SPACING);
+
+        match self.view_installations(state) {
+            Some(sidebar) => row![sidebar, content].into(),
+            None => content.into(),
+        }
+    }
+
+    /// View for the buttons opening the game and ASI folders in the file
+    /// explorer, includes the error from the last attempt
+    fn view_folders_section(state: &AppStateActive) -> Column<'_, AppMessage> {
+        let game_button: Button<_> = button(tr("Open game folder"))
+            .on_press(AppMessage::OpenFolder(state.path.clone()))
+            .padding(5);
+        let asi_button: Button<_> = button(tr("Open ASI folder"))
+            .on_press(AppMessage::OpenFolder(state.path.join(PLUGIN_DIR)))
+            .padding(5);
+
+        column![row![game_button, asi_button].spacing(10)]
+            .push_maybe(
+                state.open_folder_error.as_ref().map(|err| {
+                    text(tr_args("failed to open folder: {}", &[err])).style(text::danger)
+                }),
+            )
+            .spacing(10)
+    }
+
+    /// View for the sidebar listing the known installations, only present
+    /// when there are multiple installations to switch between
+    fn view_installations<'a>(
+        &'a self,
+        state: &'a AppStateActive,
+    ) -> Option<Column<'a, AppMessage>> {
+        if self.config.installations.len() < 2 {
+            return None;
+        }
+
+        let title_text: Text = text(tr("Installations"));
+
+        let installations =
+            self.config
+                .installations
+                .iter()
+                .fold(column![].spacing(5), |installations, path| {
+                    let current = *path == state.path;
+                    let name = installation_name(path);
+
+                    let mut select_button: Button<_> =
+                        button(text(name).size(14)).width(Length::Fill).padding(5);
+                    let mut remove_button: Button<_> = button(text("X").size(14)).padding(5);
+
+                    if current {
+                        select_button = select_button.style(button::success);
+                    } else if !state.is_busy() {
+                        select_button = select_button.on_press(AppMessage::Game(
+                            GameMessage::SwitchInstallation(path.clone()),
+                        ));
+                        remove_button = remove_button.on_press(AppMessage::Game(
+                            GameMessage::RemoveInstallation(path.clone()),
+                        ));
+                    }
+
+                    installations.push(row![select_button, remove_button].spacing(5))
+                });
+
+        let add_button: Button<_> = button(text(tr("Add installation")).size(14))
+            .on_press_maybe(
+                (!state.is_busy()).then_some(AppMessage::Game(GameMessage::PickGamePath)),
+            )
+            .padding(5);
+
+        let mut content: Column<_> = column![title_text, installations, add_button].spacing(10);
+
+        if let Some(err) = &self.installation_error {
+            content = content.push(text(err).size(14).style(text::danger));
+        }
+
+        Some(content.width(Length::Fixed(SIDEBAR_WIDTH)).padding(SPACING))
+    }
+
+    /// View with a set-up checklist for game folders that need administrator
+    /// access, only present for installs in Program Files or when the game
+    /// folder can't be modified as is
+    fn view_elevation_section(state: &AppStateActive) -> Option<Column<'_, AppMessage>> {
+        let access = state.folder_access.as_ref()?;
+        if !access.machine_wide && access.writable && access.virtual_store_files.is_empty() {
+            return None;
+        }
+
+        let title_text: Text = text(tr("Set-up checklist"));
+        let mut content: Column<_> = column![title_text].spacing(10);
+
+        if access.machine_wide {
+            content = content.push(
+                text(tr(
+                    "The game is installed for all users in Program Files, changes to the \
+                    game folder need administrator access",
+                ))
+                .style(muted_text),
+            );
+        }
+
+        if access.writable {
+            let access_text = match access.elevated {
+                true => {
+                    tr("The installer is running as administrator and can modify the game folder")
+                }
+                false => tr("The installer can modify the game folder"),
+            };
+            content = content.push(text(access_text).style(text::success));
+        } else if access.elevated {
+            content = content.push(
+                text(tr(
+                    "The game folder can't be modified even as administrator, check the \
+                    folder permissions and that no antivirus is blocking changes",
+                ))
+                .style(text::danger),
+            );
+        } else {
+            let elevation_text: Text = text(tr(
+                "The game folder can't be modified without administrator access, \
+                restart the installer as administrator to make changes.",
+            ))
+            .style(text::danger);
+            let elevation_button: Button<_> = button(tr("Restart as administrator"))
+                .on_press_maybe(
+                    (!state.relaunching)
+                        .then_some(AppMessage::Elevation(ElevationMessage::Relaunch)),
+                )
+                .padding(10);
+
+            content = content.push(elevation_text).push(elevation_button);
+        }
+
+        // Copies in the VirtualStore are only visible to the current user
+        if let (false, Some(store_dir)) = (
+            access.virtual_store_files.is_empty(),
+            &access.virtual_store_dir,
+        ) {
+            content = content.push(
+                text(tr_args(
+                    "The game loads your own copies of these files instead of the installed \
+                    ones, remove them from {}: {}",
+                    &[&store_dir.display(), &access.virtual_store_files.join(", ")],
+                ))
+                .style(text::danger),
+            );
+        }
+
+        let check_button: Button<_> = button(tr("Check again"))
+            .on_press(AppMessage::Elevation(ElevationMessage::Check))
+            .padding(10);
+        content = content.push(check_button);
+
+        if let Some(err) = &state.elevation_error {
+            content = content.push(text(err).style(text::danger));
+        }
+
+        Some(content)
+    }
+
+    /// View for installing both the patch and plugin in one step, only
+    /// present while either of them is missing
+    fn view_install_all_section<'a>(
+        state: &'a AppStateActive,
+        plugin_details: &'a PluginDetailsState,
+    ) -> Option<Column<'a, AppMessage>> {
+        let retry_button: Button<_> = button(tr("Retry"))
+            .on_press(AppMessage::InstallAll(InstallAllMessage::Start))
+            .padding(10);
+
+        let section = match &state.install_all_state {
+            InstallAllState::Initial => {
+                let details = match plugin_details {
+                    PluginDetailsState::Ready(details) => details,
+                    _ => return None,
+                };
+
+                if state.patch.is_patched() && state.plugin.is_installed() {
+                    return None;
+                }
+
+                let install_text: Text = text(tr_args(
+                    "Apply the patch and install the {} plugin in one step",
+                    &[&details.selected.release().tag_name],
+                ))
+                .style(muted_text);
+                let install_button: Button<_> = button(tr("Install everything"))
+                    .on_press(AppMessage::InstallAll(InstallAllMessage::Start))
+                    .padding(10);
+
+                column![install_text, install_button]
+            }
+            InstallAllState::Running(step) => {
+                // Patch step is skipped when already patched, the plugin
+                // step is the remaining portion of the progress
+                let plugin_start = match state.patch.state {
+                    PatchState::Patched => 0.0,
+                    _ => 0.5,
+                };
+
+                let (status, progress, fraction) = match *step {
+                    InstallStep::Patching(progress) => (tr("Applying patch..."), progress, 0.0),
+                    InstallStep::Plugin(progress) => {
+                        // Download is the only step with a known size, the
+                        // steps after it are treated as complete
+                        let plugin_fraction = match progress.map(|progress| progress.step) {
+                            Some(ProgressStep::Downloading) => {
+                                progress.and_then(|progress| progress.fraction())
+                            }
+                            Some(ProgressStep::Verifying | ProgressStep::Writing) => Some(1.0),
+                            _ => None,
+                        };
+
+                        (
+                            tr("Installing plugin..."),
+                            progress,
+                            plugin_start
+                                + (1.0 - plugin_start) * plugin_fraction.unwrap_or_default(),
+                        )
+                    }
+                };
+
+                let status_text = text(status).style(text::primary);
+                let step_text =
+                    progress.map(|progress| text(format_step(&progress)).style(muted_text));
+                let install_progress =
+                    progress_bar(0.0..=1.0, fraction).height(Length::Fixed(10.0));
+
+                column![status_text]
+                    .push_maybe(step_text)
+                    .push(install_progress)
+            }
+            InstallAllState::Success => column![row![
+                text(tr("Patch and plugin installed")).style(text::success),
+                Self::view_verified()
+            ]
+            .spacing(10)]
+            .push_maybe(Self::view_checksum_status(state))
+            .push(Self::view_launch_game(state)),
+            InstallAllState::GameRunning => column![
+                text(tr(
+                    "Mass Effect 3 is currently running, close the game before installing."
+                ))
+                .style(text::danger),
+                retry_button
+            ],
+            InstallAllState::Error(err) => {
+                Self::view_error(tr_args("Failed to install: {}", &[err]), retry_button)
+            }
+        };
+
+        Some(section.spacing(10))
+    }
+
+    /// View for installing to every known installation along with the result
+    /// for each installation, only present with multiple installations
+    fn view_batch_section<'a>(
+        &'a self,
+        state: &'a AppStateActive,
+    ) -> Option<Column<'a, AppMessage>> {
+        if self.config.installations.len() < 2 {
+            return None;
+        }
+
+        let details = match &self.plugin_details_state {
+            PluginDetailsState::Ready(details) => details,
+            _ => return None,
+        };
+
+        let title_text: Text = text(tr("All installations"));
+        let install_text: Text = text(tr_args(
+            "Apply the patch and install the {} plugin to all {} installations",
+            &[
+                &details.selected.release().tag_name,
+                &self.config.installations.len(),
+            ],
+        ))
+        .style(muted_text);
+        let install_button: Button<_> = button(tr("Install to all installations"))
+            .on_press_maybe((!state.is_busy()).then_some(AppMessage::Batch(BatchMessage::Start)))
+            .padding(10);
+
+        let content: Column<_> = column![title_text, install_text, install_button].spacing(10);
+
+        let results = state
+            .batch_results
+            .iter()
+            .fold(column![].spacing(5), |results, result| {
+                let name_text: Text = text(installation_name(&result.path)).width(Length::Fill);
+                let status_text: Text = match &result.status {
+                    BatchStatus::Pending => text(tr("Waiting")).style(muted_text),
+                    BatchStatus::Running => text(tr("Installing...")).style(text::primary),
+                    BatchStatus::Installed(ChecksumStatus::Verified) => {
+                        text(tr("Installed, checksum verified")).style(text::success)
+                    }
+                    BatchStatus::Installed(ChecksumStatus::Unavailable) => {
+                        text(tr("Installed")).style(text::success)
+                    }
+                    BatchStatus::GameRunning => {
+                        text(tr("Skipped, the game is running")).style(text::danger)
+                    }
+                    BatchStatus::Failed(err) => {
+                        text(tr_args("Failed: {}", &[err])).style(text::danger)
+                    }
+                };
+
+                results.push(
+                    row![name_text, status_text.wrapping(text::Wrapping::WordOrGlyph)].spacing(10),
+                )
+            });
+
+        Some(content.push(results))
+    }
+
+    /// View for the preview of the changes an action will make, only
+    /// present while an action is being previewed
+    fn view_preview_section(state: &AppStateActive) -> Option<Column<'_, AppMessage>> {
+        let cancel_button: Button<_> = button(tr("Cancel"))
+            .on_press(AppMessage::Preview(PreviewMessage::Cancel))
+            .padding(10);
+
+        let section = match &state.preview_state {
+            PreviewState::None => return None,
+            PreviewState::Planning => {
+                column![text(tr("Preparing preview...")).style(text::primary)]
+            }
+            PreviewState::Error(err) => column![
+                text(tr_args("Failed to preview changes: {}", &[err])).style(text::danger),
+                cancel_button
+            ],
+            PreviewState::Ready(_, changes) => {
+                let title_text: Text =
+                    text(tr("The following changes will be made:")).style(text::primary);
+
+                let changes = changes
+                    .iter()
+                    .fold(column![].spacing(5), |changes, change| {
+                        changes.push(text(describe_change(change)).style(muted_text))
+                    });
+
+                let confirm_button: Button<_> = button(tr("Confirm"))
+                    .on_press(AppMessage::Preview(PreviewMessage::Confirm))
+                    .padding(10);
+
+                column![
+                    title_text,
+                    changes,
+                    row![confirm_button, cancel_button].spacing(10)
+                ]
+            }
+        };
+
+        Some(section.spacing(10))
+    }
+
+    /// View for the plugin config section
+    fn view_plugin_config_section(state: &AppStateActive) -> Column<'_, AppMessage> {
+        let title_text: Text = text(tr("Server Connection"));
+        let help_text: Text = text(tr(
+            "Address of the Pocket Relay server the plugin should connect to",
+        ))
+        .style(muted_text);
+
+        let editable = matches!(
+            state.plugin_config_state,
+            PluginConfigState::Ready | PluginConfigState::Saved
+        );
+
+        let mut url_input = text_input("https://example.com", &state.connection_url).padding(10);
+        let mut save_button: Button<_> = button(tr("Save")).padding(10);
+
+        if editable {
+            url_input = url_input
+                .on_input(|value| {
+                    AppMessage::PluginConfig(PluginConfigMessage::SetConnectionUrl(value))
+                })
+                .on_submit(AppMessage::PluginConfig(PluginConfigMessage::Save));
+            save_button = save_button.on_press(AppMessage::PluginConfig(PluginConfigMessage::Save));
+        }
+
+        let mut content: Column<_> = column![
+            title_text,
+            help_text,
+            row![url_input, save_button].spacing(10)
+        ]
+        .spacing(10);
+
+        let status_text: Option<Text> = match &state.plugin_config_state {
+            PluginConfigState::Loading => Some(text(tr("Loading config...")).style(muted_text)),
+            PluginConfigState::Ready => None,
+            PluginConfigState::Saving => Some(text(tr("Saving config...")).style(muted_text)),
+            PluginConfigState::Saved => {
+                Some(text(tr("Saved server connection")).style(text::success))
+            }
+            PluginConfigState::Error(err) => Some(text(err).style(text::danger)),
+        };
+
+        if let Some(status_text) = status_text {
+            content = content.push(status_text);
+        }
+
+        content
+    }
+
+    /// View for the Proton launch options section, only present on
+    /// Linux where the game is run through Proton from Steam. Games from
+    /// other stores aren't launched through Steam
+    fn view_proton_section(&self, store: StoreKind) -> Option<Column<'_, AppMessage>> {
+        if cfg!(target_os = "windows") || !matches!(store, StoreKind::Steam | StoreKind::Unknown) {
+            return None;
+        }
+
+        let title_text: Text = text(tr("Proton"));
+        let help_text: Text = text(tr(
+            "When running through Proton the game must load the patched binkw32.dll, \
+            add the following to the game launch options in Steam:",
+        ))
+        .style(muted_text);
+        let options_text: Text = text(LAUNCH_OPTIONS).size(12);
+
+        let mut apply_button: Button<_> = button(tr("Add to Steam launch options")).padding(5);
+        if !matches!(self.proton_state, ProtonState::Applying) {
+            apply_button = apply_button.on_press(AppMessage::Proton(ProtonMessage::Apply));
+        }
+        let copy_button: Button<_> = button(tr("Copy launch options"))
+            .on_press(AppMessage::Proton(ProtonMessage::Copy))
+            .padding(5);
+
+        let status_text: Option<Text> = match &self.proton_state {
+            ProtonState::Initial => None,
+            ProtonState::Applying => {
+                Some(text(tr("Updating launch options...")).style(text::primary))
+            }
+            ProtonState::Applied(0) => {
+                Some(text(tr("Launch options are already set")).style(text::success))
+            }
+            ProtonState::Applied(_) => Some(
+                text(tr("Launch options updated, restart Steam to use them")).style(text::success),
+            ),
+            ProtonState::Copied => {
+                Some(text(tr("Copied launch options to clipboard")).style(text::success))
+            }
+            ProtonState::Error(err) => Some(
+                text(tr_args("failed to update launch options: {}", &[err])).style(text::danger),
+            ),
+        };
+
+        let content = column![
+            title_text,
+            help_text,
+            options_text,
+            row![apply_button, copy_button].spacing(10)
+        ]
+        .push_maybe(status_text)
+        .spacing(10);
+
+        Some(content)
+    }
+
+    /// View for the logs section
+    fn view_logs_section(&self) -> Column<'_, AppMessage> {
+        let title_text: Text = text(tr("Logs"));
+        let toggle_button: Button<_> = button(if self.show_logs {
+            tr("Hide logs")
+        } else {
+            tr("Show logs")
+        })
+        .on_press(AppMessage::Logs(LogsMessage::Toggle))
+        .padding(5);
+        let copy_button: Button<_> = button(tr("Copy diagnostics to clipboard"))
+            .on_press(AppMessage::Logs(LogsMessage::CopyDiagnostics))
+            .padding(5);
+
+        let mut content: Column<_> = column![row![title_text, toggle_button, copy_button]
+            .spacing(10)
+            .align_y(iced::Alignment::Center)]
+        .spacing(10);
+
+        if self.diagnostics_copied {
+            content =
+                content.push(text(tr("Copied diagnostics to clipboard")).style(text::success));
+        }
+
+        content = content.push(self.view_network_checks());
+
+        if self.show_logs {
+            let lines = recent_logs().into_iter().fold(column![], |lines, line| {
+                lines.push(text(line).size(12).style(muted_text))
+            });
+
+            content = content.push(
+                container(scrollable(lines).anchor_bottom())
+                    .height(Length::Fixed(150.0))
+                    .width(Length::Fill),
+            );
+        }
+
+        content
+    }
+
+    /// View for running the network diagnostics and their results
+    fn view_network_checks(&self) -> Column<'_, AppMessage> {
+        let (running, results) = match &self.network_checks {
+            NetworkChecksState::Initial => (false, &[][..]),
+            NetworkChecksState::Running(results) => (true, &results[..]),
+            NetworkChecksState::Finished(results) => (false, &results[..]),
+        };
+
+        let run_button: Button<_> = button(tr("Network diagnostics"))
+            .on_press_maybe((!running).then_some(AppMessage::Logs(LogsMessage::RunNetworkChecks)))
+            .padding(5);
+
+        let mut content: Column<_> = column![run_button].spacing(5);
+
+        for result in results {
+            let name = match result.check {
+                NetworkCheck::Dns => tr("Resolve api.github.com"),
+                NetworkCheck::Tcp => tr("Connect to api.github.com"),
+                NetworkCheck::Tls => tr("Secure connection (TLS)"),
+                NetworkCheck::Api => tr("GitHub API"),
+                NetworkCheck::Cdn => tr("Download server"),
+            };
+            let latency = result.latency.as_millis();
+
+            let result_text: Text = match &result.outcome {
+                CheckOutcome::Passed(details) => {
+                    text(format!("{name}: {details} ({latency} ms)")).style(text::success)
+                }
+                CheckOutcome::Failed(err) => {
+                    text(format!("{name}: {err} ({latency} ms)")).style(text::danger)
+                }
+                CheckOutcome::Skipped => {
+                    text(format!("{name}: {}", tr("skipped"))).style(muted_text)
+                }
+            };
+
+            content = content.push(result_text.size(12));
+        }
+
+        if running {
+            content = content.push(text(tr("Checking connection...")).style(text::primary));
+        }
+
+        content
+    }
+
+    /// View for the uninstall everything section
+    fn view_uninstall_section(state: &AppStateActive) -> Column<'_, AppMessage> {
+        let title_text: Text = text(tr("Uninstall"));
+        let retry_button: Button<_> = button(tr("Retry"))
+            .on_press(AppMessage::Uninstall(UninstallMessage::Confirm))
+            .padding(10);
+
+        let section = match &state.uninstall_state {
+            UninstallState::Initial => {
+                let uninstall_text: Text = text(tr(
+                    "Remove the plugin, its config and log files, and restore the \
+                    original binkw32.dll",
+                ))
+                .style(muted_text);
+                let remove_dir_checkbox = checkbox(
+                    tr("Remove the ASI folder if its empty"),
+                    state.remove_plugin_dir,
+                )
+                .on_toggle(|value| {
+                    AppMessage::Uninstall(UninstallMessage::SetRemovePluginDir(value))
+                });
+                let backup_checkbox = checkbox(
+                    tr("Back up the plugin config and logs, restored when reinstalling"),
+                    state.backup_plugin_files,
+                )
+                .on_toggle(|value| {
+                    AppMessage::Uninstall(UninstallMessage::SetBackupPluginFiles(value))
+                });
+                let uninstall_button: Button<_> = button(tr("Uninstall everything"))
+                    .on_press(AppMessage::Uninstall(UninstallMessage::Start))
+                    .padding(10);
+
+                column![
+                    uninstall_text,
+                    remove_dir_checkbox,
+                    backup_checkbox,
+                    uninstall_button
+                ]
+            }
+            UninstallState::Confirming => {
+                let confirm_text: Text = text(tr(
+                    "Are you sure you want to remove everything Pocket Relay has installed?",
+                ))
+                .style(text::danger);
+                let confirm_button: Button<_> = button(tr("Uninstall"))
+                    .on_press(AppMessage::Uninstall(UninstallMessage::Confirm))
+                    .padding(10);
+                let cancel_button: Button<_> = button(tr("Cancel"))
+                    .on_press(AppMessage::Uninstall(UninstallMessage::Cancel))
+                    .padding(10);
+
+                column![
+                    confirm_text,
+                    row![confirm_button, cancel_button].spacing(10)
+                ]
+            }
+            UninstallState::Loading => {
+                column![text(tr("Uninstalling...")).style(text::primary)]
+            }
+            UninstallState::Success(removed) if removed.is_empty() => {
+                column![text(tr("Nothing to uninstall")).style(muted_text)]
+            }
+            UninstallState::Success(removed) => removed.iter().fold(
+                column![text(tr("Uninstalled everything")).style(text::success)],
+                |content, change| content.push(text(change).style(muted_text)),
+            ),
+            UninstallState::GameRunning => column![
+                text(tr(
+                    "Mass Effect 3 is currently running, close the game before uninstalling."
+                ))
+                .style(text::danger),
+                retry_button
+            ],
+            UninstallState::Error(err) => column![
+                text(tr_args("Failed to uninstall: {}", &[err])).style(text::danger),
+                retry_button
+            ],
+        };
+
+        column![title_text, section.spacing(10)].spacing(10)
+    }
+
+    /// View for the compatibility scan section
+    fn view_compat_section(state: &AppStateActive) -> Column<'_, AppMessage> {
+        let title_text: Text = text(tr("Compatibility"));
+        let mut scan_button: Button<_> = button(tr("Scan again")).padding(5);
+        if !matches!(state.compat_state, CompatState::Scanning) {
+            scan_button = scan_button.on_press(AppMessage::Compat(CompatMessage::Scan));
+        }
+
+        let content: Column<_> = column![row![title_text, scan_button].spacing(10)].spacing(10);
+
+        let findings = match &state.compat_state {
+            CompatState::Scanning => {
+                return content.push(text(tr("Scanning game folder...")).style(muted_text));
+            }
+            CompatState::Error(err) => {
+                return content.push(
+                    text(tr_args("failed to scan game folder: {}", &[err])).style(text::danger),
+                );
+            }
+            CompatState::Ready(findings) => findings,
+        };
+
+        if findings.is_empty() {
+            return content.push(text(tr("No known conflicts were found")).style(text::success));
+        }
+
+        findings.iter().fold(content, |content, finding| {
+            let message_text: Text = text(&finding.message).style(match finding.severity {
+                Severity::Conflict => text::danger,
+                Severity::Warning => text::primary,
+                Severity::Info => text::base,
+            });
+            let suggestion_text: Text = text(&finding.suggestion).style(muted_text);
+
+            content.push(column![message_text, suggestion_text].spacing(5))
+        })
+    }
+
+    /// View for the leftover files section
+    fn view_leftovers_section(state: &AppStateActive) -> Column<'_, AppMessage> {
+        let title_text: Text = text(tr("Leftover files"));
+        let scan_button: Button<_> = button(tr("Scan again"))
+            .on_press_maybe(
+                (!matches!(
+                    state.leftovers_state,
+                    LeftoversState::Scanning | LeftoversState::Removing
+                ))
+                .then_some(AppMessage::Leftovers(LeftoversMessage::Scan)),
+            )
+            .padding(5);
+
+        let content: Column<_> = column![row![title_text, scan_button].spacing(10)].spacing(10);
+
+        match &state.leftovers_state {
+            LeftoversState::Scanning => {
+                content.push(text(tr("Scanning game folder...")).style(muted_text))
+            }
+            LeftoversState::Ready(leftovers) if leftovers.is_empty() => content
+                .push(text(tr("No files from previous installs were found")).style(text::success)),
+            LeftoversState::Ready(leftovers) => {
+                let help_text: Text = text(tr(
+                    "These files were left behind by a previous or manual install \
+                    and can stop the plugin from working",
+                ))
+                .style(muted_text);
+
+                let content =
+                    leftovers
+                        .iter()
+                        .fold(content.push(help_text), |content, leftover| {
+                            let reason = match leftover.kind {
+                                LeftoverKind::LegacyPlugin => tr("plugin from an older release"),
+                                LeftoverKind::MisplacedPlugin => {
+                                    tr("plugin outside of the ASI folder")
+                                }
+                                LeftoverKind::MisplacedConfig => {
+                                    tr("plugin config outside of the ASI folder")
+                                }
+                                LeftoverKind::StaleBinkw23 => {
+                                    tr("not used without the patched binkw32.dll")
+                                }
+                            };
+
+                            content.push(
+                                text(format!("{} ({reason})", leftover.name)).style(text::primary),
+                            )
+                        });
+
+                let clean_button: Button<_> = button(tr("Clean up leftover files"))
+                    .on_press(AppMessage::Leftovers(LeftoversMessage::Remove))
+                    .padding(10);
+
+                content.push(clean_button)
+            }
+            LeftoversState::Removing => {
+                content.push(text(tr("Cleaning up leftover files...")).style(text::primary))
+            }
+            LeftoversState::Removed(changes) => changes.iter().fold(
+                content.push(text(tr("Cleaned up leftover files")).style(text::success)),
+                |content, change| content.push(text(change).style(muted_text)),
+            ),
+            LeftoversState::GameRunning => content.push(
+                text(tr(
+                    "Mass Effect 3 is currently running, close the game before cleaning up.",
+                ))
+                .style(text::danger),
+            ),
+            LeftoversState::Error(err) => content.push(
+                text(tr_args("Failed to clean up leftover files: {}", &[err])).style(text::danger),
+            ),
+        }
+    }
+
+    /// View for the installation report section
+    fn view_report_section(state: &AppStateActive) -> Column<'_, AppMessage> {
+        let title_text: Text = text(tr("Installation report"));
+        let help_text: Text = text(tr(
+            "Export a report of the game files, or compare against a report \
+            from an installation that works",
+        ))
+        .style(muted_text);
+
+        let loading = matches!(state.report_state, ReportState::Loading);
+
+        let mut export_button: Button<_> = button(tr("Export report")).padding(5);
+        let mut compare_button: Button<_> = button(tr("Compare with report")).padding(5);
+        if !loading {
+            export_button = export_button.on_press(AppMessage::Report(ReportMessage::Export));
+            compare_button = compare_button.on_press(AppMessage::Report(ReportMessage::Compare));
+        }
+
+        let content: Column<_> = column![
+            title_text,
+            help_text,
+            row![export_button, compare_button].spacing(10)
+        ]
+        .spacing(10);
+
+        match &state.report_state {
+            ReportState::Initial => content,
+            ReportState::Loading => {
+                content.push(text(tr("Reading game files...")).style(muted_text))
+            }
+            ReportState::Exported(path) => content.push(
+                text(tr_args("Exported report to {}", &[&path.display()])).style(text::success),
+            ),
+            ReportState::Error(err) => content
+                .push(text(tr_args("failed to create report: {}", &[err])).style(text::danger)),
+            ReportState::Compared(differences) if differences.is_empty() => {
+                content.push(text(tr("The installation matches the report")).style(text::success))
+            }
+            ReportState::Compared(differences) => differences.iter().fold(
+                content.push(text(tr("Differences from the report:")).style(text::primary)),
+                |content, difference| {
+                    let name_text: Text = text(&difference.name);
+                    let values_text: Text = text(tr_args(
+                        "Current: {}\nReport: {}",
+                        &[&difference.current, &difference.imported],
+                    ))
+                    .size(12)
+                    .style(muted_text);
+
+                    content.push(column![name_text, values_text].spacing(5))
+                },
+            ),
+        }
+    }
+
+    /// View for the plugin log viewer, errors and warnings are highlighted
+    /// so connection failures stand out
+    fn view_plugin_log_section(state: &AppStateActive) -> Column<'_, AppMessage> {
+        let title_text: Text = text(tr("Plugin logs"));
+        let toggle_button: Button<_> = button(if state.show_plugin_log {
+            tr("Hide plugin logs")
+        } else {
+            tr("View plugin logs")
+        })
+        .on_press(AppMessage::PluginLog(PluginLogMessage::Toggle))
+        .padding(5);
+
+        let mut content: Column<_> = column![row![title_text, toggle_button]
+            .spacing(10)
+            .align_y(iced::Alignment::Center)]
+        .spacing(10);
+
+        if !state.show_plugin_log {
+            return content;
+        }
+
+        let loaded = matches!(state.plugin_log_state, PluginLogState::Loaded(_));
+        let copy_button: Button<_> = button(tr("Copy plugin logs"))
+            .on_press_maybe(loaded.then_some(AppMessage::PluginLog(PluginLogMessage::Copy)))
+            .padding(5);
+        let export_button: Button<_> = button(tr("Export plugin logs"))
+            .on_press_maybe(loaded.then_some(AppMessage::PluginLog(PluginLogMessage::Export)))
+            .padding(5);
+
+        content = content.push(row![copy_button, export_button].spacing(10));
+
+        content = match &state.plugin_log_notice {
+            None => content,
+            Some(PluginLogNotice::Copied) => {
+                content.push(text(tr("Copied plugin logs to clipboard")).style(text::success))
+            }
+            Some(PluginLogNotice::Exported(path)) => content.push(
+                text(tr_args("Exported plugin logs to {}", &[&path.display()]))
+                    .style(text::success),
+            ),
+            Some(PluginLogNotice::Error(err)) => content.push(
+                text(tr_args("failed to export plugin logs: {}", &[err])).style(text::danger),
+            ),
+        };
+
+        match &state.plugin_log_state {
+            PluginLogState::Initial | PluginLogState::Loading => {
+                content.push(text(tr("Reading plugin logs...")).style(muted_text))
+            }
+            PluginLogState::Missing => content.push(
+                text(tr(
+                    "The plugin hasn't written any logs yet, start the game with \
+                    the plugin installed",
+                ))
+                .style(muted_text),
+            ),
+            PluginLogState::Error(err) => content
+                .push(text(tr_args("failed to read plugin logs: {}", &[err])).style(text::danger)),
+            PluginLogState::Loaded(lines) => {
+                let lines = lines.iter().fold(column![], |lines, line| {
+                    let line_text: Text = text(&line.text).size(12);
+                    lines.push(match line.level {
+                        PluginLogLevel::Error => line_text.style(text::danger),
+                        PluginLogLevel::Warn => line_text.style(text::primary),
+                        PluginLogLevel::Info => line_text.style(muted_text),
+                    })
+                });
+
+                content.push(
+                    container(scrollable(lines).anchor_bottom())
+                        .height(Length::Fixed(200.0))
+                        .width(Length::Fill),
+                )
+            }
+        }
+    }
+
+    /// View for the advanced details section
+    fn view_details_section(state: &AppStateActive) -> Column<'_, AppMessage> {
+        let toggle_button: Button<_> = button(if state.show_details {
+            tr("Hide advanced details")
+        } else {
+            tr("Show advanced details")
+        })
+        .on_press(AppMessage::Details(DetailsMessage::Toggle))
+        .padding(5);
+
+        let content: Column<_> = column![toggle_button].spacing(10);
+
+        if !state.show_details {
+            return content;
+        }
+
+        let details = &state.details;
+        let missing = || tr("missing").to_string();
+
+        let mut lines = vec![
+            tr_args("Store: {}", &[&state.store]),
+            tr_args(
+                "binkw32.dll SHA256: {}",
+                &[&details.binkw32_hash.clone().unwrap_or_else(missing)],
+            ),
+            tr_args(
+                "binkw23.dll: {}",
+                &[&if details.binkw23_present {
+                    tr("present").to_string()
+                } else {
+                    missing()
+                }],
+            ),
+        ];
+
+        match &details.plugin {
+            Some(plugin) => {
+                let modified = plugin
+                    .modified
+                    .map(format_time)
+                    .unwrap_or_else(|| tr("unknown").to_string());
+
+                lines.push(tr_args(
+                    "Plugin SHA256: {}",
+                    &[&plugin.hash.clone().unwrap_or_else(missing)],
+                ));
+                lines.push(tr_args("Plugin size: {}", &[&format_bytes(plugin.size)]));
+                lines.push(tr_args("Plugin modified: {}", &[&modified]));
+            }
+            None => lines.push(tr("Plugin file: not installed").to_string()),
+        }
+
+        let refresh_button: Button<_> = button(tr("Refresh"))
+            .on_press(AppMessage::Details(DetailsMessage::Refresh))
+            .padding(5);
+
+        lines
+            .into_iter()
+            .fold(content, |content, line| {
+                content.push(text(line).size(12).style(muted_text))
+            })
+            .push(refresh_button)
+    }
+
+    /// View for the ASI plugins section
+    fn view_asi_section(state: &AppStateActive) -> Column<'_, AppMessage> {
+        let title_text: Text = text(tr("ASI Plugins"));
+        let refresh_button: Button<_> = button(tr("Refresh"))
+            .on_press(AppMessage::Asi(AsiMessage::Refresh))
+            .padding(5);
+
+        let mut content: Column<_> =
+            column![row![title_text, refresh_button].spacing(10)].spacing(10);
+
+        if let Some(err) = &state.asi_plugins_error {
+            content = content.push(text(err).style(text::danger));
+        }
+
+        let plugins = match &state.asi_plugins_state {
+            AsiPluginsState::Loading => {
+                return content.push(text(tr("Loading plugins...")).style(muted_text));
+            }
+            AsiPluginsState::Error(err) => {
+                return content
+                    .push(text(tr_args("failed to load plugins: {}", &[err])).style(text::danger));
+            }
+            AsiPluginsState::Ready(plugins) => plugins,
+        };
+
+        if plugins.is_empty() {
+            return content.push(text(tr("No ASI plugins are installed")).style(muted_text));
+        }
+
+        for plugin in plugins {
+            let status = if plugin.enabled {
+                tr("Enabled")
+            } else {
+                tr("Disabled")
+            };
+
+            let details = match &plugin.version {
+                Some(version) => format!("{status} - {} - {version}", format_bytes(plugin.size)),
+                None => format!("{status} - {}", format_bytes(plugin.size)),
+            };
+
+            let name_text: Text = text(&plugin.name);
+            let details_text: Text = text(details).style(muted_text);
+
+            let toggle_button: Button<_> = button(if plugin.enabled {
+                tr("Disable")
+            } else {
+                tr("Enable")
+            })
+            .on_press(AppMessage::Asi(AsiMessage::SetEnabled(
+                plugin.clone(),
+                !plugin.enabled,
+            )))
+            .padding(5);
+            let delete_button: Button<_> = button(tr("Delete"))
+                .on_press(AppMessage::Asi(AsiMessage::StartDelete(plugin.clone())))
+                .padding(5);
+
+            content = content.push(
+                row![
+                    column![name_text, details_text].width(Length::Fill),
+                    toggle_button,
+                    delete_button
+                ]
+                .spacing(10),
+            );
+
+            // Third-party plugins can't be restored once deleted
+            if state.asi_delete_confirm.as_ref() == Some(&plugin.path) {
+                let confirm_text: Text = text(tr_args(
+                    "Are you sure you want to delete {}? It can't be restored",
+                    &[&plugin.name],
+                ))
+                .style(text::danger);
+                let confirm_button: Button<_> = button(tr("Delete"))
+                    .on_press(AppMessage::Asi(AsiMessage::Delete(plugin.clone())))
+                    .padding(5);
+                let cancel_button: Button<_> = button(tr("Cancel"))
+                    .on_press(AppMessage::Asi(AsiMessage::CancelDelete))
+                    .padding(5);
+
+                content = content.push(
+                    row![
+                        confirm_text.width(Length::Fill),
+                        confirm_button,
+                        cancel_button
+                    ]
+                    .spacing(10)
+                    .align_y(iced::Alignment::Center),
+                );
+            }
+        }
+
+        content
+    }
+
+    /// View for the help screen listing common problems and how to fix them
+    fn view_help(help: &HelpState) -> iced::Element<'_, AppMessage> {
+        let title_text: Text = text(tr("Troubleshooting"));
+
+        let refresh_button: Button<_> = button(tr("Refresh"))
+            .on_press_maybe(
+                help.guide
+                    .is_some()
+                    .then_some(AppMessage::Help(HelpMessage::Refresh)),
+            )
+            .padding(10);
+        let close_button: Button<_> = button(tr("Close"))
+            .on_press(AppMessage::Help(HelpMessage::Close))
+            .padding(10);
+
+        let mut content: Column<_> = column![
+            title_text,
+            row![with_shortcut(close_button, "Esc"), refresh_button].spacing(10)
+        ]
+        .spacing(10);
+
+        let (document, source) = match &help.guide {
+            Some(value) => value,
+            None => {
+                content = content.push(text(tr("Loading troubleshooting guide...")));
+                return container(scrollable(content))
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .padding(SPACING)
+                    .into();
+            }
+        };
+
+        let source_text = match source {
+            HelpSource::Remote => None,
+            HelpSource::Cached => Some(tr(
+                "Couldn't fetch the latest troubleshooting guide, showing the saved copy",
+            )),
+            HelpSource::Embedded => Some(tr(
+                "Couldn't fetch the latest troubleshooting guide, showing the copy included \
+                with the installer",
+            )),
+        };
+        if let Some(source_text) = source_text {
+            content = content.push(text(source_text).style(muted_text));
+        }
+
+        for (index, topic) in document.topics.iter().enumerate() {
+            let expanded = help.expanded == Some(index);
+            let marker = if expanded { "▼" } else { "▶" };
+
+            let topic_button: Button<_> = button(text(format!("{marker} {}", topic.title)))
+                .on_press(AppMessage::Help(HelpMessage::ToggleTopic(index)))
+                .width(Length::Fill)
+                .padding(10);
+            content = content.push(topic_button);
+
+            if !expanded {
+                continue;
+            }
+
+            if !topic.summary.is_empty() {
+                content = content.push(text(&topic.summary).style(muted_text));
+            }
+
+            for (step, instruction) in topic.steps.iter().enumerate() {
+                content = content.push(text(format!("{}. {instruction}", step + 1)));
+            }
+
+            if let Some(link) = &topic.link {
+                let link_button: Button<_> = button(tr("More details"))
+                    .on_press(AppMessage::OpenLink(link.clone()))
+                    .padding(10);
+                content = content.push(link_button);
+            }
+        }
+
+        container(scrollable(content))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(SPACING)
+            .into()
+    }
+
+    /// View for the history screen listing the recorded changes to the game
+    fn view_history<'a>(&'a self, history: &'a HistoryState) -> iced::Element<'a, AppMessage> {
+        let title_text: Text = text(tr("History"));
+
+        let close_button: Button<_> = button(tr("Close"))
+            .on_press(AppMessage::History(HistoryMessage::Close))
+            .padding(10);
+
+        let mut content: Column<_> =
+            column![title_text, with_shortcut(close_button, "Esc")].spacing(10);
+
+        let entries = match &history.entries {
+            Some(Ok(value)) => value,
+            Some(Err(err)) => {
+                content = content
+                    .push(text(tr_args("failed to load history: {}", &[err])).style(text::danger));
+                return container(scrollable(content))
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .padding(SPACING)
+                    .into();
+            }
+            None => {
+                content = content.push(text(tr("Loading history...")));
+                return container(scrollable(content))
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .padding(SPACING)
+                    .into();
+            }
+        };
+
+        if entries.is_empty() {
+            content = content.push(
+                text(tr("The installer hasn't made any changes to this game")).style(muted_text),
+            );
+        }
+
+        // Changes can't be undone while the game files are being changed
+        let busy = match &self.state {
+            AppState::Active(state) => state.is_busy(),
+            AppState::Initial(_) => true,
+        };
+
+        if let Some(err) = &history.undo_error {
+            content = content.push(text(err).style(text::danger));
+        }
+
+        // Shortened hash of a file, files that didn't exist are shown as a dash
+        let short_hash = |hash: &Option<String>| match hash {
+            Some(hash) => hash.chars().take(12).collect::<String>(),
+            None => "\u{2014}".to_string(),
+        };
+
+        for (index, entry) in entries.iter().enumerate() {
+            let entry_text: Text = text(format!(
+                "{} \u{2014} {}",
+                format_time(entry.timestamp),
+                entry.action
+            ));
+
+            let mut entry_row = row![entry_text]
+                .spacing(10)
+                .align_y(iced::Alignment::Center);
+
+            // Only the most recent change can be undone
+            if index == 0 {
+                let undo_button: Button<_> = button(match history.undoing {
+                    true => tr("Undoing..."),
+                    false => tr("Undo"),
+                })
+                .on_press_maybe(
+                    (!history.undoing && !busy)
+                        .then(|| AppMessage::History(HistoryMessage::Undo(entry.id.clone()))),
+                )
+                .padding(5);
+                entry_row = entry_row.push(undo_button);
+            }
+
+            content = content.push(entry_row);
+
+            for file in &entry.files {
+                content = content.push(
+                    text(format!(
+                        "    {} {} \u{2192} {}",
+                        file.path.display(),
+                        short_hash(&file.before),
+                        short_hash(&file.after)
+                    ))
+                    .size(12)
+                    .style(muted_text),
+                );
+            }
+        }
+
+        container(scrollable(content))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(SPACING)
+            .into()
+    }
+
+    /// View for the settings screen
+    fn view_settings<'a>(&'a self, state: &'a SettingsState) -> iced::Element<'a, AppMessage> {
+        let language_text: Text = text(tr("Language")).style(muted_text);
+        let language_select = pick_list(Language::ALL, Some(state.language), |language| {
+            AppMessage::Settings(SettingsMessage::SetLanguage(language))
+        })
+        .padding(10);
+
+        let game_text: Text = text(tr("Game")).style(muted_text);
+        let game_select = pick_list(Game::ALL, Some(state.game), |game| {
+            AppMessage::Settings(SettingsMessage::SetGame(game))
+        })
+        .padding(10);
+
+        let reverts_checkbox = checkbox(
+            tr("Offer to apply the patch again when Origin or the EA App removes it"),
+            state.detect_patch_reverts,
+        )
+        .on_toggle(|value| AppMessage::Settings(SettingsMessage::SetDetectPatchReverts(value)));
+
+        let tray_checkbox = checkbox(
+            tr("Keep running in the system tray when the window is closed"),
+            state.tray_mode,
+        )
+        .on_toggle(|value| AppMessage::Settings(SettingsMessage::SetTrayMode(value)));
+
+        let proxy_text: Text = text(tr("Proxy used when connecting to GitHub")).style(muted_text);
+
+        let proxy_options = [
+            (tr("System proxy"), ProxyMode::System),
+            (tr("No proxy"), ProxyMode::Disabled),
+            (tr("Manual proxy"), ProxyMode::Manual),
+        ]
+        .into_iter()
+        .fold(row![].spacing(10), |options, (label, mode)| {
+            options.push(radio(label, mode, Some(state.proxy_mode), |mode| {
+                AppMessage::Settings(SettingsMessage::SetProxyMode(mode))
+            }))
+        });
+
+        let theme_text: Text = text(tr("Theme")).style(muted_text);
+        let theme_options = ThemeMode::ALL
+            .into_iter()
+            .fold(row![].spacing(10), |options, mode| {
+                options.push(radio(
+                    mode.to_string(),
+                    mode,
+                    Some(state.theme_mode),
+                    |mode| AppMessage::Settings(SettingsMessage::SetThemeMode(mode)),
+                ))
+            });
+
+        let accent_text: Text =
+            text(tr("Accent color (optional, hex color such as #5865f2)")).style(muted_text);
+        let accent_input = text_input("#5865f2", &state.accent_color)
+            .on_input(|value| AppMessage::Settings(SettingsMessage::SetAccentColor(value)))
+            .on_submit(AppMessage::Settings(SettingsMessage::Save))
+            .padding(10);
+
+        let mut content: Column<_> = column![
+            language_text,
+            language_select,
+            game_text,
+            game_select,
+            reverts_checkbox,
+            tray_checkbox,
+            theme_text,
+            theme_options,
+            accent_text,
+            accent_input,
+            proxy_text,
+            proxy_options
+        ]
+        .spacing(10);
+
+        if state.proxy_mode == ProxyMode::Manual {
+            let proxy_input = text_input(
+                "http://127.0.0.1:8080 or socks5://127.0.0.1:1080",
+                &state.proxy_url,
+            )
+            .on_input(|value| AppMessage::Settings(SettingsMessage::SetProxyUrl(value)))
+            .on_submit(AppMessage::Settings(SettingsMessage::Save))
+            .padding(10);
+            content = content.push(proxy_input);
+        }
+
+        let mirrors_text: Text = text(tr(
+            "Mirrors to use when GitHub is unreachable (comma separated, \
+            URL prefixes or URLs containing {url})",
+        ))
+        .style(muted_text);
+        let mirrors_input = text_input("https://mirror.example.com/", &state.mirrors)
+            .on_input(|value| AppMessage::Settings(SettingsMessage::SetMirrors(value)))
+            .on_submit(AppMessage::Settings(SettingsMessage::Save))
+            .padding(10);
+
+        content = content.push(mirrors_text).push(mirrors_input);
+
+        let token_text: Text = text(tr(
+            "GitHub personal access token (optional, raises the GitHub rate limit \
+            on shared networks)",
+        ))
+        .style(muted_text);
+        let token_input = text_input(tr("Token"), &state.github_token)
+            .on_input(|value| AppMessage::Settings(SettingsMessage::SetGitHubToken(value)))
+            .on_submit(AppMessage::Settings(SettingsMessage::Save))
+            .secure(true)
+            .padding(10);
+
+        content = content.push(token_text).push(token_input);
+
+        let limit_value = match state.download_limit {
+            0 => tr("Unlimited").to_string(),
+            limit => tr_args("{} KB/s", &[&limit]),
+        };
+        let limit_text: Text = text(tr_args(
+            "Download speed limit (for metered or shared connections): {}",
+            &[&limit_value],
+        ))
+        .style(muted_text);
+        let limit_slider = slider(0..=MAX_DOWNLOAD_LIMIT, state.download_limit, |value| {
+            AppMessage::Settings(SettingsMessage::SetDownloadLimit(value))
+        })
+        .step(DOWNLOAD_LIMIT_STEP);
+
+        content = content.push(limit_text).push(limit_slider);
+
+        let pages_text: Text = text(tr_args(
+            "Release pages to load (100 releases each): {}",
+            &[&state.max_release_pages],
+        ))
+        .style(muted_text);
+        let pages_slider = slider(1..=MAX_RELEASE_PAGES, state.max_release_pages, |value| {
+            AppMessage::Settings(SettingsMessage::SetMaxReleasePages(value))
+        });
+
+        content = content.push(pages_text).push(pages_slider);
+
+        let scale_text: Text =
+            text(tr_args("Interface scale: {}%", &[&state.ui_scale])).style(muted_text);
+        let scale_slider = slider(
+            MIN_UI_SCALE_PERCENT..=MAX_UI_SCALE_PERCENT,
+            state.ui_scale,
+            |value| AppMessage::Settings(SettingsMessage::SetUiScale(value)),
+        )
+        .step(UI_SCALE_STEP);
+
+        content = content.push(scale_text).push(scale_slider);
+
+        let feeds_text: Text = text(tr(
+            "Release feeds for additional channels such as nightly builds (comma \
+            separated, name=URL)",
+        ))
+        .style(muted_text);
+        let feeds_input = text_input(
+            "Nightly=https://example.com/releases.json",
+            &state.release_feeds,
+        )
+        .on_input(|value| AppMessage::Settings(SettingsMessage::SetReleaseFeeds(value)))
+        .on_submit(AppMessage::Settings(SettingsMessage::Save))
+        .padding(10);
+
+        // Channels from the feeds are available once the feeds input is valid
+        let mut channels = vec![ReleaseChannel::Stable, ReleaseChannel::Beta];
+        if let Ok(feeds) = parse_feeds(&state.release_feeds) {
+            channels.extend(
+                feeds
+                    .into_iter()
+                    .map(|feed| ReleaseChannel::Feed(feed.name)),
+            );
+        }
+
+        let channel_text: Text = text(tr("Default plugin release channel")).style(muted_text);
+        let channel_select = pick_list(channels, Some(state.release_channel.clone()), |channel| {
+            AppMessage::Settings(SettingsMessage::SetReleaseChannel(channel))
+        })
+        .padding(10);
+
+        content = content
+            .push(feeds_text)
+            .push(feeds_input)
+            .push(channel_text)
+            .push(channel_select);
+
+        let cache_text: Text = text(tr(
+            "Folder for downloads and cached releases (optional, uses the system \
+            folders when empty)",
+        ))
+        .style(muted_text);
+        let cache_placeholder = match portable_dir() {
+            Some(_) => tr("Portable folder"),
+            None => tr("System folders"),
+        };
+        let cache_input = text_input(cache_placeholder, &state.cache_dir)
+            .on_input(|value| AppMessage::Settings(SettingsMessage::SetCacheDir(value)))
+            .on_submit(AppMessage::Settings(SettingsMessage::Save))
+            .padding(10);
+        let cache_button: Button<_> = button(tr("Browse..."))
+            .on_press(AppMessage::Settings(SettingsMessage::PickCacheDir))
+            .padding(10);
+
+        let cache_downloads_checkbox = checkbox(
+            tr("Keep downloaded plugins to reinstall them without downloading again"),
+            state.cache_downloads,
+        )
+        .on_toggle(|value| AppMessage::Settings(SettingsMessage::SetCacheDownloads(value)));
+        let cache_usage_text = match state.cache_usage {
+            Some(usage) => tr_args(
+                "Download cache: {} files, {}",
+                &[&usage.files, &format_bytes(usage.bytes)],
+            ),
+            None => tr("Checking the download cache...").to_string(),
+        };
+        let clear_cache_button: Button<_> = button(tr("Clear download cache"))
+            .on_press_maybe(
+                (!state.clearing_cache && state.cache_usage.is_some_and(|usage| usage.files > 0))
+                    .then_some(AppMessage::Settings(SettingsMessage::ClearDownloadCache)),
+            )
+            .padding(10);
+
+        content = content
+            .push(cache_text)
+            .push(row![cache_input, cache_button].spacing(10))
+            .push(cache_downloads_checkbox)
+            .push(
+                row![text(cache_usage_text).style(muted_text), clear_cache_button]
+                    .spacing(10)
+                    .align_y(iced::Alignment::Center),
+            );
+
+        if let Some(path) = portable_dir() {
+            content = content.push(
+                text(tr_args(
+                    "Portable mode, the settings, cache and logs are stored in {}",
+                    &[&path.display()],
+                ))
+                .style(muted_text),
+            );
+        }
+
+        let links_text: Text = text(tr(
+            "Open the install links on Pocket Relay websites and server dashboards with \
+            this installer",
+        ))
+        .style(muted_text);
+        let links_button: Button<_> = button(tr("Open links with the installer"))
+            .on_press_maybe(
+                (!state.registering_links)
+                    .then_some(AppMessage::Settings(SettingsMessage::RegisterLinkHandler)),
+            )
+            .padding(10);
+        let mut links_row = row![links_button]
+            .spacing(10)
+            .align_y(iced::Alignment::Center);
+        if state.registered_links {
+            links_row =
+                links_row.push(text(tr("Links now open the installer")).style(text::success));
+        }
+
+        content = content.push(links_text).push(links_row);
+
+        let source_text: Text = text(tr(
+            "Plugin source (change to install a fork or test build of the plugin)",
+        ))
+        .style(muted_text);
+        let repository_input = text_input(DEFAULT_GITHUB_REPOSITORY, &state.plugin_repository)
+            .on_input(|value| AppMessage::Settings(SettingsMessage::SetPluginRepository(value)))
+            .on_submit(AppMessage::Settings(SettingsMessage::Save))
+            .padding(10);
+        let asset_input = text_input(DEFAULT_ASSET_NAME, &state.plugin_asset_name)
+            .on_input(|value| AppMessage::Settings(SettingsMessage::SetPluginAssetName(value)))
+            .on_submit(AppMessage::Settings(SettingsMessage::Save))
+            .padding(10);
+        let reset_source_button: Button<_> = button(tr("Use official plugin"))
+            .on_press(AppMessage::Settings(SettingsMessage::ResetPluginSource))
+            .padding(10);
+
+        content = content
+            .push(source_text)
+            .push(repository_input)
+            .push(asset_input)
+            .push(reset_source_button);
+
+        let stats_checkbox = checkbox(tr("Send anonymous usage statistics"), state.usage_stats)
+            .on_toggle(|value| AppMessage::Settings(SettingsMessage::SetUsageStats(value)));
+        let stats_text: Text = text(tr(
+            "Reports the installer version, operating system and whether each change to \
+            the game succeeded, so the most common problems can be fixed. Paths, errors \
+            and servers are never sent.",
+        ))
+        .style(muted_text);
+
+        content = content.push(stats_checkbox).push(stats_text);
+
+        if state.usage_stats {
+            let endpoint_input =
+                text_input("https://example.com/stats", &state.usage_stats_endpoint)
+                    .on_input(|value| {
+                        AppMessage::Settings(SettingsMessage::SetUsageStatsEndpoint(value))
+                    })
+                    .on_submit(AppMessage::Settings(SettingsMessage::Save))
+                    .padding(10);
+            let preview_text: Text =
+                text(tr("Example of a report that would be sent:")).style(muted_text);
+            let preview: Text = text(&state.usage_stats_preview).size(12);
+
+            content = content
+                .push(endpoint_input)
+                .push(preview_text)
+                .push(preview);
+        }
+
+        if let Some(err) = &state.error {
+            content = content.push(text(err).style(text::danger));
+        }
+
+        let save_button: Button<_> = button(tr("Save"))
+            .on_press(AppMessage::Settings(SettingsMessage::Save))
+            .padding(10);
+        let cancel_button: Button<_> = button(tr("Cancel"))
+            .on_press(AppMessage::Settings(SettingsMessage::Close))
+            .padding(10);
+
+        content = content.push(row![save_button, cancel_button].spacing(10));
+
+        container(scrollable(content))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(SPACING)
+            .into()
+    }
+
+    /// View for the downloads panel, only present when there are downloads
+    fn view_downloads_section(&self) -> Option<Column<'_, AppMessage>> {
+        if self.download_items.is_empty() {
+            return None;
+        }
+
+        let title_text: Text = text(tr("Downloads"));
+        let clear_button: Button<_> = button(tr("Clear finished"))
+            .on_press(AppMessage::Downloads(DownloadsMessage::ClearDone))
+            .padding(5);
+
+        let content = self.download_items.iter().fold(
+            column![row![title_text, clear_button]
+                .spacing(10)
+                .align_y(iced::Alignment::Center)]
+            .spacing(10),
+            |content, item| {
+                let status = match &item.status {
+                    DownloadStatus::Queued => text(tr("Queued")).style(muted_text),
+                    DownloadStatus::Downloading => text(
+                        item.progress
+                            .map(|progress| format_progress(&progress))
+                            .unwrap_or_else(|| tr("Downloading...").to_string()),
+                    )
+                    .style(text::primary),
+                    DownloadStatus::Finished => text(tr("Finished")).style(text::success),
+                    DownloadStatus::Failed(err) => {
+                        text(tr_args("Failed: {}", &[err])).style(text::danger)
+                    }
+                    DownloadStatus::Cancelled => text(tr("Cancelled")).style(muted_text),
+                };
+
+                let mut item_row = row![text(&item.name), status]
+                    .spacing(10)
+                    .align_y(iced::Alignment::Center);
+
+                if !item.status.is_done() {
+                    item_row = item_row.push(
+                        button(tr("Cancel"))
+                            .on_press(AppMessage::Downloads(DownloadsMessage::Cancel(item.id)))
+                            .padding(5),
+                    );
+                }
+
+                let fraction = item.progress.and_then(|progress| progress.fraction());
+
+                content
+                    .push(item_row)
+                    .push_maybe(fraction.map(|fraction| progress_bar(0.0..=1.0, fraction)))
+            },
+        );
+
+        Some(content)
+    }
+
+    /// View for the error from verifying the embedded DLLs, only present
+    /// when the installer is damaged
+    fn view_resources_error(&self) -> Option<Column<'_, AppMessage>> {
+        let err = self.resources_error.as_ref()?;
+
+        let error_text: Text = text(tr(
+            "The installer is damaged and can't safely patch your game. This is usually \
+            caused by an incomplete download or an antivirus modifying the installer, \
+            download the installer again and add an exclusion for it in your antivirus",
+        ))
+        .style(text::danger);
+        let details_text: Text = text(err).size(12).style(muted_text);
+
+        Some(column![error_text, details_text].spacing(10))
+    }
+
+    /// View for the link the installer was opened with, only present when
+    /// the link has a server to fill in or couldn't be opened
+    fn view_link_section(&self) -> Option<Column<'_, AppMessage>> {
+        let link_text: Text = match (&self.link_error, &self.link_server) {
+            (Some(err), _) => {
+                text(tr_args("The link could not be opened: {}", &[err])).style(text::danger)
+            }
+            (None, Some(server)) => text(tr_args(
+                "Opened from a link to the server {}, save the server connection once the \
+                plugin is installed to connect to it",
+                &[server],
+            ))
+            .style(text::primary),
+            (None, None) => return None,
+        };
+        let dismiss_button: Button<_> = button(tr("Dismiss"))
+            .on_press(AppMessage::DismissLink)
+            .padding(10);
+
+        Some(column![link_text, dismiss_button].spacing(10))
+    }
+
+    /// View for the installer update section, only present when there is
+    /// an update available
+    fn view_installer_update(&self) -> Option<Column<'_, AppMessage>> {
+        let section = match &self.installer_update_state {
+            InstallerUpdateState::None => return None,
+            InstallerUpdateState::Available(release) => {
+                let update_text: Text = text(tr_args(
+                    "A new version of the installer is available ({})",
+                    &[&release.tag_name],
+                ))
+                .style(text::primary);
+                let update_button: Button<_> = button(tr("Update installer"))
+                    .on_press(AppMessage::InstallerUpdate(InstallerUpdateMessage::Update))
+                    .padding(10);
+
+                column![update_text, update_button]
+            }
+            InstallerUpdateState::Updating => {
+                let update_text = text(tr("Updating installer...")).style(text::primary);
+                column![update_text]
+            }
+            InstallerUpdateState::Error(err) => {
+                let update_text: Text =
+                    text(tr_args("failed to update installer: {}", &[err])).style(text::danger);
+                column![update_text]
+            }
+        };
+
+        Some(section.spacing(10))
+    }
+
+    /// View for the patch game section
+    fn view_patch_section(state: &AppStateActive) -> Column<'_, AppMessage> {
+        let patched = state.patch.is_patched();
+
+        match (patched, &state.patch.alter) {
+            // Unknown binkw32.dll is present, we are in the initial state
+            (false, AlterPatchState::Initial) => match &state.patch.state {
+                PatchState::UnknownDll(hash) => Self::view_patch_unknown(hash),
+                _ => Self::view_patch_not_installed(),
+            },
+
+            // Patch is installed, we are in the initial state
+            (true, AlterPatchState::Initial) => Self::view_patch_installed(),
+
+            // Patch is installed, we are uninstalling
+            (true, AlterPatchState::Loading(_)) => Self::view_patch_uninstalling(),
+
+            // Patch is not installed, we are installing
+            (false, AlterPatchState::Loading(progress)) => Self::view_patch_installing(*progress),
+
+            // Patch was installed
+            (true, AlterPatchState::Success) => Self::view_patch_add_success(),
+
+            // Patch was uninstalled
+            (false, AlterPatchState::Success) => Self::view_patch_remove_success(),
+
+            // Game is running while installing/uninstalling
+            (patched, AlterPatchState::GameRunning) => Self::view_patch_game_running(patched),
+
+            // Error occurred while uninstalling
+            (true, AlterPatchState::Error(err)) => Self::view_patch_uninstall_error(err),
+
+            // Error occurred while installing
+            (false, AlterPatchState::Error(err)) => Self::view_patch_install_error(err),
+        }
+    }
+
+    /// View offering to apply the patch again, only present when the store
+    /// restored the original binkw32.dll over the patch
+    fn view_patch_reverted_section(state: &AppStateActive) -> Option<Column<'_, AppMessage>> {
+        if !state.patch_reverted {
+            return None;
+        }
+
+        let reverted_text: Text = text(tr(
+            "The patch was removed, the original binkw32.dll was restored (Origin and the \
+            EA App do this when repairing or updating the game). Apply the patch again to \
+            keep using Pocket Relay.",
+        ))
+        .style(text::danger);
+        let apply_button: Button<_> = button(tr("Apply patch again"))
+            .on_press_maybe((!state.is_busy()).then_some(AppMessage::Patch(PatchMessage::Add)))
+            .padding(10);
+        let dismiss_button: Button<_> = button(tr("Dismiss"))
+            .on_press(AppMessage::Patch(PatchMessage::DismissReverted))
+            .padding(10);
+
+        Some(
+            column![
+                reverted_text,
+                row![apply_button, dismiss_button].spacing(10)
+            ]
+            .spacing(10),
+        )
+    }
+
+    fn view_patch_installed() -> Column<'static, AppMessage> {
+        let patch_text: Text = text(tr("Your game is patched")).style(muted_text);
+        let remove_patch_button: Button<_> = button(tr("Remove Patch"))
+            .on_press(AppMessage::Patch(PatchMessage::Remove))
+            .padding(10);
+
+        column![patch_text, remove_patch_button].spacing(10)
+    }
+
+    fn view_patch_not_installed() -> Column<'static, AppMessage> {
+        let patch_text: Text = text(tr(
+            "Your game is not patched, you must apply the patch to use the client plugin.",
+        ))
+        .style(muted_text);
+        let apply_patch_button: Button<_> = button(tr("Apply Patch"))
+            .on_press(AppMessage::Patch(PatchMessage::Add))
+            .padding(10);
+
+        column![patch_text, with_shortcut(apply_patch_button, "Alt+P")].spacing(10)
+    }
+
+    fn view_patch_unknown(hash: &str) -> Column<'_, AppMessage> {
+        let patch_text: Text = text(tr(
+            "Your game has an unrecognized binkw32.dll, it may be from another mod or \
+            corrupted. Replacing it will back up the current file to binkw32.dll.bak",
+        ))
+        .style(text::danger);
+        let hash_text: Text = text(format!("SHA256: {hash}")).size(12).style(muted_text);
+        let replace_button: Button<_> = button(tr("Back up and replace"))
+            .on_press(AppMessage::Patch(PatchMessage::Add))
+            .padding(10);
+
+        column![patch_text, hash_text, replace_button].spacing(10)
+    }
+
+    fn view_patch_installing(progress: Option<Progress>) -> Column<'static, AppMessage> {
+        let patch_text = text(tr("Installing patch...")).style(text::primary);
+        column![patch_text]
+            .push_maybe(progress.map(Self::view_progress))
+            .spacing(10)
+    }
+
+    fn view_patch_uninstalling() -> Column<'static, AppMessage> {
+        let patch_text = text(tr("Uninstalling patch...")).style(text::primary);
+        column![patch_text].spacing(10)
+    }
+
+    fn view_patch_remove_success() -> Column<'static, AppMessage> {
+        let patch_text: Text = text(tr("Patch successfully removed.")).style(text::success);
+
+        let apply_patch_button: Button<_> = button(tr("Apply Patch"))
+            .on_press(AppMessage::Patch(PatchMessage::Add))
+            .padding(10);
+
+        column![patch_text, with_shortcut(apply_patch_button, "Alt+P")].spacing(10)
+    }
+
+    fn view_patch_add_success() -> Column<'static, AppMessage> {
+        let patch_text: Text = text(tr("Patch successfully installed.")).style(text::success);
+        let remove_patch_button: Button<_> = button(tr("Remove Patch"))
+            .on_press(AppMessage::Patch(PatchMessage::Remove))
+            .padding(10);
+
+        column![
+            row![patch_text, Self::view_verified()].spacing(10),
+            remove_patch_button
+        ]
+        .spacing(10)
+    }
+
+    /// View for the status shown once the written files have been verified
+    fn view_verified() -> Text<'static> {
+        text(tr("Verified")).style(text::success)
+    }
+
+    fn view_patch_game_running(patched: bool) -> Column<'static, AppMessage> {
+        let patch_text: Text = text(tr(
+            "Mass Effect 3 is currently running, close the game before changing the patch.",
+        ))
+        .style(text::danger);
+
+        let retry_message = if patched {
+            PatchMessage::Remove
+        } else {
+            PatchMessage::Add
+        };
+
+        let retry_button: Button<_> = button(tr("Retry"))
+            .on_press(AppMessage::Patch(retry_message))
+            .padding(10);
+        column![patch_text, retry_button].spacing(10)
+    }
+
+    /// View for an error `message` followed by the `action` button and a
+    /// button to copy the message, long messages wrap within the window
+    fn view_error<'a>(message: String, action: Button<'a, AppMessage>) -> Column<'a, AppMessage> {
+        let error_text: Text = text(message.clone())
+            .wrapping(text::Wrapping::WordOrGlyph)
+            .style(text::danger);
+        let copy_button: Button<_> = button(tr("Copy error"))
+            .on_press(AppMessage::CopyError(message))
+            .padding(10);
+
+        column![error_text, row![action, copy_button].spacing(10)].spacing(10)
+    }
+
+    fn view_patch_install_error(err: &str) -> Column<'static, AppMessage> {
+        let retry_button: Button<_> = button(tr("Retry"))
+            .on_press(AppMessage::Patch(PatchMessage::Add))
+            .padding(10);
+        Self::view_error(tr_args("failed to add patch: {}", &[&err]), retry_button)
+    }
+
+    fn view_patch_uninstall_error(err: &str) -> Column<'static, AppMessage> {
+        let retry_button: Button<_> = button(tr("Retry"))
+            .on_press(AppMessage::Patch(PatchMessage::Remove))
+            .padding(10);
+        Self::view_error(tr_args("failed to remove patch: {}", &[&err]), retry_button)
+    }
+
+    /// View for the add plugin section
+    fn view_plugin_section<'a>(
+        state: &'a AppStateActive,
+        plugin_details: &'a PluginDetailsState,
+        theme: &Theme,
+    ) -> Column<'a, AppMessage> {
+        match (state.plugin.state, &state.plugin.alter) {
+            // Plugin is installed, we are in the initial state
+            (PluginState::Installed, AlterPluginState::Initial) => {
+                Self::view_plugin_installed(state, plugin_details)
+            }
+
+            // Plugin is installed but disabled, we are in the initial state
+            (PluginState::Disabled, AlterPluginState::Initial) => Self::view_plugin_disabled(state),
+
+            // Plugin is not installed, we are in the initial state
+            (PluginState::Absent, AlterPluginState::Initial) => {
+                Self::view_plugin_not_installed(state, plugin_details, theme)
+            }
+
+            // Plugin is not installed, we are installing
+            (PluginState::Absent, AlterPluginState::Loading(progress)) => {
+                Self::view_plugin_installing(*progress)
+            }
+
+            // Plugin is installed, we are uninstalling
+            (_, AlterPluginState::Loading(_)) => Self::view_plugin_uninstalling(),
+
+            // Plugin is being updated
+            (_, AlterPluginState::Updating(progress)) => Self::view_plugin_installing(*progress),
+
+            // Plugin is being enabled or disabled
+            (_, AlterPluginState::Toggling(enabled)) => Self::view_plugin_toggling(*enabled),
+
+            // Plugin was uninstalled
+            (PluginState::Absent, AlterPluginState::Success) => {
+                Self::view_plugin_remove_success(state, plugin_details, theme)
+            }
+
+            // Plugin was installed
+            (_, AlterPluginState::Success) => Self::view_plugin_add_success(state, plugin_details),
+
+            // Game is running while installing/uninstalling
+            (_, AlterPluginState::GameRunning(retry)) => {
+                Self::view_plugin_game_running(*retry, plugin_details)
+            }
+
+            // Error occurred while updating
+            (_, AlterPluginState::UpdateError(err)) => {
+                Self::view_plugin_update_error(err, plugin_details)
+            }
+
+            // Error occurred while enabling or disabling
+            (_, AlterPluginState::ToggleError(enabled, err)) => {
+                Self::view_plugin_toggle_error(*enabled, err)
+            }
+
+            // Error occurred while installing
+            (PluginState::Absent, AlterPluginState::Error(err)) => {
+                Self::view_plugin_install_error(err)
+            }
+
+            // Error occurred while uninstalling
+            (_, AlterPluginState::Error(err)) => Self::view_plugin_uninstall_error(err),
+
+            // Plugin was removed by an antivirus after installing
+            (_, AlterPluginState::Quarantined) => Self::view_plugin_quarantined(state),
+        }
+    }
+
+    fn view_plugin_installed<'a>(
+        state: &'a AppStateActive,
+        plugin_details: &'a PluginDetailsState,
+    ) -> Column<'a, AppMessage> {
+        let plugin_text: Text =
+            text(tr("You have the Pocket Relay client plugin installed.")).style(muted_text);
+        let version_section = Self::view_plugin_version(state, plugin_details);
+        let disable_plugin_button: Button<_> = button(tr("Disable Plugin"))
+            .on_press(AppMessage::Plugin(PluginMessage::SetEnabled(false)))
+            .padding(10);
+        let remove_plugin_button: Button<_> = button(tr("Remove Plugin"))
+            .on_press(AppMessage::Plugin(PluginMessage::Remove))
+            .padding(10);
+
+        column![
+            plugin_text,
+            version_section,
+            row![disable_plugin_button, remove_plugin_button].spacing(10)
+        ]
+        .spacing(10)
+    }
+
+    /// View for the plugin being installed but disabled, the game connects
+    /// to the official servers until its enabled again
+    fn view_plugin_disabled(state: &AppStateActive) -> Column<'_, AppMessage> {
+        let plugin_text: Text = text(tr(
+            "The Pocket Relay client plugin is disabled, the game will connect to the \
+            official servers until its enabled.",
+        ))
+        .style(muted_text);
+        let installed = state.plugin.version.as_deref().unwrap_or(tr("Unknown"));
+        let version_text: Text = text(tr_args("Installed: {}", &[&installed])).style(muted_text);
+        let enable_plugin_button: Button<_> = button(tr("Enable Plugin"))
+            .on_press(AppMessage::Plugin(PluginMessage::SetEnabled(true)))
+            .padding(10);
+        let remove_plugin_button: Button<_> = button(tr("Remove Plugin"))
+            .on_press(AppMessage::Plugin(PluginMessage::Remove))
+            .padding(10);
+
+        column![
+            plugin_text,
+            version_text,
+            row![enable_plugin_button, remove_plugin_button].spacing(10)
+        ]
+        .spacing(10)
+    }
+
+    fn view_plugin_toggling(enabled: bool) -> Column<'static, AppMessage> {
+        let status = match enabled {
+            true => tr("Enabling plugin..."),
+            false => tr("Disabling plugin..."),
+        };
+        let plugin_text = text(status).style(text::primary);
+        column![plugin_text].spacing(10)
+    }
+
+    /// View for the installed plugin version and the available update
+    fn view_plugin_version<'a>(
+        state: &'a AppStateActive,
+        plugin_details: &'a PluginDetailsState,
+    ) -> Column<'a, AppMessage> {
+        let installed = state.plugin.version.as_deref().unwrap_or(tr("Unknown"));
+
+        let latest_release = match plugin_details {
+            PluginDetailsState::Ready(details) => &details.latest,
+            // Update availability is unknown
+            _ => {
+                let version_text: Text =
+                    text(tr_args("Installed: {}", &[&installed])).style(muted_text);
+                return column![version_text];
+            }
+        };
+        let latest = &latest_release.tag_name;
+
+        if !is_plugin_update_available(state.plugin.version.as_deref(), latest) {
+            let version_text: Text =
+                text(tr_args("Installed: {} (Latest)", &[&installed])).style(muted_text);
+            return column![version_text];
+        }
+
+        let version_text: Text = text(tr_args(
+            "Installed: {} \u{2014} Update available: {}",
+            &[&installed, latest],
+        ))
+        .style(text::primary);
+        let update_button: Button<_> = button(tr("Update Plugin"))
+            .on_press_maybe(plugin_update_message(plugin_details))
+            .padding(10);
+
+        column![version_text, update_button]
+            .push_maybe(required_installer_version(latest_release).map(|version| {
+                text(tr_args(
+                    "Plugin {} requires installer version {} or newer, update the \
+                    installer to install it",
+                    &[latest, &version],
+                ))
+                .style(text::danger)
+            }))
+            .spacing(10)
+    }
+
+    /// View for the banner shown when a newer plugin release is available,
+    /// only present while the plugin is installed and not being changed
+    fn view_plugin_update_banner<'a>(
+        state: &'a AppStateActive,
+        plugin_details: &'a PluginDetailsState,
+    ) -> Option<iced::Element<'a, AppMessage>> {
+        if !state.plugin.is_installed() || !state.plugin.is_idle() {
+            return None;
+        }
+
+        let latest = match plugin_details {
+            PluginDetailsState::Ready(details) => &details.latest.tag_name,
+            _ => return None,
+        };
+
+        if !is_plugin_update_available(state.plugin.version.as_deref(), latest) {
+            return None;
+        }
+
+        let update_text: Text =
+            text(tr_args("Plugin update available ({})", &[latest])).style(text::primary);
+        let update_button: Button<_> = button(tr("Update"))
+            .on_press_maybe(plugin_update_message(plugin_details))
+            .padding(5);
+
+        let banner = row![update_text, update_button]
+            .spacing(10)
+            .align_y(iced::Alignment::Center);
+
+        Some(container(banner).padding(5).into())
+    }
+
+    fn view_plugin_not_installed<'a>(
+        state: &'a AppStateActive,
+        plugin_details: &'a PluginDetailsState,
+        theme: &Theme,
+    ) -> Column<'a, AppMessage> {
+        let plugin_text: Text = text(tr(
+            "You do not have the Pocket Relay client plugin installed",
+        ))
+        .style(muted_text);
+        let add_plugin = Self::view_add_plugin(state, plugin_details, theme);
+        column![plugin_text, add_plugin].spacing(10)
+    }
+
+    fn view_plugin_installing(progress: Option<Progress>) -> Column<'static, AppMessage> {
+        let plugin_text = text(tr("Installing plugin...")).style(text::primary);
+        column![plugin_text]
+            .push_maybe(progress.map(Self::view_progress))
+            .spacing(10)
+    }
+
+    /// View for the current step of installing, includes a progress bar
+    /// when the size of the step is known
+    fn view_progress(progress: Progress) -> Column<'static, AppMessage> {
+        let step_text = text(format_step(&progress)).style(muted_text);
+        let step_progress = progress
+            .fraction()
+            .map(|fraction| progress_bar(0.0..=1.0, fraction).height(Length::Fixed(10.0)));
+
+        column![step_text].push_maybe(step_progress).spacing(10)
+    }
+
+    fn view_plugin_uninstalling() -> Column<'static, AppMessage> {
+        let plugin_text = text(tr("Uninstalling plugin...")).style(text::primary);
+        column![plugin_text].spacing(10)
+    }
+
+    fn view_plugin_add_success<'a>(
+        state: &'a AppStateActive,
+        plugin_details: &'a PluginDetailsState,
+    ) -> Column<'a, AppMessage> {
+        let plugin_text: Text =
+            text(tr("Pocket Relay client plugin successfully installed.")).style(text::success);
+        let version_section = Self::view_plugin_version(state, plugin_details);
+        let remove_plugin_button: Button<_> = button(tr("Remove Plugin"))
+            .on_press(AppMessage::Plugin(PluginMessage::Remove))
+            .padding(10);
+
+        column![
+            row![plugin_text, Self::view_verified()].spacing(10),
+            version_section,
+        ]
+        .push_maybe(Self::view_checksum_status(state))
+        .push(remove_plugin_button)
+        .push(Self::view_launch_game(state))
+        .spacing(10)
+    }
+
+    /// View for the result of checking the installed plugin against the
+    /// release checksums, none when the plugin wasn't from a release
+    fn view_checksum_status<'a>(state: &AppStateActive) -> Option<Text<'a>> {
+        let status_text = match state.checksum_status? {
+            ChecksumStatus::Verified => {
+                text(tr("Plugin matches the checksum published with the release"))
+                    .style(text::success)
+            }
+            ChecksumStatus::Unavailable => text(tr(
+                "Release doesn't include a checksums file for the plugin",
+            ))
+            .style(muted_text),
+        };
+
+        Some(status_text)
+    }
+
+    /// View for whether the plugin will be loaded when the game starts,
+    /// combines the plugin state with the ASI loaders that are present
+    fn view_readiness(state: &AppStateActive) -> Text<'_> {
+        match check_readiness(state.plugin.state, &state.patch.state, &state.loaders) {
+            Readiness::Ready(loader) => text(tr_args(
+                "Ready to play, the plugin is loaded by {}",
+                &[&loader],
+            ))
+            .style(text::success),
+            Readiness::NotInstalled => {
+                text(tr("Not ready, the plugin is not installed")).style(muted_text)
+            }
+            Readiness::Disabled => text(tr("Not ready, the plugin is disabled")).style(muted_text),
+            Readiness::WrongFolder(loader) => text(tr_args(
+                "Not ready, {} doesn't load plugins from the ASI folder, apply the patch \
+                so the plugin is loaded",
+                &[&loader],
+            ))
+            .style(text::danger),
+            Readiness::NoLoader => text(tr(
+                "Not ready, nothing will load the plugin, apply the patch so the plugin is loaded",
+            ))
+            .style(text::danger),
+        }
+    }
+
+    /// View for launching the game to check the plugin works, includes
+    /// the error from the last launch attempt
+    fn view_launch_game(state: &AppStateActive) -> Column<'_, AppMessage> {
+        let launch_button: Button<_> = button(tr("Launch Mass Effect 3"))
+            .on_press(AppMessage::LaunchGame)
+            .padding(10);
+
+        column![launch_button]
+            .push_maybe(
+                state.launch_error.as_ref().map(|err| {
+                    text(tr_args("failed to launch game: {}", &[err])).style(text::danger)
+                }),
+            )
+            .spacing(10)
+    }
+
+    fn view_plugin_remove_success<'a>(
+        state: &'a AppStateActive,
+        plugin_details: &'a PluginDetailsState,
+        theme: &Theme,
+    ) -> Column<'a, AppMessage> {
+        let plugin_text: Text =
+            text(tr("Pocket Relay client plugin successfully removed.")).style(text::success);
+
+        let add_plugin = Self::view_add_plugin(state, plugin_details, theme);
+        column![plugin_text, add_plugin].spacing(10)
+    }
+
+    fn view_plugin_game_running(
+        retry: PluginAction,
+        plugin_details: &PluginDetailsState,
+    ) -> Column<'static, AppMessage> {
+        let plugin_text: Text = text(tr(
+            "Mass Effect 3 is currently running, close the game before changing the plugin.",
+        ))
+        .style(text::danger);
+
+        let retry_message = match retry {
+            PluginAction::Add => Some(AppMessage::Plugin(PluginMessage::Add)),
+            PluginAction::Update => plugin_update_message(plugin_details),
+            PluginAction::Remove => Some(AppMessage::Plugin(PluginMessage::Remove)),
+            PluginAction::SetEnabled(enabled) => {
+                Some(AppMessage::Plugin(PluginMessage::SetEnabled(enabled)))
+            }
+        };
+        let retry_button: Button<_> = button(tr("Retry"))
+            .on_press_maybe(retry_message)
+            .padding(10);
+        column![plugin_text, retry_button].spacing(10)
+    }
+
+    fn view_plugin_toggle_error(enabled: bool, err: &str) -> Column<'static, AppMessage> {
+        let retry_button: Button<_> = button(tr("Retry"))
+            .on_press(AppMessage::Plugin(PluginMessage::SetEnabled(enabled)))
+            .padding(10);
+        let message = match enabled {
+            true => tr_args("failed to enable plugin: {}", &[&err]),
+            false => tr_args("failed to disable plugin: {}", &[&err]),
+        };
+        Self::view_error(message, retry_button)
+    }
+
+    fn view_plugin_update_error(
+        err: &str,
+        plugin_details: &PluginDetailsState,
+    ) -> Column<'static, AppMessage> {
+        let retry_button: Button<_> = button(tr("Retry"))
+            .on_press_maybe(plugin_update_message(plugin_details))
+            .padding(10);
+        Self::view_error(
+            tr_args("failed to update plugin: {}", &[&err]),
+            retry_button,
+        )
+    }
+
+    fn view_plugin_install_error(err: &str) -> Column<'static, AppMessage> {
+        let retry_button: Button<_> = button(tr("Retry"))
+            .on_press(AppMessage::Plugin(PluginMessage::Add))
+            .padding(10);
+        Self::view_error(
+            tr_args("failed to install plugin: {}", &[&err]),
+            retry_button,
+        )
+    }
+
+    fn view_plugin_uninstall_error(err: &str) -> Column<'static, AppMessage> {
+        let retry_button: Button<_> = button(tr("Retry"))
+            .on_press(AppMessage::Plugin(PluginMessage::Remove))
+            .padding(10);
+        Self::view_error(
+            tr_args("failed to remove plugin: {}", &[&err]),
+            retry_button,
+        )
+    }
+
+    /// View for the plugin being removed by an antivirus after installing,
+    /// explains how to exclude the plugin folder before reinstalling
+    fn view_plugin_quarantined(state: &AppStateActive) -> Column<'_, AppMessage> {
+        let plugin_text: Text = text(tr(
+            "The plugin was removed right after it was installed, this is usually your \
+            antivirus falsely detecting the plugin as a threat and quarantining it.",
+        ))
+        .style(text::danger);
+
+        let exclusion_text: Text = text(tr(
+            "Add an exclusion for the following folder in your antivirus (for Windows \
+            Defender: Virus & threat protection > Manage settings > Exclusions), \
+            then reinstall the plugin:",
+        ))
+        .style(muted_text);
+        let folder_text: Text = text(state.path.join(PLUGIN_DIR).display().to_string()).size(12);
+
+        let reinstall_button: Button<_> = button(tr("Reinstall"))
+            .on_press(AppMessage::Plugin(PluginMessage::Add))
+            .padding(10);
+
+        column![plugin_text, exclusion_text, folder_text, reinstall_button].spacing(10)
+    }
+
+    /// View for the add plugin details and buttons
+    fn view_add_plugin<'a>(
+        state: &'a AppStateActive,
+        plugin_details: &'a PluginDetailsState,
+        theme: &Theme,
+    ) -> Column<'a, AppMessage> {
+        let release_section =
+            Self::view_plugin_release(plugin_details, state.server_version.as_ref(), theme);
+
+        // Adding can be requested before the details load, the plugin is
+        // installed once they're ready
+        let queued_section = match plugin_details {
+            PluginDetailsState::Loading if state.plugin_add_queued => {
+                let queued_text: Text = text(tr(
+                    "The plugin will be installed once the release details load...",
+                ))
+                .style(text::primary);
+                let cancel_button: Button<_> = button(tr("Cancel"))
+                    .on_press(AppMessage::Plugin(PluginMessage::CancelQueuedAdd))
+                    .padding(5);
+
+                Some(
+                    row![queued_text, cancel_button]
+                        .spacing(10)
+                        .align_y(iced::Alignment::Center),
+                )
+            }
+            PluginDetailsState::Loading => {
+                let add_plugin_button: Button<_> = button(tr("Add Plugin"))
+                    .on_press(AppMessage::Plugin(PluginMessage::Add))
+                    .padding(10);
+
+                Some(row![with_shortcut(add_plugin_button, "Alt+A")])
+            }
+            _ => None,
+        };
+
+        // Installing from a file is always available, even without network access
+        let add_file_button: Button<_> = button(tr("Install from file..."))
+            .on_press(AppMessage::Plugin(PluginMessage::AddFromFile))
+            .padding(10);
+
+        release_section
+            .push_maybe(queued_section)
+            .push(add_file_button)
+    }
+
+    /// View for the plugin release details and version selection
+    fn view_plugin_release<'a>(
+        plugin_details: &'a PluginDetailsState,
+        server_version: Option<&'a Version>,
+        theme: &Theme,
+    ) -> Column<'a, AppMessage> {
+        match plugin_details {
+            // Still loading the plugin details
+            PluginDetailsState::Loading => {
+                let plugin_version_text: Text =
+                    text(tr("Loading latest plugin version details...")).style(muted_text);
+                // Slow networks can leave the details loading for a while
+                let retry_button: Button<_> = button(tr("Retry"))
+                    .on_press(AppMessage::PluginDetails(PluginDetailsMessage::Retry))
+                    .padding(5);
+                column![row![plugin_version_text, retry_button]
+                    .spacing(10)
+                    .align_y(iced::Alignment::Center)]
+                .spacing(10)
+            }
+            PluginDetailsState::Error(err) => {
+                let plugin_version_text: Text =
+                    text(tr_args("Unable to load latest plugin version: {}", &[err]))
+                        .style(muted_text);
+                let network_text: Text = text(tr(
+                    "Use \"Network diagnostics\" in the logs section to find out why",
+                ))
+                .style(muted_text);
+                let retry_button: Button<_> = button(tr("Retry"))
+                    .on_press(AppMessage::PluginDetails(PluginDetailsMessage::Retry))
+                    .padding(10);
+                column![plugin_version_text, network_text, retry_button].spacing(10)
+            }
+            PluginDetailsState::Ready(plugin_details) => {
+                let version = &plugin_details.latest.tag_name;
+
+                let plugin_version_text: Text = text(tr_args(
+                    "The latest version of the plugin client is {}",
+                    &[version],
+                ))
+                .style(muted_text);
+
+                let required_version =
+                    required_installer_version(plugin_details.selected.release());
+
+                let add_plugin_button: Button<_> = button(tr("Add Plugin"))
+                    .on_press_maybe(
+                        required_version
+                            .is_none()
+                            .then_some(AppMessage::Plugin(PluginMessage::Add)),
+                    )
+                    .padding(10);
+
+                let version_select = combo_box(
+                    &plugin_details.release_type_state,
+                    tr("Select version"),
+                    Some(&plugin_details.selected),
+                    |value| AppMessage::Plugin(PluginMessage::SelectType(value)),
+                )
+                .padding(10);
+
+                let notes_button: Button<_> = button(if plugin_details.show_release_notes {
+                    tr("Hide notes")
+                } else {
+                    tr("Release notes")
+                })
+                .on_press(AppMessage::Plugin(PluginMessage::ToggleReleaseNotes))
+                .padding(10);
+
+                let add_row = row![
+                    with_shortcut(add_plugin_button, "Alt+A"),
+                    version_select,
+                    notes_button
+                ]
+                .spacing(10);
+                let mut content = column![plugin_version_text, add_row].spacing(10);
+
+                if let Some(version) = &required_version {
+                    content = content.push(
+                        text(tr_args(
+                            "Plugin {} requires installer version {} or newer, update the \
+                            installer to install it",
+                            &[&plugin_details.selected.release().tag_name, version],
+                        ))
+                        .style(text::danger),
+                    );
+                }
+
+                // Publish date and size of the plugin file that would be installed
+                let release = plugin_details.selected.release();
+                let asset_size = plugin_details
+                    .source
+                    .select_asset(release, plugin_details.selected_asset.as_deref())
+                    .map(|asset| asset.size);
+                // Publish date is missing when the API didn't provide it
+                let published = Some(release.published_at.as_str())
+                    .filter(|published_at| !published_at.is_empty())
+                    .map(format_timestamp);
+                let release_info = match (published, asset_size) {
+                    (Some(published), Some(size)) => Some(tr_args(
+                        "Published {}, download size {}",
+                        &[&published, &format_bytes(size)],
+                    )),
+                    (Some(published), None) => Some(tr_args("Published {}", &[&published])),
+                    (None, Some(size)) => Some(tr_args("Download size {}", &[&format_bytes(size)])),
+                    (None, None) => None,
+                };
+                content = content.push_maybe(release_info.map(|info| text(info).style(muted_text)));
+
+                if plugin_details.has_more_releases {
+                    content = content.push(
+                        text(tr(
+                            "Older releases aren't listed, raise the release page limit \
+                            in the settings to see them",
+                        ))
+                        .style(muted_text),
+                    );
+                }
+
+                // Only offer a choice when the release includes multiple plugin files
+                let release = plugin_details.selected.release();
+                let asset_names: Vec<String> = plugin_details
+                    .source
+                    .find_assets(release)
+                    .into_iter()
+                    .map(|asset| asset.name.clone())
+                    .collect();
+
+                if asset_names.len() > 1 {
+                    let asset_text: Text = text(tr("Plugin file")).style(muted_text);
+                    let asset_select =
+                        pick_list(asset_names, plugin_details.asset_name(release), |value| {
+                            AppMessage::Plugin(PluginMessage::SelectAsset(value))
+                        })
+                        .padding(10);
+
+                    content = content.push(
+                        row![asset_text, asset_select]
+                            .spacing(10)
+                            .align_y(iced::Alignment::Center),
+                    );
+                }
+
+                if let Some(version) = server_version {
+                    content = content.push_maybe(Self::view_server_compatibility(
+                        plugin_details.selected.release(),
+                        version,
+                    ));
+                }
+
+                if plugin_details.show_release_notes {
+                    content = content.push(Self::view_release_notes(plugin_details, theme));
+                }
+
+                content
+            }
+        }
+    }
+
+    /// View for the warning shown when the `release` doesn't support the
+    /// server `version`, none when the release is compatible
+    fn view_server_compatibility<'a>(
+        release: &GitHubRelease,
+        version: &Version,
+    ) -> Option<Text<'a>> {
+        let message = match check_compatibility(release, version) {
+            ServerCompatibility::Compatible => return None,
+            ServerCompatibility::TooNew => tr_args(
+                "Plugin {} is too new for your server (version {}), update the server \
+                or pick an older plugin version",
+                &[&release.tag_name, version],
+            ),
+            ServerCompatibility::TooOld => tr_args(
+                "Plugin {} is too old for your server (version {}), pick a newer \
+                plugin version",
+                &[&release.tag_name, version],
+            ),
+        };
+
+        Some(text(message).style(text::danger))
+    }
+
+    /// View for the release notes panel of the selected release
+    fn view_release_notes<'a>(
+        plugin_details: &'a PluginDetails,
+        theme: &Theme,
+    ) -> Column<'a, AppMessage> {
+        let release = plugin_details.selected.release();
+
+        let notes: iced::Element<'_, AppMessage> = if plugin_details.release_notes.is_empty() {
+            text(tr("This release has no release notes"))
+                .style(muted_text)
+                .into()
+        } else {
+            markdown::view(
+                &plugin_details.release_notes,
+                markdown::Settings::default(),
+                markdown::Style::from_palette(theme.palette()),
+            )
+            .map(|url| AppMessage::OpenLink(url.to_string()))
+        };
+
+        // Release URL is missing when the API didn't provide it
+        let github_button: Button<_> = button(tr("View on GitHub"))
+            .on_press_maybe(
+                (!release.html_url.is_empty())
+                    .then(|| AppMessage::OpenLink(release.html_url.clone())),
+            )
+            .padding(5);
+
+        column![container(notes).padding(10), github_button].spacing(10)
+    }
+
+    /// Window size for the initial state, expanded when there are
+    /// detected game paths to choose from
+    fn initial_window_size(&self) -> Size {
+        let has_update = !matches!(self.installer_update_state, InstallerUpdateState::None);
+
+        let size = if self.detected_paths.is_empty() && !has_update {
+            WINDOW_SIZE
+        } else {
+            EXPANDED_WINDOW_SIZE
+        };
+
+        size * self.config.ui_scale()
+    }
+
+    /// Handles the `message` then records a summary of the resulting
+    /// state to include in crash reports
+    fn update_tracked(&mut self, message: AppMessage) -> Task<AppMessage> {
+        let task = self.update(message);
+        let reverted = self.check_patch_reverted();
+        set_app_state(self.state_summary());
+        Task::batch([task, reverted])
+    }
+
+    /// Checks whether the store restored the original binkw32.dll over the
+    /// patch (e.g Origin or the EA App repairing the game) so applying the
+    /// patch again can be offered, the game folder is remembered once patched
+    fn check_patch_reverted(&mut self) -> Task<AppMessage> {
+        let state = match &mut self.state {
+            AppState::Active(state) => state,
+            _ => return Task::none(),
+        };
+
+        // Patch state is only settled once the operation finishes
+        if state.is_busy() {
+            return Task::none();
+        }
+
+        let known = self.config.patched_installations.contains(&state.path);
+
+        match state.patch.state {
+            PatchState::Patched => {
+                state.patch_reverted = false;
+                if known {
+                    return Task::none();
+                }
+
+                self.config.patched_installations.push(state.path.clone());
+                save_config_task(self.config.clone())
+            }
+            PatchState::Unpatched
+                if known && self.config.detect_patch_reverts && !state.patch_reverted =>
+            {
+                debug!("patch was reverted for {}", state.path.display());
+                state.patch_reverted = true;
+
+                if !self.unfocused {
+                    return Task::none();
+                }
+
+                Task::future(notify(
+                    tr("Patch removed").to_string(),
+                    tr(
+                        "The original binkw32.dll was restored, open the installer to apply \
+                    the patch again",
+                    )
+                    .to_string(),
+                ))
+                .discard()
+            }
+            _ => Task::none(),
+        }
+    }
+
+    /// Short summary of the current screen and game state
+    fn state_summary(&self) -> String {
+        let screen = match (&self.state, &self.settings) {
+            (_, Some(_)) => "settings".to_string(),
+            (AppState::Initial(_), None) => "choose game".to_string(),
+            (AppState::Active(state), None) => format!(
+                "game {} (patch: {:?}, plugin: {:?}, version: {:?}, busy: {})",
+                state.path.display(),
+                state.patch.state,
+                state.plugin.state,
+                state.plugin.version,
+                state.is_busy()
+            ),
+        };
+
+        let plugin_details = match &self.plugin_details_state {
+            PluginDetailsState::Loading => "loading",
+            PluginDetailsState::Ready(_) => "ready",
+            PluginDetailsState::Error(_) => "error",
+        };
+
+        format!("screen: {screen}, plugin details: {plugin_details}")
+    }
+
+    fn update(&mut self, message: AppMessage) -> Task<AppMessage> {
+        match message {
+            AppMessage::Game(msg) => {
+                let previous_path = self.active_path();
+                let task = self.update_game(msg).map(AppMessage::Game);
+                let current_path = self.active_path();
+
+                // Load the ASI plugins and plugin config when a game is selected
+                if let Some(path) = current_path.filter(|path| previous_path.as_ref() != Some(path))
+                {
+                    return Task::batch([
+                        task,
+                        self.update_asi(AsiMessage::Refresh),
+                        self.update_compat(CompatMessage::Scan),
+                        self.update_leftovers(LeftoversMessage::Scan),
+                        self.update_plugin_config(PluginConfigMessage::Refresh),
+                        self.track(Task::perform(check_folder_access(path), |access| {
+                            AppMessage::Elevation(ElevationMessage::Checked(access))
+                        })),
+                    ]);
+                }
+
+                task
+            }
+            AppMessage::Patch(msg @ (PatchMessage::Add | PatchMessage::Remove))
+                if self.config.preview_changes =>
+            {
+                self.preview_action(PendingAction::Patch(msg))
+            }
+            AppMessage::Plugin(
+                msg @ (PluginMessage::Add
+                | PluginMessage::Update
+                | PluginMessage::Remove
+                | PluginMessage::PickedFile(Ok(Some(_)))),
+            ) if self.config.preview_changes => self.preview_action(PendingAction::Plugin(msg)),
+            AppMessage::InstallAll(InstallAllMessage::Start) if self.config.preview_changes => {
+                self.preview_action(PendingAction::InstallAll)
+            }
+            AppMessage::InstallAll(msg) => {
+                let changed = matches!(msg, InstallAllMessage::Finished(Ok(_)));
+                let notification = match &msg {
+                    InstallAllMessage::Finished(result) => Task::batch([
+                        self.install_notification_task(result),
+                        self.usage_report_task(UsageOperation::InstallAll, result),
+                    ]),
+                    _ => Task::none(),
+                };
+                let task = self.update_install_all(msg).map(AppMessage::InstallAll);
+
+                // Reload the ASI plugins and details to include the changes
+                if changed {
+                    return Task::batch([
+                        task,
+                        notification,
+                        self.update_asi(AsiMessage::Refresh),
+                        self.update_details(DetailsMessage::Refresh),
+                        self.restore_backup_task(),
+                        self.quarantine_check_task(),
+                    ]);
+                }
+
+                Task::batch([task, notification])
+            }
+            AppMessage::Batch(msg) => {
+                let finished = matches!(msg, BatchMessage::Finished(..));
+                let report = match &msg {
+                    BatchMessage::Finished(_, result) => {
+                        self.usage_report_task(UsageOperation::InstallAll, result)
+                    }
+                    _ => Task::none(),
+                };
+                let task = Task::batch([self.update_batch(msg), report]);
+
+                // Reload the active game once every installation has finished
+                if finished && !self.is_busy() {
+                    return Task::batch([
+                        task,
+                        self.refresh_game(),
+                        self.update_details(DetailsMessage::Refresh),
+                    ]);
+                }
+
+                task
+            }
+            AppMessage::Uninstall(msg) => {
+                let changed = matches!(msg, UninstallMessage::Finished(Ok(_)));
+                let report = match &msg {
+                    UninstallMessage::Finished(result) => {
+                        self.usage_report_task(UsageOperation::Uninstall, result)
+                    }
+                    _ => Task::none(),
+                };
+                let task = Task::batch([
+                    self.update_uninstall(msg).map(AppMessage::Uninstall),
+                    report,
+                ]);
+
+                // Reload the ASI plugins, plugin config and details to reflect the removal
+                if changed {
+                    return Task::batch([
+                        task,
+                        self.update_asi(AsiMessage::Refresh),
+                        self.update_leftovers(LeftoversMessage::Scan),
+                        self.update_plugin_config(PluginConfigMessage::Refresh),
+                        self.update_details(DetailsMessage::Refresh),
+                    ]);
+                }
+
+                task
+            }
+            AppMessage::Elevation(msg) => self.update_elevation(msg),
+            AppMessage::Logs(msg) => self.update_logs(msg),
+            AppMessage::Proton(msg) => self.update_proton(msg),
+            AppMessage::Preview(msg) => self.update_preview(msg),
+            AppMessage::Patch(msg) => {
+                let changed = matches!(
+                    msg,
+                    PatchMessage::Added(Ok(_)) | PatchMessage::Removed(Ok(_))
+                );
+                let report = match &msg {
+                    PatchMessage::Added(result) => {
+                        self.usage_report_task(UsageOperation::ApplyPatch, result)
+                    }
+                    PatchMessage::Removed(result) => {
+                        self.usage_report_task(UsageOperation::RemovePatch, result)
+                    }
+                    _ => Task::none(),
+                };
+                let task = Task::batch([self.update_patch(msg).map(AppMessage::Patch), report]);
+
+                // Scan again as the patch replaces any conflicting binkw32.dll
+                if changed {
+                    return Task::batch([
+                        task,
+                        self.update_compat(CompatMessage::Scan),
+                        self.update_leftovers(LeftoversMessage::Scan),
+                        self.update_details(DetailsMessage::Refresh),
+                    ]);
+                }
+
+                task
+            }
+            AppMessage::Plugin(msg) => {
+                let changed = matches!(
+                    msg,
+                    PluginMessage::Added(Ok(_)) | PluginMessage::Removed(Ok(_))
+                );
+                let added = matches!(msg, PluginMessage::Added(Ok(_)));
+                let notification = match &msg {
+                    PluginMessage::Added(result) => Task::batch([
+                        self.install_notification_task(result),
+                        self.usage_report_task(UsageOperation::InstallPlugin, result),
+                    ]),
+                    PluginMessage::Removed(result) => {
+                        self.usage_report_task(UsageOperation::RemovePlugin, result)
+                    }
+                    _ => Task::none(),
+                };
+                let task = self.update_plugin(msg).map(AppMessage::Plugin);
+
+                // Reload the ASI plugins and details to include the plugin changes
+                if changed {
+                    let (restore, quarantine_check) = match added {
+                        true => (self.restore_backup_task(), self.quarantine_check_task()),
+                        false => (Task::none(), Task::none()),
+                    };
+
+                    return Task::batch([
+                        task,
+                        notification,
+                        self.update_asi(AsiMessage::Refresh),
+                        self.update_details(DetailsMessage::Refresh),
+                        restore,
+                        quarantine_check,
+                    ]);
+                }
+
+                Task::batch([task, notification])
+            }
+            AppMessage::Asi(msg) => self.update_asi(msg),
+            AppMessage::Compat(msg) => self.update_compat(msg),
+            AppMessage::Leftovers(msg) => {
+                let changed = matches!(msg, LeftoversMessage::Removed(Ok(_)));
+                let task = self.update_leftovers(msg);
+
+                // Reload the game as the plugin or its config may have moved
+                if changed {
+                    return Task::batch([
+                        task,
+                        self.refresh_game(),
+                        self.update_plugin_config(PluginConfigMessage::Refresh),
+                        self.update_compat(CompatMessage::Scan),
+                    ]);
+                }
+
+                task
+            }
+            AppMessage::Report(msg) => self.update_report(msg),
+            AppMessage::Details(msg) => self.update_details(msg),
+            AppMessage::PluginLog(msg) => self.update_plugin_log(msg),
+            AppMessage::Downloads(msg) => {
+                match msg {
+                    DownloadsMessage::Changed(items) => self.download_items = items,
+                    DownloadsMessage::Cancel(id) => self.downloads.cancel(id),
+                    DownloadsMessage::ClearDone => self.downloads.clear_done(),
+                }
+                Task::none()
+            }
+            AppMessage::PluginConfig(msg) => self.update_plugin_config(msg),
+            AppMessage::RefreshGame => self.refresh_game(),
+            AppMessage::Shortcut(shortcut) => self.update_shortcut(shortcut),
+            AppMessage::CopyError(message) => iced::clipboard::write(message),
+            AppMessage::DismissLink => {
+                self.link_server = None;
+                self.link_error = None;
+                Task::none()
+            }
+            AppMessage::LaunchGame => {
+                if let AppState::Active(state) = &mut self.state {
+                    state.launch_error = launch_game(&state.path, state.store).err().map(|err| {
+                        error!("failed to launch game: {err:#}");
+                        format!("{err:#}")
+                    });
+                }
+
+                Task::none()
+            }
+            AppMessage::OpenFolder(path) => {
+                if let AppState::Active(state) = &mut self.state {
+                    state.open_folder_error = open_folder(&path).err().map(|err| {
+                        error!("failed to open folder {}: {err:#}", path.display());
+                        format!("{err:#}")
+                    });
+                }
+
+                Task::none()
+            }
+            AppMessage::CloseRequested(id) => {
+                if self.config.tray_mode && self.tray_ready {
+                    debug!("hiding window to the tray");
+                    return window::change_mode(id, window::Mode::Hidden);
+                }
+
+                iced::exit()
+            }
+            AppMessage::Tray(event) => self.update_tray(event),
+            AppMessage::FocusChanged(focused) => {
+                self.unfocused = !focused;
+
+                // The game files may have been changed while in the background
+                if focused {
+                    return self.refresh_game();
+                }
+
+                Task::none()
+            }
+            AppMessage::OpenLink(url) => {
+                if let Err(err) = open::that_detached(&url) {
+                    error!("failed to open link {url}: {err}");
+                }
+                Task::none()
+            }
+            AppMessage::PluginDetails(msg) => {
+                let task = self
+                    .update_plugin_details(msg)
+                    .map(AppMessage::PluginDetails);
+                Task::batch([task, self.take_queued_plugin_add()])
+            }
+            AppMessage::InstallerUpdate(msg) => self
+                .update_installer_update(msg)
+                .map(AppMessage::InstallerUpdate),
+            AppMessage::Settings(msg) => self.update_settings(msg),
+            AppMessage::Help(msg) => self.update_help(msg),
+            AppMessage::History(msg) => self.update_history(msg),
+        }
+    }
+
+    fn update_compat(&mut self, msg: CompatMessage) -> Task<AppMessage> {
+        let state = match &mut self.state {
+            AppState::Active(state) => state,
+            _ => return Task::none(),
+        };
+
+        match msg {
+            CompatMessage::Scan => {
+                state.compat_state = CompatState::Scanning;
+                state.track(
+                    Task::perform(scan_compatibility(state.path.clone()), map_error_string)
+                        .map(CompatMessage::Scanned)
+                        .map(AppMessage::Compat),
+                )
+            }
+            CompatMessage::Scanned(result) => {
+                state.compat_state = match result {
+                    Ok(findings) => CompatState::Ready(findings),
+                    Err(err) => CompatState::Error(err),
+                };
+                Task::none()
+            }
+        }
+    }
 
-        column![patch_text, remove_patch_button].spacing(10)
+    fn update_leftovers(&mut self, msg: LeftoversMessage) -> Task<AppMessage> {
+        let state = match &mut self.state {
+            AppState::Active(state) => state,
+            _ => return Task::none(),
+        };
+
+        match msg {
+            LeftoversMessage::Scan if state.is_busy() => Task::none(),
+            LeftoversMessage::Scan => {
+                state.leftovers_state = LeftoversState::Scanning;
+                state.track(
+                    Task::perform(
+                        scan_leftovers(state.path.clone()),
+                        LeftoversMessage::Scanned,
+                    )
+                    .map(AppMessage::Leftovers),
+                )
+            }
+            LeftoversMessage::Scanned(leftovers) => {
+                state.leftovers_state = LeftoversState::Ready(leftovers);
+                Task::none()
+            }
+            LeftoversMessage::Remove if state.is_busy() => Task::none(),
+            LeftoversMessage::Remove => {
+                let leftovers = match &state.leftovers_state {
+                    LeftoversState::Ready(leftovers) => leftovers.clone(),
+                    _ => return Task::none(),
+                };
+
+                state.leftovers_state = LeftoversState::Removing;
+                state.track(
+                    Task::perform(
+                        remove_leftovers(state.path.clone(), leftovers),
+                        map_action_error,
+                    )
+                    .map(LeftoversMessage::Removed)
+                    .map(AppMessage::Leftovers),
+                )
+            }
+            LeftoversMessage::Removed(result) => {
+                state.leftovers_state = match result {
+                    Ok(changes) => LeftoversState::Removed(changes),
+                    Err(ActionError::GameRunning) => LeftoversState::GameRunning,
+                    Err(ActionError::Failed(err)) => LeftoversState::Error(err),
+                };
+                Task::none()
+            }
+        }
+    }
+
+    fn update_details(&mut self, msg: DetailsMessage) -> Task<AppMessage> {
+        let state = match &mut self.state {
+            AppState::Active(state) => state,
+            _ => return Task::none(),
+        };
+
+        match msg {
+            DetailsMessage::Toggle => {
+                state.show_details = !state.show_details;
+                Task::none()
+            }
+            DetailsMessage::Refresh => state.track(
+                Task::perform(
+                    read_game_details(state.path.clone()),
+                    DetailsMessage::Loaded,
+                )
+                .map(AppMessage::Details),
+            ),
+            DetailsMessage::Loaded(details) => {
+                state.details = details;
+                Task::none()
+            }
+        }
+    }
+
+    fn update_plugin_log(&mut self, msg: PluginLogMessage) -> Task<AppMessage> {
+        let state = match &mut self.state {
+            AppState::Active(state) => state,
+            _ => return Task::none(),
+        };
+
+        match msg {
+            PluginLogMessage::Toggle => {
+                state.show_plugin_log = !state.show_plugin_log;
+                state.plugin_log_notice = None;
+
+                if !state.show_plugin_log {
+                    return Task::none();
+                }
+
+                state.plugin_log_state = PluginLogState::Loading;
+                self.update_plugin_log(PluginLogMessage::Refresh)
+            }
+            PluginLogMessage::Refresh => state.track(
+                Task::perform(read_plugin_log(state.path.clone()), map_error_string)
+                    .map(PluginLogMessage::Loaded)
+                    .map(AppMessage::PluginLog),
+            ),
+            PluginLogMessage::Loaded(result) => {
+                state.plugin_log_state = match result {
+                    Ok(Some(lines)) => PluginLogState::Loaded(lines),
+                    Ok(None) => PluginLogState::Missing,
+                    Err(err) => PluginLogState::Error(err),
+                };
+                Task::none()
+            }
+            PluginLogMessage::Copy => {
+                let lines = match &state.plugin_log_state {
+                    PluginLogState::Loaded(lines) => lines,
+                    _ => return Task::none(),
+                };
+
+                let contents = lines
+                    .iter()
+                    .map(|line| line.text.as_str())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+
+                state.plugin_log_notice = Some(PluginLogNotice::Copied);
+                iced::clipboard::write(contents)
+            }
+            PluginLogMessage::Export => {
+                state.plugin_log_notice = None;
+                let game_path = state.path.clone();
+
+                state
+                    .track(Task::perform(
+                        async move {
+                            let path = match pick_plugin_log_save_path().await? {
+                                Some(value) => value,
+                                None => return Ok(None),
+                            };
+
+                            export_plugin_log(game_path, path.clone()).await?;
+
+                            Ok(Some(path))
+                        },
+                        map_error_string,
+                    ))
+                    .map(PluginLogMessage::Exported)
+                    .map(AppMessage::PluginLog)
+            }
+            PluginLogMessage::Exported(result) => {
+                state.plugin_log_notice = match result {
+                    Ok(Some(path)) => Some(PluginLogNotice::Exported(path)),
+                    Ok(None) => None,
+                    Err(err) => Some(PluginLogNotice::Error(err)),
+                };
+                Task::none()
+            }
+        }
+    }
+
+    fn update_report(&mut self, msg: ReportMessage) -> Task<AppMessage> {
+        let state = match &mut self.state {
+            AppState::Active(state) => state,
+            _ => return Task::none(),
+        };
+
+        match msg {
+            ReportMessage::Export => {
+                state.report_state = ReportState::Loading;
+                let game_path = state.path.clone();
+
+                state
+                    .track(Task::perform(
+                        async move {
+                            let path = match pick_report_save_path().await? {
+                                Some(value) => value,
+                                None => return Ok(None),
+                            };
+
+                            let report = create_report(game_path).await?;
+                            write_report(&report, &path).await?;
+
+                            Ok(Some(path))
+                        },
+                        map_error_string,
+                    ))
+                    .map(ReportMessage::Exported)
+                    .map(AppMessage::Report)
+            }
+            ReportMessage::Exported(result) => {
+                state.report_state = match result {
+                    Ok(Some(path)) => ReportState::Exported(path),
+                    Ok(None) => ReportState::Initial,
+                    Err(err) => ReportState::Error(err),
+                };
+                Task::none()
+            }
+            ReportMessage::Compare => {
+                state.report_state = ReportState::Loading;
+                let game_path = state.path.clone();
+
+                state
+                    .track(Task::perform(
+                        async move {
+                            let path = match pick_report_file().await? {
+                                Some(value) => value,
+                                None => return Ok(None),
+                            };
+
+                            let imported = read_report(&path).await?;
+                            let current = create_report(game_path).await?;
+
+                            Ok(Some(diff_reports(&current, &imported)))
+                        },
+                        map_error_string,
+                    ))
+                    .map(ReportMessage::Compared)
+                    .map(AppMessage::Report)
+            }
+            ReportMessage::Compared(result) => {
+                state.report_state = match result {
+                    Ok(Some(differences)) => ReportState::Compared(differences),
+                    Ok(None) => ReportState::Initial,
+                    Err(err) => ReportState::Error(err),
+                };
+                Task::none()
+            }
+        }
+    }
+
+    fn update_asi(&mut self, msg: AsiMessage) -> Task<AppMessage> {
+        let state = match &mut self.state {
+            AppState::Active(state) => state,
+            _ => return Task::none(),
+        };
+
+        let task = match msg {
+            AsiMessage::Refresh => {
+                state.asi_plugins_state = AsiPluginsState::Loading;
+
+                Task::perform(list_asi_plugins(state.path.clone()), map_error_string)
+                    .map(AsiMessage::Loaded)
+            }
+            AsiMessage::Loaded(result) => {
+                state.asi_delete_confirm = None;
+                state.asi_plugins_state = match result {
+                    Ok(plugins) => AsiPluginsState::Ready(plugins),
+                    Err(err) => {
+                        error!("failed to load asi plugins: {err}");
+                        AsiPluginsState::Error(err)
+                    }
+                };
+                Task::none()
+            }
+            // Plugins can't be changed while the plugin is being installed
+            AsiMessage::SetEnabled(..) | AsiMessage::Delete(_) if state.is_busy() => Task::none(),
+            AsiMessage::SetEnabled(plugin, enabled) => {
+                state.asi_plugins_error = None;
+
+                Task::perform(
+                    set_asi_plugin_enabled(state.path.clone(), plugin, enabled),
+                    map_action_error,
+                )
+                .map(AsiMessage::Changed)
+            }
+            AsiMessage::StartDelete(plugin) => {
+                state.asi_delete_confirm = Some(plugin.path);
+                Task::none()
+            }
+            AsiMessage::CancelDelete => {
+                state.asi_delete_confirm = None;
+                Task::none()
+            }
+            AsiMessage::Delete(plugin) => {
+                state.asi_plugins_error = None;
+                state.asi_delete_confirm = None;
+
+                Task::perform(
+                    delete_asi_plugin(state.path.clone(), plugin),
+                    map_action_error,
+                )
+                .map(AsiMessage::Changed)
+            }
+            AsiMessage::Changed(result) => {
+                state.asi_plugins_error = match result {
+                    Ok(()) => None,
+                    Err(ActionError::GameRunning) => Some(
+                        tr("Mass Effect 3 is currently running, close the game before \
+                        changing plugins.")
+                        .to_string(),
+                    ),
+                    Err(ActionError::Failed(err)) => {
+                        error!("failed to change asi plugin: {err}");
+                        Some(tr_args("failed to change plugin: {}", &[&err]))
+                    }
+                };
+
+                // Reload the plugins to reflect the changes
+                Task::done(AsiMessage::Refresh)
+            }
+        };
+
+        state.track(task).map(AppMessage::Asi)
+    }
+
+    fn update_logs(&mut self, msg: LogsMessage) -> Task<AppMessage> {
+        match msg {
+            LogsMessage::Toggle => {
+                self.show_logs = !self.show_logs;
+            }
+            LogsMessage::CopyDiagnostics => {
+                self.diagnostics_copied = false;
+
+                return Task::perform(collect_diagnostics(self.active_path()), |report| {
+                    AppMessage::Logs(LogsMessage::CollectedDiagnostics(report))
+                });
+            }
+            LogsMessage::CollectedDiagnostics(report) => {
+                self.diagnostics_copied = true;
+                return iced::clipboard::write(report);
+            }
+            LogsMessage::RunNetworkChecks => {
+                if let NetworkChecksState::Running(_) = self.network_checks {
+                    return Task::none();
+                }
+
+                self.network_checks = NetworkChecksState::Running(Vec::new());
+
+                return Task::run(network_checks_stream(self.http_client.clone()), |msg| {
+                    AppMessage::Logs(msg)
+                });
+            }
+            LogsMessage::NetworkChecked(result) => {
+                if let NetworkChecksState::Running(results) = &mut self.network_checks {
+                    results.push(result);
+                }
+            }
+            LogsMessage::NetworkChecksFinished => {
+                if let NetworkChecksState::Running(results) = &mut self.network_checks {
+                    self.network_checks = NetworkChecksState::Finished(std::mem::take(results));
+                }
+            }
+        }
+
+        Task::none()
+    }
+
+    fn update_proton(&mut self, msg: ProtonMessage) -> Task<AppMessage> {
+        match msg {
+            ProtonMessage::Apply => {
+                self.proton_state = ProtonState::Applying;
+
+                return Task::perform(apply_launch_options(), map_error_string)
+                    .map(|result| AppMessage::Proton(ProtonMessage::Applied(result)));
+            }
+            ProtonMessage::Applied(result) => {
+                self.proton_state = match result {
+                    Ok(updated) => ProtonState::Applied(updated),
+                    Err(err) => {
+                        error!("failed to set launch options: {err}");
+                        ProtonState::Error(err)
+                    }
+                };
+            }
+            ProtonMessage::Copy => {
+                self.proton_state = ProtonState::Copied;
+                return iced::clipboard::write(LAUNCH_OPTIONS.to_string());
+            }
+        }
+
+        Task::none()
+    }
+
+    fn update_elevation(&mut self, msg: ElevationMessage) -> Task<AppMessage> {
+        let state = match &mut self.state {
+            AppState::Active(state) => state,
+            _ => return Task::none(),
+        };
+
+        match msg {
+            ElevationMessage::Check => {
+                let path = state.path.clone();
+                return state.track(Task::perform(check_folder_access(path), |access| {
+                    AppMessage::Elevation(ElevationMessage::Checked(access))
+                }));
+            }
+            ElevationMessage::Checked(access) => {
+                state.folder_access = Some(access);
+            }
+            ElevationMessage::Relaunch if state.relaunching => {}
+            ElevationMessage::Relaunch => {
+                state.relaunching = true;
+                state.elevation_error = None;
+
+                // The UAC prompt blocks until answered
+                return Task::perform(
+                    async {
+                        spawn_blocking(relaunch_elevated)
+                            .await
+                            .context("failed to join native thread")?
+                    },
+                    map_error_string,
+                )
+                .map(|result| AppMessage::Elevation(ElevationMessage::Relaunched(result)));
+            }
+            ElevationMessage::Relaunched(result) => {
+                state.relaunching = false;
+                match result {
+                    Ok(()) => return iced::exit(),
+                    Err(err) => {
+                        error!("failed to restart as administrator: {err}");
+                        state.elevation_error = Some(err);
+                    }
+                }
+            }
+        }
+
+        Task::none()
+    }
+
+    fn update_uninstall(&mut self, msg: UninstallMessage) -> Task<UninstallMessage> {
+        let state = match &mut self.state {
+            AppState::Active(state) => state,
+            _ => return Task::none(),
+        };
+
+        match msg {
+            UninstallMessage::SetRemovePluginDir(value) => {
+                state.remove_plugin_dir = value;
+            }
+            UninstallMessage::SetBackupPluginFiles(value) => {
+                state.backup_plugin_files = value;
+            }
+            UninstallMessage::Start => {
+                state.uninstall_state = UninstallState::Confirming;
+            }
+            UninstallMessage::Cancel => {
+                state.uninstall_state = UninstallState::Initial;
+            }
+            UninstallMessage::Confirm if state.is_busy() => {}
+            // Result arrived after uninstalling stopped being tracked
+            UninstallMessage::Finished(_)
+                if !matches!(state.uninstall_state, UninstallState::Loading) =>
+            {
+                debug!("ignoring stale uninstall result");
+            }
+            UninstallMessage::Confirm => {
+                state.uninstall_state = UninstallState::Loading;
+
+                let path = state.path.to_path_buf();
+
+                return state.track(
+                    Task::perform(
+                        record_change(
+                            HistoryAction::Uninstall,
+                            path.clone(),
+                            uninstall_all(path, state.remove_plugin_dir, state.backup_plugin_files),
+                        ),
+                        map_action_error,
+                    )
+                    .map(UninstallMessage::Finished),
+                );
+            }
+            UninstallMessage::Finished(result) => match result {
+                Ok(removed) => {
+                    state.uninstall_state = UninstallState::Success(removed);
+                    state.patch.reset(PatchState::Unpatched);
+                    state.plugin.reset(PluginState::Absent, None);
+                    state.install_all_state = InstallAllState::Initial;
+
+                    // Removed on purpose, not reverted by the store
+                    self.config
+                        .patched_installations
+                        .retain(|path| *path != state.path);
+                    return save_config_task(self.config.clone());
+                }
+                Err(ActionError::GameRunning) => {
+                    state.uninstall_state = UninstallState::GameRunning;
+                }
+                Err(ActionError::Failed(err)) => {
+                    error!("failed to uninstall: {err}");
+                    state.uninstall_state = UninstallState::Error(err);
+                }
+            },
+        }
+
+        Task::none()
+    }
+
+    fn update_install_all(&mut self, msg: InstallAllMessage) -> Task<InstallAllMessage> {
+        let state = match &mut self.state {
+            AppState::Active(state) => state,
+            _ => return Task::none(),
+        };
+
+        match msg {
+            InstallAllMessage::Start if state.is_busy() => {}
+            // Result arrived after installing stopped being tracked
+            InstallAllMessage::Finished(_)
+                if !matches!(state.install_all_state, InstallAllState::Running(_)) =>
+            {
+                debug!("ignoring stale install result");
+            }
+            InstallAllMessage::Start => {
+                let (release, asset_name) = match &self.plugin_details_state {
+                    PluginDetailsState::Ready(details) => {
+                        let release = details.selected.release();
+                        match details.asset_name(release) {
+                            Some(asset_name) => (release.clone(), asset_name),
+                            None => return Task::none(),
+                        }
+                    }
+                    _ => return Task::none(),
+                };
+
+                let path = state.path.to_path_buf();
+                let first_step = match state.patch.state {
+                    PatchState::Patched => InstallStep::Plugin(None),
+                    _ => InstallStep::Patching(None),
+                };
+
+                state.install_all_state = InstallAllState::Running(first_step);
+                state.checksum_status = None;
+
+                return state.track(Task::run(
+                    install_all_stream(
+                        self.http_client.clone(),
+                        self.downloads.clone(),
+                        self.config.mirrors.clone(),
+                        path,
+                        release,
+                        asset_name,
+                    ),
+                    |msg| msg,
+                ));
+            }
+            InstallAllMessage::Progress(step) => {
+                if let InstallAllState::Running(current) = &mut state.install_all_state {
+                    *current = step;
+                }
+            }
+            InstallAllMessage::ChecksumChecked(status) => {
+                state.checksum_status = Some(status);
+            }
+            InstallAllMessage::Finished(result) => match result {
+                Ok(version) => {
+                    state.install_all_state = InstallAllState::Success;
+                    state.patch.reset(PatchState::Patched);
+                    state.plugin.reset(PluginState::Installed, version);
+                }
+                Err(ActionError::GameRunning) => {
+                    state.install_all_state = InstallAllState::GameRunning;
+                }
+                Err(ActionError::Failed(err)) => {
+                    error!("failed to install patch and plugin: {err}");
+                    state.install_all_state = InstallAllState::Error(err);
+                }
+            },
+        }
+
+        Task::none()
+    }
+
+    fn update_batch(&mut self, msg: BatchMessage) -> Task<AppMessage> {
+        let state = match &mut self.state {
+            AppState::Active(state) => state,
+            _ => return Task::none(),
+        };
+
+        match msg {
+            BatchMessage::Start if state.is_busy() => {}
+            BatchMessage::Start => {
+                let (release, asset_name) = match &self.plugin_details_state {
+                    PluginDetailsState::Ready(details) => {
+                        let release = details.selected.release();
+                        match details.asset_name(release) {
+                            Some(asset_name) => (release.clone(), asset_name),
+                            None => return Task::none(),
+                        }
+                    }
+                    _ => return Task::none(),
+                };
+
+                let paths = self.config.installations.clone();
+
+                state.batch_results = paths
+                    .iter()
+                    .map(|path| BatchResult {
+                        path: path.clone(),
+                        status: BatchStatus::Pending,
+                    })
+                    .collect();
+
+                return state.track(
+                    Task::run(
+                        batch_install_stream(
+                            self.http_client.clone(),
+                            self.downloads.clone(),
+                            self.config.mirrors.clone(),
+                            paths,
+                            release,
+                            asset_name,
+                        ),
+                        |msg| msg,
+                    )
+                    .map(AppMessage::Batch),
+                );
+            }
+            BatchMessage::Started(index) => {
+                if let Some(result) = state.batch_results.get_mut(index) {
+                    result.status = BatchStatus::Running;
+                }
+            }
+            BatchMessage::Finished(index, result) => {
+                if let Some(batch_result) = state.batch_results.get_mut(index) {
+                    batch_result.status = match result {
+                        Ok(status) => BatchStatus::Installed(status),
+                        Err(ActionError::GameRunning) => BatchStatus::GameRunning,
+                        Err(ActionError::Failed(err)) => {
+                            error!(
+                                "failed to install to {}: {err}",
+                                batch_result.path.display()
+                            );
+                            BatchStatus::Failed(err)
+                        }
+                    };
+                }
+            }
+        }
+
+        Task::none()
+    }
+
+    /// Plans the changes for the `action` so they can be previewed
+    /// before the action is applied
+    fn preview_action(&mut self, action: PendingAction) -> Task<AppMessage> {
+        let state = match &mut self.state {
+            AppState::Active(state) => state,
+            _ => return Task::none(),
+        };
+
+        let path = state.path.clone();
+
+        let task = match &action {
+            PendingAction::Patch(PatchMessage::Add) => {
+                Task::perform(plan_apply_patch(game(), path), map_error_string)
+            }
+            PendingAction::Patch(_) => {
+                Task::perform(plan_remove_patch(game(), path), map_error_string)
+            }
+            PendingAction::Plugin(PluginMessage::Add | PluginMessage::Update) => {
+                let details = match &self.plugin_details_state {
+                    PluginDetailsState::Ready(details) => details,
+                    _ => return Task::none(),
+                };
+
+                let release = match &action {
+                    PendingAction::Plugin(PluginMessage::Update) => &details.latest,
+                    _ => details.selected.release(),
+                };
+                if required_installer_version(release).is_some() {
+                    return Task::none();
+                }
+                let asset_name = match details.asset_name(release) {
+                    Some(value) => value,
+                    None => return Task::none(),
+                };
+
+                Task::perform(
+                    plan_apply_plugin(path, release.clone(), asset_name),
+                    map_error_string,
+                )
+            }
+            PendingAction::Plugin(PluginMessage::PickedFile(Ok(Some(file_path)))) => Task::perform(
+                plan_apply_plugin_file(path, file_path.clone()),
+                map_error_string,
+            ),
+            PendingAction::Plugin(_) => Task::perform(plan_remove_plugin(path), map_error_string),
+            PendingAction::InstallAll => {
+                let (release, asset_name) = match &self.plugin_details_state {
+                    PluginDetailsState::Ready(details) => {
+                        let release = details.selected.release();
+                        match details.asset_name(release) {
+                            Some(asset_name) => (release.clone(), asset_name),
+                            None => return Task::none(),
+                        }
+                    }
+                    _ => return Task::none(),
+                };
+                let patched = state.patch.is_patched();
+
+                Task::perform(
+                    async move {
+                        let mut changes = match patched {
+                            true => Vec::new(),
+                            false => plan_apply_patch(game(), path.clone()).await?,
+                        };
+                        changes.extend(plan_apply_plugin(path, release, asset_name).await?);
+                        Ok(changes)
+                    },
+                    map_error_string,
+                )
+            }
+        };
+
+        state.preview_state = PreviewState::Planning;
+
+        state.track(task.map(move |result| {
+            AppMessage::Preview(PreviewMessage::Planned(action.clone(), result))
+        }))
+    }
+
+    fn update_preview(&mut self, msg: PreviewMessage) -> Task<AppMessage> {
+        if let PreviewMessage::SetEnabled(enabled) = msg {
+            self.config.preview_changes = enabled;
+            return save_config_task(self.config.clone());
+        }
+
+        let state = match &mut self.state {
+            AppState::Active(state) => state,
+            _ => return Task::none(),
+        };
+
+        match msg {
+            PreviewMessage::SetEnabled(_) => {}
+            PreviewMessage::Planned(action, result) => {
+                state.preview_state = match result {
+                    Ok(changes) => PreviewState::Ready(action, changes),
+                    Err(err) => {
+                        error!("failed to preview changes: {err}");
+                        PreviewState::Error(err)
+                    }
+                };
+            }
+            PreviewMessage::Confirm => {
+                let action = match std::mem::take(&mut state.preview_state) {
+                    PreviewState::Ready(action, _) => action,
+                    _ => return Task::none(),
+                };
+
+                return match action {
+                    PendingAction::Patch(msg) => self.update_patch(msg).map(AppMessage::Patch),
+                    PendingAction::Plugin(msg) => self.update_plugin(msg).map(AppMessage::Plugin),
+                    PendingAction::InstallAll => self
+                        .update_install_all(InstallAllMessage::Start)
+                        .map(AppMessage::InstallAll),
+                };
+            }
+            PreviewMessage::Cancel => {
+                state.preview_state = PreviewState::None;
+            }
+        }
+
+        Task::none()
     }
 
-    fn view_patch_not_installed() -> Column<'static, AppMessage> {
-        let patch_text: Text =
-            text("Your game is not patched, you must apply the patch to use the client plugin.")
-                .color(DARK_TEXT);
-        let apply_patch_button: Button<_> = button("Apply Patch")
-            .on_press(AppMessage::Patch(PatchMessage::Add))
-            .padding(10);
+    fn update_plugin_config(&mut self, msg: PluginConfigMessage) -> Task<AppMessage> {
+        let state = match &mut self.state {
+            AppState::Active(state) => state,
+            _ => return Task::none(),
+        };
 
-        column![patch_text, apply_patch_button].spacing(10)
-    }
+        let task = match msg {
+            PluginConfigMessage::Refresh => {
+                state.plugin_config_state = PluginConfigState::Loading;
 
-    fn view_patch_installing() -> Column<'static, AppMessage> {
-        let patch_text = text("Installing patch...").color(Palette::DARK.primary);
-        column![patch_text].spacing(10)
+                Task::perform(read_plugin_config(state.path.clone()), map_error_string)
+                    .map(PluginConfigMessage::Loaded)
+            }
+            PluginConfigMessage::Loaded(result) => match result {
+                Ok(config) => {
+                    state.connection_url = config.connection_url.clone().unwrap_or_default();
+                    state.plugin_config = config;
+                    state.plugin_config_state = PluginConfigState::Ready;
+                    state.server_version = None;
+
+                    // Server from the link is only filled in, the user
+                    // still chooses whether to save it
+                    if let Some(server) = &self.link_server {
+                        state.connection_url = server.clone();
+                    }
+
+                    match state.plugin_config.connection_url.clone() {
+                        Some(url) => server_version_task(self.http_client.clone(), url),
+                        None => Task::none(),
+                    }
+                }
+                Err(err) => {
+                    error!("failed to load plugin config: {err}");
+                    state.plugin_config_state =
+                        PluginConfigState::Error(tr_args("failed to load config: {}", &[&err]));
+                    Task::none()
+                }
+            },
+            PluginConfigMessage::SetConnectionUrl(url) => {
+                state.connection_url = url;
+                state.plugin_config_state = PluginConfigState::Ready;
+                Task::none()
+            }
+            PluginConfigMessage::Save if state.is_busy() => Task::none(),
+            PluginConfigMessage::Save => {
+                let url = state.connection_url.trim();
+                state.plugin_config.connection_url =
+                    Some(url.to_string()).filter(|url| !url.is_empty());
+                state.plugin_config_state = PluginConfigState::Saving;
+
+                Task::perform(
+                    write_plugin_config(state.path.clone(), state.plugin_config.clone()),
+                    map_error_string,
+                )
+                .map(PluginConfigMessage::Saved)
+            }
+            PluginConfigMessage::Saved(result) => match result {
+                Ok(()) => {
+                    state.plugin_config_state = PluginConfigState::Saved;
+                    state.server_version = None;
+                    self.link_server = None;
+
+                    // Check the newly configured server
+                    match state.plugin_config.connection_url.clone() {
+                        Some(url) => server_version_task(self.http_client.clone(), url),
+                        None => Task::none(),
+                    }
+                }
+                Err(err) => {
+                    error!("failed to save plugin config: {err}");
+                    state.plugin_config_state =
+                        PluginConfigState::Error(tr_args("failed to save config: {}", &[&err]));
+                    Task::none()
+                }
+            },
+            PluginConfigMessage::ServerChecked(result) => {
+                state.server_version = match result {
+                    Ok(version) => Some(version),
+                    // Servers are often offline, compatibility is only checked when reachable
+                    Err(err) => {
+                        debug!("failed to check server version: {err}");
+                        None
+                    }
+                };
+                Task::none()
+            }
+        };
+
+        state.track(task).map(AppMessage::PluginConfig)
     }
 
-    fn view_patch_uninstalling() -> Column<'static, AppMessage> {
-        let patch_text = text("Uninstalling patch...").color(Palette::DARK.primary);
-        column![patch_text].spacing(10)
+    /// Tracks the `task` against the active game so it is aborted when
+    /// the game is changed
+    fn track<T: 'static>(&mut self, task: Task<T>) -> Task<T> {
+        match &mut self.state {
+            AppState::Active(state) => state.track(task),
+            AppState::Initial(_) => task,
+        }
     }
 
-    fn view_patch_remove_success() -> Column<'static, AppMessage> {
-        let patch_text: Text = text("Patch successfully removed.").color(Palette::DARK.success);
+    /// Whether an operation is modifying the files of the active game
+    fn is_busy(&self) -> bool {
+        matches!(&self.state, AppState::Active(state) if state.is_busy())
+    }
 
-        let apply_patch_button: Button<_> = button("Apply Patch")
-            .on_press(AppMessage::Patch(PatchMessage::Add))
-            .padding(10);
+    /// Reloads the patch and plugin state and the ASI plugins of the active game
+    fn refresh_game(&mut self) -> Task<AppMessage> {
+        // Changes made by an operation are reloaded when it finishes
+        if self.is_busy() || self.active_path().is_none() {
+            return Task::none();
+        }
 
-        column![patch_text, apply_patch_button].spacing(10)
+        Task::batch([
+            self.update_game(GameMessage::Refresh).map(AppMessage::Game),
+            self.update_asi(AsiMessage::Refresh),
+        ])
     }
 
-    fn view_patch_add_success() -> Column<'static, AppMessage> {
-        let patch_text: Text = text("Patch successfully installed.").color(Palette::DARK.success);
-        let remove_patch_button: Button<_> = button("Remove Patch")
-            .on_press(AppMessage::Patch(PatchMessage::Remove))
-            .padding(10);
+    /// Restores the plugin config and logs that were backed up when the
+    /// plugin was uninstalled, then reloads the plugin config
+    fn restore_backup_task(&mut self) -> Task<AppMessage> {
+        let path = match self.active_path() {
+            Some(value) => value,
+            None => return Task::none(),
+        };
 
-        column![patch_text, remove_patch_button].spacing(10)
+        self.track(Task::perform(restore_plugin_backup(path), |result| {
+            if let Err(err) = result {
+                error!("failed to restore plugin backup: {err:#}");
+            }
+
+            AppMessage::PluginConfig(PluginConfigMessage::Refresh)
+        }))
     }
 
-    fn view_patch_install_error(err: &str) -> Column<'static, AppMessage> {
-        let patch_text: Text =
-            text(format!("failed to add patch: {err}")).color(Palette::DARK.danger);
+    /// Checks the plugin wasn't quarantined by an antivirus a moment after
+    /// it was installed. Not tracked as busy so the UI stays usable
+    fn quarantine_check_task(&self) -> Task<AppMessage> {
+        let path = match self.active_path() {
+            Some(value) => value,
+            None => return Task::none(),
+        };
 
-        let retry_button: Button<_> = button("Retry")
-            .on_press(AppMessage::Patch(PatchMessage::Add))
-            .padding(10);
-        column![patch_text, retry_button].spacing(10)
+        Task::perform(check_plugin_quarantine(path.clone()), move |present| {
+            AppMessage::Plugin(PluginMessage::QuarantineChecked(path.clone(), present))
+        })
     }
 
-    fn view_patch_uninstall_error(err: &str) -> Column<'static, AppMessage> {
-        let patch_text: Text =
-            text(format!("failed to remove patch: {err}")).color(Palette::DARK.danger);
+    /// Path of the active game folder, none if no game is selected
+    fn active_path(&self) -> Option<PathBuf> {
+        match &self.state {
+            AppState::Active(state) => Some(state.path.clone()),
+            AppState::Initial(_) => None,
+        }
+    }
 
-        let retry_button: Button<_> = button("Retry")
-            .on_press(AppMessage::Patch(PatchMessage::Remove))
-            .padding(10);
-        column![patch_text, retry_button].spacing(10)
+    /// Window size for the current state
+    fn window_size(&self) -> Size {
+        // Settings and help screens are shown in place of the main screen
+        let main_screen = self.settings.is_none() && self.help.is_none() && self.history.is_none();
+
+        let size = match (&self.state, main_screen) {
+            (AppState::Initial(_), true) => return self.initial_window_size(),
+            // Widen the window to fit the installations sidebar
+            (AppState::Active(_), true) if self.config.installations.len() > 1 => Size::new(
+                EXPANDED_WINDOW_SIZE.width + SIDEBAR_WIDTH,
+                EXPANDED_WINDOW_SIZE.height,
+            ),
+            _ => EXPANDED_WINDOW_SIZE,
+        };
+
+        size * self.config.ui_scale()
     }
 
-    /// View for the add plugin section
-    fn view_plugin_section<'a>(
-        state: &'a AppStateActive,
-        plugin_details: &'a PluginDetailsState,
-    ) -> Column<'a, AppMessage> {
-        match (state.plugin, &state.alter_plugin_state) {
-            // Plugin is installed, we are in the initial state
-            (true, AlterPluginState::Initial) => Self::view_plugin_installed(),
+    /// Background checks for a newer plugin release while the plugin is
+    /// installed, along with tracking the window focus
+    fn subscription(&self) -> Subscription<AppMessage> {
+        let focus = iced::event::listen_with(|event, _, _| match event {
+            iced::Event::Window(window::Event::Focused) => Some(AppMessage::FocusChanged(true)),
+            iced::Event::Window(window::Event::Unfocused) => Some(AppMessage::FocusChanged(false)),
+            _ => None,
+        });
+
+        let update_check = match &self.state {
+            AppState::Active(state) if state.plugin.is_present() => {
+                iced::time::every(PLUGIN_UPDATE_CHECK_INTERVAL)
+                    .map(|_| AppMessage::PluginDetails(PluginDetailsMessage::Refresh))
+            }
+            _ => Subscription::none(),
+        };
 
-            // Plugin is not installed, we are in the initial state
-            (false, AlterPluginState::Initial) => Self::view_plugin_not_installed(plugin_details),
+        // Reload the state when another tool changes the game files
+        let watch = match &self.state {
+            AppState::Active(state) => {
+                Subscription::run_with_id(state.path.clone(), watch_game_folder(state.path.clone()))
+                    .map(|_| AppMessage::RefreshGame)
+            }
+            AppState::Initial(_) => Subscription::none(),
+        };
 
-            // Plugin is installed, we are uninstalling
-            (true, AlterPluginState::Loading) => Self::view_plugin_uninstalling(),
+        // Follow the plugin log as the game writes to it
+        let plugin_log = match &self.state {
+            AppState::Active(state) if state.show_plugin_log => {
+                iced::time::every(PLUGIN_LOG_REFRESH_INTERVAL)
+                    .map(|_| AppMessage::PluginLog(PluginLogMessage::Refresh))
+            }
+            _ => Subscription::none(),
+        };
 
-            // Plugin is not installed, we are installing
-            (false, AlterPluginState::Loading) => Self::view_plugin_installing(),
+        let downloads = Subscription::run_with_id("downloads", self.downloads.changes())
+            .map(|items| AppMessage::Downloads(DownloadsMessage::Changed(items)));
 
-            // Plugin was installed
-            (true, AlterPluginState::Success) => Self::view_plugin_add_success(),
+        let shortcuts = keyboard::on_key_press(|key, modifiers| {
+            shortcut_for_key(key, modifiers).map(AppMessage::Shortcut)
+        });
 
-            // Plugin was uninstalled
-            (false, AlterPluginState::Success) => Self::view_plugin_remove_success(plugin_details),
+        let close_requests = window::close_requests().map(AppMessage::CloseRequested);
 
-            // Error occurred while uninstalling
-            (true, AlterPluginState::Error(err)) => Self::view_plugin_uninstall_error(err),
+        let tray = match self.config.tray_mode {
+            true => Subscription::run_with_id("tray", tray_events()).map(AppMessage::Tray),
+            false => Subscription::none(),
+        };
 
-            // Error occurred while installing
-            (false, AlterPluginState::Error(err)) => Self::view_plugin_install_error(err),
-        }
+        Subscription::batch([
+            focus,
+            update_check,
+            watch,
+            plugin_log,
+            downloads,
+            shortcuts,
+            close_requests,
+            tray,
+        ])
     }
 
-    fn view_plugin_installed() -> Column<'static, AppMessage> {
-        let plugin_text: Text =
-            text("You have the Pocket Relay client plugin installed.").color(DARK_TEXT);
-        let remove_plugin_button: Button<_> = button("Remove Plugin")
-            .on_press(AppMessage::Plugin(PluginMessage::Remove))
-            .padding(10);
+    /// Handles an `event` from the tray icon, the quick actions go through
+    /// the same messages as their buttons
+    fn update_tray(&mut self, event: TrayEvent) -> Task<AppMessage> {
+        let action = match event {
+            TrayEvent::Ready => {
+                self.tray_ready = true;
+                return Task::none();
+            }
+            TrayEvent::Unavailable => {
+                self.tray_ready = false;
+                return Task::none();
+            }
+            TrayEvent::Action(action) => action,
+        };
 
-        column![plugin_text, remove_plugin_button].spacing(10)
+        match action {
+            TrayAction::Show => get_latest().and_then(|id| {
+                Task::batch([
+                    window::change_mode(id, window::Mode::Windowed),
+                    window::gain_focus(id),
+                ])
+            }),
+            TrayAction::CheckUpdates => {
+                Task::done(AppMessage::PluginDetails(PluginDetailsMessage::Refresh))
+            }
+            TrayAction::OpenGameFolder => match &self.state {
+                AppState::Active(state) => Task::done(AppMessage::OpenFolder(state.path.clone())),
+                AppState::Initial(_) => Task::none(),
+            },
+            TrayAction::LaunchGame => match &self.state {
+                AppState::Active(_) => Task::done(AppMessage::LaunchGame),
+                AppState::Initial(_) => Task::none(),
+            },
+            TrayAction::Quit => iced::exit(),
+        }
     }
 
-    fn view_plugin_not_installed(plugin_details: &PluginDetailsState) -> Column<'_, AppMessage> {
-        let plugin_text: Text =
-            text("You do not have the Pocket Relay client plugin installed").color(DARK_TEXT);
-        let add_plugin = Self::view_add_plugin(plugin_details);
-        column![plugin_text, add_plugin].spacing(10)
+    /// Handles a keyboard `shortcut`, actions go through the same messages as
+    /// their buttons and are ignored when the button wouldn't be available
+    fn update_shortcut(&mut self, shortcut: Shortcut) -> Task<AppMessage> {
+        let message = match (shortcut, &self.state) {
+            (Shortcut::FocusNext, _) => return focus_next(),
+            (Shortcut::FocusPrevious, _) => return focus_previous(),
+            (Shortcut::Back, _) if self.settings.is_some() => {
+                AppMessage::Settings(SettingsMessage::Close)
+            }
+            // Actions aren't available behind the settings screen
+            (_, _) if self.settings.is_some() => return Task::none(),
+            (Shortcut::Back, _) if self.help.is_some() => AppMessage::Help(HelpMessage::Close),
+            (_, _) if self.help.is_some() => return Task::none(),
+            (Shortcut::Back, _) if self.history.is_some() => {
+                AppMessage::History(HistoryMessage::Close)
+            }
+            (_, _) if self.history.is_some() => return Task::none(),
+            (Shortcut::Back, AppState::Active(state)) => match state.preview_state {
+                PreviewState::Ready(..) | PreviewState::Error(_) => {
+                    AppMessage::Preview(PreviewMessage::Cancel)
+                }
+                _ if state.is_busy() => return Task::none(),
+                _ => AppMessage::Game(GameMessage::ClearGamePath),
+            },
+            (Shortcut::ApplyPatch, AppState::Active(state))
+                if !state.patch.is_patched() && !state.is_busy() =>
+            {
+                AppMessage::Patch(PatchMessage::Add)
+            }
+            (Shortcut::AddPlugin, AppState::Active(state))
+                if !state.plugin.is_present()
+                    && !state.is_busy()
+                    && !matches!(self.plugin_details_state, PluginDetailsState::Error(_)) =>
+            {
+                AppMessage::Plugin(PluginMessage::Add)
+            }
+            _ => return Task::none(),
+        };
+
+        self.update(message)
     }
 
-    fn view_plugin_installing() -> Column<'static, AppMessage> {
-        let plugin_text = text("Installing plugin...").color(Palette::DARK.primary);
-        column![plugin_text].spacing(10)
+    /// Creates a task sending an anonymous usage report for the `result` of
+    /// the `operation`, only when the user opted in to usage statistics
+    fn usage_report_task<V>(
+        &self,
+        operation: UsageOperation,
+        result: &Result<V, ActionError>,
+    ) -> Task<AppMessage> {
+        let endpoint = match self.config.usage_stats_endpoint() {
+            Some(value) => value.to_string(),
+            None => return Task::none(),
+        };
+
+        let outcome = match result {
+            Ok(_) => UsageOutcome::Success,
+            Err(ActionError::GameRunning) => UsageOutcome::GameRunning,
+            Err(ActionError::Failed(_)) => UsageOutcome::Failed,
+        };
+        let http_client = self.http_client.clone();
+
+        Task::future(async move {
+            let report = UsageReport::new(operation, outcome);
+            if let Err(err) = send_usage_report(http_client, endpoint, report).await {
+                debug!("failed to send usage report: {err:#}");
+            }
+        })
+        .discard()
     }
 
-    fn view_plugin_uninstalling() -> Column<'static, AppMessage> {
-        let plugin_text = text("Uninstalling plugin...").color(Palette::DARK.primary);
-        column![plugin_text].spacing(10)
+    /// Creates a task showing a desktop notification for the `result` of
+    /// installing the plugin, only when the window is in the background
+    fn install_notification_task(
+        &self,
+        result: &Result<Option<String>, ActionError>,
+    ) -> Task<AppMessage> {
+        if !self.unfocused {
+            return Task::none();
+        }
+
+        let (summary, body) = match result {
+            Ok(Some(version)) => (
+                tr("Installation complete").to_string(),
+                tr_args("Pocket Relay plugin {} installed", &[version]),
+            ),
+            Ok(None) => (
+                tr("Installation complete").to_string(),
+                tr("Pocket Relay plugin installed").to_string(),
+            ),
+            Err(ActionError::GameRunning) => (
+                tr("Installation failed").to_string(),
+                tr("Mass Effect 3 is running, close the game and try again").to_string(),
+            ),
+            Err(ActionError::Failed(err)) => (tr("Installation failed").to_string(), err.clone()),
+        };
+
+        Task::future(notify(summary, body)).discard()
     }
 
-    fn view_plugin_add_success() -> Column<'static, AppMessage> {
-        let plugin_text: Text =
-            text("Pocket Relay client plugin successfully installed.").color(Palette::DARK.success);
-        let remove_plugin_button: Button<_> = button("Remove Plugin")
-            .on_press(AppMessage::Plugin(PluginMessage::Remove))
-            .padding(10);
+    fn update_help(&mut self, msg: HelpMessage) -> Task<AppMessage> {
+        match msg {
+            HelpMessage::Open => {
+                self.help = Some(HelpState {
+                    guide: None,
+                    expanded: None,
+                });
+
+                let size = self.window_size();
+                return Task::batch([
+                    self.load_help_task(),
+                    get_latest().and_then(move |id| resize(id, size)),
+                ]);
+            }
+            HelpMessage::Close => {
+                self.help = None;
+
+                let size = self.window_size();
+                return get_latest().and_then(move |id| resize(id, size));
+            }
+            HelpMessage::Refresh => {
+                if let Some(help) = &mut self.help {
+                    help.guide = None;
+                    return self.load_help_task();
+                }
+            }
+            HelpMessage::Loaded(document, source) => {
+                if let Some(help) = &mut self.help {
+                    help.guide = Some((document, source));
+                }
+            }
+            HelpMessage::ToggleTopic(index) => {
+                if let Some(help) = &mut self.help {
+                    help.expanded = match help.expanded {
+                        Some(expanded) if expanded == index => None,
+                        _ => Some(index),
+                    };
+                }
+            }
+        }
 
-        column![plugin_text, remove_plugin_button].spacing(10)
+        Task::none()
     }
 
-    fn view_plugin_remove_success(plugin_details: &PluginDetailsState) -> Column<'_, AppMessage> {
-        let plugin_text: Text =
-            text("Pocket Relay client plugin successfully removed.").color(Palette::DARK.success);
+    fn update_history(&mut self, msg: HistoryMessage) -> Task<AppMessage> {
+        match msg {
+            HistoryMessage::Open => {
+                self.history = Some(HistoryState {
+                    entries: None,
+                    undoing: false,
+                    undo_error: None,
+                });
+
+                let size = self.window_size();
+                return Task::batch([
+                    self.load_history_task(),
+                    get_latest().and_then(move |id| resize(id, size)),
+                ]);
+            }
+            HistoryMessage::Close => {
+                self.history = None;
 
-        let add_plugin = Self::view_add_plugin(plugin_details);
-        column![plugin_text, add_plugin].spacing(10)
+                let size = self.window_size();
+                return get_latest().and_then(move |id| resize(id, size));
+            }
+            HistoryMessage::Loaded(result) => {
+                if let Some(history) = &mut self.history {
+                    history.entries = Some(result);
+                }
+            }
+            HistoryMessage::Undo(id) => {
+                let history = match &mut self.history {
+                    Some(value) => value,
+                    None => return Task::none(),
+                };
+
+                let path = match &self.state {
+                    AppState::Active(state) if !state.is_busy() => state.path.clone(),
+                    _ => return Task::none(),
+                };
+
+                if history.undoing {
+                    return Task::none();
+                }
+
+                history.undoing = true;
+                history.undo_error = None;
+
+                return Task::perform(undo_change(path, id), map_action_error)
+                    .map(|result| AppMessage::History(HistoryMessage::Undone(result)));
+            }
+            HistoryMessage::Undone(result) => {
+                if let Some(history) = &mut self.history {
+                    history.undoing = false;
+                    history.undo_error = match result {
+                        Ok(()) => None,
+                        Err(ActionError::GameRunning) => Some(
+                            tr("The game is currently running, close the game before \
+                            undoing changes.")
+                            .to_string(),
+                        ),
+                        Err(ActionError::Failed(err)) => {
+                            error!("failed to undo change: {err}");
+                            Some(tr_args("failed to undo change: {}", &[&err]))
+                        }
+                    };
+                }
+
+                // Undoing the patch isn't the store reverting it, the patch
+                // is remembered again if its still applied
+                if let AppState::Active(state) = &self.state {
+                    self.config
+                        .patched_installations
+                        .retain(|path| path != &state.path);
+                }
+
+                return Task::batch([
+                    self.load_history_task(),
+                    self.update_game(GameMessage::Refresh).map(AppMessage::Game),
+                    save_config_task(self.config.clone()),
+                ]);
+            }
+        }
+
+        Task::none()
     }
 
-    fn view_plugin_install_error(err: &str) -> Column<'static, AppMessage> {
-        let patch_text: Text =
-            text(format!("failed to install plugin: {err}")).color(Palette::DARK.danger);
+    /// Creates a task loading the recorded changes to the active game
+    fn load_history_task(&self) -> Task<AppMessage> {
+        let path = match &self.state {
+            AppState::Active(state) => state.path.clone(),
+            AppState::Initial(_) => return Task::none(),
+        };
 
-        let retry_button: Button<_> = button("Retry")
-            .on_press(AppMessage::Plugin(PluginMessage::Add))
-            .padding(10);
-        column![patch_text, retry_button].spacing(10)
+        Task::perform(load_history(path), map_error_string)
+            .map(|result| AppMessage::History(HistoryMessage::Loaded(result)))
     }
 
-    fn view_plugin_uninstall_error(err: &str) -> Column<'static, AppMessage> {
-        let patch_text: Text =
-            text(format!("failed to remove plugin: {err}")).color(Palette::DARK.danger);
+    /// Creates a task loading the troubleshooting guide
+    fn load_help_task(&self) -> Task<AppMessage> {
+        Task::perform(
+            load_help(
+                self.http_client.clone(),
+                self.config.mirrors.clone(),
+                self.config.data_cache_dir(),
+            ),
+            |(document, source)| AppMessage::Help(HelpMessage::Loaded(document, source)),
+        )
+    }
+
+    fn update_settings(&mut self, msg: SettingsMessage) -> Task<AppMessage> {
+        match msg {
+            SettingsMessage::Open => {
+                let (proxy_mode, proxy_url) = match &self.config.proxy {
+                    ProxyConfig::System => (ProxyMode::System, String::new()),
+                    ProxyConfig::Disabled => (ProxyMode::Disabled, String::new()),
+                    ProxyConfig::Manual(url) => (ProxyMode::Manual, url.clone()),
+                };
+
+                self.settings = Some(SettingsState {
+                    proxy_mode,
+                    proxy_url,
+                    mirrors: self.config.mirrors.join(", "),
+                    github_token: self.config.github_token.clone().unwrap_or_default(),
+                    language: self.config.language,
+                    game: self.config.game,
+                    theme_mode: self.config.theme,
+                    accent_color: self.config.accent_color.clone().unwrap_or_default(),
+                    plugin_repository: self.config.plugin_source.repository.clone(),
+                    plugin_asset_name: self.config.plugin_source.asset_name.clone(),
+                    download_limit: self.config.download_limit.unwrap_or_default(),
+                    max_release_pages: self.config.max_release_pages.clamp(1, MAX_RELEASE_PAGES),
+                    release_channel: self.config.release_channel.clone(),
+                    release_feeds: format_feeds(&self.config.release_feeds),
+                    cache_dir: self
+                        .config
+                        .cache_dir
+                        .as_ref()
+                        .map(|path| path.display().to_string())
+                        .unwrap_or_default(),
+                    cache_downloads: self.config.cache_downloads,
+                    cache_usage: None,
+                    clearing_cache: false,
+                    registering_links: false,
+                    registered_links: false,
+                    ui_scale: (self.config.ui_scale() * 100.0).round() as u32,
+                    detect_patch_reverts: self.config.detect_patch_reverts,
+                    tray_mode: self.config.tray_mode,
+                    usage_stats: self.config.usage_stats,
+                    usage_stats_endpoint: self
+                        .config
+                        .usage_stats_endpoint
+                        .clone()
+                        .unwrap_or_default(),
+                    usage_stats_preview: UsageReport::new(
+                        UsageOperation::InstallPlugin,
+                        UsageOutcome::Success,
+                    )
+                    .to_json(),
+                    error: None,
+                });
+
+                let usage_task = match self
+                    .config
+                    .data_cache_dir()
+                    .map(|path| asset_cache_dir(&path))
+                {
+                    Some(dir) => Task::perform(async move { cache_usage(&dir).await }, |usage| {
+                        AppMessage::Settings(SettingsMessage::LoadedCacheUsage(usage))
+                    }),
+                    None => Task::done(AppMessage::Settings(SettingsMessage::LoadedCacheUsage(
+                        CacheUsage::default(),
+                    ))),
+                };
+
+                let size = self.window_size();
+                return Task::batch([
+                    usage_task,
+                    get_latest().and_then(move |id| resize(id, size)),
+                ]);
+            }
+            SettingsMessage::Close => {
+                self.settings = None;
+
+                let size = self.window_size();
+                return get_latest().and_then(move |id| resize(id, size));
+            }
+            SettingsMessage::SetProxyMode(mode) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.proxy_mode = mode;
+                    settings.error = None;
+                }
+            }
+            SettingsMessage::SetProxyUrl(url) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.proxy_url = url;
+                    settings.error = None;
+                }
+            }
+            SettingsMessage::SetMirrors(mirrors) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.mirrors = mirrors;
+                    settings.error = None;
+                }
+            }
+            SettingsMessage::SetGitHubToken(token) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.github_token = token;
+                    settings.error = None;
+                }
+            }
+            SettingsMessage::SetLanguage(language) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.language = language;
+                }
+            }
+            SettingsMessage::SetGame(game) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.game = game;
+                }
+            }
+            SettingsMessage::SetThemeMode(mode) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.theme_mode = mode;
+                }
+            }
+            SettingsMessage::SetAccentColor(color) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.accent_color = color;
+                    settings.error = None;
+                }
+            }
+            SettingsMessage::SetPluginRepository(repository) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.plugin_repository = repository;
+                    settings.error = None;
+                }
+            }
+            SettingsMessage::SetPluginAssetName(asset_name) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.plugin_asset_name = asset_name;
+                    settings.error = None;
+                }
+            }
+            SettingsMessage::ResetPluginSource => {
+                if let Some(settings) = &mut self.settings {
+                    let source = PluginSource::default();
+                    settings.plugin_repository = source.repository;
+                    settings.plugin_asset_name = source.asset_name;
+                    settings.error = None;
+                }
+            }
+            SettingsMessage::SetDownloadLimit(limit) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.download_limit = limit;
+                }
+            }
+            SettingsMessage::SetMaxReleasePages(pages) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.max_release_pages = pages;
+                }
+            }
+            SettingsMessage::SetUiScale(scale) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.ui_scale = scale;
+                }
+            }
+            SettingsMessage::SetReleaseChannel(channel) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.release_channel = channel;
+                }
+            }
+            SettingsMessage::SetDetectPatchReverts(enabled) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.detect_patch_reverts = enabled;
+                }
+            }
+            SettingsMessage::SetTrayMode(enabled) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.tray_mode = enabled;
+                }
+            }
+            SettingsMessage::SetCacheDownloads(enabled) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.cache_downloads = enabled;
+                }
+            }
+            SettingsMessage::LoadedCacheUsage(usage) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.cache_usage = Some(usage);
+                }
+            }
+            SettingsMessage::ClearDownloadCache => {
+                let dir = match self
+                    .config
+                    .data_cache_dir()
+                    .map(|path| asset_cache_dir(&path))
+                {
+                    Some(value) => value,
+                    None => return Task::none(),
+                };
+
+                if let Some(settings) = &mut self.settings {
+                    settings.clearing_cache = true;
+                }
+
+                return Task::perform(async move { clear_cache(&dir).await }, map_error_string)
+                    .map(|result| {
+                        AppMessage::Settings(SettingsMessage::ClearedDownloadCache(result))
+                    });
+            }
+            SettingsMessage::ClearedDownloadCache(result) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.clearing_cache = false;
+                    match result {
+                        Ok(()) => settings.cache_usage = Some(CacheUsage::default()),
+                        Err(err) => settings.error = Some(err),
+                    }
+                }
+            }
+            SettingsMessage::RegisterLinkHandler => {
+                if let Some(settings) = &mut self.settings {
+                    settings.registering_links = true;
+                    settings.registered_links = false;
+                }
+
+                return Task::perform(
+                    async {
+                        spawn_blocking(register_link_handler)
+                            .await
+                            .context("failed to join native thread")?
+                    },
+                    map_error_string,
+                )
+                .map(|result| {
+                    AppMessage::Settings(SettingsMessage::RegisteredLinkHandler(result))
+                });
+            }
+            SettingsMessage::RegisteredLinkHandler(result) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.registering_links = false;
+                    match result {
+                        Ok(()) => settings.registered_links = true,
+                        Err(err) => {
+                            error!("failed to register link handler: {err}");
+                            settings.error = Some(err);
+                        }
+                    }
+                }
+            }
+            SettingsMessage::SetUsageStats(enabled) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.usage_stats = enabled;
+                    settings.error = None;
+                }
+            }
+            SettingsMessage::SetUsageStatsEndpoint(endpoint) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.usage_stats_endpoint = endpoint;
+                    settings.error = None;
+                }
+            }
+            SettingsMessage::SetReleaseFeeds(release_feeds) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.release_feeds = release_feeds;
+                    settings.error = None;
+                }
+            }
+            SettingsMessage::SetCacheDir(cache_dir) => {
+                if let Some(settings) = &mut self.settings {
+                    settings.cache_dir = cache_dir;
+                    settings.error = None;
+                }
+            }
+            SettingsMessage::PickCacheDir => {
+                return Task::perform(pick_cache_dir(), map_error_string)
+                    .map(|result| AppMessage::Settings(SettingsMessage::PickedCacheDir(result)));
+            }
+            SettingsMessage::PickedCacheDir(result) => {
+                if let Some(settings) = &mut self.settings {
+                    match result {
+                        Ok(Some(path)) => {
+                            settings.cache_dir = path.display().to_string();
+                            settings.error = None;
+                        }
+                        // User cancelled picking a folder
+                        Ok(None) => {}
+                        Err(err) => {
+                            error!("failed to pick cache folder: {err}");
+                            settings.error = Some(err);
+                        }
+                    }
+                }
+            }
+            SettingsMessage::Save => {
+                let busy = self.is_busy();
+                let settings = match &mut self.settings {
+                    Some(value) => value,
+                    None => return Task::none(),
+                };
+
+                let proxy = match settings.proxy_mode {
+                    ProxyMode::System => ProxyConfig::System,
+                    ProxyMode::Disabled => ProxyConfig::Disabled,
+                    ProxyMode::Manual => {
+                        let url = settings.proxy_url.trim().to_string();
+                        if let Err(err) = parse_proxy(&url) {
+                            settings.error = Some(format!("{err:#}"));
+                            return Task::none();
+                        }
+                        ProxyConfig::Manual(url)
+                    }
+                };
+
+                let http_client = match create_http_client(&proxy) {
+                    Ok(value) => value,
+                    Err(err) => {
+                        settings.error = Some(format!("{err:#}"));
+                        return Task::none();
+                    }
+                };
+
+                let mirrors: Vec<String> = settings
+                    .mirrors
+                    .split(',')
+                    .map(|mirror| mirror.trim().to_string())
+                    .filter(|mirror| !mirror.is_empty())
+                    .collect();
+
+                let github_token = Some(settings.github_token.trim().to_string())
+                    .filter(|token| !token.is_empty());
+
+                let accent_color = Some(settings.accent_color.trim().to_string())
+                    .filter(|color| !color.is_empty());
+                let accent = match accent_color.as_deref().map(parse_accent_color) {
+                    Some(Some(color)) => Some(color),
+                    Some(None) => {
+                        settings.error = Some(
+                            tr("Invalid accent color, expected a hex color such as #5865f2")
+                                .to_string(),
+                        );
+                        return Task::none();
+                    }
+                    None => None,
+                };
+
+                // Empty inputs use the official plugin
+                let plugin_repository = match settings.plugin_repository.trim() {
+                    "" => DEFAULT_GITHUB_REPOSITORY,
+                    value => value,
+                };
+                if !is_valid_repository(plugin_repository) {
+                    settings.error = Some(
+                        tr(
+                            "Invalid plugin repository, expected a GitHub repository such as \
+                        PocketRelay/PocketRelayClientPlugin",
+                        )
+                        .to_string(),
+                    );
+                    return Task::none();
+                }
+
+                let plugin_asset_name = match settings.plugin_asset_name.trim() {
+                    "" => DEFAULT_ASSET_NAME,
+                    value => value,
+                };
+                if !is_valid_asset_name(plugin_asset_name) {
+                    settings.error = Some(
+                        tr("Invalid plugin asset name, expected a file name such as \
+                        pocket-relay-plugin.asi")
+                        .to_string(),
+                    );
+                    return Task::none();
+                }
+
+                let plugin_source = PluginSource {
+                    repository: plugin_repository.to_string(),
+                    asset_name: plugin_asset_name.to_string(),
+                };
+
+                // Empty input uses the system folders
+                let cache_dir = match settings.cache_dir.trim() {
+                    "" => None,
+                    value => Some(PathBuf::from(value)),
+                };
+                if cache_dir.as_ref().is_some_and(|path| !path.is_absolute()) {
+                    settings.error =
+                        Some(tr("Invalid cache folder, expected a full folder path").to_string());
+                    return Task::none();
+                }
+
+                let language = settings.language;
 
-        let retry_button: Button<_> = button("Retry")
-            .on_press(AppMessage::Plugin(PluginMessage::Remove))
-            .padding(10);
-        column![patch_text, retry_button].spacing(10)
-    }
+                // Changing the game discards the state of the current game
+                let game = settings.game;
+                if game != self.config.game && busy {
+                    settings.error = Some(
+                        tr("The game can't be changed while changes are being made").to_string(),
+                    );
+                    return Task::none();
+                }
 
-    /// View for the add plugin details and buttons
-    fn view_add_plugin(plugin_details: &PluginDetailsState) -> Column<'_, AppMessage> {
-        match plugin_details {
-            // Still loading the plugin details
-            PluginDetailsState::Loading => {
-                let plugin_version_text: Text =
-                    text("Loading latest plugin version details...").color(DARK_TEXT);
-                column![plugin_version_text].spacing(10)
-            }
-            PluginDetailsState::Error(err) => {
-                let plugin_version_text: Text =
-                    text(format!("Unable to load latest plugin version: {err}")).color(DARK_TEXT);
-                column![plugin_version_text].spacing(10)
-            }
-            PluginDetailsState::Ready(plugin_details) => {
-                let release = match &plugin_details.selected {
-                    ReleaseType::Stable(value) => value,
-                    ReleaseType::Beta(value) => value,
+                let theme_mode = settings.theme_mode;
+                let download_limit = Some(settings.download_limit).filter(|limit| *limit > 0);
+                let max_release_pages = settings.max_release_pages;
+                let release_feeds = match parse_feeds(&settings.release_feeds) {
+                    Ok(value) => value,
+                    Err(err) => {
+                        settings.error = Some(format!("{err:#}"));
+                        return Task::none();
+                    }
                 };
 
-                let version = &release.tag_name;
+                // Channel from a removed feed can't be followed
+                let release_channel = match &settings.release_channel {
+                    ReleaseChannel::Feed(name)
+                        if !release_feeds.iter().any(|feed| feed.name == *name) =>
+                    {
+                        ReleaseChannel::Stable
+                    }
+                    channel => channel.clone(),
+                };
+                let ui_scale = settings.ui_scale as f32 / 100.0;
 
-                let plugin_version_text: Text = text(format!(
-                    "The latest version of the plugin client is {version}"
-                ))
-                .color(DARK_TEXT);
+                // Empty input keeps the statistics from being sent
+                let usage_stats_endpoint = match settings.usage_stats_endpoint.trim() {
+                    "" => None,
+                    value => Some(value.to_string()),
+                };
+                if usage_stats_endpoint.as_deref().is_some_and(|endpoint| {
+                    !endpoint.starts_with("http://") && !endpoint.starts_with("https://")
+                }) {
+                    settings.error = Some(
+                        tr("Invalid statistics endpoint, expected an http:// or https:// URL")
+                            .to_string(),
+                    );
+                    return Task::none();
+                }
+                if settings.usage_stats && usage_stats_endpoint.is_none() {
+                    settings.error =
+                        Some(tr("Enter the endpoint to send usage statistics to").to_string());
+                    return Task::none();
+                }
+                let usage_stats = settings.usage_stats;
+                let detect_patch_reverts = settings.detect_patch_reverts;
+                let tray_mode = settings.tray_mode;
+                let cache_downloads = settings.cache_downloads;
+
+                self.settings = None;
+                self.http_client = http_client;
+                self.config.proxy = proxy;
+                self.config.mirrors = mirrors;
+                self.config.github_token = github_token;
+                self.config.language = language;
+                self.config.theme = theme_mode;
+                self.config.accent_color = accent_color;
+                self.config.download_limit = download_limit;
+                self.downloads.set_speed_limit(download_limit);
+                self.config.cache_dir = cache_dir;
+                self.downloads.set_download_dir(self.config.download_dir());
+                self.config.cache_downloads = cache_downloads;
+                self.downloads.set_cache_dir(self.config.asset_cache_dir());
+                self.config.ui_scale = ui_scale;
+                self.config.usage_stats = usage_stats;
+                self.config.detect_patch_reverts = detect_patch_reverts;
+                self.config.tray_mode = tray_mode;
+                // Tray icon is removed along with its subscription
+                self.tray_ready &= tray_mode;
+                self.config.usage_stats_endpoint = usage_stats_endpoint;
+                self.theme = create_theme(theme_mode, accent);
+                set_language(language);
+
+                // Installations of the previous game can't be used with the new game
+                let game_changed = self.config.game != game;
+                if game_changed {
+                    self.state = AppState::default();
+                    self.config.game_path = None;
+                    self.config.installations.clear();
+                    self.detected_paths.clear();
+                }
+                self.config.game = game;
+                set_game(game);
+
+                // Releases from the previous source can't be installed
+                let source_changed = self.config.plugin_source != plugin_source;
+                self.config.plugin_source = plugin_source;
+
+                // Selected release is picked from the release channel
+                let channel_changed = self.config.release_channel != release_channel
+                    || self.config.release_feeds != release_feeds
+                    || self.config.max_release_pages != max_release_pages;
+                self.config.max_release_pages = max_release_pages;
+                self.config.release_channel = release_channel;
+                self.config.release_feeds = release_feeds;
+
+                let mut tasks = vec![save_config_task(self.config.clone())];
+
+                // Retry loading the plugin details with the new settings
+                if source_changed
+                    || channel_changed
+                    || matches!(self.plugin_details_state, PluginDetailsState::Error(_))
+                {
+                    tasks.push(self.load_plugin_details().map(AppMessage::PluginDetails));
+                }
 
-                let add_plugin_button: Button<_> = button("Add Plugin")
-                    .on_press(AppMessage::Plugin(PluginMessage::Add))
-                    .padding(10);
+                if game_changed {
+                    tasks.push(detect_game_paths_task());
+                }
 
-                let version_select = combo_box(
-                    &plugin_details.release_type_state,
-                    "Select version",
-                    Some(&plugin_details.selected),
-                    |value| AppMessage::Plugin(PluginMessage::SelectType(value)),
-                )
-                .padding(10);
+                let size = self.window_size();
+                tasks.push(get_latest().and_then(move |id| resize(id, size)));
 
-                let add_row = row![add_plugin_button, version_select].spacing(10);
-                column![plugin_version_text, add_row].spacing(10)
+                return Task::batch(tasks);
             }
         }
+
+        Task::none()
     }
 
-    fn update(&mut self, message: AppMessage) -> Task<AppMessage> {
-        match message {
-            AppMessage::Game(msg) => self.update_game(msg).map(AppMessage::Game),
-            AppMessage::Patch(msg) => self.update_patch(msg).map(AppMessage::Patch),
-            AppMessage::Plugin(msg) => self.update_plugin(msg).map(AppMessage::Plugin),
-            AppMessage::PluginDetails(msg) => self
-                .update_plugin_details(msg)
-                .map(AppMessage::PluginDetails),
+    fn update_installer_update(
+        &mut self,
+        msg: InstallerUpdateMessage,
+    ) -> Task<InstallerUpdateMessage> {
+        match msg {
+            InstallerUpdateMessage::Checked(result) => match result {
+                Ok(Some(release)) => {
+                    debug!("installer update available: {}", release.tag_name);
+                    self.installer_update_state = InstallerUpdateState::Available(release);
+
+                    // Resize window to fit the update section
+                    if matches!(self.state, AppState::Initial(_)) {
+                        let size = self.initial_window_size();
+                        return get_latest().and_then(move |id| resize(id, size));
+                    }
+                }
+                Ok(None) => {}
+                Err(err) => error!("failed to check for installer update: {err}"),
+            },
+            InstallerUpdateMessage::Update => {
+                let release = match std::mem::take(&mut self.installer_update_state) {
+                    InstallerUpdateState::Available(release) => release,
+                    state => {
+                        self.installer_update_state = state;
+                        return Task::none();
+                    }
+                };
+
+                self.installer_update_state = InstallerUpdateState::Updating;
+
+                return Task::perform(
+                    apply_update(
+                        self.http_client.clone(),
+                        self.downloads.clone(),
+                        self.config.mirrors.clone(),
+                        release,
+                    ),
+                    map_error_string,
+                )
+                .map(InstallerUpdateMessage::Updated);
+            }
+            InstallerUpdateMessage::Updated(result) => {
+                if let Err(err) = result {
+                    error!("failed to update installer: {err}");
+                    self.installer_update_state = InstallerUpdateState::Error(err);
+                } else {
+                    // New installer has been started, close this one
+                    return iced::exit();
+                }
+            }
         }
+
+        Task::none()
     }
 
     fn update_game(&mut self, msg: GameMessage) -> Task<GameMessage> {
         match msg {
             GameMessage::PickGamePath => {
-                return Task::perform(pick_game_state(), map_error_string)
+                return Task::perform(pick_game_state(false), map_error_string)
                     .map(GameMessage::PickedGameResult);
             }
-            GameMessage::PickedGameResult(result) => {
-                match result {
-                    Ok(state) => {
-                        debug!("picked path: {state:?}");
-
-                        if let Some(state) = state {
-                            self.state = AppState::Active(AppStateActive {
-                                patched: state.patched,
-                                plugin: state.plugin,
-                                path: state.path,
-                                alter_plugin_state: Default::default(),
-                                alter_patch_state: Default::default(),
-                            });
-
-                            // Resize window to fit next screen
-                            return get_latest().and_then(|id| resize(id, EXPANDED_WINDOW_SIZE));
-                        } else {
-                            self.state = AppState::default()
-                        }
+            GameMessage::PickGameFolder => {
+                return Task::perform(pick_game_state(true), map_error_string)
+                    .map(GameMessage::PickedGameResult);
+            }
+            GameMessage::SelectGamePath(path) => {
+                return Task::perform(
+                    async move { read_game_state(&path).await.map(Some) },
+                    map_error_string,
+                )
+                .map(GameMessage::PickedGameResult);
+            }
+            GameMessage::DetectedGamePaths(paths) => {
+                debug!("detected game paths: {paths:?}");
+
+                self.detected_paths = paths;
+
+                // Resize window to fit the detected paths
+                if matches!(self.state, AppState::Initial(_)) {
+                    let size = self.initial_window_size();
+                    return get_latest().and_then(move |id| resize(id, size));
+                }
+            }
+            GameMessage::LoadedSavedGame(state) => {
+                debug!("loaded saved path: {state:?}");
+
+                // Only use the saved game if the user hasn't already picked one
+                if let (Some(state), AppState::Initial(_)) = (state, &self.state) {
+                    return self.set_game_state(state);
+                }
+            }
+            GameMessage::PickedGameResult(result) => match result {
+                Ok(state) => {
+                    debug!("picked path: {state:?}");
+
+                    if let Some(state) = state {
+                        return self.set_game_state(state);
+                    } else if let AppState::Initial(_) = self.state {
+                        self.state = AppState::default()
                     }
-                    Err(err) => {
-                        error!("failed to pick game path: {err}");
+                }
+                Err(err) => {
+                    error!("failed to pick game path: {err}");
 
-                        if let AppState::Initial(state) = &mut self.state {
-                            state.pick_file_error = Some(err);
-                        }
+                    if let AppState::Initial(state) = &mut self.state {
+                        state.pick_file_error = Some(err);
                     }
                 }
+            },
+            GameMessage::SwitchInstallation(path) => {
+                self.installation_error = None;
+
+                return Task::perform(read_folder_game_state(path), map_error_string)
+                    .map(GameMessage::SwitchedInstallation);
+            }
+            GameMessage::SwitchedInstallation(result) => match result {
+                Ok(state) => return self.set_game_state(state),
+                Err(err) => {
+                    error!("failed to switch installation: {err}");
+                    self.installation_error =
+                        Some(tr_args("Failed to switch installation: {}", &[&err]));
+                }
+            },
+            GameMessage::RemoveInstallation(path) => {
+                self.config.installations.retain(|value| *value != path);
+
+                let size = self.window_size();
+                return Task::batch([
+                    get_latest().and_then(move |id| resize(id, size)),
+                    save_config_task(self.config.clone()),
+                ]);
             }
             GameMessage::ClearGamePath => {
+                if self.is_busy() {
+                    return Task::none();
+                }
+
                 self.state = AppState::default();
+                self.config.game_path = None;
 
                 // Resize window to fit main screen
-                return get_latest().and_then(|id| resize(id, WINDOW_SIZE));
+                let size = self.initial_window_size();
+                return Task::batch([
+                    get_latest().and_then(move |id| resize(id, size)),
+                    save_config_task(self.config.clone()),
+                ]);
+            }
+            GameMessage::Refresh => {
+                let path = match self.active_path() {
+                    Some(value) => value,
+                    None => return Task::none(),
+                };
+
+                return self.track(
+                    Task::perform(read_folder_game_state(path), map_error_string)
+                        .map(GameMessage::Refreshed),
+                );
+            }
+            GameMessage::Refreshed(result) => {
+                let state = match &mut self.state {
+                    AppState::Active(state) => state,
+                    _ => return Task::none(),
+                };
+
+                match result {
+                    // Changes made by an operation are applied when it finishes
+                    Ok(_) if state.is_busy() => {}
+                    Ok(game) => {
+                        state.patch.refresh(game.patch_state);
+                        state.plugin.refresh(game.plugin, game.plugin_version);
+                        state.exe_warning = game.warning;
+                        state.details = game.details;
+                        state.store = game.store;
+                        state.loaders = game.loaders;
+                    }
+                    Err(err) => error!("failed to refresh game state: {err}"),
+                }
             }
         }
 
         Task::none()
     }
 
+    /// Sets the active game state and saves the game path to the config
+    fn set_game_state(&mut self, state: GameState) -> Task<GameMessage> {
+        // Changing the game part way through an operation would leave it incomplete
+        if self.is_busy() {
+            debug!("ignoring game change while an operation is in progress");
+            return Task::none();
+        }
+
+        self.config.game_path = Some(state.path.clone());
+        self.installation_error = None;
+
+        // Remember the installation so it can be switched back to
+        if !self.config.installations.contains(&state.path) {
+            self.config.installations.push(state.path.clone());
+        }
+
+        self.state = AppState::Active(AppStateActive {
+            patch: PatchStatus::new(state.patch_state),
+            plugin: PluginStatus::new(state.plugin, state.plugin_version),
+            path: state.path,
+            store: state.store,
+            loaders: state.loaders,
+            asi_plugins_state: Default::default(),
+            compat_state: Default::default(),
+            leftovers_state: Default::default(),
+            report_state: Default::default(),
+            show_plugin_log: false,
+            plugin_log_state: Default::default(),
+            plugin_log_notice: None,
+            asi_plugins_error: None,
+            asi_delete_confirm: None,
+            plugin_config_state: Default::default(),
+            plugin_config: Default::default(),
+            connection_url: String::new(),
+            preview_state: Default::default(),
+            install_all_state: Default::default(),
+            batch_results: Vec::new(),
+            uninstall_state: Default::default(),
+            remove_plugin_dir: false,
+            backup_plugin_files: true,
+            folder_access: None,
+            relaunching: false,
+            elevation_error: None,
+            exe_warning: state.warning,
+            patch_reverted: false,
+            details: state.details,
+            show_details: false,
+            launch_error: None,
+            plugin_add_queued: false,
+            open_folder_error: None,
+            checksum_status: None,
+            server_version: None,
+            tasks: Vec::new(),
+        });
+
+        // Resize window to fit next screen
+        let size = self.window_size();
+        Task::batch([
+            get_latest().and_then(move |id| resize(id, size)),
+            save_config_task(self.config.clone()),
+        ])
+    }
+
     fn update_patch(&mut self, msg: PatchMessage) -> Task<PatchMessage> {
         let state = match &mut self.state {
             AppState::Active(state) => state,
-            _ => panic!("app reached invalid state, expecting 'Active' state"),
+            // Game was changed before the result arrived
+            _ => {
+                debug!("ignoring patch message without an active game: {msg:?}");
+                return Task::none();
+            }
         };
 
         match msg {
+            PatchMessage::DismissReverted => {
+                state.patch_reverted = false;
+
+                // Forgotten so the notice isn't shown again for the folder
+                self.config
+                    .patched_installations
+                    .retain(|path| *path != state.path);
+                return save_config_task(self.config.clone());
+            }
+            PatchMessage::Add | PatchMessage::Remove if state.is_busy() => {}
             PatchMessage::Add => {
-                state.alter_patch_state = AlterPatchState::Loading;
+                if let Err(err) = state.patch.start() {
+                    debug!("ignoring patch action: {err}");
+                    return Task::none();
+                }
+
+                let path = state.path.to_path_buf();
 
-                return Task::perform(apply_patch(state.path.to_path_buf()), map_error_string)
-                    .map(PatchMessage::Added);
+                return state.track(Task::run(apply_patch_stream(path), |msg| msg));
             }
             PatchMessage::Remove => {
-                state.alter_patch_state = AlterPatchState::Loading;
+                if let Err(err) = state.patch.start() {
+                    debug!("ignoring patch action: {err}");
+                    return Task::none();
+                }
 
-                return Task::perform(remove_patch(state.path.to_path_buf()), map_error_string)
-                    .map(PatchMessage::Removed);
+                let path = state.path.to_path_buf();
+
+                return state.track(
+                    Task::perform(
+                        record_change(
+                            HistoryAction::RemovePatch,
+                            path.clone(),
+                            remove_patch(game(), path),
+                        ),
+                        map_action_error,
+                    )
+                    .map(PatchMessage::Removed),
+                );
             }
+            PatchMessage::Progress(progress) => state.patch.progress(progress),
             PatchMessage::Added(result) => {
-                if let Err(err) = result {
-                    error!("failed to apply patch: {err}");
-                    state.alter_patch_state = AlterPatchState::Error(err);
-                } else {
-                    state.alter_patch_state = AlterPatchState::Success;
-                    state.patched = true;
+                // Result arrived after the action stopped being tracked
+                if let Err(err) = state.patch.finish_apply(result) {
+                    debug!("ignoring patch result: {err}");
                 }
             }
             PatchMessage::Removed(result) => {
-                if let Err(err) = result {
-                    error!("failed to remove patch: {err}");
-                    state.alter_patch_state = AlterPatchState::Error(err);
-                } else {
-                    state.alter_patch_state = AlterPatchState::Success;
-                    state.patched = false;
+                let removed = result.is_ok();
+
+                // Result arrived after the action stopped being tracked
+                if let Err(err) = state.patch.finish_remove(result) {
+                    debug!("ignoring patch result: {err}");
+                    return Task::none();
+                }
+
+                // Removed on purpose, not reverted by the store
+                if removed {
+                    self.config
+                        .patched_installations
+                        .retain(|path| *path != state.path);
+                    return save_config_task(self.config.clone());
                 }
             }
         }
@@ -710,57 +7103,244 @@ impl App {
     fn update_plugin(&mut self, msg: PluginMessage) -> Task<PluginMessage> {
         let state = match &mut self.state {
             AppState::Active(state) => state,
-            _ => panic!("app reached invalid state, expecting 'Active' state"),
+            // Game was changed before the result arrived
+            _ => {
+                debug!("ignoring plugin message without an active game: {msg:?}");
+                return Task::none();
+            }
         };
 
         match msg {
+            PluginMessage::Add
+            | PluginMessage::Update
+            | PluginMessage::Remove
+            | PluginMessage::SetEnabled(_)
+            | PluginMessage::PickedFile(Ok(Some(_)))
+                if state.is_busy() => {}
             PluginMessage::Add => {
-                let release = match &self.plugin_details_state {
-                    PluginDetailsState::Ready(details) => &details.selected,
-                    _ => panic!("invalid plugin details state, expecting 'Ready' state"),
+                let (release, asset_name) = match &self.plugin_details_state {
+                    PluginDetailsState::Ready(details) => {
+                        let release = details.selected.release();
+                        if let Some(version) = required_installer_version(release) {
+                            debug!("release {} requires installer {version}", release.tag_name);
+                            return Task::none();
+                        }
+
+                        match details.asset_name(release) {
+                            Some(asset_name) => (release.clone(), asset_name),
+                            None => return Task::none(),
+                        }
+                    }
+                    // Install once the details load
+                    PluginDetailsState::Loading => {
+                        state.plugin_add_queued = true;
+                        return Task::none();
+                    }
+                    PluginDetailsState::Error(_) => return Task::none(),
                 };
 
-                let release = match release {
-                    ReleaseType::Stable(value) => value.clone(),
-                    ReleaseType::Beta(value) => value.clone(),
+                state.plugin_add_queued = false;
+
+                if let Err(err) = state.plugin.start_install() {
+                    debug!("ignoring plugin action: {err}");
+                    return Task::none();
+                }
+
+                let path = state.path.to_path_buf();
+                state.checksum_status = None;
+
+                return state.track(Task::run(
+                    apply_plugin_stream(
+                        self.http_client.clone(),
+                        self.downloads.clone(),
+                        self.config.mirrors.clone(),
+                        path,
+                        release,
+                        asset_name,
+                    ),
+                    |msg| msg,
+                ));
+            }
+            PluginMessage::Update => {
+                let (release, asset_name) = match &self.plugin_details_state {
+                    PluginDetailsState::Ready(details) => {
+                        // Latest falls back to a release that may need a newer installer
+                        let release = &details.latest;
+                        if let Some(version) = required_installer_version(release) {
+                            debug!("release {} requires installer {version}", release.tag_name);
+                            return Task::none();
+                        }
+
+                        match details.asset_name(release) {
+                            Some(asset_name) => (release.clone(), asset_name),
+                            None => return Task::none(),
+                        }
+                    }
+                    // Plugin details were reloaded before the action was handled
+                    _ => return Task::none(),
                 };
 
+                if let Err(err) = state.plugin.start_update() {
+                    debug!("ignoring plugin action: {err}");
+                    return Task::none();
+                }
+
                 let path = state.path.to_path_buf();
+                state.checksum_status = None;
+
+                return state.track(Task::run(
+                    apply_plugin_stream(
+                        self.http_client.clone(),
+                        self.downloads.clone(),
+                        self.config.mirrors.clone(),
+                        path,
+                        release,
+                        asset_name,
+                    ),
+                    |msg| msg,
+                ));
+            }
+            PluginMessage::AddFromFile => {
+                return Task::perform(pick_plugin_file(), map_error_string)
+                    .map(PluginMessage::PickedFile);
+            }
+            PluginMessage::PickedFile(result) => {
+                let file_path = match result {
+                    Ok(Some(value)) => value,
+                    // User cancelled picking a file
+                    Ok(None) => return Task::none(),
+                    Err(err) => {
+                        error!("failed to pick plugin file: {err}");
+                        state.plugin.alter = AlterPluginState::Error(err);
+                        return Task::none();
+                    }
+                };
 
-                state.alter_plugin_state = AlterPluginState::Loading;
+                if let Err(err) = state.plugin.start_install() {
+                    debug!("ignoring plugin action: {err}");
+                    return Task::none();
+                }
 
-                return Task::perform(apply_plugin(path, release), map_error_string)
-                    .map(PluginMessage::Added);
+                let path = state.path.to_path_buf();
+                state.checksum_status = None;
+
+                // Version of a local plugin file is unknown
+                return state.track(
+                    Task::perform(
+                        async move {
+                            record_change(
+                                HistoryAction::InstallPlugin,
+                                path.clone(),
+                                apply_plugin_file(path, file_path),
+                            )
+                            .await
+                            .map(|_| None)
+                        },
+                        map_action_error,
+                    )
+                    .map(PluginMessage::Added),
+                );
+            }
+            PluginMessage::CancelQueuedAdd => {
+                state.plugin_add_queued = false;
             }
             PluginMessage::Remove => {
+                if let Err(err) = state.plugin.start_remove() {
+                    debug!("ignoring plugin action: {err}");
+                    return Task::none();
+                }
+
                 let path = state.path.to_path_buf();
 
-                state.alter_plugin_state = AlterPluginState::Loading;
+                return state.track(
+                    Task::perform(
+                        record_change(
+                            HistoryAction::RemovePlugin,
+                            path.clone(),
+                            remove_plugin(path),
+                        ),
+                        map_action_error,
+                    )
+                    .map(PluginMessage::Removed),
+                );
+            }
+            PluginMessage::SetEnabled(enabled) => {
+                if let Err(err) = state.plugin.start_toggle(enabled) {
+                    debug!("ignoring plugin action: {err}");
+                    return Task::none();
+                }
+
+                let path = state.path.to_path_buf();
 
-                return Task::perform(remove_plugin(path), map_error_string)
-                    .map(PluginMessage::Removed);
+                return state.track(
+                    Task::perform(
+                        record_change(
+                            match enabled {
+                                true => HistoryAction::EnablePlugin,
+                                false => HistoryAction::DisablePlugin,
+                            },
+                            path.clone(),
+                            set_plugin_enabled(path, enabled),
+                        ),
+                        map_action_error,
+                    )
+                    .map(PluginMessage::EnabledChanged),
+                );
             }
+            // Results that arrive after the action stopped being tracked are ignored
             PluginMessage::Added(result) => {
-                if let Err(err) = result {
-                    error!("failed to add plugin: {err:?}");
-                    state.alter_plugin_state = AlterPluginState::Error(err);
-                } else {
-                    state.alter_plugin_state = AlterPluginState::Success;
-                    state.plugin = true;
+                if let Err(err) = state.plugin.finish_install(result) {
+                    debug!("ignoring plugin result: {err}");
+                }
+            }
+            PluginMessage::EnabledChanged(result) => {
+                if let Err(err) = state.plugin.finish_toggle(result) {
+                    debug!("ignoring plugin result: {err}");
                 }
             }
             PluginMessage::Removed(result) => {
-                if let Err(err) = result {
-                    error!("failed to remove plugin: {err:?}");
-                    state.alter_plugin_state = AlterPluginState::Error(err);
-                } else {
-                    state.alter_plugin_state = AlterPluginState::Success;
-                    state.plugin = false;
+                if let Err(err) = state.plugin.finish_remove(result) {
+                    debug!("ignoring plugin result: {err}");
+                }
+            }
+            PluginMessage::QuarantineChecked(path, present) => {
+                // Plugin is being changed again or the game was changed
+                if present || path != state.path || state.is_busy() {
+                    return Task::none();
+                }
+
+                if state.plugin.quarantined().is_err() {
+                    return Task::none();
+                }
+
+                error!("plugin was removed after install, likely quarantined by an antivirus");
+
+                // Install everything can't have succeeded without the plugin
+                if let InstallAllState::Success = state.install_all_state {
+                    state.install_all_state = InstallAllState::Initial;
+                }
+            }
+            PluginMessage::Progress(progress) => state.plugin.progress(progress),
+            PluginMessage::ToggleReleaseNotes => {
+                if let PluginDetailsState::Ready(plugin_details) = &mut self.plugin_details_state {
+                    plugin_details.show_release_notes = !plugin_details.show_release_notes;
                 }
             }
             PluginMessage::SelectType(release_type) => {
                 if let PluginDetailsState::Ready(plugin_details) = &mut self.plugin_details_state {
+                    self.config.release_channel = release_type.channel();
+                    plugin_details.release_notes = parse_release_notes(release_type.release());
                     plugin_details.selected = release_type;
+
+                    return save_config_task(self.config.clone());
+                }
+            }
+            PluginMessage::ChecksumChecked(status) => {
+                state.checksum_status = Some(status);
+            }
+            PluginMessage::SelectAsset(asset_name) => {
+                if let PluginDetailsState::Ready(plugin_details) = &mut self.plugin_details_state {
+                    plugin_details.selected_asset = Some(asset_name);
                 }
             }
         }
@@ -768,8 +7348,43 @@ impl App {
         Task::none()
     }
 
+    /// Adds the plugin when adding was queued while the plugin details were
+    /// loading, the queue is cleared when the details fail to load
+    fn take_queued_plugin_add(&mut self) -> Task<AppMessage> {
+        let state = match &mut self.state {
+            AppState::Active(state) if state.plugin_add_queued => state,
+            _ => return Task::none(),
+        };
+
+        match &self.plugin_details_state {
+            PluginDetailsState::Loading => Task::none(),
+            PluginDetailsState::Ready(_) => {
+                state.plugin_add_queued = false;
+                Task::done(AppMessage::Plugin(PluginMessage::Add))
+            }
+            PluginDetailsState::Error(_) => {
+                state.plugin_add_queued = false;
+                Task::none()
+            }
+        }
+    }
+
+    /// Starts loading the plugin details, replacing any load in progress
+    fn load_plugin_details(&mut self) -> Task<PluginDetailsMessage> {
+        self.plugin_details_state = PluginDetailsState::Loading;
+
+        let (task, handle) =
+            plugin_details_task(self.http_client.clone(), &self.config).abortable();
+        if let Some(previous) = self.plugin_details_handle.replace(handle) {
+            previous.abort();
+        }
+
+        task
+    }
+
     fn update_plugin_details(&mut self, msg: PluginDetailsMessage) -> Task<PluginDetailsMessage> {
         match msg {
+            PluginDetailsMessage::Retry => return self.load_plugin_details(),
             PluginDetailsMessage::Loaded(result) => {
                 self.plugin_details_state = match result {
                     Ok(value) => PluginDetailsState::Ready(value),
@@ -779,12 +7394,399 @@ impl App {
                     }
                 }
             }
+            PluginDetailsMessage::Refresh => {
+                // Details are already being loaded
+                if let PluginDetailsState::Loading = self.plugin_details_state {
+                    return Task::none();
+                }
+
+                return Task::perform(
+                    get_plugin_details(
+                        self.http_client.clone(),
+                        self.config.mirrors.clone(),
+                        self.config.api_options(),
+                        self.config.release_channel.clone(),
+                        self.config.release_feeds.clone(),
+                        self.config.plugin_source.clone(),
+                    ),
+                    map_error_string,
+                )
+                .map(PluginDetailsMessage::Refreshed);
+            }
+            PluginDetailsMessage::Refreshed(result) => match result {
+                Ok(value) => {
+                    // Keep the current selection unless there is a newer release
+                    let changed = match &self.plugin_details_state {
+                        PluginDetailsState::Ready(details) => {
+                            details.latest.tag_name != value.latest.tag_name
+                        }
+                        _ => true,
+                    };
+
+                    if changed {
+                        debug!("found plugin release {}", value.latest.tag_name);
+                        self.plugin_details_state = PluginDetailsState::Ready(value);
+                    }
+                }
+                // Background checks keep the existing details on failure
+                Err(err) => error!("failed to check for plugin update: {err:?}"),
+            },
         }
 
         Task::none()
     }
 }
 
+/// Creates a stream that applies the patch, producing progress messages
+/// for each step followed by the final result
+fn apply_patch_stream(path: PathBuf) -> impl iced::futures::Stream<Item = PatchMessage> {
+    iced::stream::channel(10, move |mut output| async move {
+        let mut progress_output = output.clone();
+        let result = record_change(
+            HistoryAction::ApplyPatch,
+            path.clone(),
+            apply_patch(game(), path, move |progress| {
+                // Progress updates are best effort, skipped if the UI is behind
+                _ = progress_output.try_send(PatchMessage::Progress(progress));
+            }),
+        )
+        .await;
+
+        _ = output
+            .send(PatchMessage::Added(map_action_error(result)))
+            .await;
+    })
+}
+
+/// Creates a stream that applies the plugin, producing progress messages
+/// for each step followed by the final result
+fn apply_plugin_stream(
+    http_client: reqwest::Client,
+    downloads: DownloadManager,
+    mirrors: Vec<String>,
+    path: PathBuf,
+    release: GitHubRelease,
+    asset_name: String,
+) -> impl iced::futures::Stream<Item = PluginMessage> {
+    iced::stream::channel(100, move |mut output| async move {
+        let mut progress_output = output.clone();
+        let version = release.tag_name.clone();
+        let result = record_change(
+            HistoryAction::InstallPlugin,
+            path.clone(),
+            apply_plugin(
+                http_client,
+                downloads,
+                mirrors,
+                path,
+                release,
+                asset_name,
+                move |progress| {
+                    // Progress updates are best effort, skipped if the UI is behind
+                    _ = progress_output.try_send(PluginMessage::Progress(progress));
+                },
+            ),
+        )
+        .await;
+
+        let result = match result {
+            Ok(status) => {
+                _ = output.send(PluginMessage::ChecksumChecked(status)).await;
+                Ok(Some(version))
+            }
+            Err(err) => Err(err),
+        };
+
+        _ = output
+            .send(PluginMessage::Added(map_action_error(result)))
+            .await;
+    })
+}
+
+/// Creates a stream that runs the network checks, producing the result of
+/// each check as it finishes followed by a finished message
+fn network_checks_stream(
+    http_client: reqwest::Client,
+) -> impl iced::futures::Stream<Item = LogsMessage> {
+    // Buffer fits the result of every check so none are dropped
+    iced::stream::channel(10, move |mut output| async move {
+        let mut result_output = output.clone();
+        run_network_checks(http_client, move |result| {
+            _ = result_output.try_send(LogsMessage::NetworkChecked(result));
+        })
+        .await;
+
+        _ = output.send(LogsMessage::NetworkChecksFinished).await;
+    })
+}
+
+/// Creates a stream that applies the patch and installs the plugin from
+/// the `release`, emitting progress messages followed by the result
+fn install_all_stream(
+    http_client: reqwest::Client,
+    downloads: DownloadManager,
+    mirrors: Vec<String>,
+    path: PathBuf,
+    release: GitHubRelease,
+    asset_name: String,
+) -> impl iced::futures::Stream<Item = InstallAllMessage> {
+    iced::stream::channel(100, move |mut output| async move {
+        let mut progress_output = output.clone();
+        let version = release.tag_name.clone();
+        let result = record_change(
+            HistoryAction::InstallAll,
+            path.clone(),
+            install_all(
+                http_client,
+                downloads,
+                mirrors,
+                path,
+                release,
+                asset_name,
+                move |step| {
+                    // Progress updates are best effort, skipped if the UI is behind
+                    _ = progress_output.try_send(InstallAllMessage::Progress(step));
+                },
+            ),
+        )
+        .await;
+
+        let result = match result {
+            Ok(status) => {
+                _ = output
+                    .send(InstallAllMessage::ChecksumChecked(status))
+                    .await;
+                Ok(Some(version))
+            }
+            Err(err) => Err(err),
+        };
+
+        _ = output
+            .send(InstallAllMessage::Finished(map_action_error(result)))
+            .await;
+    })
+}
+
+/// Creates a stream that applies the patch and installs the plugin from the
+/// `release` to each of the installations at `paths` one at a time, emitting
+/// when each installation starts followed by its result
+fn batch_install_stream(
+    http_client: reqwest::Client,
+    downloads: DownloadManager,
+    mirrors: Vec<String>,
+    paths: Vec<PathBuf>,
+    release: GitHubRelease,
+    asset_name: String,
+) -> impl iced::futures::Stream<Item = BatchMessage> {
+    iced::stream::channel(10, move |mut output| async move {
+        for (index, path) in paths.into_iter().enumerate() {
+            _ = output.send(BatchMessage::Started(index)).await;
+
+            let result = record_change(
+                HistoryAction::InstallAll,
+                path.clone(),
+                install_all(
+                    http_client.clone(),
+                    downloads.clone(),
+                    mirrors.clone(),
+                    path,
+                    release.clone(),
+                    asset_name.clone(),
+                    |_| {},
+                ),
+            )
+            .await;
+
+            _ = output
+                .send(BatchMessage::Finished(index, map_action_error(result)))
+                .await;
+        }
+    })
+}
+
+/// Maps a key press to its keyboard shortcut, none for keys without one
+fn shortcut_for_key(key: keyboard::Key, modifiers: keyboard::Modifiers) -> Option<Shortcut> {
+    match key.as_ref() {
+        keyboard::Key::Named(Named::Tab) if modifiers.shift() => Some(Shortcut::FocusPrevious),
+        keyboard::Key::Named(Named::Tab) => Some(Shortcut::FocusNext),
+        keyboard::Key::Named(Named::Escape) => Some(Shortcut::Back),
+        keyboard::Key::Character(value) if modifiers.alt() => {
+            match value.to_ascii_lowercase().as_str() {
+                "p" => Some(Shortcut::ApplyPatch),
+                "a" => Some(Shortcut::AddPlugin),
+                "b" => Some(Shortcut::Back),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Wraps the `button` with a tooltip naming its keyboard `shortcut`
+fn with_shortcut<'a>(button: Button<'a, AppMessage>, shortcut: &'a str) -> Tooltip<'a, AppMessage> {
+    tooltip(button, text(shortcut).size(12), tooltip::Position::Bottom)
+        .style(container::rounded_box)
+        .padding(5)
+}
+
+/// Checks whether the `latest` plugin release is newer than the `installed`
+/// plugin version, unknown installed versions can always be updated
+fn is_plugin_update_available(installed: Option<&str>, latest: &str) -> bool {
+    match installed {
+        Some(installed) => match (parse_tag_version(installed), parse_tag_version(latest)) {
+            (Some(installed), Some(latest)) => installed < latest,
+            _ => installed != latest,
+        },
+        None => true,
+    }
+}
+
+/// Message for updating the plugin to the latest release, none until the
+/// plugin details are loaded or when the latest release requires a newer
+/// installer
+fn plugin_update_message(plugin_details: &PluginDetailsState) -> Option<AppMessage> {
+    match plugin_details {
+        PluginDetailsState::Ready(details)
+            if required_installer_version(&details.latest).is_none() =>
+        {
+            Some(AppMessage::Plugin(PluginMessage::Update))
+        }
+        _ => None,
+    }
+}
+
+/// Describes a planned change for the changes preview
+fn describe_change(change: &PlannedChange) -> String {
+    let path = change.path.display();
+
+    match &change.kind {
+        ChangeKind::Create(size) => {
+            tr_args("{} will be created, {}", &[&path, &format_bytes(*size)])
+        }
+        ChangeKind::Overwrite {
+            size,
+            backup: Some(backup),
+        } => tr_args(
+            "{} will be overwritten (backed up to {}), {}",
+            &[&path, &backup.display(), &format_bytes(*size)],
+        ),
+        ChangeKind::Overwrite { size, backup: None } => {
+            tr_args("{} will be overwritten, {}", &[&path, &format_bytes(*size)])
+        }
+        ChangeKind::Restore(backup) => {
+            tr_args("{} will be restored from {}", &[&path, &backup.display()])
+        }
+        ChangeKind::Delete => tr_args("{} will be deleted", &[&path]),
+    }
+}
+
+/// Describes the current step of the `progress` (e.g "Downloading 43%")
+fn format_step(progress: &Progress) -> String {
+    match progress.step {
+        ProgressStep::Checking => tr("Checking...").to_string(),
+        ProgressStep::Downloading => match progress.percent() {
+            Some(percent) => tr_args("Downloading {}%", &[&percent]),
+            None => tr_args("Downloading... ({})", &[&format_bytes(progress.bytes)]),
+        },
+        ProgressStep::Verifying => tr("Verifying...").to_string(),
+        ProgressStep::Writing => tr("Writing files...").to_string(),
+    }
+}
+
+/// Formats the downloaded and total size of a download
+fn format_progress(progress: &DownloadProgress) -> String {
+    let downloaded = format_bytes(progress.downloaded);
+    match progress.total {
+        Some(total) => format!("{downloaded} / {}", format_bytes(total)),
+        None => downloaded,
+    }
+}
+
+/// Maps the result of an action that modifies the game files, keeping
+/// the game running error distinct from other errors
+fn map_action_error<V>(result: anyhow::Result<V>) -> Result<V, ActionError> {
+    result.map_err(|err| {
+        if err.downcast_ref::<GameRunningError>().is_some() {
+            ActionError::GameRunning
+        } else {
+            error!("action failed: {err:?}");
+            ActionError::Failed(describe_error(&err))
+        }
+    })
+}
+
 fn map_error_string<V>(result: anyhow::Result<V>) -> Result<V, String> {
-    result.map_err(|err| format!("{err:?}"))
+    result.map_err(|err| {
+        error!("task failed: {err:?}");
+        describe_error(&err)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::read_game_state_with;
+    use crate::{
+        bink::PatchState,
+        game_fs::{test_util::TempDir, TokioFs},
+        plugin::{PluginState, PLUGIN_DIR, PLUGIN_NAME, PLUGIN_VERSION_NAME},
+        profile::profile,
+    };
+
+    /// Creates a game folder containing an executable, the patched binkw32.dll
+    /// is used as the executable as it is a 32-bit PE file
+    fn create_game(binkw32: &[u8]) -> TempDir {
+        let game = TempDir::new();
+        let profile = profile();
+        std::fs::write(game.path().join(profile.exe_name), profile.patch.patched).unwrap();
+        std::fs::write(game.path().join("binkw32.dll"), binkw32).unwrap();
+        game
+    }
+
+    /// State of a patched game with the plugin installed is read
+    #[tokio::test]
+    async fn test_read_game_state() {
+        let game = create_game(profile().patch.patched);
+        let asi_path = game.path().join(PLUGIN_DIR);
+        std::fs::create_dir_all(&asi_path).unwrap();
+        std::fs::write(asi_path.join(PLUGIN_NAME), b"plugin").unwrap();
+        std::fs::write(asi_path.join(PLUGIN_VERSION_NAME), "v1.0.0\n").unwrap();
+
+        let state = read_game_state_with(&TokioFs, &game.path().join(profile().exe_name))
+            .await
+            .unwrap();
+
+        assert_eq!(state.path, game.path());
+        assert!(matches!(state.patch_state, PatchState::Patched));
+        assert_eq!(state.plugin, PluginState::Installed);
+        assert_eq!(state.plugin_version.as_deref(), Some("v1.0.0"));
+    }
+
+    /// State of an unmodified game is read
+    #[tokio::test]
+    async fn test_read_game_state_unpatched() {
+        let game = create_game(profile().patch.unpatched);
+
+        let state = read_game_state_with(&TokioFs, &game.path().join(profile().exe_name))
+            .await
+            .unwrap();
+
+        assert!(matches!(state.patch_state, PatchState::Unpatched));
+        assert_eq!(state.plugin, PluginState::Absent);
+        assert_eq!(state.plugin_version, None);
+    }
+
+    /// Executables that aren't the game and folders missing the game files
+    /// are rejected
+    #[tokio::test]
+    async fn test_read_game_state_invalid() {
+        let game = create_game(profile().patch.unpatched);
+        let exe_path = game.path().join(profile().exe_name);
+
+        std::fs::write(&exe_path, b"not an executable").unwrap();
+        assert!(read_game_state_with(&TokioFs, &exe_path).await.is_err());
+
+        std::fs::write(&exe_path, profile().patch.patched).unwrap();
+        std::fs::remove_file(game.path().join("binkw32.dll")).unwrap();
+        assert!(read_game_state_with(&TokioFs, &exe_path).await.is_err());
+    }
 }
@@ -0,0 +1,152 @@
+//! Module for backing up the plugin config and log files when uninstalling,
+//! the backup is restored when the plugin is installed again so the server
+//! settings aren't lost across reinstalls
+
+use crate::{
+    config::config_dir, fs_util::atomic_write, plugin::PLUGIN_DIR,
+    plugin_config::PLUGIN_CONFIG_NAME, plugin_log::PLUGIN_LOG_NAME,
+};
+use anyhow::Context;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::{
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+/// Name of the folder within the installer config folder storing backups
+const BACKUPS_FOLDER: &str = "plugin-backups";
+/// Name of the file describing a backup
+const MANIFEST_NAME: &str = "backup.json";
+/// Files from the plugin directory that are backed up
+const BACKUP_FILES: &[&str] = &[PLUGIN_CONFIG_NAME, PLUGIN_LOG_NAME];
+
+/// Description of a backup stored alongside the backed up files
+#[derive(Debug, Serialize, Deserialize)]
+struct BackupManifest {
+    /// Game folder the files were backed up from
+    game_path: PathBuf,
+    /// Names of the backed up files
+    files: Vec<String>,
+}
+
+/// Determines the path to the folder storing backups
+fn backups_dir() -> anyhow::Result<PathBuf> {
+    config_dir()
+        .map(|path| path.join(BACKUPS_FOLDER))
+        .context("missing config directory")
+}
+
+/// Copies the plugin config and log files from the game folder at
+/// `game_path` into a new timestamped backup folder.
+///
+/// Returns the path to the backup folder, none when there was nothing
+/// to back up
+pub async fn backup_plugin_files(game_path: &Path) -> anyhow::Result<Option<PathBuf>> {
+    let asi_path = game_path.join(PLUGIN_DIR);
+
+    let files: Vec<String> = BACKUP_FILES
+        .iter()
+        .filter(|name| asi_path.join(name).is_file())
+        .map(|name| name.to_string())
+        .collect();
+
+    if files.is_empty() {
+        return Ok(None);
+    }
+
+    // Timestamps are sortable, colons aren't allowed in Windows file names
+    let timestamp = humantime::format_rfc3339_seconds(SystemTime::now())
+        .to_string()
+        .replace(':', "-");
+    let backup_path = backups_dir()?.join(timestamp);
+
+    tokio::fs::create_dir_all(&backup_path)
+        .await
+        .context("failed to create backup folder")?;
+
+    for name in &files {
+        tokio::fs::copy(asi_path.join(name), backup_path.join(name))
+            .await
+            .with_context(|| format!("failed to back up {name}"))?;
+    }
+
+    let manifest = BackupManifest {
+        game_path: game_path.to_path_buf(),
+        files,
+    };
+    let contents = serde_json::to_vec_pretty(&manifest).context("failed to serialize backup")?;
+    atomic_write(backup_path.join(MANIFEST_NAME), contents).await?;
+
+    debug!("backed up plugin files to {}", backup_path.display());
+
+    Ok(Some(backup_path))
+}
+
+/// Restores the newest backup taken from the game folder at `game_path`,
+/// files that already exist in the plugin directory are kept. The backup
+/// is removed once restored.
+///
+/// Returns whether a backup was restored
+pub async fn restore_plugin_backup(game_path: PathBuf) -> anyhow::Result<bool> {
+    let (backup_path, manifest) = match find_latest_backup(&game_path).await? {
+        Some(value) => value,
+        None => return Ok(false),
+    };
+
+    let asi_path = game_path.join(PLUGIN_DIR);
+
+    for name in &manifest.files {
+        let path = asi_path.join(name);
+        if path.exists() {
+            continue;
+        }
+
+        tokio::fs::copy(backup_path.join(name), &path)
+            .await
+            .with_context(|| format!("failed to restore {name}"))?;
+    }
+
+    tokio::fs::remove_dir_all(&backup_path)
+        .await
+        .context("failed to remove restored backup")?;
+
+    debug!("restored plugin backup from {}", backup_path.display());
+
+    Ok(true)
+}
+
+/// Finds the newest backup taken from the game folder at `game_path`
+async fn find_latest_backup(game_path: &Path) -> anyhow::Result<Option<(PathBuf, BackupManifest)>> {
+    let mut entries = match tokio::fs::read_dir(backups_dir()?).await {
+        Ok(value) => value,
+        // No backups have been taken
+        Err(_) => return Ok(None),
+    };
+
+    let mut latest: Option<(PathBuf, BackupManifest)> = None;
+
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+
+        let manifest: BackupManifest = match tokio::fs::read(path.join(MANIFEST_NAME))
+            .await
+            .ok()
+            .and_then(|contents| serde_json::from_slice(&contents).ok())
+        {
+            Some(value) => value,
+            None => continue,
+        };
+
+        if manifest.game_path != game_path {
+            continue;
+        }
+
+        // Backup folder names are timestamps so the greatest is the newest
+        if latest.as_ref().is_none_or(|(latest, _)| path > *latest) {
+            latest = Some((path, manifest));
+        }
+    }
+
+    Ok(latest)
+}
@@ -0,0 +1,681 @@
+//! Module for the command line mode, allows mod managers and other tools
+//! to patch the game and install the plugin without the user interface.
+//!
+//! Results are printed to stdout as text or JSON (`--output json`) and each
+//! outcome has its own exit code so wrapper tooling can react to them.
+//!
+//! Release Windows builds don't open a console of their own, the output is
+//! written to the console of the terminal the installer was started from
+
+use crate::{
+    backup::restore_plugin_backup,
+    bink::{apply_patch, patch_state, verify_embedded_resources, verify_patch, PatchState},
+    config::{Config, ReleaseChannel, PORTABLE_ARG},
+    deep_link::is_link,
+    downloads::DownloadManager,
+    github::GitHubRelease,
+    history::{record_change, HistoryAction},
+    http::create_http_client,
+    install::install_all,
+    plugin::{
+        check_plugin_quarantine, get_plugin_feed_releases, get_plugin_releases,
+        read_plugin_version, verify_installed_plugin, ChecksumStatus,
+    },
+    process::GameRunningError,
+    profile::{game, profile},
+    self_test::{run_self_test, SelfTestResult, SelfTestStatus},
+    update::required_installer_version,
+    validate::validate_game_exe,
+};
+use anyhow::{bail, Context};
+use log::{error, warn};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// Usage information printed for `--help` and invalid arguments
+const USAGE: &str = "\
+Usage: pocket-relay-plugin-installer <COMMAND> [--game <PATH>] [--output <text|json>]
+       pocket-relay-plugin-installer [PATH]
+       pocket-relay-plugin-installer pocket-relay-installer://install?server=<URL>
+
+Opening the installer with only a PATH to the game folder, MassEffect3.exe
+or a shortcut to the game starts the installer with that game selected.
+Opening a pocket-relay-installer:// link fills in the server connection
+once the game is selected, links are registered from the settings
+
+Commands:
+  patch        Apply the patch to the game
+  install      Apply the patch and install the latest plugin release
+  verify       Verify the patch and plugin are installed correctly
+  --self-test  Patch, install and uninstall on a test game folder to check
+               the installer works on this system
+
+Options:
+  --game <PATH>     Game folder or MassEffect3.exe, defaults to the saved game
+  --output <FORMAT> Output format, either text (default) or json
+  --portable        Store the config, cache and logs beside the executable
+
+Exit codes:
+  0  Success
+  1  Operation failed
+  2  Invalid arguments
+  3  Nothing to do, already patched or installed
+  4  Verification or self test failed
+  5  The game is running
+
+On Windows the output is written to the terminal the installer was run
+from, the prompt may appear before the output so run the installer with
+`start /wait` in cmd or check $LASTEXITCODE in PowerShell";
+
+/// Attaches to the console of the process that started the installer so the
+/// output is visible, release Windows builds are GUI programs that don't
+/// have a console of their own
+#[cfg(target_os = "windows")]
+fn attach_parent_console() {
+    use windows_sys::Win32::System::Console::{AttachConsole, ATTACH_PARENT_PROCESS};
+
+    // Fails when already attached (debug builds) or when started without a
+    // console, in both cases there's nothing else to do
+    // SAFETY: Only changes which console the standard handles write to
+    _ = unsafe { AttachConsole(ATTACH_PARENT_PROCESS) };
+}
+
+/// Output already goes to the terminal on other platforms
+#[cfg(not(target_os = "windows"))]
+fn attach_parent_console() {}
+
+/// Commands by the argument that runs them, arguments matching these are
+/// never treated as a game path
+const COMMANDS: [(&str, Command); 4] = [
+    ("patch", Command::Patch),
+    ("install", Command::Install),
+    ("verify", Command::Verify),
+    ("--self-test", Command::SelfTest),
+];
+
+/// Commands available in the command line mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Command {
+    /// Apply the patch to the game
+    Patch,
+    /// Apply the patch and install the plugin
+    Install,
+    /// Verify the patch and plugin
+    Verify,
+    /// Run the self test against a synthetic game folder
+    SelfTest,
+}
+
+impl Command {
+    /// Finds the command run by the `arg`
+    fn from_arg(arg: &str) -> Option<Command> {
+        COMMANDS
+            .iter()
+            .find(|(name, _)| *name == arg)
+            .map(|(_, command)| *command)
+    }
+}
+
+/// Format the result of a command is printed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    /// Human readable message
+    Text,
+    /// Machine readable JSON object
+    Json,
+}
+
+/// Parsed command line arguments
+struct CliArgs {
+    /// Command to run
+    command: Command,
+    /// Game folder provided with `--game`
+    game_path: Option<PathBuf>,
+}
+
+/// Outcome of running a command
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Outcome {
+    /// Patch was applied to the game
+    PatchApplied,
+    /// Game was already patched, nothing was changed
+    AlreadyPatched,
+    /// Plugin was installed
+    PluginInstalled,
+    /// Latest plugin was already installed, nothing was changed
+    AlreadyInstalled,
+    /// Patch and plugin passed verification
+    Verified,
+    /// Patch or plugin failed verification
+    VerificationFailed,
+    /// Every step of the self test passed
+    SelfTestPassed,
+    /// A step of the self test failed
+    SelfTestFailed,
+    /// Game files can't be changed while the game is running
+    GameRunning,
+    /// Operation failed
+    Failed,
+    /// Arguments were invalid
+    InvalidArguments,
+}
+
+impl Outcome {
+    /// Exit code for the outcome
+    fn exit_code(self) -> i32 {
+        match self {
+            Outcome::PatchApplied
+            | Outcome::PluginInstalled
+            | Outcome::Verified
+            | Outcome::SelfTestPassed => 0,
+            Outcome::Failed => 1,
+            Outcome::InvalidArguments => 2,
+            Outcome::AlreadyPatched | Outcome::AlreadyInstalled => 3,
+            Outcome::VerificationFailed | Outcome::SelfTestFailed => 4,
+            Outcome::GameRunning => 5,
+        }
+    }
+}
+
+/// Result of a command, printed once the command finishes
+#[derive(Debug, Serialize)]
+struct CommandResult {
+    /// Command that was run, none if the arguments were invalid
+    command: Option<Command>,
+    /// Outcome of the command
+    outcome: Outcome,
+    /// Exit code the installer exits with
+    exit_code: i32,
+    /// Description of the outcome
+    message: String,
+    /// Game folder the command was run against
+    game_path: Option<PathBuf>,
+    /// Installed plugin version when known
+    plugin_version: Option<String>,
+    /// Result of each step when running the self test
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    steps: Vec<SelfTestResult>,
+}
+
+impl CommandResult {
+    fn new(command: Option<Command>, outcome: Outcome, message: impl Into<String>) -> Self {
+        Self {
+            command,
+            outcome,
+            exit_code: outcome.exit_code(),
+            message: message.into(),
+            game_path: None,
+            plugin_version: None,
+            steps: Vec::new(),
+        }
+    }
+}
+
+/// Runs the command line mode when any arguments were provided.
+///
+/// Returns the exit code to exit with, none when there were no arguments
+/// or only a game path or link and the user interface should be started instead
+pub fn run(config: &Config) -> Option<i32> {
+    let args = cli_args();
+    if args.is_empty() || parse_launch_path(&args).is_some() || parse_launch_link(&args).is_some() {
+        return None;
+    }
+
+    attach_parent_console();
+
+    // Output format is parsed first so argument errors use the same format
+    let (output, args) = match parse_output_format(args) {
+        Ok(value) => value,
+        Err(err) => {
+            let result = CommandResult::new(None, Outcome::InvalidArguments, format!("{err:#}"));
+            return Some(print_result(OutputFormat::Text, &result));
+        }
+    };
+
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        println!("{USAGE}");
+        return Some(0);
+    }
+
+    let result = match parse_args(args) {
+        Ok(args) => run_command(config, args),
+        Err(err) => {
+            eprintln!("{USAGE}\n");
+            CommandResult::new(None, Outcome::InvalidArguments, format!("{err:#}"))
+        }
+    };
+
+    Some(print_result(output, &result))
+}
+
+/// Arguments the installer was started with
+fn cli_args() -> Vec<String> {
+    // Portable mode is handled when loading the config
+    std::env::args()
+        .skip(1)
+        .filter(|arg| arg != PORTABLE_ARG)
+        .collect()
+}
+
+/// Game path the user interface should be opened with, provided when the
+/// installer is opened from the game executable (e.g "Open with") or from
+/// a shortcut created by a mod manager
+pub fn launch_path() -> Option<PathBuf> {
+    parse_launch_path(&cli_args())
+}
+
+/// Game path or link the installer was opened with, to pass on when
+/// starting another copy of the installer. Game paths are made absolute as
+/// the other copy may start in a different folder
+pub fn launch_arg() -> Option<String> {
+    let args = cli_args();
+    match parse_launch_path(&args) {
+        Some(path) => {
+            let path = std::path::absolute(&path).unwrap_or(path);
+            Some(path.to_string_lossy().into_owned())
+        }
+        None => parse_launch_link(&args),
+    }
+}
+
+/// Link the user interface should be opened with, provided when the
+/// installer is opened from a pocket-relay-installer:// link
+pub fn launch_link() -> Option<String> {
+    parse_launch_link(&cli_args())
+}
+
+/// Parses the link from the `args`, only a single argument that is a link
+fn parse_launch_link(args: &[String]) -> Option<String> {
+    match args {
+        [arg] if is_link(arg) => Some(arg.clone()),
+        _ => None,
+    }
+}
+
+/// Parses the game path from the `args`, only a single argument that
+/// isn't a command or option and points to an existing file or folder
+fn parse_launch_path(args: &[String]) -> Option<PathBuf> {
+    match args {
+        [arg] if !arg.starts_with('-') && Command::from_arg(arg).is_none() => {
+            Some(PathBuf::from(arg)).filter(|path| path.exists())
+        }
+        _ => None,
+    }
+}
+
+/// Takes the `--output` option out of the `args`, returning the output
+/// format along with the remaining arguments
+fn parse_output_format(args: Vec<String>) -> anyhow::Result<(OutputFormat, Vec<String>)> {
+    let mut output = OutputFormat::Text;
+    let mut remaining = Vec::with_capacity(args.len());
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg != "--output" {
+            remaining.push(arg);
+            continue;
+        }
+
+        output = match args.next().as_deref() {
+            Some("text") => OutputFormat::Text,
+            Some("json") => OutputFormat::Json,
+            Some(value) => bail!("unknown output format: {value}"),
+            None => bail!("missing value for --output"),
+        };
+    }
+
+    Ok((output, remaining))
+}
+
+/// Parses the command and its options from the `args`
+fn parse_args(args: Vec<String>) -> anyhow::Result<CliArgs> {
+    let mut command = None;
+    let mut game_path = None;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--game" => {
+                let value = args.next().context("missing value for --game")?;
+                game_path = Some(PathBuf::from(value));
+            }
+            _ => match Command::from_arg(&arg) {
+                Some(_) if command.is_some() => bail!("only one command can be run at a time"),
+                Some(value) => command = Some(value),
+                None => bail!("unknown argument: {arg}"),
+            },
+        }
+    }
+
+    Ok(CliArgs {
+        command: command.context("missing command")?,
+        game_path,
+    })
+}
+
+/// Prints the `result` in the `output` format, returns the exit code
+fn print_result(output: OutputFormat, result: &CommandResult) -> i32 {
+    match output {
+        OutputFormat::Text if result.exit_code == 0 => println!("{}", result.message),
+        // Failures are printed to stderr so they are seen when only stdout is captured
+        OutputFormat::Text => eprintln!("{}", result.message),
+        OutputFormat::Json => match serde_json::to_string(result) {
+            Ok(value) => println!("{value}"),
+            Err(err) => error!("failed to serialize command result: {err}"),
+        },
+    }
+
+    result.exit_code
+}
+
+/// Runs the command from the `args` to completion
+fn run_command(config: &Config, args: CliArgs) -> CommandResult {
+    let command = args.command;
+
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(value) => value,
+        Err(err) => {
+            return CommandResult::new(
+                Some(command),
+                Outcome::Failed,
+                format!("failed to start async runtime: {err}"),
+            );
+        }
+    };
+
+    runtime.block_on(async move {
+        // Self test uses its own game folder
+        if command == Command::SelfTest {
+            return run_self_test_command().await;
+        }
+
+        let game_path = match resolve_game_path(config, args.game_path).await {
+            Ok(value) => value,
+            Err(err) => {
+                return CommandResult::new(
+                    Some(command),
+                    Outcome::InvalidArguments,
+                    format!("{err:#}"),
+                );
+            }
+        };
+
+        let result = match command {
+            Command::Patch => run_patch(&game_path).await,
+            Command::Install => run_install(config, &game_path).await,
+            Command::Verify => run_verify(&game_path).await,
+            Command::SelfTest => unreachable!("self test is run without a game folder"),
+        };
+
+        let mut result = match result {
+            Ok((outcome, message)) => CommandResult::new(Some(command), outcome, message),
+            Err(err) => {
+                error!("command failed: {err:?}");
+
+                let outcome = match err.downcast_ref::<GameRunningError>() {
+                    Some(_) => Outcome::GameRunning,
+                    None => Outcome::Failed,
+                };
+
+                CommandResult::new(Some(command), outcome, format!("{err:#}"))
+            }
+        };
+
+        result.plugin_version = read_plugin_version(&game_path).await;
+        result.game_path = Some(game_path);
+        result
+    })
+}
+
+/// Determines the game folder from the `--game` option, falling back to the
+/// game saved in the `config`. Accepts either the folder or the executable
+async fn resolve_game_path(config: &Config, game_path: Option<PathBuf>) -> anyhow::Result<PathBuf> {
+    let game_path = game_path
+        .or_else(|| config.game_path.clone())
+        .context("missing game path, provide one with --game")?;
+
+    let (game_path, exe_path) = if game_path.is_file() {
+        let parent = game_path.parent().context("missing game folder")?;
+        (parent.to_path_buf(), game_path)
+    } else {
+        let exe_path = game_path.join(profile().exe_name);
+        (game_path, exe_path)
+    };
+
+    if !exe_path.is_file() {
+        bail!("{} is missing {}", game_path.display(), profile().exe_name);
+    }
+
+    if let Some(warning) = validate_game_exe(&exe_path).await? {
+        warn!("{warning}");
+    }
+
+    Ok(game_path)
+}
+
+/// Runs the self test within a temporary folder, the message lists the
+/// result of each step
+async fn run_self_test_command() -> CommandResult {
+    let dir = std::env::temp_dir().join(format!(
+        "pocket-relay-installer-self-test-{}",
+        std::process::id()
+    ));
+    _ = tokio::fs::remove_dir_all(&dir).await;
+
+    let steps = run_self_test(game(), &dir).await;
+
+    if let Err(err) = tokio::fs::remove_dir_all(&dir).await {
+        warn!("failed to remove self test folder: {err}");
+    }
+
+    let mut lines: Vec<String> = steps
+        .iter()
+        .map(|result| {
+            let status = match result.status {
+                SelfTestStatus::Passed => "PASS",
+                SelfTestStatus::Failed => "FAIL",
+                SelfTestStatus::Skipped => "SKIP",
+            };
+
+            match &result.error {
+                Some(err) => format!("{status} {}: {err}", result.step.description()),
+                None => format!("{status} {}", result.step.description()),
+            }
+        })
+        .collect();
+
+    let passed = steps
+        .iter()
+        .all(|result| result.status == SelfTestStatus::Passed);
+    let outcome = if passed {
+        lines.push("self test passed".to_string());
+        Outcome::SelfTestPassed
+    } else {
+        lines.push("self test failed".to_string());
+        Outcome::SelfTestFailed
+    };
+
+    let mut result = CommandResult::new(Some(Command::SelfTest), outcome, lines.join("\n"));
+    result.steps = steps;
+    result
+}
+
+/// Applies the patch unless the game is already patched
+async fn run_patch(game_path: &Path) -> anyhow::Result<(Outcome, String)> {
+    if let Ok(PatchState::Patched) = patch_state(game(), game_path).await {
+        return Ok((
+            Outcome::AlreadyPatched,
+            "game is already patched".to_string(),
+        ));
+    }
+
+    record_change(
+        HistoryAction::ApplyPatch,
+        game_path.to_path_buf(),
+        apply_patch(game(), game_path.to_path_buf(), |_| {}),
+    )
+    .await
+    .context("failed to apply patch")?;
+
+    Ok((Outcome::PatchApplied, "patch applied".to_string()))
+}
+
+/// Applies the patch and installs the latest plugin release unless both are
+/// already installed
+async fn run_install(config: &Config, game_path: &Path) -> anyhow::Result<(Outcome, String)> {
+    verify_embedded_resources(game()).context("installer files are damaged")?;
+
+    let http_client = create_http_client(&config.proxy)?;
+    let mut releases = match config.channel_feed() {
+        Some(feed) => {
+            get_plugin_feed_releases(
+                http_client.clone(),
+                feed.clone(),
+                config.plugin_source.clone(),
+            )
+            .await?
+        }
+        None => {
+            get_plugin_releases(
+                http_client.clone(),
+                config.mirrors.clone(),
+                config.api_options(),
+                config.plugin_source.clone(),
+            )
+            .await?
+            .releases
+        }
+    };
+
+    // Releases requiring a newer installer can't be installed by this one
+    releases.retain(|release| required_installer_version(release).is_none());
+
+    let release = select_release(releases, &config.release_channel)?;
+    let asset_name = config
+        .plugin_source
+        .select_asset(&release, None)
+        .context("missing plugin asset file")?
+        .name
+        .clone();
+
+    let patched = matches!(
+        patch_state(game(), game_path).await,
+        Ok(PatchState::Patched)
+    );
+    let installed = read_plugin_version(game_path).await;
+    if patched && installed.as_deref() == Some(release.tag_name.as_str()) {
+        return Ok((
+            Outcome::AlreadyInstalled,
+            format!("plugin {} is already installed", release.tag_name),
+        ));
+    }
+
+    let downloads = DownloadManager::default();
+    downloads.set_speed_limit(config.download_limit);
+    downloads.set_download_dir(config.download_dir());
+    downloads.set_cache_dir(config.asset_cache_dir());
+
+    let tag_name = release.tag_name.clone();
+
+    let checksum_status = record_change(
+        HistoryAction::InstallAll,
+        game_path.to_path_buf(),
+        install_all(
+            http_client,
+            downloads,
+            config.mirrors.clone(),
+            game_path.to_path_buf(),
+            release,
+            asset_name,
+            |_| {},
+        ),
+    )
+    .await?;
+
+    if let Err(err) = restore_plugin_backup(game_path.to_path_buf()).await {
+        error!("failed to restore plugin backup: {err:#}");
+    }
+
+    if !check_plugin_quarantine(game_path.to_path_buf()).await {
+        return Ok((
+            Outcome::VerificationFailed,
+            "plugin was removed after it was installed, it was likely quarantined by an \
+            antivirus"
+                .to_string(),
+        ));
+    }
+
+    let message = match checksum_status {
+        ChecksumStatus::Verified => format!("plugin {tag_name} installed, checksum verified"),
+        ChecksumStatus::Unavailable => format!("plugin {tag_name} installed"),
+    };
+
+    Ok((Outcome::PluginInstalled, message))
+}
+
+/// Verifies the patch and plugin installed in the game
+async fn run_verify(game_path: &Path) -> anyhow::Result<(Outcome, String)> {
+    let result = match verify_patch(game(), game_path).await {
+        Ok(()) => verify_installed_plugin(game_path).await,
+        Err(err) => Err(err),
+    };
+
+    Ok(match result {
+        Ok(()) => (Outcome::Verified, "patch and plugin verified".to_string()),
+        Err(err) => (Outcome::VerificationFailed, format!("{err:#}")),
+    })
+}
+
+/// Selects the newest release from the preferred `channel`, falling back to
+/// the newest release. The `releases` are sorted from newest to oldest
+fn select_release(
+    releases: Vec<GitHubRelease>,
+    channel: &ReleaseChannel,
+) -> anyhow::Result<GitHubRelease> {
+    let position = match channel {
+        // Every release from a feed belongs to its channel
+        ReleaseChannel::Feed(_) => 0,
+        _ => {
+            let beta = *channel == ReleaseChannel::Beta;
+            releases
+                .iter()
+                .position(|release| release.prerelease == beta)
+                .unwrap_or_default()
+        }
+    };
+
+    releases
+        .into_iter()
+        .nth(position)
+        .context("no release versions found")
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_launch_path;
+
+    /// Only a single existing path that isn't a command or option opens
+    /// the user interface with that game
+    #[test]
+    fn test_parse_launch_path() {
+        let dir = std::env::temp_dir();
+        let arg = |value: &str| vec![value.to_string()];
+
+        assert_eq!(
+            parse_launch_path(&arg(&dir.display().to_string())),
+            Some(dir.clone())
+        );
+        assert_eq!(parse_launch_path(&arg("install")), None);
+        assert_eq!(parse_launch_path(&arg("--self-test")), None);
+        assert_eq!(
+            parse_launch_path(&arg(&dir.join("missing-game").display().to_string())),
+            None
+        );
+        assert_eq!(
+            parse_launch_path(&[dir.display().to_string(), "--portable".to_string()]),
+            None
+        );
+    }
+}
@@ -0,0 +1,256 @@
+//! Module for scanning the game folder for existing mods and loaders that
+//! are known to conflict with the patch or plugin, most install problems
+//! come from a setup that was already modded
+
+use crate::{
+    asi::{list_asi_plugins, ASI_EXTENSION},
+    bink::{patch_state, PatchState},
+    fs_util::resolve_file_name,
+    i18n::{tr, tr_args},
+    plugin::PLUGIN_NAME,
+    profile::game,
+};
+use anyhow::Context;
+use log::debug;
+use std::path::{Path, PathBuf};
+
+/// DLL names used by wrapper loaders (e.g Ultimate ASI Loader or ReShade),
+/// the game loads these from its folder instead of the system copies
+pub const WRAPPER_DLLS: &[&str] = &[
+    "d3d9.dll",
+    "dinput8.dll",
+    "dsound.dll",
+    "version.dll",
+    "winmm.dll",
+];
+
+/// Folders that other ASI loaders load plugins from
+const OTHER_ASI_DIRS: &[&str] = &["scripts", "plugins"];
+
+/// Prefix of DLC folders installed by mods
+const DLC_MOD_PREFIX: &str = "DLC_MOD_";
+
+/// Prefix of the coalesced files used by the Legendary Edition, the
+/// original game only has a single "Coalesced.bin"
+const LE_COALESCED_PREFIX: &str = "coalesced_";
+
+/// How serious a compatibility finding is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// Unlikely to cause problems, reported in case it does
+    Info,
+    /// May prevent the plugin from working
+    Warning,
+    /// Known to prevent the plugin or game from working
+    Conflict,
+}
+
+/// Compatibility problem found within the game folder
+#[derive(Debug, Clone)]
+pub struct Finding {
+    /// How serious the problem is
+    pub severity: Severity,
+    /// Description of what was found
+    pub message: String,
+    /// Suggested action to resolve the problem
+    pub suggestion: String,
+}
+
+impl Finding {
+    fn new(severity: Severity, message: String, suggestion: &str) -> Self {
+        Self {
+            severity,
+            message,
+            suggestion: suggestion.to_string(),
+        }
+    }
+}
+
+/// Scans the game folder at `game_path` for known conflicting setups,
+/// findings are sorted from most to least serious
+pub async fn scan_compatibility(game_path: PathBuf) -> anyhow::Result<Vec<Finding>> {
+    let mut findings = Vec::new();
+
+    scan_binkw32(&game_path, &mut findings).await;
+    scan_wrapper_dlls(&game_path, &mut findings).await;
+    scan_asi_plugins(&game_path, &mut findings).await?;
+
+    // Game root is two folders above "Binaries/Win32"
+    if let Some(root_path) = game_path.parent().and_then(Path::parent) {
+        let biogame_path = resolve_file_name(root_path, "BIOGame").await;
+        scan_dlc_mods(&biogame_path, &mut findings).await;
+        scan_coalesced(&biogame_path, &mut findings).await;
+    }
+
+    findings.sort_by_key(|finding| std::cmp::Reverse(finding.severity as u8));
+
+    debug!("compatibility scan found {} problems", findings.len());
+
+    Ok(findings)
+}
+
+/// Checks for a binkw32.dll replaced by another mod
+async fn scan_binkw32(game_path: &Path, findings: &mut Vec<Finding>) {
+    // Missing binkw32.dll is reported by the patch section
+    if !matches!(
+        patch_state(game(), game_path).await,
+        Ok(PatchState::UnknownDll(_))
+    ) {
+        return;
+    }
+
+    // Loaders that proxy binkw32.dll move the original to binkw23.dll
+    let binkw23_path = resolve_file_name(game_path, "binkw23.dll").await;
+
+    let finding = if binkw23_path.is_file() {
+        Finding::new(
+            Severity::Conflict,
+            tr(
+                "binkw32.dll has been replaced by another ASI loader, such as the \
+                ME3Tweaks binkw32 bypass",
+            )
+            .to_string(),
+            tr("Apply the patch to replace it, plugins in the ASI folder will still be loaded"),
+        )
+    } else {
+        Finding::new(
+            Severity::Warning,
+            tr("binkw32.dll is not a known version, it may be modified or corrupted").to_string(),
+            tr("Apply the patch to replace it, or verify the game files through your launcher"),
+        )
+    };
+
+    findings.push(finding);
+}
+
+/// Checks for wrapper DLLs that load plugins or hook into the game
+async fn scan_wrapper_dlls(game_path: &Path, findings: &mut Vec<Finding>) {
+    for name in WRAPPER_DLLS {
+        let path = resolve_file_name(game_path, name).await;
+        if !path.is_file() {
+            continue;
+        }
+
+        findings.push(Finding::new(
+            Severity::Warning,
+            tr_args(
+                "{} is a wrapper loader (such as Ultimate ASI Loader or ReShade), it can \
+                load plugins twice or stop the client plugin from loading",
+                &[name],
+            ),
+            tr("Remove or rename the file if the client plugin doesn't load"),
+        ));
+    }
+
+    for dir in OTHER_ASI_DIRS {
+        let path = resolve_file_name(game_path, dir).await;
+        if has_asi_files(&path).await {
+            findings.push(Finding::new(
+                Severity::Warning,
+                tr_args(
+                    "The {} folder contains ASI plugins for another ASI loader",
+                    &[dir],
+                ),
+                tr("Move the plugins into the ASI folder so they are only loaded once"),
+            ));
+        }
+    }
+}
+
+/// Checks for other ASI plugins loaded alongside the client plugin
+async fn scan_asi_plugins(game_path: &Path, findings: &mut Vec<Finding>) -> anyhow::Result<()> {
+    let plugins = list_asi_plugins(game_path.to_path_buf())
+        .await
+        .context("failed to list ASI plugins")?;
+
+    findings.extend(
+        plugins
+            .into_iter()
+            .filter(|plugin| plugin.enabled && !plugin.name.eq_ignore_ascii_case(PLUGIN_NAME))
+            .map(|plugin| {
+                Finding::new(
+                    Severity::Info,
+                    tr_args(
+                        "ASI plugin {} is loaded with the client plugin",
+                        &[&plugin.name],
+                    ),
+                    tr("Disable it from the ASI plugins list if the game crashes or can't connect"),
+                )
+            }),
+    );
+
+    Ok(())
+}
+
+/// Checks for DLC mods installed into the game
+async fn scan_dlc_mods(biogame_path: &Path, findings: &mut Vec<Finding>) {
+    let dlc_path = resolve_file_name(biogame_path, "DLC").await;
+    let mods: Vec<String> = read_dir_names(&dlc_path)
+        .await
+        .into_iter()
+        .filter(|name| {
+            name.get(..DLC_MOD_PREFIX.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(DLC_MOD_PREFIX))
+        })
+        .collect();
+
+    if mods.is_empty() {
+        return;
+    }
+
+    findings.push(Finding::new(
+        Severity::Info,
+        tr_args("DLC mods are installed: {}", &[&mods.join(", ")]),
+        tr(
+            "Players in the same lobby need the same multiplayer mods, disable mods that \
+            change multiplayer if you have trouble joining games",
+        ),
+    ));
+}
+
+/// Checks for Legendary Edition coalesced files copied into the game
+async fn scan_coalesced(biogame_path: &Path, findings: &mut Vec<Finding>) {
+    let cooked_path = resolve_file_name(biogame_path, "CookedPCConsole").await;
+
+    for name in read_dir_names(&cooked_path).await {
+        let lower_name = name.to_lowercase();
+        if !lower_name.starts_with(LE_COALESCED_PREFIX) || !lower_name.ends_with(".bin") {
+            continue;
+        }
+
+        findings.push(Finding::new(
+            Severity::Conflict,
+            tr_args(
+                "{} is a Legendary Edition file, Legendary Edition mods don't work with \
+                the original Mass Effect 3",
+                &[&name],
+            ),
+            tr("Remove the mod or repair the game files through your launcher"),
+        ));
+    }
+}
+
+/// Checks whether the folder at `path` contains any ASI plugins
+async fn has_asi_files(path: &Path) -> bool {
+    read_dir_names(path)
+        .await
+        .iter()
+        .any(|name| name.to_lowercase().ends_with(ASI_EXTENSION))
+}
+
+/// Reads the names of the entries within the folder at `path`, missing
+/// or unreadable folders have no entries
+async fn read_dir_names(path: &Path) -> Vec<String> {
+    let mut names = Vec::new();
+
+    let mut entries = match tokio::fs::read_dir(path).await {
+        Ok(value) => value,
+        Err(_) => return names,
+    };
+
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        names.push(entry.file_name().to_string_lossy().to_string());
+    }
+
+    names
+}
@@ -0,0 +1,304 @@
+//! Module for persisting the installer settings between runs
+
+use crate::{
+    asset_cache::asset_cache_dir, feed::ReleaseFeed, fs_util::atomic_write, github::ApiOptions,
+    i18n::Language, plugin::PluginSource, profile::Game, theme::ThemeMode,
+};
+use anyhow::Context;
+use log::{debug, error};
+use serde::{Deserialize, Serialize};
+use std::{
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+/// Name of the folder within the platform config and cache directories
+pub const CONFIG_FOLDER: &str = "pocket-relay-plugin-installer";
+/// Name of the config file
+const CONFIG_FILE: &str = "config.json";
+/// Name of the folder within a custom cache folder storing downloads
+const DOWNLOADS_FOLDER: &str = "downloads";
+/// Name of the folder within the portable folder storing downloads and
+/// cached data
+const PORTABLE_CACHE_FOLDER: &str = "cache";
+
+/// Argument enabling portable mode
+pub const PORTABLE_ARG: &str = "--portable";
+/// Name of the file beside the executable enabling portable mode
+const PORTABLE_MARKER: &str = "portable.txt";
+
+/// Default number of times to retry transient GitHub API failures
+const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Default number of pages of releases to request, 100 releases each
+const DEFAULT_MAX_RELEASE_PAGES: u32 = 10;
+
+/// Smallest and largest scale factors for the user interface
+const MIN_UI_SCALE: f32 = 0.75;
+const MAX_UI_SCALE: f32 = 2.0;
+
+/// Persisted installer settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Last used game folder
+    pub game_path: Option<PathBuf>,
+    /// Known game folders that can be switched between
+    pub installations: Vec<PathBuf>,
+    /// Game folders the patch was applied to, used to notice when the store
+    /// restores the original binkw32.dll (e.g when repairing the game)
+    pub patched_installations: Vec<PathBuf>,
+    /// Whether to offer applying the patch again when the store restores
+    /// the original binkw32.dll
+    pub detect_patch_reverts: bool,
+    /// Whether closing the window hides it to the system tray, the tray
+    /// icon offers quick actions while the window is hidden
+    pub tray_mode: bool,
+    /// Preferred plugin release channel
+    pub release_channel: ReleaseChannel,
+    /// Feeds providing additional release channels
+    pub release_feeds: Vec<ReleaseFeed>,
+    /// Proxy to use for HTTP requests
+    pub proxy: ProxyConfig,
+    /// Mirrors to fallback to when GitHub is unreachable, either URL prefixes
+    /// or URLs containing a "{url}" placeholder for the original URL
+    pub mirrors: Vec<String>,
+    /// Number of times to retry transient GitHub API failures
+    pub max_retries: u32,
+    /// Maximum number of pages of releases to request, older releases
+    /// beyond the limit aren't listed
+    pub max_release_pages: u32,
+    /// GitHub personal access token for authenticating API requests
+    pub github_token: Option<String>,
+    /// Whether to preview the file changes before applying them
+    pub preview_changes: bool,
+    /// Language to display the user interface in
+    pub language: Language,
+    /// Game to install to
+    pub game: Game,
+    /// Light or dark appearance of the user interface
+    pub theme: ThemeMode,
+    /// Custom accent color in hex form (e.g "#5865f2")
+    pub accent_color: Option<String>,
+    /// Repository and asset to obtain plugin releases from
+    pub plugin_source: PluginSource,
+    /// Maximum download speed in KiB/s, none for unlimited
+    pub download_limit: Option<u32>,
+    /// Folder to store downloads and cached data in, none to use the
+    /// system temp and cache folders
+    pub cache_dir: Option<PathBuf>,
+    /// Whether to keep downloaded plugins so reinstalling a version doesn't
+    /// download it again
+    pub cache_downloads: bool,
+    /// Scale factor for the user interface on top of the display scale
+    /// (e.g 1.5 for 150%)
+    pub ui_scale: f32,
+    /// Whether to send anonymous usage statistics, off unless the user opts in
+    pub usage_stats: bool,
+    /// URL to send the anonymous usage statistics to
+    pub usage_stats_endpoint: Option<String>,
+}
+
+impl Config {
+    /// Options for making GitHub API requests using this config
+    pub fn api_options(&self) -> ApiOptions {
+        ApiOptions {
+            max_retries: self.max_retries,
+            token: self.github_token.clone(),
+            cache_dir: self.data_cache_dir(),
+            max_release_pages: self.max_release_pages as usize,
+        }
+    }
+
+    /// Release feed providing the preferred release channel, none for the
+    /// channels from GitHub releases or when the feed was removed
+    pub fn channel_feed(&self) -> Option<&ReleaseFeed> {
+        match &self.release_channel {
+            ReleaseChannel::Feed(name) => self.release_feeds.iter().find(|feed| feed.name == *name),
+            _ => None,
+        }
+    }
+
+    /// Folder to store downloads and cached data in, the portable folder is
+    /// used when no folder is set in portable mode. None to use the system
+    /// temp and cache folders
+    pub fn effective_cache_dir(&self) -> Option<PathBuf> {
+        self.cache_dir
+            .clone()
+            .or_else(|| portable_dir().map(|path| path.join(PORTABLE_CACHE_FOLDER)))
+    }
+
+    /// Folder to keep cached data (releases, the guide and downloaded plugins)
+    /// in, the system cache folder when no other folder is used
+    pub fn data_cache_dir(&self) -> Option<PathBuf> {
+        self.effective_cache_dir()
+            .or_else(|| dirs::cache_dir().map(|path| path.join(CONFIG_FOLDER)))
+    }
+
+    /// Folder to stage downloads in, none to use the system temp folder
+    pub fn download_dir(&self) -> Option<PathBuf> {
+        self.effective_cache_dir()
+            .map(|path| path.join(DOWNLOADS_FOLDER))
+    }
+
+    /// Folder to keep downloaded plugins in, none when reusing downloads
+    /// is disabled
+    pub fn asset_cache_dir(&self) -> Option<PathBuf> {
+        self.data_cache_dir()
+            .map(|path| asset_cache_dir(&path))
+            .filter(|_| self.cache_downloads)
+    }
+
+    /// Endpoint to send anonymous usage statistics to, none unless the user
+    /// opted in and provided an endpoint
+    pub fn usage_stats_endpoint(&self) -> Option<&str> {
+        self.usage_stats_endpoint
+            .as_deref()
+            .filter(|_| self.usage_stats)
+    }
+
+    /// Scale factor for the user interface, limited to the supported range
+    pub fn ui_scale(&self) -> f32 {
+        match self.ui_scale.is_finite() {
+            true => self.ui_scale.clamp(MIN_UI_SCALE, MAX_UI_SCALE),
+            false => 1.0,
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            game_path: None,
+            installations: Vec::new(),
+            patched_installations: Vec::new(),
+            detect_patch_reverts: true,
+            tray_mode: false,
+            release_channel: ReleaseChannel::default(),
+            release_feeds: Vec::new(),
+            proxy: ProxyConfig::default(),
+            mirrors: Vec::new(),
+            max_retries: DEFAULT_MAX_RETRIES,
+            max_release_pages: DEFAULT_MAX_RELEASE_PAGES,
+            github_token: None,
+            preview_changes: false,
+            language: Language::default(),
+            game: Game::default(),
+            theme: ThemeMode::default(),
+            accent_color: None,
+            plugin_source: PluginSource::default(),
+            download_limit: None,
+            cache_dir: None,
+            cache_downloads: true,
+            ui_scale: 1.0,
+            usage_stats: false,
+            usage_stats_endpoint: None,
+        }
+    }
+}
+
+/// Proxy configuration for HTTP requests
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase", tag = "mode", content = "url")]
+pub enum ProxyConfig {
+    /// Use the system proxy settings
+    #[default]
+    System,
+    /// Don't use any proxy
+    Disabled,
+    /// Use the provided proxy URL (e.g "http://127.0.0.1:8080" or "socks5://127.0.0.1:1080")
+    Manual(String),
+}
+
+/// Plugin release channels
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReleaseChannel {
+    /// Latest full release
+    #[default]
+    Stable,
+    /// Latest pre-release
+    Beta,
+    /// Latest release from the release feed with the matching name
+    Feed(String),
+}
+
+/// Folder beside the executable storing the config, cache and logs in
+/// portable mode, none when not running in portable mode. Portable mode
+/// is enabled by the [PORTABLE_ARG] or a [PORTABLE_MARKER] file beside
+/// the executable
+pub fn portable_dir() -> Option<&'static Path> {
+    static PORTABLE_DIR: OnceLock<Option<PathBuf>> = OnceLock::new();
+
+    PORTABLE_DIR
+        .get_or_init(|| {
+            let exe_path = std::env::current_exe().ok()?;
+            let exe_dir = exe_path.parent()?;
+
+            let portable = std::env::args().skip(1).any(|arg| arg == PORTABLE_ARG)
+                || exe_dir.join(PORTABLE_MARKER).is_file();
+
+            portable.then(|| exe_dir.join(CONFIG_FOLDER))
+        })
+        .as_deref()
+}
+
+/// Determines the path to the installer folder, beside the executable in
+/// portable mode otherwise within the platform config directory
+pub fn config_dir() -> Option<PathBuf> {
+    match portable_dir() {
+        Some(path) => Some(path.to_path_buf()),
+        None => dirs::config_dir().map(|path| path.join(CONFIG_FOLDER)),
+    }
+}
+
+/// Determines the path to the config file
+fn config_path() -> Option<PathBuf> {
+    config_dir().map(|path| path.join(CONFIG_FILE))
+}
+
+/// Reads the config from the platform config directory, uses the
+/// default config if none is saved or the config is invalid
+pub fn read_config() -> Config {
+    let path = match config_path() {
+        Some(value) => value,
+        None => return Config::default(),
+    };
+
+    let contents = match std::fs::read(&path) {
+        Ok(value) => value,
+        Err(err) => {
+            debug!("unable to read config {}: {err}", path.display());
+            return Config::default();
+        }
+    };
+
+    match serde_json::from_slice(&contents) {
+        Ok(value) => value,
+        Err(err) => {
+            error!("invalid config file, using default: {err}");
+            Config::default()
+        }
+    }
+}
+
+/// Saves the provided config to the platform config directory
+pub async fn save_config(config: Config) -> anyhow::Result<()> {
+    let path = config_path().context("missing config directory")?;
+
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .context("failed to create config directory")?;
+    }
+
+    let contents = serde_json::to_vec_pretty(&config).context("failed to serialize config")?;
+
+    atomic_write(path, contents)
+        .await
+        .context("failed to write config")?;
+
+    debug!("saved config");
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+//! Module for reporting crashes, the installer has no console window on
+//! Windows so a panic would otherwise close it without any explanation.
+//! Each crash writes a report to disk and the user is shown where to find it
+
+use crate::{config::config_dir, logging::recent_logs, APP_VERSION};
+use log::error;
+use std::{
+    backtrace::Backtrace,
+    fmt::Write,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Name of the folder within the config folder storing crash reports
+const CRASHES_FOLDER: &str = "crashes";
+
+/// Number of recent log lines included in a crash report
+const CRASH_LOG_LINES: usize = 100;
+
+/// Summary of the state of the app, included in crash reports
+static APP_STATE: Mutex<String> = Mutex::new(String::new());
+
+/// Whether to show a dialog when crashing, only enabled once the user
+/// interface is running so the command line mode isn't blocked by a dialog
+static SHOW_DIALOG: AtomicBool = AtomicBool::new(false);
+
+/// Installs the panic hook writing crash reports, the previous hook still
+/// runs first so the panic is logged
+pub fn init() {
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        previous_hook(info);
+
+        let report = create_crash_report(&info.to_string());
+        let path = match write_crash_report(&report) {
+            Ok(value) => Some(value),
+            Err(err) => {
+                error!("failed to write crash report: {err}");
+                None
+            }
+        };
+
+        // Panics on other threads are caught by the async runtime, only
+        // the main thread panicking closes the installer
+        let main_thread = std::thread::current().name() == Some("main");
+        if main_thread && SHOW_DIALOG.load(Ordering::Relaxed) {
+            show_crash_dialog(path);
+        }
+    }));
+}
+
+/// Enables showing a dialog pointing at the crash report when crashing
+pub fn enable_dialog() {
+    SHOW_DIALOG.store(true, Ordering::Relaxed);
+}
+
+/// Updates the summary of the app state included in crash reports
+pub fn set_app_state(summary: String) {
+    if let Ok(mut value) = APP_STATE.lock() {
+        *value = summary;
+    }
+}
+
+/// Creates the crash report for the `panic` message
+fn create_crash_report(panic: &str) -> String {
+    let mut report = String::new();
+
+    _ = writeln!(report, "Installer version: {APP_VERSION}");
+    _ = writeln!(
+        report,
+        "OS: {} ({})",
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    );
+    _ = writeln!(
+        report,
+        "Thread: {}",
+        std::thread::current().name().unwrap_or("unnamed")
+    );
+    _ = writeln!(report, "\nPanic: {panic}");
+
+    // Lock may be poisoned if the panic happened while updating the state
+    let state = match APP_STATE.lock() {
+        Ok(value) => value.clone(),
+        Err(err) => err.into_inner().clone(),
+    };
+    _ = writeln!(report, "\nApp state: {state}");
+
+    _ = writeln!(report, "\nBacktrace:\n{}", Backtrace::force_capture());
+
+    let logs = recent_logs();
+    let skip = logs.len().saturating_sub(CRASH_LOG_LINES);
+
+    _ = writeln!(report, "Recent logs:");
+    for line in logs.into_iter().skip(skip) {
+        _ = writeln!(report, "{line}");
+    }
+
+    report
+}
+
+/// Writes the crash `report` to a new file in the crashes folder, falls
+/// back to the temp folder when there is no config folder
+fn write_crash_report(report: &str) -> std::io::Result<PathBuf> {
+    let dir = config_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join(CRASHES_FOLDER);
+    std::fs::create_dir_all(&dir)?;
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let path = dir.join(format!("crash-{timestamp}.txt"));
+
+    std::fs::write(&path, report)?;
+
+    Ok(path)
+}
+
+/// Shows a native dialog telling the user the installer crashed and where
+/// the crash report was written to
+fn show_crash_dialog(path: Option<PathBuf>) {
+    let text = match path {
+        Some(path) => format!(
+            "The installer crashed unexpectedly.\n\nA crash report was saved to:\n{}\n\n\
+            Please include it when reporting the problem.",
+            path.display()
+        ),
+        None => "The installer crashed unexpectedly and the crash report couldn't be saved, \
+            see the installer log for details."
+            .to_string(),
+    };
+
+    if let Err(err) = native_dialog::MessageDialog::new()
+        .set_type(native_dialog::MessageType::Error)
+        .set_title("Pocket Relay Plugin Installer crashed")
+        .set_text(&text)
+        .show_alert()
+    {
+        error!("failed to show crash dialog: {err}");
+    }
+}
@@ -0,0 +1,274 @@
+//! Module for the links that open the installer, lets the Pocket Relay
+//! website or a server dashboard link to installing the client plugin with
+//! the server already filled in
+//! (e.g pocket-relay-installer://install?server=https://example.com)
+
+use crate::config::{portable_dir, PORTABLE_ARG};
+use anyhow::{bail, Context};
+use log::debug;
+use reqwest::Url;
+
+/// Scheme of the links opened by the installer
+pub const LINK_SCHEME: &str = "pocket-relay-installer";
+
+/// Longest server address accepted from a link
+const MAX_SERVER_LENGTH: usize = 256;
+
+/// Details from a link the installer was opened with
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeepLink {
+    /// Address of the server the plugin should connect to
+    pub server: Option<String>,
+}
+
+/// Checks whether the `value` is a link for the installer rather than a path
+pub fn is_link(value: &str) -> bool {
+    value
+        .split_once(':')
+        .is_some_and(|(scheme, _)| scheme.eq_ignore_ascii_case(LINK_SCHEME))
+}
+
+/// Parses a link for the installer, only the "install" action is supported
+pub fn parse_link(value: &str) -> anyhow::Result<DeepLink> {
+    let url = Url::parse(value.trim()).context("invalid link")?;
+    if url.scheme() != LINK_SCHEME {
+        bail!("not a {LINK_SCHEME} link");
+    }
+
+    // Links without the slashes (e.g pocket-relay-installer:install) have
+    // the action as their path instead of the host
+    let action = match url.host_str() {
+        Some(host) => host.to_string(),
+        None => url.path().trim_matches('/').to_string(),
+    };
+    if !action.eq_ignore_ascii_case("install") {
+        bail!("unsupported link action: {action}");
+    }
+
+    let mut link = DeepLink::default();
+    for (key, value) in url.query_pairs() {
+        match key.as_ref() {
+            "server" => link.server = Some(parse_server(&value)?),
+            // Links from newer websites may have details this version doesn't use
+            _ => debug!("ignoring link parameter: {key}"),
+        }
+    }
+
+    Ok(link)
+}
+
+/// Checks the server address from a link is something that can be used as
+/// the connection URL, links come from outside the installer so anything
+/// unusual is rejected rather than written to the plugin config
+fn parse_server(value: &str) -> anyhow::Result<String> {
+    let server = value.trim();
+    if server.is_empty() {
+        bail!("link server address is empty");
+    }
+    if server.len() > MAX_SERVER_LENGTH {
+        bail!("link server address is too long");
+    }
+    if server.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        bail!("link server address contains invalid characters");
+    }
+
+    if let Some((scheme, _)) = server.split_once("://") {
+        if !scheme.eq_ignore_ascii_case("http") && !scheme.eq_ignore_ascii_case("https") {
+            bail!("link server address must use http or https");
+        }
+    }
+
+    Ok(server.to_string())
+}
+
+/// Registers the installer at its current location to open links for the
+/// current user, registering again is needed after moving the installer.
+///
+/// This function performs blocking IO and should be run on a blocking thread
+pub fn register_link_handler() -> anyhow::Result<()> {
+    let exe_path = std::env::current_exe().context("failed to find installer path")?;
+    platform::register(&exe_path, portable_dir().is_some())?;
+    debug!("registered link handler: {}", exe_path.display());
+    Ok(())
+}
+
+/// Extra arguments placed after the installer path when opened from links,
+/// portable installers keep using their portable folder
+fn handler_args(portable: bool) -> String {
+    match portable {
+        true => format!(" {PORTABLE_ARG}"),
+        false => String::new(),
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    //! Links are registered as a URL protocol within the classes of the
+    //! current user, so registering doesn't need administrator access
+
+    use super::{handler_args, LINK_SCHEME};
+    use anyhow::Context;
+    use std::path::Path;
+    use winreg::{enums::HKEY_CURRENT_USER, RegKey};
+
+    /// Registers the installer at `exe_path` as the handler for links
+    pub fn register(exe_path: &Path, portable: bool) -> anyhow::Result<()> {
+        let (key, _) = RegKey::predef(HKEY_CURRENT_USER)
+            .create_subkey(format!("Software\\Classes\\{LINK_SCHEME}"))
+            .context("failed to create link protocol key")?;
+        key.set_value("", &"URL:Pocket Relay Installer")?;
+        key.set_value("URL Protocol", &"")?;
+
+        let (icon, _) = key.create_subkey("DefaultIcon")?;
+        icon.set_value("", &format!("\"{}\",0", exe_path.display()))?;
+
+        let (command, _) = key
+            .create_subkey("shell\\open\\command")
+            .context("failed to create link command key")?;
+        let command_line = format!(
+            "\"{}\"{} \"%1\"",
+            exe_path.display(),
+            handler_args(portable)
+        );
+        command.set_value("", &command_line)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    //! Links are registered through a desktop entry for the scheme that's
+    //! set as its default handler with xdg-mime
+
+    use super::{handler_args, LINK_SCHEME};
+    use anyhow::{bail, Context};
+    use std::{path::Path, process::Command};
+
+    /// Registers the installer at `exe_path` as the handler for links
+    pub fn register(exe_path: &Path, portable: bool) -> anyhow::Result<()> {
+        let applications_dir = dirs::data_dir()
+            .context("failed to find data folder")?
+            .join("applications");
+        std::fs::create_dir_all(&applications_dir)
+            .context("failed to create applications folder")?;
+
+        let entry_name = format!("{LINK_SCHEME}.desktop");
+        let entry = format!(
+            "[Desktop Entry]\n\
+            Type=Application\n\
+            Name=Pocket Relay Plugin Installer\n\
+            Exec={}{} %u\n\
+            MimeType=x-scheme-handler/{LINK_SCHEME};\n\
+            NoDisplay=true\n\
+            Terminal=false\n",
+            quote_exec_arg(&exe_path.display().to_string()),
+            handler_args(portable)
+        );
+        std::fs::write(applications_dir.join(&entry_name), entry)
+            .context("failed to write desktop entry")?;
+
+        let status = Command::new("xdg-mime")
+            .arg("default")
+            .arg(&entry_name)
+            .arg(format!("x-scheme-handler/{LINK_SCHEME}"))
+            .status()
+            .context("failed to run xdg-mime")?;
+        if !status.success() {
+            bail!("xdg-mime failed to set the link handler ({status})");
+        }
+
+        Ok(())
+    }
+
+    /// Quotes the `value` as an argument of the Exec key, reserved characters
+    /// are escaped for the quoting then backslashes again for the desktop
+    /// entry string, percent signs are doubled so they aren't field codes
+    pub(super) fn quote_exec_arg(value: &str) -> String {
+        let mut quoted = String::with_capacity(value.len() + 2);
+        quoted.push('"');
+        for c in value.chars() {
+            match c {
+                '"' | '`' | '$' => {
+                    quoted.push_str("\\\\");
+                    quoted.push(c);
+                }
+                '\\' => quoted.push_str("\\\\\\\\"),
+                '%' => quoted.push_str("%%"),
+                c => quoted.push(c),
+            }
+        }
+        quoted.push('"');
+        quoted
+    }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+mod platform {
+    //! Links can't be registered on other platforms
+
+    use std::path::Path;
+
+    /// Placeholder for registering the link handler
+    pub fn register(_exe_path: &Path, _portable: bool) -> anyhow::Result<()> {
+        anyhow::bail!("links can't be opened with the installer on this platform")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{is_link, parse_link, DeepLink};
+
+    /// Links are told apart from game paths by their scheme
+    #[test]
+    fn test_is_link() {
+        assert!(is_link("pocket-relay-installer://install"));
+        assert!(is_link("Pocket-Relay-Installer://install"));
+        assert!(!is_link("C:\\Games\\Mass Effect 3"));
+        assert!(!is_link("/home/user/Mass Effect 3"));
+    }
+
+    /// Server addresses are taken from install links
+    #[test]
+    fn test_parse_link() {
+        let link =
+            parse_link("pocket-relay-installer://install?server=https%3A%2F%2Fexample.com%3A5000")
+                .unwrap();
+        assert_eq!(
+            link,
+            DeepLink {
+                server: Some("https://example.com:5000".to_string())
+            }
+        );
+
+        let link = parse_link("pocket-relay-installer://install/?server=example.com").unwrap();
+        assert_eq!(link.server.as_deref(), Some("example.com"));
+
+        let link = parse_link("pocket-relay-installer:install").unwrap();
+        assert_eq!(link, DeepLink::default());
+    }
+
+    /// Unknown actions and unusual server addresses are rejected
+    #[test]
+    fn test_parse_link_invalid() {
+        assert!(parse_link("pocket-relay-installer://uninstall").is_err());
+        assert!(parse_link("https://install?server=example.com").is_err());
+        assert!(parse_link("pocket-relay-installer://install?server=").is_err());
+        assert!(parse_link("pocket-relay-installer://install?server=a%20b").is_err());
+        assert!(parse_link("pocket-relay-installer://install?server=file:///etc").is_err());
+    }
+
+    /// Exec arguments are quoted with reserved characters escaped
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_quote_exec_arg() {
+        assert_eq!(
+            super::platform::quote_exec_arg("/opt/Pocket Relay/installer"),
+            "\"/opt/Pocket Relay/installer\""
+        );
+        assert_eq!(
+            super::platform::quote_exec_arg("/opt/$100%/a\\b"),
+            "\"/opt/\\\\$100%%/a\\\\\\\\b\""
+        );
+    }
+}
@@ -0,0 +1,61 @@
+//! Module for reading detailed information about the patch and plugin
+//! files, shown to users that want to verify the files themselves
+
+use crate::{
+    bink::binkw32_hash,
+    fs_util::resolve_file_name,
+    plugin::{PLUGIN_DIR, PLUGIN_NAME},
+};
+use sha256::try_async_digest;
+use std::{
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+/// Detailed information about the game files
+#[derive(Debug, Clone, Default)]
+pub struct GameDetails {
+    /// SHA256 hash of the binkw32.dll if it could be read
+    pub binkw32_hash: Option<String>,
+    /// Whether the binkw23.dll is present
+    pub binkw23_present: bool,
+    /// Details of the plugin file if installed
+    pub plugin: Option<FileDetails>,
+}
+
+/// Details of a single file
+#[derive(Debug, Clone)]
+pub struct FileDetails {
+    /// SHA256 hash of the file if it could be read
+    pub hash: Option<String>,
+    /// Size of the file in bytes
+    pub size: u64,
+    /// When the file was last modified if known
+    pub modified: Option<SystemTime>,
+}
+
+/// Reads the details of the game files in the game folder at `game_path`
+pub async fn read_game_details(game_path: PathBuf) -> GameDetails {
+    let binkw23_path = resolve_file_name(&game_path, "binkw23.dll").await;
+    let plugin_path = game_path.join(PLUGIN_DIR).join(PLUGIN_NAME);
+
+    GameDetails {
+        binkw32_hash: binkw32_hash(&game_path).await.ok(),
+        binkw23_present: binkw23_path.is_file(),
+        plugin: read_file_details(&plugin_path).await,
+    }
+}
+
+/// Reads the details of the file at `path`, none if the file is missing
+async fn read_file_details(path: &Path) -> Option<FileDetails> {
+    let metadata = tokio::fs::metadata(path).await.ok()?;
+    if !metadata.is_file() {
+        return None;
+    }
+
+    Some(FileDetails {
+        hash: try_async_digest(path).await.ok(),
+        size: metadata.len(),
+        modified: metadata.modified().ok(),
+    })
+}
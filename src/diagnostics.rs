@@ -0,0 +1,47 @@
+//! Module for collecting diagnostic details to include in support requests
+
+use crate::{
+    bink::binkw32_hash, hashes::embedded_manifest, logging::recent_logs, store::detect_store,
+    APP_VERSION,
+};
+use std::{fmt::Write, path::PathBuf};
+
+/// Collects the installer version, system details, game details and the
+/// recent log lines into a report that can be shared for support
+pub async fn collect_diagnostics(game_path: Option<PathBuf>) -> String {
+    let mut report = String::new();
+
+    _ = writeln!(report, "Installer version: {APP_VERSION}");
+    _ = writeln!(
+        report,
+        "OS: {} ({})",
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    );
+
+    _ = writeln!(
+        report,
+        "Known hashes: version {}",
+        embedded_manifest().version
+    );
+
+    match game_path {
+        Some(game_path) => {
+            _ = writeln!(report, "Game path: {}", game_path.display());
+            _ = writeln!(report, "Store: {:?}", detect_store(&game_path));
+
+            match binkw32_hash(&game_path).await {
+                Ok(hash) => _ = writeln!(report, "binkw32.dll hash: {hash}"),
+                Err(err) => _ = writeln!(report, "binkw32.dll hash: {err:#}"),
+            }
+        }
+        None => _ = writeln!(report, "Game path: not selected"),
+    }
+
+    _ = writeln!(report, "\nRecent logs:");
+    for line in recent_logs() {
+        _ = writeln!(report, "{line}");
+    }
+
+    report
+}
@@ -0,0 +1,307 @@
+//! Module for detecting when the game folder requires administrator
+//! access and relaunching the installer with elevated permissions
+
+use crate::{
+    cli::launch_arg,
+    config::{portable_dir, PORTABLE_ARG},
+    plugin::PLUGIN_DIR,
+    preflight::probe_write,
+};
+use anyhow::Context;
+use log::debug;
+use std::{
+    iter::repeat_n,
+    path::{Path, PathBuf},
+};
+
+/// Game files that override the installed files when a per-user copy is
+/// present in the VirtualStore
+const VIRTUAL_STORE_FILES: &[&str] = &["binkw32.dll", "binkw23.dll"];
+
+/// Access the installer has to the game folder, used to guide the user
+/// through setting up installs that need administrator access
+#[derive(Debug, Clone)]
+pub struct FolderAccess {
+    /// Whether the game folder is within Program Files, installed for all
+    /// users of the machine
+    pub machine_wide: bool,
+    /// Whether files can be written to the game folder
+    pub writable: bool,
+    /// Whether the installer is running as administrator
+    pub elevated: bool,
+    /// Per-user VirtualStore folder for the game folder, Windows only
+    pub virtual_store_dir: Option<PathBuf>,
+    /// Per-user copies of the game files within the VirtualStore, relative
+    /// to the game folder. The game loads these instead of the installed files
+    pub virtual_store_files: Vec<String>,
+}
+
+/// Checks how the game folder at `game_path` can be accessed
+pub async fn check_folder_access(game_path: PathBuf) -> FolderAccess {
+    let writable = is_writable(&game_path).await;
+    let virtual_store_dir = virtual_store_dir(&game_path);
+    let store_dir = virtual_store_dir.clone();
+
+    let (machine_wide, elevated, virtual_store_files) = tokio::task::spawn_blocking(move || {
+        (
+            is_machine_wide(&game_path),
+            is_elevated(),
+            store_dir
+                .map(|store_dir| find_virtual_store_files(&store_dir))
+                .unwrap_or_default(),
+        )
+    })
+    .await
+    .unwrap_or_default();
+
+    let access = FolderAccess {
+        machine_wide,
+        writable,
+        elevated,
+        virtual_store_dir,
+        virtual_store_files,
+    };
+
+    debug!("game folder access: {access:?}");
+
+    access
+}
+
+/// Checks whether files can be written to the game folder at `game_path`
+/// by creating and removing a temporary file
+pub async fn is_writable(game_path: &Path) -> bool {
+    match probe_write(game_path).await {
+        Ok(()) => true,
+        Err(err) => {
+            debug!("game folder is not writable: {err}");
+            false
+        }
+    }
+}
+
+/// Checks whether the `game_path` is within one of the Program Files folders,
+/// only Windows restricts these folders to administrators
+fn is_machine_wide(game_path: &Path) -> bool {
+    if cfg!(not(target_os = "windows")) {
+        return false;
+    }
+
+    ["ProgramFiles", "ProgramFiles(x86)", "ProgramW6432"]
+        .into_iter()
+        .filter_map(std::env::var_os)
+        .any(|program_files| game_path.starts_with(program_files))
+}
+
+/// Checks whether the installer is running as administrator from the
+/// elevation of the process token, failing to read the token is treated
+/// as not elevated
+#[cfg(target_os = "windows")]
+fn is_elevated() -> bool {
+    use windows_sys::Win32::{
+        Foundation::{CloseHandle, HANDLE},
+        Security::{GetTokenInformation, TokenElevation, TOKEN_ELEVATION, TOKEN_QUERY},
+        System::Threading::{GetCurrentProcess, OpenProcessToken},
+    };
+
+    let mut token: HANDLE = std::ptr::null_mut();
+
+    // SAFETY: The token handle is only used after being opened successfully
+    // and is closed before returning
+    unsafe {
+        if OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut token) == 0 {
+            debug!(
+                "failed to open process token: {}",
+                std::io::Error::last_os_error()
+            );
+            return false;
+        }
+
+        let mut elevation = TOKEN_ELEVATION { TokenIsElevated: 0 };
+        let mut length = 0;
+        let result = GetTokenInformation(
+            token,
+            TokenElevation,
+            &mut elevation as *mut TOKEN_ELEVATION as *mut _,
+            std::mem::size_of::<TOKEN_ELEVATION>() as u32,
+            &mut length,
+        );
+        let err = std::io::Error::last_os_error();
+        CloseHandle(token);
+
+        if result == 0 {
+            debug!("failed to read token elevation: {err}");
+            return false;
+        }
+
+        elevation.TokenIsElevated != 0
+    }
+}
+
+/// Checks whether the installer is running as administrator, only
+/// relevant on Windows
+#[cfg(not(target_os = "windows"))]
+fn is_elevated() -> bool {
+    false
+}
+
+/// Determines the per-user VirtualStore folder for the `game_path`, Windows
+/// redirects writes to Program Files from programs without administrator
+/// access (such as the game) into this folder
+fn virtual_store_dir(game_path: &Path) -> Option<PathBuf> {
+    if cfg!(not(target_os = "windows")) {
+        return None;
+    }
+
+    let local_app_data = std::env::var_os("LOCALAPPDATA")?;
+
+    // VirtualStore mirrors the path without the drive prefix
+    let relative: PathBuf = game_path
+        .components()
+        .filter(|component| matches!(component, std::path::Component::Normal(_)))
+        .collect();
+
+    Some(
+        PathBuf::from(local_app_data)
+            .join("VirtualStore")
+            .join(relative),
+    )
+}
+
+/// Finds the per-user copies of the game files within the VirtualStore
+/// `store_dir` that override the installed files, relative to the game folder.
+///
+/// This function performs blocking IO and should be run on a blocking thread
+fn find_virtual_store_files(store_dir: &Path) -> Vec<String> {
+    let mut files: Vec<String> = VIRTUAL_STORE_FILES
+        .iter()
+        .filter(|name| store_dir.join(name).is_file())
+        .map(|name| name.to_string())
+        .collect();
+
+    // Any plugin or config file in the ASI folder overrides the installed one
+    if let Ok(entries) = std::fs::read_dir(store_dir.join(PLUGIN_DIR)) {
+        files.extend(
+            entries
+                .flatten()
+                .filter(|entry| entry.path().is_file())
+                .map(|entry| format!("{PLUGIN_DIR}/{}", entry.file_name().to_string_lossy())),
+        );
+    }
+
+    files
+}
+
+/// Starts a new copy of the installer with administrator permissions,
+/// the caller should exit the current copy once started.
+///
+/// This function blocks until the UAC prompt is answered and should be run
+/// on a blocking thread
+pub fn relaunch_elevated() -> anyhow::Result<()> {
+    let exe_path = std::env::current_exe().context("failed to find current executable")?;
+    let arguments = relaunch_args(portable_dir().is_some(), launch_arg().as_deref());
+
+    run_as_administrator(&exe_path, &arguments)
+}
+
+/// Command line for the copy of the installer started as administrator,
+/// portable mode and the game path or link the installer was opened with
+/// must carry over
+fn relaunch_args(portable: bool, launch_arg: Option<&str>) -> String {
+    let mut args = Vec::new();
+    if portable {
+        args.push(PORTABLE_ARG.to_string());
+    }
+    if let Some(launch_arg) = launch_arg {
+        args.push(quote_arg(launch_arg));
+    }
+    args.join(" ")
+}
+
+/// Quotes the `value` as a single argument of a Windows command line,
+/// backslashes are only escaped when they come before a quote
+fn quote_arg(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+
+    let mut backslashes = 0;
+    for c in value.chars() {
+        match c {
+            '\\' => {
+                backslashes += 1;
+                continue;
+            }
+            '"' => quoted.extend(repeat_n('\\', backslashes * 2 + 1)),
+            _ => quoted.extend(repeat_n('\\', backslashes)),
+        }
+        backslashes = 0;
+        quoted.push(c);
+    }
+
+    // Backslashes before the closing quote would escape it
+    quoted.extend(repeat_n('\\', backslashes * 2));
+    quoted.push('"');
+    quoted
+}
+
+/// Starts the executable at `exe_path` with the `arguments` as administrator
+#[cfg(target_os = "windows")]
+fn run_as_administrator(exe_path: &Path, arguments: &str) -> anyhow::Result<()> {
+    use std::{ffi::OsStr, iter::once, os::windows::ffi::OsStrExt};
+    use windows_sys::Win32::UI::{Shell::ShellExecuteW, WindowsAndMessaging::SW_SHOWNORMAL};
+
+    /// Encodes the `value` as a null terminated wide string
+    fn wide(value: &OsStr) -> Vec<u16> {
+        value.encode_wide().chain(once(0)).collect()
+    }
+
+    let operation = wide(OsStr::new("runas"));
+    let file = wide(exe_path.as_os_str());
+    let parameters = wide(OsStr::new(arguments));
+
+    // The "runas" verb triggers the UAC prompt
+    // SAFETY: The strings are null terminated and outlive the call
+    let result = unsafe {
+        ShellExecuteW(
+            std::ptr::null_mut(),
+            operation.as_ptr(),
+            file.as_ptr(),
+            parameters.as_ptr(),
+            std::ptr::null(),
+            SW_SHOWNORMAL,
+        )
+    };
+
+    // Values above 32 indicate success, declining the UAC prompt fails
+    // with a cancelled error
+    if result as isize <= 32 {
+        let err = std::io::Error::last_os_error();
+        anyhow::bail!("administrator access was not granted: {err}");
+    }
+
+    Ok(())
+}
+
+/// Starts the executable as administrator, only supported on Windows
+#[cfg(not(target_os = "windows"))]
+fn run_as_administrator(_exe_path: &Path, _arguments: &str) -> anyhow::Result<()> {
+    anyhow::bail!("restarting as administrator is only supported on Windows")
+}
+
+#[cfg(test)]
+mod test {
+    use super::relaunch_args;
+
+    /// The game path or link is passed on as a single quoted argument
+    #[test]
+    fn test_relaunch_args() {
+        assert_eq!(relaunch_args(false, None), "");
+        assert_eq!(
+            relaunch_args(true, Some("C:\\Program Files\\Mass Effect 3\\")),
+            "--portable \"C:\\Program Files\\Mass Effect 3\\\\\""
+        );
+        assert_eq!(
+            relaunch_args(false, Some("pocket-relay-installer://install?server=\"a\"")),
+            "\"pocket-relay-installer://install?server=\\\"a\\\"\""
+        );
+    }
+}
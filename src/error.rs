@@ -0,0 +1,49 @@
+//! Module for converting common failures into messages that explain to
+//! the user what went wrong and what to do, rather than showing the
+//! raw error chain
+
+use crate::{
+    i18n::tr,
+    mirror::{RequestFailure, SourceErrors},
+};
+use std::io;
+
+/// Windows error code for "Access is denied"
+const ERROR_ACCESS_DENIED: i32 = 5;
+
+/// Creates a message describing `err` for displaying to the user
+pub fn describe_error(err: &anyhow::Error) -> String {
+    if err
+        .chain()
+        .filter_map(|cause| cause.downcast_ref::<io::Error>())
+        .any(is_access_denied)
+    {
+        return tr(
+            "Administrator access is required to modify the game folder, \
+            use \"Restart as administrator\" or install the game outside Program Files",
+        )
+        .to_string();
+    }
+
+    let failure = err
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<SourceErrors>())
+        .and_then(SourceErrors::failure)
+        .or_else(|| RequestFailure::from_anyhow(err));
+
+    match failure {
+        Some(RequestFailure::Unreachable) => {
+            tr("Cannot reach GitHub, check your internet connection").to_string()
+        }
+        Some(RequestFailure::RateLimited) => {
+            tr("GitHub rate limited, wait a while and try again").to_string()
+        }
+        None => format!("{err:#}"),
+    }
+}
+
+/// Checks whether the IO error was caused by missing permissions
+fn is_access_denied(err: &io::Error) -> bool {
+    err.kind() == io::ErrorKind::PermissionDenied
+        || (cfg!(target_os = "windows") && err.raw_os_error() == Some(ERROR_ACCESS_DENIED))
+}
@@ -0,0 +1,41 @@
+//! Module for opening folders in the file explorer of the system, so the
+//! game files can be inspected without hunting down the path
+
+use anyhow::Context;
+use std::path::Path;
+
+/// Opens the folder at `path` in the file explorer
+pub fn open_folder(path: &Path) -> anyhow::Result<()> {
+    if !path.is_dir() {
+        anyhow::bail!("folder doesn't exist: {}", path.display());
+    }
+
+    platform::open_folder(path).context("failed to open file explorer")
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    //! Folders are opened directly in Windows Explorer, the default handler
+    //! for folders can be replaced by other applications
+
+    use std::{io, path::Path, process::Command};
+
+    /// Opens the folder at `path` in Windows Explorer
+    pub fn open_folder(path: &Path) -> io::Result<()> {
+        // Explorer doesn't wait for the window or report errors through
+        // its exit code so it's not waited on
+        Command::new("explorer.exe").arg(path).spawn().map(|_| ())
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+mod platform {
+    //! Folders are opened with the default handler for folders (e.g xdg-open)
+
+    use std::{io, path::Path};
+
+    /// Opens the folder at `path` with the default folder handler
+    pub fn open_folder(path: &Path) -> io::Result<()> {
+        open::that_detached(path)
+    }
+}
@@ -0,0 +1,92 @@
+//! Module for formatting dates and sizes for display, following the
+//! conventions of the selected user interface language and showing times
+//! in the local timezone
+
+use crate::{
+    fs_util::{scale_bytes, BYTE_UNIT_COUNT},
+    i18n::{language, tr, Language},
+};
+use chrono::{DateTime, Local};
+use std::time::SystemTime;
+
+/// Formats a number of bytes as a human readable size
+pub fn format_bytes(bytes: u64) -> String {
+    let units: [&str; BYTE_UNIT_COUNT] = [tr("B"), tr("KB"), tr("MB"), tr("GB")];
+
+    match scale_bytes(bytes) {
+        (_, 0) => format!("{bytes} {}", units[0]),
+        (value, unit) => {
+            let value = format!("{value:.1}");
+            format!("{} {}", localize_decimal(&value, language()), units[unit])
+        }
+    }
+}
+
+/// Replaces the decimal point in a formatted `value` with the separator
+/// used by the `language`
+fn localize_decimal(value: &str, language: Language) -> String {
+    match language {
+        Language::English => value.to_string(),
+        Language::French
+        | Language::German
+        | Language::Polish
+        | Language::Russian
+        | Language::PortugueseBrazil => value.replace('.', ","),
+    }
+}
+
+/// Date and time format used by the `language`
+fn date_time_format(language: Language) -> &'static str {
+    match language {
+        Language::English => "%Y-%m-%d %H:%M",
+        Language::French | Language::PortugueseBrazil => "%d/%m/%Y %H:%M",
+        Language::German | Language::Polish | Language::Russian => "%d.%m.%Y %H:%M",
+    }
+}
+
+/// Formats a `time` in the local timezone
+pub fn format_time(time: SystemTime) -> String {
+    let time: DateTime<Local> = time.into();
+    time.format(date_time_format(language())).to_string()
+}
+
+/// Formats an ISO 8601 `timestamp` (e.g the date a release was published)
+/// in the local timezone, timestamps that can't be parsed are shown as is
+pub fn format_timestamp(timestamp: &str) -> String {
+    match DateTime::parse_from_rfc3339(timestamp) {
+        Ok(time) => time
+            .with_timezone(&Local)
+            .format(date_time_format(language()))
+            .to_string(),
+        Err(_) => timestamp.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{date_time_format, format_timestamp, localize_decimal};
+    use crate::i18n::Language;
+    use chrono::{DateTime, Local};
+
+    /// Decimal separators follow the language
+    #[test]
+    fn test_localize_decimal() {
+        assert_eq!(localize_decimal("1.5", Language::English), "1.5");
+        assert_eq!(localize_decimal("1.5", Language::German), "1,5");
+        assert_eq!(localize_decimal("1.5", Language::PortugueseBrazil), "1,5");
+    }
+
+    /// Timestamps are shown in the local timezone and invalid timestamps
+    /// are left as is
+    #[test]
+    fn test_format_timestamp() {
+        let expected = DateTime::parse_from_rfc3339("2024-01-02T03:04:05Z")
+            .unwrap()
+            .with_timezone(&Local)
+            .format(date_time_format(Language::English))
+            .to_string();
+
+        assert_eq!(format_timestamp("2024-01-02T03:04:05Z"), expected);
+        assert_eq!(format_timestamp("yesterday"), "yesterday");
+    }
+}
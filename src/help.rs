@@ -0,0 +1,172 @@
+//! Module for the troubleshooting guide shown in the help screen, the guide is
+//! fetched from the Pocket Relay docs repository so fixes can be updated
+//! without releasing a new installer. The last fetched guide is cached for
+//! offline use and a copy is embedded for when it was never fetched
+
+use crate::{
+    fs_util::atomic_write,
+    mirror::{mirror_urls, RequestFailure, SourceErrors},
+};
+use log::{debug, error};
+use serde::{Deserialize, Serialize};
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// URL of the troubleshooting guide within the docs repository
+const HELP_URL: &str =
+    "https://raw.githubusercontent.com/PocketRelay/Docs/main/installer/troubleshooting.json";
+
+/// Name of the file within the installer cache folder storing the guide
+const HELP_CACHE_NAME: &str = "troubleshooting.json";
+
+/// Time to wait for the guide to be fetched
+const HELP_REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Copy of the guide embedded in the installer
+const EMBEDDED_HELP: &str = include_str!("./resources/troubleshooting.json");
+
+/// Troubleshooting guide listing common problems and their fixes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HelpDocument {
+    /// Common problems
+    pub topics: Vec<HelpTopic>,
+}
+
+/// Common problem and the steps to fix it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HelpTopic {
+    /// Title of the problem (e.g "The plugin isn't loading")
+    pub title: String,
+    /// Short description of the symptoms
+    #[serde(default)]
+    pub summary: String,
+    /// Steps to fix the problem in order
+    pub steps: Vec<String>,
+    /// Link to further details about the problem
+    #[serde(default)]
+    pub link: Option<String>,
+}
+
+/// Where the shown guide was loaded from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HelpSource {
+    /// Fetched from the docs repository
+    Remote,
+    /// Cached from a previous fetch
+    Cached,
+    /// Embedded in the installer
+    Embedded,
+}
+
+/// Loads the troubleshooting guide, fetching the latest guide through the
+/// `mirrors` and falling back to the guide cached in the `cache_dir` or the
+/// embedded guide when it can't be fetched
+pub async fn load_help(
+    http_client: reqwest::Client,
+    mirrors: Vec<String>,
+    cache_dir: Option<PathBuf>,
+) -> (HelpDocument, HelpSource) {
+    let cache_path = cache_path(cache_dir.as_deref());
+
+    match fetch_help(&http_client, &mirrors).await {
+        Ok((document, contents)) => {
+            if let Some(cache_path) = &cache_path {
+                write_cached_help(cache_path, contents).await;
+            }
+
+            return (document, HelpSource::Remote);
+        }
+        Err(err) => error!("failed to fetch troubleshooting guide: {err}"),
+    }
+
+    if let Some(document) = read_cached_help(cache_path.as_deref()).await {
+        return (document, HelpSource::Cached);
+    }
+
+    let document =
+        serde_json::from_str(EMBEDDED_HELP).unwrap_or(HelpDocument { topics: Vec::new() });
+    (document, HelpSource::Embedded)
+}
+
+/// Fetches the latest guide trying each of the `mirrors` in turn, the
+/// parsed guide is returned along with its contents for caching
+async fn fetch_help(
+    http_client: &reqwest::Client,
+    mirrors: &[String],
+) -> Result<(HelpDocument, String), SourceErrors> {
+    let mut errors = SourceErrors::default();
+
+    for url in mirror_urls(HELP_URL, mirrors) {
+        let result = async {
+            http_client
+                .get(&url)
+                .timeout(HELP_REQUEST_TIMEOUT)
+                .send()
+                .await?
+                .error_for_status()?
+                .text()
+                .await
+        }
+        .await;
+
+        let contents = match result {
+            Ok(value) => value,
+            Err(err) => {
+                let failure = RequestFailure::from_reqwest(&err);
+                errors.push(&url, err, failure);
+                continue;
+            }
+        };
+
+        match serde_json::from_str(&contents) {
+            Ok(document) => {
+                errors.succeeded(&url);
+                return Ok((document, contents));
+            }
+            Err(err) => errors.push(&url, format!("invalid guide: {err}"), None),
+        }
+    }
+
+    Err(errors)
+}
+
+/// Determines the path to the cached guide within the `cache_dir`, none
+/// when there's no folder to cache the guide in
+fn cache_path(cache_dir: Option<&Path>) -> Option<PathBuf> {
+    cache_dir.map(|path| path.join(HELP_CACHE_NAME))
+}
+
+/// Reads the cached guide, none if the guide was never cached or the
+/// cache is invalid
+async fn read_cached_help(cache_path: Option<&Path>) -> Option<HelpDocument> {
+    let cache_path = cache_path?;
+    let contents = tokio::fs::read(cache_path).await.ok()?;
+
+    match serde_json::from_slice(&contents) {
+        Ok(value) => Some(value),
+        Err(err) => {
+            debug!(
+                "ignoring invalid troubleshooting cache {}: {err}",
+                cache_path.display()
+            );
+            None
+        }
+    }
+}
+
+/// Saves the `contents` of the guide to the cache, failures are logged
+/// as the guide can still be shown
+async fn write_cached_help(cache_path: &Path, contents: String) {
+    if let Some(parent) = cache_path.parent() {
+        if let Err(err) = tokio::fs::create_dir_all(parent).await {
+            error!("failed to create cache directory: {err}");
+            return;
+        }
+    }
+
+    if let Err(err) = atomic_write(cache_path, contents).await {
+        error!("failed to write troubleshooting cache: {err}");
+    }
+}
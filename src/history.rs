@@ -0,0 +1,389 @@
+//! Module for the history of changes the installer made to the game files,
+//! each change is recorded in a journal along with the hashes of the files
+//! before and after the change. Copies of the replaced files are kept so the
+//! most recent change to a game can be undone
+
+use crate::{
+    asi::DISABLED_EXTENSION,
+    bink::BINKW32_BACKUP_NAME,
+    config::config_dir,
+    fs_util::{atomic_write, resolve_file_name},
+    i18n::tr,
+    plugin::{PLUGIN_DIR, PLUGIN_NAME, PLUGIN_VERSION_NAME},
+    plugin_config::PLUGIN_CONFIG_NAME,
+    process::ensure_game_not_running,
+};
+use anyhow::{bail, Context};
+use log::{debug, error};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::Display,
+    future::Future,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+use tokio::sync::Mutex;
+
+/// Name of the folder within the installer config folder storing the
+/// copies of replaced files
+const HISTORY_FOLDER: &str = "history";
+/// Name of the journal file within the installer config folder
+const JOURNAL_NAME: &str = "history.json";
+/// Maximum number of changes kept in the journal, the oldest changes and
+/// their copies are removed first
+const MAX_ENTRIES: usize = 20;
+
+/// Lock held while the journal is being changed
+static JOURNAL_LOCK: Mutex<()> = Mutex::const_new(());
+
+/// Change made to the game files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HistoryAction {
+    /// Applied the patch
+    ApplyPatch,
+    /// Removed the patch
+    RemovePatch,
+    /// Installed or updated the plugin
+    InstallPlugin,
+    /// Removed the plugin
+    RemovePlugin,
+    /// Enabled the plugin
+    EnablePlugin,
+    /// Disabled the plugin
+    DisablePlugin,
+    /// Applied the patch and installed the plugin together
+    InstallAll,
+    /// Uninstalled everything
+    Uninstall,
+}
+
+impl Display for HistoryAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            HistoryAction::ApplyPatch => tr("Applied the patch"),
+            HistoryAction::RemovePatch => tr("Removed the patch"),
+            HistoryAction::InstallPlugin => tr("Installed the plugin"),
+            HistoryAction::RemovePlugin => tr("Removed the plugin"),
+            HistoryAction::EnablePlugin => tr("Enabled the plugin"),
+            HistoryAction::DisablePlugin => tr("Disabled the plugin"),
+            HistoryAction::InstallAll => tr("Applied the patch and installed the plugin"),
+            HistoryAction::Uninstall => tr("Uninstalled everything"),
+        })
+    }
+}
+
+/// Recorded change to the game files
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Unique ID of the change, also the name of the folder storing the
+    /// copies of the replaced files
+    pub id: String,
+    /// When the change was made
+    pub timestamp: SystemTime,
+    /// Change that was made
+    pub action: HistoryAction,
+    /// Game folder the change was made to
+    pub game_path: PathBuf,
+    /// Files the change modified
+    pub files: Vec<HistoryFile>,
+}
+
+/// File modified by a recorded change
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryFile {
+    /// Path to the file relative to the game folder
+    pub path: PathBuf,
+    /// SHA256 hash of the file before the change, none if it didn't exist
+    pub before: Option<String>,
+    /// SHA256 hash of the file after the change, none if it was removed
+    pub after: Option<String>,
+    /// Name of the copy of the file from before the change
+    backup: Option<String>,
+}
+
+/// Contents of the files the installer changes, taken before a change
+struct FileSnapshot {
+    /// Path relative to the game folder and the contents of each file,
+    /// none for files that don't exist
+    files: Vec<(PathBuf, Option<Vec<u8>>)>,
+}
+
+/// Runs the `change` to the game folder at `game_path`, recording the
+/// files it modified in the history once it succeeds. Failing to record
+/// the change is logged rather than failing the change
+pub async fn record_change<T>(
+    action: HistoryAction,
+    game_path: PathBuf,
+    change: impl Future<Output = anyhow::Result<T>>,
+) -> anyhow::Result<T> {
+    let snapshot = snapshot_files(&game_path).await;
+    let value = change.await?;
+
+    if let Err(err) = record_entry(action, &game_path, snapshot).await {
+        error!("failed to record change history: {err:#}");
+    }
+
+    Ok(value)
+}
+
+/// Loads the recorded changes to the game folder at `game_path`, the
+/// newest change is first
+pub async fn load_history(game_path: PathBuf) -> anyhow::Result<Vec<HistoryEntry>> {
+    let mut entries: Vec<HistoryEntry> = read_journal()
+        .await?
+        .into_iter()
+        .filter(|entry| entry.game_path == game_path)
+        .collect();
+    entries.reverse();
+    Ok(entries)
+}
+
+/// Undoes the most recent change to the game folder at `game_path` by
+/// restoring the files from before the change. The change with the `id`
+/// must still be the most recent and the files must not have been
+/// modified since the change was made
+pub async fn undo_change(game_path: PathBuf, id: String) -> anyhow::Result<()> {
+    let _lock = JOURNAL_LOCK.lock().await;
+
+    let mut entries = read_journal().await?;
+    let index = entries
+        .iter()
+        .rposition(|entry| entry.game_path == game_path)
+        .context("no changes to undo")?;
+
+    if entries[index].id != id {
+        bail!("A newer change was made, only the most recent change can be undone");
+    }
+
+    ensure_game_not_running(&game_path).await?;
+
+    let entry = &entries[index];
+    let backup_path = history_dir()?.join(&entry.id);
+
+    // Files changed since would be lost by restoring them
+    for file in &entry.files {
+        let current = tokio::fs::read(game_path.join(&file.path))
+            .await
+            .ok()
+            .map(sha256::digest);
+
+        if current != file.after {
+            bail!(
+                "{} was changed after this change was made, it can't be undone",
+                file.path.display()
+            );
+        }
+    }
+
+    for file in &entry.files {
+        let path = game_path.join(&file.path);
+
+        match &file.backup {
+            Some(backup) => {
+                let contents = tokio::fs::read(backup_path.join(backup))
+                    .await
+                    .with_context(|| format!("missing copy of {}", file.path.display()))?;
+
+                if let Some(parent) = path.parent() {
+                    tokio::fs::create_dir_all(parent)
+                        .await
+                        .with_context(|| format!("failed to create {}", parent.display()))?;
+                }
+
+                atomic_write(&path, contents)
+                    .await
+                    .with_context(|| format!("failed to restore {}", file.path.display()))?;
+            }
+            None => {
+                if path.exists() {
+                    tokio::fs::remove_file(&path)
+                        .await
+                        .with_context(|| format!("failed to remove {}", file.path.display()))?;
+                }
+            }
+        }
+    }
+
+    debug!("undid change {} to {}", entry.id, game_path.display());
+
+    let entry = entries.remove(index);
+    write_journal(&entries).await?;
+    remove_backup(&entry.id).await;
+
+    Ok(())
+}
+
+/// Takes a snapshot of the files within the game folder at `game_path`
+/// that the installer changes
+async fn snapshot_files(game_path: &Path) -> FileSnapshot {
+    let disabled_plugin_name = format!("{PLUGIN_NAME}{DISABLED_EXTENSION}");
+
+    let mut paths: Vec<PathBuf> = Vec::new();
+
+    // Patch files use whichever case the game has
+    for name in ["binkw32.dll", "binkw23.dll", BINKW32_BACKUP_NAME] {
+        let path = resolve_file_name(game_path, name).await;
+        if let Ok(path) = path.strip_prefix(game_path) {
+            paths.push(path.to_path_buf());
+        }
+    }
+
+    for name in [
+        PLUGIN_NAME,
+        &disabled_plugin_name,
+        PLUGIN_VERSION_NAME,
+        PLUGIN_CONFIG_NAME,
+    ] {
+        paths.push(Path::new(PLUGIN_DIR).join(name));
+    }
+
+    let mut files = Vec::with_capacity(paths.len());
+    for path in paths {
+        let contents = tokio::fs::read(game_path.join(&path)).await.ok();
+        files.push((path, contents));
+    }
+
+    FileSnapshot { files }
+}
+
+/// Records the files that changed since the `snapshot` was taken as a
+/// new entry in the journal, unchanged files aren't recorded
+async fn record_entry(
+    action: HistoryAction,
+    game_path: &Path,
+    snapshot: FileSnapshot,
+) -> anyhow::Result<()> {
+    let _lock = JOURNAL_LOCK.lock().await;
+
+    let timestamp = SystemTime::now();
+
+    // Timestamps are sortable, colons aren't allowed in Windows file names
+    let mut id = humantime::format_rfc3339_millis(timestamp)
+        .to_string()
+        .replace(':', "-");
+
+    let history_dir = history_dir()?;
+    let mut entries = read_journal().await?;
+
+    // Changes made within the same millisecond
+    while entries.iter().any(|entry| entry.id == id) {
+        id.push('_');
+    }
+
+    let backup_path = history_dir.join(&id);
+    let mut files = Vec::new();
+
+    for (index, (path, before)) in snapshot.files.into_iter().enumerate() {
+        let after = tokio::fs::read(game_path.join(&path)).await.ok();
+        if before == after {
+            continue;
+        }
+
+        let backup = match &before {
+            Some(contents) => {
+                let name = index.to_string();
+
+                tokio::fs::create_dir_all(&backup_path)
+                    .await
+                    .context("failed to create history folder")?;
+                atomic_write(backup_path.join(&name), contents)
+                    .await
+                    .with_context(|| format!("failed to save copy of {}", path.display()))?;
+
+                Some(name)
+            }
+            None => None,
+        };
+
+        files.push(HistoryFile {
+            path,
+            before: before.map(sha256::digest),
+            after: after.map(sha256::digest),
+            backup,
+        });
+    }
+
+    if files.is_empty() {
+        debug!("change made no modifications, not recording history");
+        return Ok(());
+    }
+
+    entries.push(HistoryEntry {
+        id,
+        timestamp,
+        action,
+        game_path: game_path.to_path_buf(),
+        files,
+    });
+
+    // Remove the oldest changes along with their copies
+    let excess = entries.len().saturating_sub(MAX_ENTRIES);
+    let removed: Vec<HistoryEntry> = entries.drain(..excess).collect();
+
+    write_journal(&entries).await?;
+
+    for entry in removed {
+        remove_backup(&entry.id).await;
+    }
+
+    debug!("recorded {action:?} to {}", game_path.display());
+
+    Ok(())
+}
+
+/// Determines the path to the folder storing the copies of replaced files
+fn history_dir() -> anyhow::Result<PathBuf> {
+    config_dir()
+        .map(|path| path.join(HISTORY_FOLDER))
+        .context("missing config directory")
+}
+
+/// Reads the recorded changes from the journal, oldest first
+async fn read_journal() -> anyhow::Result<Vec<HistoryEntry>> {
+    let journal_path = config_dir()
+        .context("missing config directory")?
+        .join(JOURNAL_NAME);
+
+    let contents = match tokio::fs::read(&journal_path).await {
+        Ok(value) => value,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err).context("failed to read history"),
+    };
+
+    serde_json::from_slice(&contents).context("failed to parse history")
+}
+
+/// Writes the recorded `entries` to the journal
+async fn write_journal(entries: &[HistoryEntry]) -> anyhow::Result<()> {
+    let config_dir = config_dir().context("missing config directory")?;
+
+    tokio::fs::create_dir_all(&config_dir)
+        .await
+        .context("failed to create config directory")?;
+
+    let contents = serde_json::to_vec_pretty(entries).context("failed to serialize history")?;
+    atomic_write(config_dir.join(JOURNAL_NAME), contents)
+        .await
+        .context("failed to save history")
+}
+
+/// Removes the copies of the replaced files for the change with the `id`,
+/// failures are logged as the copies are no longer needed
+async fn remove_backup(id: &str) {
+    let backup_path = match history_dir() {
+        Ok(value) => value.join(id),
+        Err(_) => return,
+    };
+
+    if !backup_path.exists() {
+        return;
+    }
+
+    if let Err(err) = tokio::fs::remove_dir_all(&backup_path).await {
+        error!(
+            "failed to remove history copy {}: {err}",
+            backup_path.display()
+        );
+    }
+}
@@ -0,0 +1,38 @@
+//! Module for creating the HTTP client used for requests
+
+use crate::config::ProxyConfig;
+use anyhow::Context;
+use reqwest::Proxy;
+use std::time::Duration;
+
+/// Client user agent created from the name and version
+pub const USER_AGENT: &str = concat!("PocketRelayPluginInstaller/v", env!("CARGO_PKG_VERSION"));
+
+/// Time to wait for a connection to be established
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+/// Time to wait for more data from a response before giving up, large
+/// downloads on slow connections are fine as long as data keeps arriving
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Creates a new HTTP client using the provided `proxy` configuration
+pub fn create_http_client(proxy: &ProxyConfig) -> anyhow::Result<reqwest::Client> {
+    let mut builder = reqwest::Client::builder()
+        .user_agent(USER_AGENT)
+        .connect_timeout(CONNECT_TIMEOUT)
+        .read_timeout(READ_TIMEOUT);
+
+    builder = match proxy {
+        // System proxy is used by default
+        ProxyConfig::System => builder,
+        ProxyConfig::Disabled => builder.no_proxy(),
+        ProxyConfig::Manual(url) => builder.proxy(parse_proxy(url)?),
+    };
+
+    builder.build().context("failed to build http client")
+}
+
+/// Parses a proxy URL for proxying all requests, supports HTTP, HTTPS
+/// and SOCKS5 proxies
+pub fn parse_proxy(url: &str) -> anyhow::Result<Proxy> {
+    Proxy::all(url).context("invalid proxy url")
+}
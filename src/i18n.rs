@@ -0,0 +1,130 @@
+//! Module for translating the user interface, translations are embedded
+//! tables mapping the English text to the translated text. Text missing
+//! from a table falls back to the English text
+
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::Display,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+mod de;
+mod fr;
+mod pl;
+mod pt_br;
+mod ru;
+
+/// Table of English text and the translation of that text
+type Translations = &'static [(&'static str, &'static str)];
+
+/// Currently selected language, stored as the [Language] discriminant
+static LANGUAGE: AtomicU8 = AtomicU8::new(Language::English as u8);
+
+/// Languages the user interface can be displayed in
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Language {
+    /// English, the language the text is written in
+    #[default]
+    #[serde(rename = "en")]
+    English,
+    /// French
+    #[serde(rename = "fr")]
+    French,
+    /// German
+    #[serde(rename = "de")]
+    German,
+    /// Polish
+    #[serde(rename = "pl")]
+    Polish,
+    /// Russian
+    #[serde(rename = "ru")]
+    Russian,
+    /// Brazilian Portuguese
+    #[serde(rename = "pt-BR")]
+    PortugueseBrazil,
+}
+
+impl Language {
+    /// All the available languages
+    pub const ALL: [Language; 6] = [
+        Language::English,
+        Language::French,
+        Language::German,
+        Language::Polish,
+        Language::Russian,
+        Language::PortugueseBrazil,
+    ];
+
+    /// Translation table for the language, none for English
+    fn translations(&self) -> Option<Translations> {
+        match self {
+            Language::English => None,
+            Language::French => Some(fr::TRANSLATIONS),
+            Language::German => Some(de::TRANSLATIONS),
+            Language::Polish => Some(pl::TRANSLATIONS),
+            Language::Russian => Some(ru::TRANSLATIONS),
+            Language::PortugueseBrazil => Some(pt_br::TRANSLATIONS),
+        }
+    }
+
+    /// Obtains the language from its discriminant
+    fn from_u8(value: u8) -> Language {
+        Language::ALL
+            .into_iter()
+            .find(|language| *language as u8 == value)
+            .unwrap_or_default()
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Languages are named in their own language so they can be found
+        f.write_str(match self {
+            Language::English => "English",
+            Language::French => "Français",
+            Language::German => "Deutsch",
+            Language::Polish => "Polski",
+            Language::Russian => "Русский",
+            Language::PortugueseBrazil => "Português (Brasil)",
+        })
+    }
+}
+
+/// Sets the language used for translating text
+pub fn set_language(language: Language) {
+    LANGUAGE.store(language as u8, Ordering::Relaxed);
+}
+
+/// Obtains the language used for translating text
+pub fn language() -> Language {
+    Language::from_u8(LANGUAGE.load(Ordering::Relaxed))
+}
+
+/// Translates the English `text` into the current language
+pub fn tr(text: &'static str) -> &'static str {
+    language()
+        .translations()
+        .and_then(|translations| {
+            translations
+                .iter()
+                .find(|(english, _)| *english == text)
+                .map(|(_, translated)| *translated)
+        })
+        .unwrap_or(text)
+}
+
+/// Translates the English `text` into the current language then replaces
+/// each "{}" placeholder with the next of the provided `args`
+pub fn tr_args(text: &'static str, args: &[&dyn Display]) -> String {
+    let mut parts = tr(text).split("{}");
+    let mut output = parts.next().unwrap_or_default().to_string();
+
+    for (index, part) in parts.enumerate() {
+        if let Some(arg) = args.get(index) {
+            output.push_str(&arg.to_string());
+        }
+        output.push_str(part);
+    }
+
+    output
+}
@@ -0,0 +1,1434 @@
+//! German translations
+
+use super::Translations;
+
+pub const TRANSLATIONS: Translations = &[
+    (
+        "Stable ({})",
+        "Stabil ({})",
+    ),
+    (
+        "Beta ({})",
+        "Beta ({})",
+    ),
+    (
+        "Please click the button below to choose your game path. When the file picker opens navigate to the folder containing MassEffect3.exe and pick that file",
+        "Klicke auf die Schaltfläche unten, um den Spielpfad auszuwählen. Wenn sich die Dateiauswahl öffnet, navigiere zum Ordner mit MassEffect3.exe und wähle diese Datei aus",
+    ),
+    (
+        "Choose game path",
+        "Spielpfad auswählen",
+    ),
+    (
+        "Settings",
+        "Einstellungen",
+    ),
+    (
+        "failed to pick file: {}",
+        "Datei konnte nicht ausgewählt werden: {}",
+    ),
+    (
+        "Or choose one of the following detected game installs:",
+        "Oder wähle eine der folgenden erkannten Spielinstallationen:",
+    ),
+    (
+        "Back",
+        "Zurück",
+    ),
+    (
+        "Preview changes",
+        "Änderungen vorab anzeigen",
+    ),
+    (
+        "Installations",
+        "Installationen",
+    ),
+    (
+        "Add installation",
+        "Installation hinzufügen",
+    ),
+    (
+        "The game folder can't be modified without administrator access, restart the installer as administrator to make changes.",
+        "Der Spielordner kann ohne Administratorrechte nicht geändert werden, starte das Installationsprogramm als Administrator neu, um Änderungen vorzunehmen.",
+    ),
+    (
+        "Restart as administrator",
+        "Als Administrator neu starten",
+    ),
+    (
+        "Retry",
+        "Erneut versuchen",
+    ),
+    (
+        "Apply the patch and install the {} plugin in one step",
+        "Den Patch anwenden und das Plugin {} in einem Schritt installieren",
+    ),
+    (
+        "Install everything",
+        "Alles installieren",
+    ),
+    (
+        "Applying patch...",
+        "Patch wird angewendet...",
+    ),
+    (
+        "Installing plugin...",
+        "Plugin wird installiert...",
+    ),
+    (
+        "Patch and plugin installed",
+        "Patch und Plugin installiert",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before installing.",
+        "Mass Effect 3 läuft gerade, schließe das Spiel vor der Installation.",
+    ),
+    (
+        "Failed to install: {}",
+        "Installation fehlgeschlagen: {}",
+    ),
+    (
+        "Cancel",
+        "Abbrechen",
+    ),
+    (
+        "Preparing preview...",
+        "Vorschau wird vorbereitet...",
+    ),
+    (
+        "Failed to preview changes: {}",
+        "Vorschau der Änderungen fehlgeschlagen: {}",
+    ),
+    (
+        "The following changes will be made:",
+        "Die folgenden Änderungen werden vorgenommen:",
+    ),
+    (
+        "Confirm",
+        "Bestätigen",
+    ),
+    (
+        "Server Connection",
+        "Serververbindung",
+    ),
+    (
+        "Address of the Pocket Relay server the plugin should connect to",
+        "Adresse des Pocket Relay-Servers, mit dem sich das Plugin verbinden soll",
+    ),
+    (
+        "Save",
+        "Speichern",
+    ),
+    (
+        "Loading config...",
+        "Konfiguration wird geladen...",
+    ),
+    (
+        "Saving config...",
+        "Konfiguration wird gespeichert...",
+    ),
+    (
+        "Saved server connection",
+        "Serververbindung gespeichert",
+    ),
+    (
+        "Logs",
+        "Protokolle",
+    ),
+    (
+        "Hide logs",
+        "Protokolle ausblenden",
+    ),
+    (
+        "Show logs",
+        "Protokolle anzeigen",
+    ),
+    (
+        "Copy diagnostics to clipboard",
+        "Diagnosedaten in die Zwischenablage kopieren",
+    ),
+    (
+        "Copied diagnostics to clipboard",
+        "Diagnosedaten in die Zwischenablage kopiert",
+    ),
+    (
+        "Uninstall",
+        "Deinstallieren",
+    ),
+    (
+        "Remove the plugin, its config and log files, and restore the original binkw32.dll",
+        "Das Plugin, seine Konfiguration und Protokolldateien entfernen und die originale binkw32.dll wiederherstellen",
+    ),
+    (
+        "Remove the ASI folder if its empty",
+        "Den ASI-Ordner entfernen, wenn er leer ist",
+    ),
+    (
+        "Uninstall everything",
+        "Alles deinstallieren",
+    ),
+    (
+        "Are you sure you want to remove everything Pocket Relay has installed?",
+        "Bist du sicher, dass du alles entfernen möchtest, was Pocket Relay installiert hat?",
+    ),
+    (
+        "Uninstalling...",
+        "Wird deinstalliert...",
+    ),
+    (
+        "Nothing to uninstall",
+        "Nichts zu deinstallieren",
+    ),
+    (
+        "Uninstalled everything",
+        "Alles deinstalliert",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before uninstalling.",
+        "Mass Effect 3 läuft gerade, schließe das Spiel vor der Deinstallation.",
+    ),
+    (
+        "Failed to uninstall: {}",
+        "Deinstallation fehlgeschlagen: {}",
+    ),
+    (
+        "ASI Plugins",
+        "ASI-Plugins",
+    ),
+    (
+        "Refresh",
+        "Aktualisieren",
+    ),
+    (
+        "Loading plugins...",
+        "Plugins werden geladen...",
+    ),
+    (
+        "failed to load plugins: {}",
+        "Plugins konnten nicht geladen werden: {}",
+    ),
+    (
+        "No ASI plugins are installed",
+        "Es sind keine ASI-Plugins installiert",
+    ),
+    (
+        "Enabled",
+        "Aktiviert",
+    ),
+    (
+        "Disabled",
+        "Deaktiviert",
+    ),
+    (
+        "Disable",
+        "Deaktivieren",
+    ),
+    (
+        "Enable",
+        "Aktivieren",
+    ),
+    (
+        "Delete",
+        "Löschen",
+    ),
+    (
+        "Language",
+        "Sprache",
+    ),
+    (
+        "Proxy used when connecting to GitHub",
+        "Proxy für die Verbindung zu GitHub",
+    ),
+    (
+        "System proxy",
+        "System-Proxy",
+    ),
+    (
+        "No proxy",
+        "Kein Proxy",
+    ),
+    (
+        "Manual proxy",
+        "Manueller Proxy",
+    ),
+    (
+        "Mirrors to use when GitHub is unreachable (comma separated, URL prefixes or URLs containing {url})",
+        "Spiegelserver, wenn GitHub nicht erreichbar ist (durch Kommas getrennt, URL-Präfixe oder URLs mit {url})",
+    ),
+    (
+        "GitHub personal access token (optional, raises the GitHub rate limit on shared networks)",
+        "Persönliches GitHub-Zugriffstoken (optional, erhöht das GitHub-Anfragelimit in gemeinsam genutzten Netzwerken)",
+    ),
+    (
+        "Token",
+        "Token",
+    ),
+    (
+        "A new version of the installer is available ({})",
+        "Eine neue Version des Installationsprogramms ist verfügbar ({})",
+    ),
+    (
+        "Update installer",
+        "Installationsprogramm aktualisieren",
+    ),
+    (
+        "Updating installer...",
+        "Installationsprogramm wird aktualisiert...",
+    ),
+    (
+        "failed to update installer: {}",
+        "Installationsprogramm konnte nicht aktualisiert werden: {}",
+    ),
+    (
+        "Your game is patched",
+        "Dein Spiel ist gepatcht",
+    ),
+    (
+        "Remove Patch",
+        "Patch entfernen",
+    ),
+    (
+        "Your game is not patched, you must apply the patch to use the client plugin.",
+        "Dein Spiel ist nicht gepatcht, du musst den Patch anwenden, um das Client-Plugin zu verwenden.",
+    ),
+    (
+        "Apply Patch",
+        "Patch anwenden",
+    ),
+    (
+        "Your game has an unrecognized binkw32.dll, it may be from another mod or corrupted. Replacing it will back up the current file to binkw32.dll.bak",
+        "Dein Spiel hat eine unbekannte binkw32.dll, sie stammt möglicherweise von einem anderen Mod oder ist beschädigt. Beim Ersetzen wird die aktuelle Datei als binkw32.dll.bak gesichert",
+    ),
+    (
+        "Back up and replace",
+        "Sichern und ersetzen",
+    ),
+    (
+        "Installing patch...",
+        "Patch wird installiert...",
+    ),
+    (
+        "Uninstalling patch...",
+        "Patch wird deinstalliert...",
+    ),
+    (
+        "Patch successfully removed.",
+        "Patch erfolgreich entfernt.",
+    ),
+    (
+        "Patch successfully installed.",
+        "Patch erfolgreich installiert.",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before changing the patch.",
+        "Mass Effect 3 läuft gerade, schließe das Spiel, bevor du den Patch änderst.",
+    ),
+    (
+        "failed to add patch: {}",
+        "Patch konnte nicht angewendet werden: {}",
+    ),
+    (
+        "failed to remove patch: {}",
+        "Patch konnte nicht entfernt werden: {}",
+    ),
+    (
+        "You have the Pocket Relay client plugin installed.",
+        "Das Pocket Relay-Client-Plugin ist installiert.",
+    ),
+    (
+        "Remove Plugin",
+        "Plugin entfernen",
+    ),
+    (
+        "Unknown",
+        "Unbekannt",
+    ),
+    (
+        "Installed: {}",
+        "Installiert: {}",
+    ),
+    (
+        "Installed: {} (Latest)",
+        "Installiert: {} (Aktuell)",
+    ),
+    (
+        "Installed: {} \u{2014} Update available: {}",
+        "Installiert: {} \u{2014} Update verfügbar: {}",
+    ),
+    (
+        "Update Plugin",
+        "Plugin aktualisieren",
+    ),
+    (
+        "You do not have the Pocket Relay client plugin installed",
+        "Das Pocket Relay-Client-Plugin ist nicht installiert",
+    ),
+    (
+        "Uninstalling plugin...",
+        "Plugin wird deinstalliert...",
+    ),
+    (
+        "Pocket Relay client plugin successfully installed.",
+        "Pocket Relay-Client-Plugin erfolgreich installiert.",
+    ),
+    (
+        "Pocket Relay client plugin successfully removed.",
+        "Pocket Relay-Client-Plugin erfolgreich entfernt.",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before changing the plugin.",
+        "Mass Effect 3 läuft gerade, schließe das Spiel, bevor du das Plugin änderst.",
+    ),
+    (
+        "failed to update plugin: {}",
+        "Plugin konnte nicht aktualisiert werden: {}",
+    ),
+    (
+        "failed to install plugin: {}",
+        "Plugin konnte nicht installiert werden: {}",
+    ),
+    (
+        "failed to remove plugin: {}",
+        "Plugin konnte nicht entfernt werden: {}",
+    ),
+    (
+        "Install from file...",
+        "Aus Datei installieren...",
+    ),
+    (
+        "Loading latest plugin version details...",
+        "Details zur neuesten Plugin-Version werden geladen...",
+    ),
+    (
+        "Unable to load latest plugin version: {}",
+        "Neueste Plugin-Version konnte nicht geladen werden: {}",
+    ),
+    (
+        "The latest version of the plugin client is {}",
+        "Die neueste Version des Client-Plugins ist {}",
+    ),
+    (
+        "Add Plugin",
+        "Plugin hinzufügen",
+    ),
+    (
+        "Select version",
+        "Version auswählen",
+    ),
+    (
+        "Hide notes",
+        "Hinweise ausblenden",
+    ),
+    (
+        "Release notes",
+        "Versionshinweise",
+    ),
+    (
+        "This release has no release notes",
+        "Diese Version hat keine Versionshinweise",
+    ),
+    (
+        "View on GitHub",
+        "Auf GitHub ansehen",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before changing plugins.",
+        "Mass Effect 3 läuft gerade, schließe das Spiel, bevor du Plugins änderst.",
+    ),
+    (
+        "failed to change plugin: {}",
+        "Plugin konnte nicht geändert werden: {}",
+    ),
+    (
+        "failed to load config: {}",
+        "Konfiguration konnte nicht geladen werden: {}",
+    ),
+    (
+        "failed to save config: {}",
+        "Konfiguration konnte nicht gespeichert werden: {}",
+    ),
+    (
+        "Failed to switch installation: {}",
+        "Installation konnte nicht gewechselt werden: {}",
+    ),
+    (
+        "{} will be created, {}",
+        "{} wird erstellt, {}",
+    ),
+    (
+        "{} will be overwritten (backed up to {}), {}",
+        "{} wird überschrieben (gesichert als {}), {}",
+    ),
+    (
+        "{} will be overwritten, {}",
+        "{} wird überschrieben, {}",
+    ),
+    (
+        "{} will be restored from {}",
+        "{} wird aus {} wiederhergestellt",
+    ),
+    (
+        "{} will be deleted",
+        "{} wird gelöscht",
+    ),
+    (
+        "Administrator access is required to modify the game folder, use \"Restart as administrator\" or install the game outside Program Files",
+        "Zum Ändern des Spielordners sind Administratorrechte erforderlich, verwende \"Als Administrator neu starten\" oder installiere das Spiel außerhalb von Program Files",
+    ),
+    (
+        "Theme",
+        "Design",
+    ),
+    (
+        "System",
+        "System",
+    ),
+    (
+        "Light",
+        "Hell",
+    ),
+    (
+        "Dark",
+        "Dunkel",
+    ),
+    (
+        "Accent color (optional, hex color such as #5865f2)",
+        "Akzentfarbe (optional, Hex-Farbe wie #5865f2)",
+    ),
+    (
+        "Invalid accent color, expected a hex color such as #5865f2",
+        "Ungültige Akzentfarbe, erwartet wird eine Hex-Farbe wie #5865f2",
+    ),
+    (
+        "Proton",
+        "Proton",
+    ),
+    (
+        "When running through Proton the game must load the patched binkw32.dll, add the following to the game launch options in Steam:",
+        "Bei der Ausführung über Proton muss das Spiel die gepatchte binkw32.dll laden, füge Folgendes zu den Startoptionen des Spiels in Steam hinzu:",
+    ),
+    (
+        "Add to Steam launch options",
+        "Zu den Steam-Startoptionen hinzufügen",
+    ),
+    (
+        "Copy launch options",
+        "Startoptionen kopieren",
+    ),
+    (
+        "Updating launch options...",
+        "Startoptionen werden aktualisiert...",
+    ),
+    (
+        "Launch options are already set",
+        "Die Startoptionen sind bereits gesetzt",
+    ),
+    (
+        "Launch options updated, restart Steam to use them",
+        "Startoptionen aktualisiert, starte Steam neu, um sie zu verwenden",
+    ),
+    (
+        "Copied launch options to clipboard",
+        "Startoptionen in die Zwischenablage kopiert",
+    ),
+    (
+        "failed to update launch options: {}",
+        "Startoptionen konnten nicht aktualisiert werden: {}",
+    ),
+    (
+        "Verified",
+        "Überprüft",
+    ),
+    (
+        "Plugin update available ({})",
+        "Plugin-Update verfügbar ({})",
+    ),
+    (
+        "Update",
+        "Aktualisieren",
+    ),
+    (
+        "Cannot reach GitHub, check your internet connection",
+        "GitHub ist nicht erreichbar, überprüfe deine Internetverbindung",
+    ),
+    (
+        "GitHub rate limited, wait a while and try again",
+        "GitHub-Anfragelimit erreicht, warte eine Weile und versuche es erneut",
+    ),
+    (
+        "Invalid plugin repository, expected a GitHub repository such as PocketRelay/PocketRelayClientPlugin",
+        "Ungültiges Plugin-Repository, erwartet wird ein GitHub-Repository wie PocketRelay/PocketRelayClientPlugin",
+    ),
+    (
+        "Invalid plugin asset name, expected a file name such as pocket-relay-plugin.asi",
+        "Ungültiger Plugin-Dateiname, erwartet wird ein Dateiname wie pocket-relay-plugin.asi",
+    ),
+    (
+        "Plugin source (change to install a fork or test build of the plugin)",
+        "Plugin-Quelle (ändern, um einen Fork oder Test-Build des Plugins zu installieren)",
+    ),
+    (
+        "Use official plugin",
+        "Offizielles Plugin verwenden",
+    ),
+    (
+        "Using plugin releases from {} ({})",
+        "Plugin-Versionen werden von {} ({}) verwendet",
+    ),
+    (
+        "binkw32.dll has been replaced by another ASI loader, such as the ME3Tweaks binkw32 bypass",
+        "binkw32.dll wurde durch einen anderen ASI-Loader ersetzt, etwa den ME3Tweaks-binkw32-Bypass",
+    ),
+    (
+        "Apply the patch to replace it, plugins in the ASI folder will still be loaded",
+        "Wende den Patch an, um sie zu ersetzen, Plugins im ASI-Ordner werden weiterhin geladen",
+    ),
+    (
+        "binkw32.dll is not a known version, it may be modified or corrupted",
+        "binkw32.dll ist keine bekannte Version, sie ist möglicherweise verändert oder beschädigt",
+    ),
+    (
+        "Apply the patch to replace it, or verify the game files through your launcher",
+        "Wende den Patch an, um sie zu ersetzen, oder überprüfe die Spieldateien über deinen Launcher",
+    ),
+    (
+        "{} is a wrapper loader (such as Ultimate ASI Loader or ReShade), it can load plugins twice or stop the client plugin from loading",
+        "{} ist ein Wrapper-Loader (etwa Ultimate ASI Loader oder ReShade), er kann Plugins doppelt laden oder das Laden des Client-Plugins verhindern",
+    ),
+    (
+        "Remove or rename the file if the client plugin doesn't load",
+        "Entferne oder benenne die Datei um, wenn das Client-Plugin nicht geladen wird",
+    ),
+    (
+        "The {} folder contains ASI plugins for another ASI loader",
+        "Der Ordner {} enthält ASI-Plugins für einen anderen ASI-Loader",
+    ),
+    (
+        "Move the plugins into the ASI folder so they are only loaded once",
+        "Verschiebe die Plugins in den ASI-Ordner, damit sie nur einmal geladen werden",
+    ),
+    (
+        "ASI plugin {} is loaded with the client plugin",
+        "Das ASI-Plugin {} wird zusammen mit dem Client-Plugin geladen",
+    ),
+    (
+        "Disable it from the ASI plugins list if the game crashes or can't connect",
+        "Deaktiviere es in der ASI-Pluginliste, wenn das Spiel abstürzt oder keine Verbindung herstellen kann",
+    ),
+    (
+        "DLC mods are installed: {}",
+        "DLC-Mods sind installiert: {}",
+    ),
+    (
+        "Players in the same lobby need the same multiplayer mods, disable mods that change multiplayer if you have trouble joining games",
+        "Spieler in derselben Lobby brauchen dieselben Mehrspieler-Mods, deaktiviere Mods, die den Mehrspielermodus verändern, wenn du Probleme beim Beitreten hast",
+    ),
+    (
+        "{} is a Legendary Edition file, Legendary Edition mods don't work with the original Mass Effect 3",
+        "{} ist eine Datei der Legendary Edition, Mods der Legendary Edition funktionieren nicht mit dem originalen Mass Effect 3",
+    ),
+    (
+        "Remove the mod or repair the game files through your launcher",
+        "Entferne die Mod oder repariere die Spieldateien über deinen Launcher",
+    ),
+    (
+        "Compatibility",
+        "Kompatibilität",
+    ),
+    (
+        "Scan again",
+        "Erneut prüfen",
+    ),
+    (
+        "Scanning game folder...",
+        "Spielordner wird geprüft...",
+    ),
+    (
+        "failed to scan game folder: {}",
+        "Prüfen des Spielordners fehlgeschlagen: {}",
+    ),
+    (
+        "No known conflicts were found",
+        "Es wurden keine bekannten Konflikte gefunden",
+    ),
+    (
+        "Installation report",
+        "Installationsbericht",
+    ),
+    (
+        "Export a report of the game files, or compare against a report from an installation that works",
+        "Exportiere einen Bericht über die Spieldateien oder vergleiche mit dem Bericht einer funktionierenden Installation",
+    ),
+    (
+        "Export report",
+        "Bericht exportieren",
+    ),
+    (
+        "Compare with report",
+        "Mit Bericht vergleichen",
+    ),
+    (
+        "Reading game files...",
+        "Spieldateien werden gelesen...",
+    ),
+    (
+        "Exported report to {}",
+        "Bericht nach {} exportiert",
+    ),
+    (
+        "failed to create report: {}",
+        "Erstellen des Berichts fehlgeschlagen: {}",
+    ),
+    (
+        "The installation matches the report",
+        "Die Installation stimmt mit dem Bericht überein",
+    ),
+    (
+        "Differences from the report:",
+        "Unterschiede zum Bericht:",
+    ),
+    (
+        "Current: {}\nReport: {}",
+        "Aktuell: {}\nBericht: {}",
+    ),
+    (
+        "Installation complete",
+        "Installation abgeschlossen",
+    ),
+    (
+        "Pocket Relay plugin {} installed",
+        "Pocket Relay-Plugin {} installiert",
+    ),
+    (
+        "Pocket Relay plugin installed",
+        "Pocket Relay-Plugin installiert",
+    ),
+    (
+        "Installation failed",
+        "Installation fehlgeschlagen",
+    ),
+    (
+        "Mass Effect 3 is running, close the game and try again",
+        "Mass Effect 3 läuft, schließe das Spiel und versuche es erneut",
+    ),
+    (
+        "Unlimited",
+        "Unbegrenzt",
+    ),
+    (
+        "{} KB/s",
+        "{} KB/s",
+    ),
+    (
+        "Download speed limit (for metered or shared connections): {}",
+        "Download-Geschwindigkeitsbegrenzung (für getaktete oder geteilte Verbindungen): {}",
+    ),
+    (
+        "Show advanced details",
+        "Erweiterte Details anzeigen",
+    ),
+    (
+        "Hide advanced details",
+        "Erweiterte Details ausblenden",
+    ),
+    (
+        "missing",
+        "fehlt",
+    ),
+    (
+        "present",
+        "vorhanden",
+    ),
+    (
+        "unknown",
+        "unbekannt",
+    ),
+    (
+        "binkw32.dll SHA256: {}",
+        "binkw32.dll SHA256: {}",
+    ),
+    (
+        "binkw23.dll: {}",
+        "binkw23.dll: {}",
+    ),
+    (
+        "Plugin SHA256: {}",
+        "Plugin SHA256: {}",
+    ),
+    (
+        "Plugin size: {}",
+        "Plugin-Größe: {}",
+    ),
+    (
+        "Plugin modified: {}",
+        "Plugin geändert: {}",
+    ),
+    (
+        "Plugin file: not installed",
+        "Plugin-Datei: nicht installiert",
+    ),
+    (
+        "Back up the plugin config and logs, restored when reinstalling",
+        "Plugin-Konfiguration und Protokolle sichern, werden bei einer Neuinstallation wiederhergestellt",
+    ),
+    (
+        "Downloads",
+        "Downloads",
+    ),
+    (
+        "Clear finished",
+        "Abgeschlossene entfernen",
+    ),
+    (
+        "Queued",
+        "In Warteschlange",
+    ),
+    (
+        "Downloading...",
+        "Wird heruntergeladen...",
+    ),
+    (
+        "Finished",
+        "Abgeschlossen",
+    ),
+    (
+        "Failed: {}",
+        "Fehlgeschlagen: {}",
+    ),
+    (
+        "Cancelled",
+        "Abgebrochen",
+    ),
+    (
+        "The installer is damaged and can't safely patch your game. This is usually caused by an incomplete download or an antivirus modifying the installer, download the installer again and add an exclusion for it in your antivirus",
+        "Das Installationsprogramm ist beschädigt und kann dein Spiel nicht sicher patchen. Ursache ist meist ein unvollständiger Download oder ein Antivirenprogramm, das das Installationsprogramm verändert hat. Lade das Installationsprogramm erneut herunter und füge in deinem Antivirenprogramm eine Ausnahme dafür hinzu",
+    ),
+    (
+        "The plugin was removed right after it was installed, this is usually your antivirus falsely detecting the plugin as a threat and quarantining it.",
+        "Das Plugin wurde direkt nach der Installation entfernt. Meist hat dein Antivirenprogramm das Plugin fälschlicherweise als Bedrohung erkannt und in Quarantäne verschoben.",
+    ),
+    (
+        "Add an exclusion for the following folder in your antivirus (for Windows Defender: Virus & threat protection > Manage settings > Exclusions), then reinstall the plugin:",
+        "Füge in deinem Antivirenprogramm eine Ausnahme für den folgenden Ordner hinzu (für Windows Defender: Viren- & Bedrohungsschutz > Einstellungen verwalten > Ausschlüsse) und installiere das Plugin dann erneut:",
+    ),
+    (
+        "Reinstall",
+        "Neu installieren",
+    ),
+    (
+        "Default plugin release channel",
+        "Standard-Veröffentlichungskanal des Plugins",
+    ),
+    (
+        "Stable",
+        "Stabil",
+    ),
+    (
+        "Beta",
+        "Beta",
+    ),
+    (
+        "Folder for downloads and cached releases (optional, uses the system folders when empty)",
+        "Ordner für Downloads und zwischengespeicherte Veröffentlichungen (optional, verwendet die Systemordner wenn leer)",
+    ),
+    (
+        "System folders",
+        "Systemordner",
+    ),
+    (
+        "Browse...",
+        "Durchsuchen...",
+    ),
+    (
+        "Invalid cache folder, expected a full folder path",
+        "Ungültiger Cache-Ordner, erwartet wird ein vollständiger Ordnerpfad",
+    ),
+    (
+        "Launch Mass Effect 3",
+        "Mass Effect 3 starten",
+    ),
+    (
+        "failed to launch game: {}",
+        "Spiel konnte nicht gestartet werden: {}",
+    ),
+    (
+        "Plugin {} is too new for your server (version {}), update the server or pick an older plugin version",
+        "Plugin {} ist zu neu für deinen Server (Version {}), aktualisiere den Server oder wähle eine ältere Plugin-Version",
+    ),
+    (
+        "Plugin {} is too old for your server (version {}), pick a newer plugin version",
+        "Plugin {} ist zu alt für deinen Server (Version {}), wähle eine neuere Plugin-Version",
+    ),
+    (
+        "Network diagnostics",
+        "Netzwerkdiagnose",
+    ),
+    (
+        "Resolve api.github.com",
+        "api.github.com auflösen",
+    ),
+    (
+        "Connect to api.github.com",
+        "Mit api.github.com verbinden",
+    ),
+    (
+        "Secure connection (TLS)",
+        "Sichere Verbindung (TLS)",
+    ),
+    (
+        "GitHub API",
+        "GitHub-API",
+    ),
+    (
+        "Download server",
+        "Download-Server",
+    ),
+    (
+        "skipped",
+        "übersprungen",
+    ),
+    (
+        "Checking connection...",
+        "Verbindung wird geprüft...",
+    ),
+    (
+        "Use \"Network diagnostics\" in the logs section to find out why",
+        "Verwende \"Netzwerkdiagnose\" im Protokollbereich, um die Ursache zu finden",
+    ),
+    (
+        "Plugin file",
+        "Plugin-Datei",
+    ),
+    (
+        "Plugin matches the checksum published with the release",
+        "Das Plugin stimmt mit der mit dem Release veröffentlichten Prüfsumme überein",
+    ),
+    (
+        "Release doesn't include a checksums file for the plugin",
+        "Das Release enthält keine Prüfsummendatei für das Plugin",
+    ),
+    (
+        "Interface scale: {}%",
+        "Oberflächenskalierung: {}%",
+    ),
+    (
+        "Copy error",
+        "Fehler kopieren",
+    ),
+    (
+        "Release feeds for additional channels such as nightly builds (comma separated, name=URL)",
+        "Release-Feeds für zusätzliche Kanäle wie Nightly-Builds (kommagetrennt, Name=URL)",
+    ),
+    (
+        "Plugin logs",
+        "Plugin-Protokolle",
+    ),
+    (
+        "View plugin logs",
+        "Plugin-Protokolle anzeigen",
+    ),
+    (
+        "Hide plugin logs",
+        "Plugin-Protokolle ausblenden",
+    ),
+    (
+        "Copy plugin logs",
+        "Plugin-Protokolle kopieren",
+    ),
+    (
+        "Export plugin logs",
+        "Plugin-Protokolle exportieren",
+    ),
+    (
+        "Copied plugin logs to clipboard",
+        "Plugin-Protokolle in die Zwischenablage kopiert",
+    ),
+    (
+        "Exported plugin logs to {}",
+        "Plugin-Protokolle nach {} exportiert",
+    ),
+    (
+        "failed to export plugin logs: {}",
+        "Plugin-Protokolle konnten nicht exportiert werden: {}",
+    ),
+    (
+        "Reading plugin logs...",
+        "Plugin-Protokolle werden gelesen...",
+    ),
+    (
+        "The plugin hasn't written any logs yet, start the game with the plugin installed",
+        "Das Plugin hat noch keine Protokolle geschrieben, starte das Spiel mit installiertem Plugin",
+    ),
+    (
+        "failed to read plugin logs: {}",
+        "Plugin-Protokolle konnten nicht gelesen werden: {}",
+    ),
+    (
+        "Leftover files",
+        "Übrig gebliebene Dateien",
+    ),
+    (
+        "No files from previous installs were found",
+        "Es wurden keine Dateien früherer Installationen gefunden",
+    ),
+    (
+        "These files were left behind by a previous or manual install and can stop the plugin from working",
+        "Diese Dateien stammen von einer früheren oder manuellen Installation und können verhindern, dass das Plugin funktioniert",
+    ),
+    (
+        "plugin from an older release",
+        "Plugin aus einer älteren Version",
+    ),
+    (
+        "plugin outside of the ASI folder",
+        "Plugin außerhalb des ASI-Ordners",
+    ),
+    (
+        "plugin config outside of the ASI folder",
+        "Plugin-Konfiguration außerhalb des ASI-Ordners",
+    ),
+    (
+        "not used without the patched binkw32.dll",
+        "ohne die gepatchte binkw32.dll nicht verwendet",
+    ),
+    (
+        "Clean up leftover files",
+        "Übrig gebliebene Dateien bereinigen",
+    ),
+    (
+        "Cleaning up leftover files...",
+        "Übrig gebliebene Dateien werden bereinigt...",
+    ),
+    (
+        "Cleaned up leftover files",
+        "Übrig gebliebene Dateien bereinigt",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before cleaning up.",
+        "Mass Effect 3 läuft gerade, schließe das Spiel vor dem Bereinigen.",
+    ),
+    (
+        "Failed to clean up leftover files: {}",
+        "Übrig gebliebene Dateien konnten nicht bereinigt werden: {}",
+    ),
+    (
+        "All installations",
+        "Alle Installationen",
+    ),
+    (
+        "Apply the patch and install the {} plugin to all {} installations",
+        "Den Patch anwenden und das Plugin {} in allen {} Installationen installieren",
+    ),
+    (
+        "Install to all installations",
+        "In allen Installationen installieren",
+    ),
+    (
+        "Waiting",
+        "Wartet",
+    ),
+    (
+        "Installing...",
+        "Wird installiert...",
+    ),
+    (
+        "Installed, checksum verified",
+        "Installiert, Prüfsumme verifiziert",
+    ),
+    (
+        "Installed",
+        "Installiert",
+    ),
+    (
+        "Skipped, the game is running",
+        "Übersprungen, das Spiel läuft",
+    ),
+    (
+        "Portable folder",
+        "Portabler Ordner",
+    ),
+    (
+        "Portable mode, the settings, cache and logs are stored in {}",
+        "Portabler Modus, Einstellungen, Cache und Protokolle werden in {} gespeichert",
+    ),
+    (
+        "Game",
+        "Spiel",
+    ),
+    (
+        "The game can't be changed while changes are being made",
+        "Das Spiel kann nicht gewechselt werden, während Änderungen vorgenommen werden",
+    ),
+    (
+        "Set-up checklist",
+        "Checkliste zur Einrichtung",
+    ),
+    (
+        "The game is installed for all users in Program Files, changes to the game folder need administrator access",
+        "Das Spiel ist für alle Benutzer unter Program Files installiert, Änderungen am Spielordner erfordern Administratorrechte",
+    ),
+    (
+        "The installer is running as administrator and can modify the game folder",
+        "Das Installationsprogramm läuft als Administrator und kann den Spielordner ändern",
+    ),
+    (
+        "The installer can modify the game folder",
+        "Das Installationsprogramm kann den Spielordner ändern",
+    ),
+    (
+        "The game folder can't be modified even as administrator, check the folder permissions and that no antivirus is blocking changes",
+        "Der Spielordner kann selbst als Administrator nicht geändert werden, prüfe die Ordnerberechtigungen und ob ein Virenschutz Änderungen blockiert",
+    ),
+    (
+        "The game loads your own copies of these files instead of the installed ones, remove them from {}: {}",
+        "Das Spiel lädt deine eigenen Kopien dieser Dateien statt der installierten, entferne sie aus {}: {}",
+    ),
+    (
+        "Check again",
+        "Erneut prüfen",
+    ),
+    (
+        "Send anonymous usage statistics",
+        "Anonyme Nutzungsstatistiken senden",
+    ),
+    (
+        "Reports the installer version, operating system and whether each change to the game succeeded, so the most common problems can be fixed. Paths, errors and servers are never sent.",
+        "Meldet die Version des Installationsprogramms, das Betriebssystem und ob jede Änderung am Spiel erfolgreich war, damit die häufigsten Probleme behoben werden können. Pfade, Fehler und Server werden nie gesendet.",
+    ),
+    (
+        "Example of a report that would be sent:",
+        "Beispiel für einen Bericht, der gesendet würde:",
+    ),
+    (
+        "Invalid statistics endpoint, expected an http:// or https:// URL",
+        "Ungültiger Statistik-Endpunkt, erwartet wird eine http://- oder https://-URL",
+    ),
+    (
+        "Enter the endpoint to send usage statistics to",
+        "Gib den Endpunkt ein, an den Nutzungsstatistiken gesendet werden",
+    ),
+    (
+        "Help",
+        "Hilfe",
+    ),
+    (
+        "Close",
+        "Schließen",
+    ),
+    (
+        "Troubleshooting",
+        "Fehlerbehebung",
+    ),
+    (
+        "Loading troubleshooting guide...",
+        "Anleitung zur Fehlerbehebung wird geladen...",
+    ),
+    (
+        "Couldn't fetch the latest troubleshooting guide, showing the saved copy",
+        "Die neueste Anleitung zur Fehlerbehebung konnte nicht abgerufen werden, die gespeicherte Kopie wird angezeigt",
+    ),
+    (
+        "Couldn't fetch the latest troubleshooting guide, showing the copy included with the installer",
+        "Die neueste Anleitung zur Fehlerbehebung konnte nicht abgerufen werden, die im Installationsprogramm enthaltene Kopie wird angezeigt",
+    ),
+    (
+        "More details",
+        "Weitere Details",
+    ),
+    (
+        "Apply patch again",
+        "Patch erneut anwenden",
+    ),
+    (
+        "Dismiss",
+        "Ausblenden",
+    ),
+    (
+        "Patch removed",
+        "Patch entfernt",
+    ),
+    (
+        "The original binkw32.dll was restored, open the installer to apply the patch again",
+        "Die originale binkw32.dll wurde wiederhergestellt, öffne den Installer, um den Patch erneut anzuwenden",
+    ),
+    (
+        "The patch was removed, the original binkw32.dll was restored (Origin and the EA App do this when repairing or updating the game). Apply the patch again to keep using Pocket Relay.",
+        "Der Patch wurde entfernt, die originale binkw32.dll wurde wiederhergestellt (Origin und die EA App tun dies beim Reparieren oder Aktualisieren des Spiels). Wende den Patch erneut an, um Pocket Relay weiter zu nutzen.",
+    ),
+    (
+        "Offer to apply the patch again when Origin or the EA App removes it",
+        "Anbieten, den Patch erneut anzuwenden, wenn Origin oder die EA App ihn entfernt",
+    ),
+    (
+        "Disable Plugin",
+        "Plugin deaktivieren",
+    ),
+    (
+        "Enable Plugin",
+        "Plugin aktivieren",
+    ),
+    (
+        "The Pocket Relay client plugin is disabled, the game will connect to the official servers until its enabled.",
+        "Das Pocket Relay Client-Plugin ist deaktiviert, das Spiel verbindet sich mit den offiziellen Servern, bis es aktiviert wird.",
+    ),
+    (
+        "Enabling plugin...",
+        "Plugin wird aktiviert...",
+    ),
+    (
+        "Disabling plugin...",
+        "Plugin wird deaktiviert...",
+    ),
+    (
+        "failed to enable plugin: {}",
+        "Plugin konnte nicht aktiviert werden: {}",
+    ),
+    (
+        "failed to disable plugin: {}",
+        "Plugin konnte nicht deaktiviert werden: {}",
+    ),
+    (
+        "History",
+        "Verlauf",
+    ),
+    (
+        "Undo",
+        "Rückgängig",
+    ),
+    (
+        "Undoing...",
+        "Wird rückgängig gemacht...",
+    ),
+    (
+        "Applied the patch",
+        "Patch angewendet",
+    ),
+    (
+        "Removed the patch",
+        "Patch entfernt",
+    ),
+    (
+        "Installed the plugin",
+        "Plugin installiert",
+    ),
+    (
+        "Removed the plugin",
+        "Plugin entfernt",
+    ),
+    (
+        "Enabled the plugin",
+        "Plugin aktiviert",
+    ),
+    (
+        "Disabled the plugin",
+        "Plugin deaktiviert",
+    ),
+    (
+        "Applied the patch and installed the plugin",
+        "Patch angewendet und Plugin installiert",
+    ),
+    (
+        "failed to load history: {}",
+        "Verlauf konnte nicht geladen werden: {}",
+    ),
+    (
+        "Loading history...",
+        "Verlauf wird geladen...",
+    ),
+    (
+        "The installer hasn't made any changes to this game",
+        "Der Installer hat keine Änderungen an diesem Spiel vorgenommen",
+    ),
+    (
+        "The game is currently running, close the game before undoing changes.",
+        "Das Spiel läuft gerade, schließe das Spiel, bevor du Änderungen rückgängig machst.",
+    ),
+    (
+        "failed to undo change: {}",
+        "Änderung konnte nicht rückgängig gemacht werden: {}",
+    ),
+    (
+        "Choose folder",
+        "Ordner wählen",
+    ),
+    (
+        "EA App",
+        "EA App",
+    ),
+    (
+        "Store: {}",
+        "Store: {}",
+    ),
+    (
+        "Origin may remove the patch when it updates or repairs the game, apply the patch again if Pocket Relay stops working",
+        "Origin kann den Patch beim Aktualisieren oder Reparieren des Spiels entfernen, wende den Patch erneut an, wenn Pocket Relay nicht mehr funktioniert",
+    ),
+    (
+        "The EA App may remove the patch when it updates or repairs the game, apply the patch again if Pocket Relay stops working",
+        "Die EA App kann den Patch beim Aktualisieren oder Reparieren des Spiels entfernen, wende den Patch erneut an, wenn Pocket Relay nicht mehr funktioniert",
+    ),
+    (
+        "the Pocket Relay patch",
+        "den Pocket Relay-Patch",
+    ),
+    (
+        "another binkw32.dll loader",
+        "einen anderen binkw32.dll-Loader",
+    ),
+    (
+        "Ready to play, the plugin is loaded by {}",
+        "Spielbereit, das Plugin wird durch {} geladen",
+    ),
+    (
+        "Not ready, the plugin is not installed",
+        "Nicht bereit, das Plugin ist nicht installiert",
+    ),
+    (
+        "Not ready, the plugin is disabled",
+        "Nicht bereit, das Plugin ist deaktiviert",
+    ),
+    (
+        "Not ready, {} doesn't load plugins from the ASI folder, apply the patch so the plugin is loaded",
+        "Nicht bereit, {} lädt keine Plugins aus dem ASI-Ordner, wende den Patch an, damit das Plugin geladen wird",
+    ),
+    (
+        "Not ready, nothing will load the plugin, apply the patch so the plugin is loaded",
+        "Nicht bereit, nichts lädt das Plugin, wende den Patch an, damit das Plugin geladen wird",
+    ),
+    (
+        "Keep running in the system tray when the window is closed",
+        "Beim Schließen des Fensters im Infobereich weiterlaufen",
+    ),
+    (
+        "Show installer",
+        "Installer anzeigen",
+    ),
+    (
+        "Check for plugin updates",
+        "Nach Plugin-Updates suchen",
+    ),
+    (
+        "Open game folder",
+        "Spielordner öffnen",
+    ),
+    (
+        "Quit",
+        "Beenden",
+    ),
+    (
+        "Open ASI folder",
+        "ASI-Ordner öffnen",
+    ),
+    (
+        "failed to open folder: {}",
+        "Ordner konnte nicht geöffnet werden: {}",
+    ),
+    (
+        "The plugin will be installed once the release details load...",
+        "Das Plugin wird installiert, sobald die Versionsdetails geladen sind...",
+    ),
+    (
+        "Release pages to load (100 releases each): {}",
+        "Zu ladende Versionsseiten (je 100 Versionen): {}",
+    ),
+    (
+        "Older releases aren't listed, raise the release page limit in the settings to see them",
+        "Ältere Versionen werden nicht aufgeführt, erhöhe das Limit der Versionsseiten in den Einstellungen, um sie zu sehen",
+    ),
+    (
+        "requires installer {}",
+        "erfordert Installer {}",
+    ),
+    (
+        "Plugin {} requires installer version {} or newer, update the installer to install it",
+        "Plugin {} erfordert Installer-Version {} oder neuer, aktualisiere den Installer, um es zu installieren",
+    ),
+    (
+        "Keep downloaded plugins to reinstall them without downloading again",
+        "Heruntergeladene Plugins behalten, um sie ohne erneuten Download neu zu installieren",
+    ),
+    (
+        "Download cache: {} files, {}",
+        "Download-Cache: {} Dateien, {}",
+    ),
+    (
+        "Checking the download cache...",
+        "Download-Cache wird geprüft...",
+    ),
+    (
+        "Clear download cache",
+        "Download-Cache leeren",
+    ),
+    (
+        "Published {}, download size {}",
+        "Veröffentlicht am {}, Downloadgröße {}",
+    ),
+    (
+        "Published {}",
+        "Veröffentlicht am {}",
+    ),
+    (
+        "B",
+        "B",
+    ),
+    (
+        "KB",
+        "KB",
+    ),
+    (
+        "MB",
+        "MB",
+    ),
+    (
+        "GB",
+        "GB",
+    ),
+    (
+        "Checking...",
+        "Wird geprüft...",
+    ),
+    (
+        "Downloading {}%",
+        "Wird heruntergeladen {} %",
+    ),
+    (
+        "Downloading... ({})",
+        "Wird heruntergeladen... ({})",
+    ),
+    (
+        "Verifying...",
+        "Dateien werden überprüft...",
+    ),
+    (
+        "Writing files...",
+        "Dateien werden geschrieben...",
+    ),
+    (
+        "Download size {}",
+        "Downloadgröße {}",
+    ),
+    (
+        "Open the install links on Pocket Relay websites and server dashboards with this installer",
+        "Installationslinks auf Pocket Relay-Webseiten und Server-Dashboards mit diesem Installationsprogramm öffnen",
+    ),
+    (
+        "Open links with the installer",
+        "Links mit dem Installationsprogramm öffnen",
+    ),
+    (
+        "Links now open the installer",
+        "Links öffnen jetzt das Installationsprogramm",
+    ),
+    (
+        "The link could not be opened: {}",
+        "Der Link konnte nicht geöffnet werden: {}",
+    ),
+    (
+        "Opened from a link to the server {}, save the server connection once the plugin is installed to connect to it",
+        "Über einen Link zum Server {} geöffnet, speichere die Serververbindung nach der Installation des Plugins, um dich damit zu verbinden",
+    ),
+    (
+        "Are you sure you want to delete {}? It can't be restored",
+        "Möchtest du {} wirklich löschen? Es kann nicht wiederhergestellt werden",
+    ),
+];
@@ -0,0 +1,1434 @@
+//! French translations
+
+use super::Translations;
+
+pub const TRANSLATIONS: Translations = &[
+    (
+        "Stable ({})",
+        "Stable ({})",
+    ),
+    (
+        "Beta ({})",
+        "Bêta ({})",
+    ),
+    (
+        "Please click the button below to choose your game path. When the file picker opens navigate to the folder containing MassEffect3.exe and pick that file",
+        "Cliquez sur le bouton ci-dessous pour choisir le chemin du jeu. Lorsque le sélecteur de fichiers s'ouvre, accédez au dossier contenant MassEffect3.exe et sélectionnez ce fichier",
+    ),
+    (
+        "Choose game path",
+        "Choisir le chemin du jeu",
+    ),
+    (
+        "Settings",
+        "Paramètres",
+    ),
+    (
+        "failed to pick file: {}",
+        "impossible de sélectionner le fichier : {}",
+    ),
+    (
+        "Or choose one of the following detected game installs:",
+        "Ou choisissez l'une des installations du jeu détectées :",
+    ),
+    (
+        "Back",
+        "Retour",
+    ),
+    (
+        "Preview changes",
+        "Prévisualiser les modifications",
+    ),
+    (
+        "Installations",
+        "Installations",
+    ),
+    (
+        "Add installation",
+        "Ajouter une installation",
+    ),
+    (
+        "The game folder can't be modified without administrator access, restart the installer as administrator to make changes.",
+        "Le dossier du jeu ne peut pas être modifié sans accès administrateur, redémarrez l'installateur en tant qu'administrateur pour effectuer des modifications.",
+    ),
+    (
+        "Restart as administrator",
+        "Redémarrer en tant qu'administrateur",
+    ),
+    (
+        "Retry",
+        "Réessayer",
+    ),
+    (
+        "Apply the patch and install the {} plugin in one step",
+        "Appliquer le patch et installer le plugin {} en une seule étape",
+    ),
+    (
+        "Install everything",
+        "Tout installer",
+    ),
+    (
+        "Applying patch...",
+        "Application du patch...",
+    ),
+    (
+        "Installing plugin...",
+        "Installation du plugin...",
+    ),
+    (
+        "Patch and plugin installed",
+        "Patch et plugin installés",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before installing.",
+        "Mass Effect 3 est en cours d'exécution, fermez le jeu avant d'installer.",
+    ),
+    (
+        "Failed to install: {}",
+        "Échec de l'installation : {}",
+    ),
+    (
+        "Cancel",
+        "Annuler",
+    ),
+    (
+        "Preparing preview...",
+        "Préparation de l'aperçu...",
+    ),
+    (
+        "Failed to preview changes: {}",
+        "Échec de l'aperçu des modifications : {}",
+    ),
+    (
+        "The following changes will be made:",
+        "Les modifications suivantes seront effectuées :",
+    ),
+    (
+        "Confirm",
+        "Confirmer",
+    ),
+    (
+        "Server Connection",
+        "Connexion au serveur",
+    ),
+    (
+        "Address of the Pocket Relay server the plugin should connect to",
+        "Adresse du serveur Pocket Relay auquel le plugin doit se connecter",
+    ),
+    (
+        "Save",
+        "Enregistrer",
+    ),
+    (
+        "Loading config...",
+        "Chargement de la configuration...",
+    ),
+    (
+        "Saving config...",
+        "Enregistrement de la configuration...",
+    ),
+    (
+        "Saved server connection",
+        "Connexion au serveur enregistrée",
+    ),
+    (
+        "Logs",
+        "Journaux",
+    ),
+    (
+        "Hide logs",
+        "Masquer les journaux",
+    ),
+    (
+        "Show logs",
+        "Afficher les journaux",
+    ),
+    (
+        "Copy diagnostics to clipboard",
+        "Copier les diagnostics dans le presse-papiers",
+    ),
+    (
+        "Copied diagnostics to clipboard",
+        "Diagnostics copiés dans le presse-papiers",
+    ),
+    (
+        "Uninstall",
+        "Désinstaller",
+    ),
+    (
+        "Remove the plugin, its config and log files, and restore the original binkw32.dll",
+        "Supprimer le plugin, sa configuration et ses fichiers journaux, et restaurer le binkw32.dll d'origine",
+    ),
+    (
+        "Remove the ASI folder if its empty",
+        "Supprimer le dossier ASI s'il est vide",
+    ),
+    (
+        "Uninstall everything",
+        "Tout désinstaller",
+    ),
+    (
+        "Are you sure you want to remove everything Pocket Relay has installed?",
+        "Voulez-vous vraiment supprimer tout ce que Pocket Relay a installé ?",
+    ),
+    (
+        "Uninstalling...",
+        "Désinstallation...",
+    ),
+    (
+        "Nothing to uninstall",
+        "Rien à désinstaller",
+    ),
+    (
+        "Uninstalled everything",
+        "Tout a été désinstallé",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before uninstalling.",
+        "Mass Effect 3 est en cours d'exécution, fermez le jeu avant de désinstaller.",
+    ),
+    (
+        "Failed to uninstall: {}",
+        "Échec de la désinstallation : {}",
+    ),
+    (
+        "ASI Plugins",
+        "Plugins ASI",
+    ),
+    (
+        "Refresh",
+        "Actualiser",
+    ),
+    (
+        "Loading plugins...",
+        "Chargement des plugins...",
+    ),
+    (
+        "failed to load plugins: {}",
+        "impossible de charger les plugins : {}",
+    ),
+    (
+        "No ASI plugins are installed",
+        "Aucun plugin ASI n'est installé",
+    ),
+    (
+        "Enabled",
+        "Activé",
+    ),
+    (
+        "Disabled",
+        "Désactivé",
+    ),
+    (
+        "Disable",
+        "Désactiver",
+    ),
+    (
+        "Enable",
+        "Activer",
+    ),
+    (
+        "Delete",
+        "Supprimer",
+    ),
+    (
+        "Language",
+        "Langue",
+    ),
+    (
+        "Proxy used when connecting to GitHub",
+        "Proxy utilisé pour se connecter à GitHub",
+    ),
+    (
+        "System proxy",
+        "Proxy système",
+    ),
+    (
+        "No proxy",
+        "Aucun proxy",
+    ),
+    (
+        "Manual proxy",
+        "Proxy manuel",
+    ),
+    (
+        "Mirrors to use when GitHub is unreachable (comma separated, URL prefixes or URLs containing {url})",
+        "Miroirs à utiliser lorsque GitHub est inaccessible (séparés par des virgules, préfixes d'URL ou URL contenant {url})",
+    ),
+    (
+        "GitHub personal access token (optional, raises the GitHub rate limit on shared networks)",
+        "Jeton d'accès personnel GitHub (facultatif, augmente la limite de requêtes GitHub sur les réseaux partagés)",
+    ),
+    (
+        "Token",
+        "Jeton",
+    ),
+    (
+        "A new version of the installer is available ({})",
+        "Une nouvelle version de l'installateur est disponible ({})",
+    ),
+    (
+        "Update installer",
+        "Mettre à jour l'installateur",
+    ),
+    (
+        "Updating installer...",
+        "Mise à jour de l'installateur...",
+    ),
+    (
+        "failed to update installer: {}",
+        "impossible de mettre à jour l'installateur : {}",
+    ),
+    (
+        "Your game is patched",
+        "Votre jeu est patché",
+    ),
+    (
+        "Remove Patch",
+        "Retirer le patch",
+    ),
+    (
+        "Your game is not patched, you must apply the patch to use the client plugin.",
+        "Votre jeu n'est pas patché, vous devez appliquer le patch pour utiliser le plugin client.",
+    ),
+    (
+        "Apply Patch",
+        "Appliquer le patch",
+    ),
+    (
+        "Your game has an unrecognized binkw32.dll, it may be from another mod or corrupted. Replacing it will back up the current file to binkw32.dll.bak",
+        "Votre jeu contient un binkw32.dll non reconnu, il provient peut-être d'un autre mod ou est corrompu. Le remplacer sauvegardera le fichier actuel dans binkw32.dll.bak",
+    ),
+    (
+        "Back up and replace",
+        "Sauvegarder et remplacer",
+    ),
+    (
+        "Installing patch...",
+        "Installation du patch...",
+    ),
+    (
+        "Uninstalling patch...",
+        "Désinstallation du patch...",
+    ),
+    (
+        "Patch successfully removed.",
+        "Patch retiré avec succès.",
+    ),
+    (
+        "Patch successfully installed.",
+        "Patch installé avec succès.",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before changing the patch.",
+        "Mass Effect 3 est en cours d'exécution, fermez le jeu avant de modifier le patch.",
+    ),
+    (
+        "failed to add patch: {}",
+        "impossible d'appliquer le patch : {}",
+    ),
+    (
+        "failed to remove patch: {}",
+        "impossible de retirer le patch : {}",
+    ),
+    (
+        "You have the Pocket Relay client plugin installed.",
+        "Le plugin client Pocket Relay est installé.",
+    ),
+    (
+        "Remove Plugin",
+        "Retirer le plugin",
+    ),
+    (
+        "Unknown",
+        "Inconnue",
+    ),
+    (
+        "Installed: {}",
+        "Installée : {}",
+    ),
+    (
+        "Installed: {} (Latest)",
+        "Installée : {} (Dernière)",
+    ),
+    (
+        "Installed: {} \u{2014} Update available: {}",
+        "Installée : {} \u{2014} Mise à jour disponible : {}",
+    ),
+    (
+        "Update Plugin",
+        "Mettre à jour le plugin",
+    ),
+    (
+        "You do not have the Pocket Relay client plugin installed",
+        "Le plugin client Pocket Relay n'est pas installé",
+    ),
+    (
+        "Uninstalling plugin...",
+        "Désinstallation du plugin...",
+    ),
+    (
+        "Pocket Relay client plugin successfully installed.",
+        "Plugin client Pocket Relay installé avec succès.",
+    ),
+    (
+        "Pocket Relay client plugin successfully removed.",
+        "Plugin client Pocket Relay retiré avec succès.",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before changing the plugin.",
+        "Mass Effect 3 est en cours d'exécution, fermez le jeu avant de modifier le plugin.",
+    ),
+    (
+        "failed to update plugin: {}",
+        "impossible de mettre à jour le plugin : {}",
+    ),
+    (
+        "failed to install plugin: {}",
+        "impossible d'installer le plugin : {}",
+    ),
+    (
+        "failed to remove plugin: {}",
+        "impossible de retirer le plugin : {}",
+    ),
+    (
+        "Install from file...",
+        "Installer depuis un fichier...",
+    ),
+    (
+        "Loading latest plugin version details...",
+        "Chargement des détails de la dernière version du plugin...",
+    ),
+    (
+        "Unable to load latest plugin version: {}",
+        "Impossible de charger la dernière version du plugin : {}",
+    ),
+    (
+        "The latest version of the plugin client is {}",
+        "La dernière version du plugin client est {}",
+    ),
+    (
+        "Add Plugin",
+        "Ajouter le plugin",
+    ),
+    (
+        "Select version",
+        "Choisir la version",
+    ),
+    (
+        "Hide notes",
+        "Masquer les notes",
+    ),
+    (
+        "Release notes",
+        "Notes de version",
+    ),
+    (
+        "This release has no release notes",
+        "Cette version n'a pas de notes de version",
+    ),
+    (
+        "View on GitHub",
+        "Voir sur GitHub",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before changing plugins.",
+        "Mass Effect 3 est en cours d'exécution, fermez le jeu avant de modifier les plugins.",
+    ),
+    (
+        "failed to change plugin: {}",
+        "impossible de modifier le plugin : {}",
+    ),
+    (
+        "failed to load config: {}",
+        "impossible de charger la configuration : {}",
+    ),
+    (
+        "failed to save config: {}",
+        "impossible d'enregistrer la configuration : {}",
+    ),
+    (
+        "Failed to switch installation: {}",
+        "Impossible de changer d'installation : {}",
+    ),
+    (
+        "{} will be created, {}",
+        "{} sera créé, {}",
+    ),
+    (
+        "{} will be overwritten (backed up to {}), {}",
+        "{} sera écrasé (sauvegardé dans {}), {}",
+    ),
+    (
+        "{} will be overwritten, {}",
+        "{} sera écrasé, {}",
+    ),
+    (
+        "{} will be restored from {}",
+        "{} sera restauré depuis {}",
+    ),
+    (
+        "{} will be deleted",
+        "{} sera supprimé",
+    ),
+    (
+        "Administrator access is required to modify the game folder, use \"Restart as administrator\" or install the game outside Program Files",
+        "Un accès administrateur est nécessaire pour modifier le dossier du jeu, utilisez \"Redémarrer en tant qu'administrateur\" ou installez le jeu en dehors de Program Files",
+    ),
+    (
+        "Theme",
+        "Thème",
+    ),
+    (
+        "System",
+        "Système",
+    ),
+    (
+        "Light",
+        "Clair",
+    ),
+    (
+        "Dark",
+        "Sombre",
+    ),
+    (
+        "Accent color (optional, hex color such as #5865f2)",
+        "Couleur d'accent (facultatif, couleur hexadécimale comme #5865f2)",
+    ),
+    (
+        "Invalid accent color, expected a hex color such as #5865f2",
+        "Couleur d'accent invalide, une couleur hexadécimale comme #5865f2 est attendue",
+    ),
+    (
+        "Proton",
+        "Proton",
+    ),
+    (
+        "When running through Proton the game must load the patched binkw32.dll, add the following to the game launch options in Steam:",
+        "Lors de l'exécution via Proton, le jeu doit charger le binkw32.dll patché, ajoutez ceci aux options de lancement du jeu dans Steam :",
+    ),
+    (
+        "Add to Steam launch options",
+        "Ajouter aux options de lancement Steam",
+    ),
+    (
+        "Copy launch options",
+        "Copier les options de lancement",
+    ),
+    (
+        "Updating launch options...",
+        "Mise à jour des options de lancement...",
+    ),
+    (
+        "Launch options are already set",
+        "Les options de lancement sont déjà définies",
+    ),
+    (
+        "Launch options updated, restart Steam to use them",
+        "Options de lancement mises à jour, redémarrez Steam pour les utiliser",
+    ),
+    (
+        "Copied launch options to clipboard",
+        "Options de lancement copiées dans le presse-papiers",
+    ),
+    (
+        "failed to update launch options: {}",
+        "impossible de mettre à jour les options de lancement : {}",
+    ),
+    (
+        "Verified",
+        "Vérifié",
+    ),
+    (
+        "Plugin update available ({})",
+        "Mise à jour du plugin disponible ({})",
+    ),
+    (
+        "Update",
+        "Mettre à jour",
+    ),
+    (
+        "Cannot reach GitHub, check your internet connection",
+        "Impossible de joindre GitHub, vérifiez votre connexion Internet",
+    ),
+    (
+        "GitHub rate limited, wait a while and try again",
+        "Limite de requêtes GitHub atteinte, patientez un moment puis réessayez",
+    ),
+    (
+        "Invalid plugin repository, expected a GitHub repository such as PocketRelay/PocketRelayClientPlugin",
+        "Dépôt du plugin invalide, un dépôt GitHub tel que PocketRelay/PocketRelayClientPlugin est attendu",
+    ),
+    (
+        "Invalid plugin asset name, expected a file name such as pocket-relay-plugin.asi",
+        "Nom de fichier du plugin invalide, un nom de fichier tel que pocket-relay-plugin.asi est attendu",
+    ),
+    (
+        "Plugin source (change to install a fork or test build of the plugin)",
+        "Source du plugin (à modifier pour installer un fork ou une version de test du plugin)",
+    ),
+    (
+        "Use official plugin",
+        "Utiliser le plugin officiel",
+    ),
+    (
+        "Using plugin releases from {} ({})",
+        "Utilisation des versions du plugin de {} ({})",
+    ),
+    (
+        "binkw32.dll has been replaced by another ASI loader, such as the ME3Tweaks binkw32 bypass",
+        "binkw32.dll a été remplacé par un autre chargeur ASI, comme le contournement binkw32 de ME3Tweaks",
+    ),
+    (
+        "Apply the patch to replace it, plugins in the ASI folder will still be loaded",
+        "Appliquez le patch pour le remplacer, les plugins du dossier ASI seront toujours chargés",
+    ),
+    (
+        "binkw32.dll is not a known version, it may be modified or corrupted",
+        "binkw32.dll n'est pas une version connue, il est peut-être modifié ou corrompu",
+    ),
+    (
+        "Apply the patch to replace it, or verify the game files through your launcher",
+        "Appliquez le patch pour le remplacer, ou vérifiez les fichiers du jeu via votre launcher",
+    ),
+    (
+        "{} is a wrapper loader (such as Ultimate ASI Loader or ReShade), it can load plugins twice or stop the client plugin from loading",
+        "{} est un chargeur wrapper (comme Ultimate ASI Loader ou ReShade), il peut charger les plugins deux fois ou empêcher le chargement du plugin client",
+    ),
+    (
+        "Remove or rename the file if the client plugin doesn't load",
+        "Supprimez ou renommez le fichier si le plugin client ne se charge pas",
+    ),
+    (
+        "The {} folder contains ASI plugins for another ASI loader",
+        "Le dossier {} contient des plugins ASI pour un autre chargeur ASI",
+    ),
+    (
+        "Move the plugins into the ASI folder so they are only loaded once",
+        "Déplacez les plugins dans le dossier ASI pour qu'ils ne soient chargés qu'une fois",
+    ),
+    (
+        "ASI plugin {} is loaded with the client plugin",
+        "Le plugin ASI {} est chargé avec le plugin client",
+    ),
+    (
+        "Disable it from the ASI plugins list if the game crashes or can't connect",
+        "Désactivez-le dans la liste des plugins ASI si le jeu plante ou ne peut pas se connecter",
+    ),
+    (
+        "DLC mods are installed: {}",
+        "Des mods DLC sont installés : {}",
+    ),
+    (
+        "Players in the same lobby need the same multiplayer mods, disable mods that change multiplayer if you have trouble joining games",
+        "Les joueurs d'un même salon ont besoin des mêmes mods multijoueur, désactivez les mods qui modifient le multijoueur si vous avez du mal à rejoindre des parties",
+    ),
+    (
+        "{} is a Legendary Edition file, Legendary Edition mods don't work with the original Mass Effect 3",
+        "{} est un fichier de l'Édition Légendaire, les mods de l'Édition Légendaire ne fonctionnent pas avec le Mass Effect 3 original",
+    ),
+    (
+        "Remove the mod or repair the game files through your launcher",
+        "Supprimez le mod ou réparez les fichiers du jeu via votre launcher",
+    ),
+    (
+        "Compatibility",
+        "Compatibilité",
+    ),
+    (
+        "Scan again",
+        "Analyser à nouveau",
+    ),
+    (
+        "Scanning game folder...",
+        "Analyse du dossier du jeu...",
+    ),
+    (
+        "failed to scan game folder: {}",
+        "échec de l'analyse du dossier du jeu : {}",
+    ),
+    (
+        "No known conflicts were found",
+        "Aucun conflit connu n'a été trouvé",
+    ),
+    (
+        "Installation report",
+        "Rapport d'installation",
+    ),
+    (
+        "Export a report of the game files, or compare against a report from an installation that works",
+        "Exportez un rapport des fichiers du jeu, ou comparez-le avec le rapport d'une installation qui fonctionne",
+    ),
+    (
+        "Export report",
+        "Exporter le rapport",
+    ),
+    (
+        "Compare with report",
+        "Comparer avec un rapport",
+    ),
+    (
+        "Reading game files...",
+        "Lecture des fichiers du jeu...",
+    ),
+    (
+        "Exported report to {}",
+        "Rapport exporté vers {}",
+    ),
+    (
+        "failed to create report: {}",
+        "échec de la création du rapport : {}",
+    ),
+    (
+        "The installation matches the report",
+        "L'installation correspond au rapport",
+    ),
+    (
+        "Differences from the report:",
+        "Différences avec le rapport :",
+    ),
+    (
+        "Current: {}\nReport: {}",
+        "Actuel : {}\nRapport : {}",
+    ),
+    (
+        "Installation complete",
+        "Installation terminée",
+    ),
+    (
+        "Pocket Relay plugin {} installed",
+        "Plugin Pocket Relay {} installé",
+    ),
+    (
+        "Pocket Relay plugin installed",
+        "Plugin Pocket Relay installé",
+    ),
+    (
+        "Installation failed",
+        "Échec de l'installation",
+    ),
+    (
+        "Mass Effect 3 is running, close the game and try again",
+        "Mass Effect 3 est en cours d'exécution, fermez le jeu et réessayez",
+    ),
+    (
+        "Unlimited",
+        "Illimitée",
+    ),
+    (
+        "{} KB/s",
+        "{} Ko/s",
+    ),
+    (
+        "Download speed limit (for metered or shared connections): {}",
+        "Limite de vitesse de téléchargement (pour les connexions limitées ou partagées) : {}",
+    ),
+    (
+        "Show advanced details",
+        "Afficher les détails avancés",
+    ),
+    (
+        "Hide advanced details",
+        "Masquer les détails avancés",
+    ),
+    (
+        "missing",
+        "absent",
+    ),
+    (
+        "present",
+        "présent",
+    ),
+    (
+        "unknown",
+        "inconnu",
+    ),
+    (
+        "binkw32.dll SHA256: {}",
+        "SHA256 de binkw32.dll : {}",
+    ),
+    (
+        "binkw23.dll: {}",
+        "binkw23.dll : {}",
+    ),
+    (
+        "Plugin SHA256: {}",
+        "SHA256 du plugin : {}",
+    ),
+    (
+        "Plugin size: {}",
+        "Taille du plugin : {}",
+    ),
+    (
+        "Plugin modified: {}",
+        "Plugin modifié : {}",
+    ),
+    (
+        "Plugin file: not installed",
+        "Fichier du plugin : non installé",
+    ),
+    (
+        "Back up the plugin config and logs, restored when reinstalling",
+        "Sauvegarder la configuration et les journaux du plugin, restaurés lors de la réinstallation",
+    ),
+    (
+        "Downloads",
+        "Téléchargements",
+    ),
+    (
+        "Clear finished",
+        "Effacer les terminés",
+    ),
+    (
+        "Queued",
+        "En attente",
+    ),
+    (
+        "Downloading...",
+        "Téléchargement...",
+    ),
+    (
+        "Finished",
+        "Terminé",
+    ),
+    (
+        "Failed: {}",
+        "Échec : {}",
+    ),
+    (
+        "Cancelled",
+        "Annulé",
+    ),
+    (
+        "The installer is damaged and can't safely patch your game. This is usually caused by an incomplete download or an antivirus modifying the installer, download the installer again and add an exclusion for it in your antivirus",
+        "L'installateur est endommagé et ne peut pas patcher votre jeu en toute sécurité. Cela est généralement causé par un téléchargement incomplet ou un antivirus modifiant l'installateur, téléchargez à nouveau l'installateur et ajoutez une exclusion pour celui-ci dans votre antivirus",
+    ),
+    (
+        "The plugin was removed right after it was installed, this is usually your antivirus falsely detecting the plugin as a threat and quarantining it.",
+        "Le plugin a été supprimé juste après son installation, c'est généralement votre antivirus qui le détecte à tort comme une menace et le met en quarantaine.",
+    ),
+    (
+        "Add an exclusion for the following folder in your antivirus (for Windows Defender: Virus & threat protection > Manage settings > Exclusions), then reinstall the plugin:",
+        "Ajoutez une exclusion pour le dossier suivant dans votre antivirus (pour Windows Defender : Protection contre les virus et menaces > Gérer les paramètres > Exclusions), puis réinstallez le plugin :",
+    ),
+    (
+        "Reinstall",
+        "Réinstaller",
+    ),
+    (
+        "Default plugin release channel",
+        "Canal de version du plugin par défaut",
+    ),
+    (
+        "Stable",
+        "Stable",
+    ),
+    (
+        "Beta",
+        "Bêta",
+    ),
+    (
+        "Folder for downloads and cached releases (optional, uses the system folders when empty)",
+        "Dossier des téléchargements et des versions en cache (facultatif, utilise les dossiers système si vide)",
+    ),
+    (
+        "System folders",
+        "Dossiers système",
+    ),
+    (
+        "Browse...",
+        "Parcourir...",
+    ),
+    (
+        "Invalid cache folder, expected a full folder path",
+        "Dossier de cache invalide, un chemin de dossier complet est attendu",
+    ),
+    (
+        "Launch Mass Effect 3",
+        "Lancer Mass Effect 3",
+    ),
+    (
+        "failed to launch game: {}",
+        "échec du lancement du jeu : {}",
+    ),
+    (
+        "Plugin {} is too new for your server (version {}), update the server or pick an older plugin version",
+        "Le plugin {} est trop récent pour votre serveur (version {}), mettez à jour le serveur ou choisissez une version plus ancienne du plugin",
+    ),
+    (
+        "Plugin {} is too old for your server (version {}), pick a newer plugin version",
+        "Le plugin {} est trop ancien pour votre serveur (version {}), choisissez une version plus récente du plugin",
+    ),
+    (
+        "Network diagnostics",
+        "Diagnostic réseau",
+    ),
+    (
+        "Resolve api.github.com",
+        "Résolution de api.github.com",
+    ),
+    (
+        "Connect to api.github.com",
+        "Connexion à api.github.com",
+    ),
+    (
+        "Secure connection (TLS)",
+        "Connexion sécurisée (TLS)",
+    ),
+    (
+        "GitHub API",
+        "API GitHub",
+    ),
+    (
+        "Download server",
+        "Serveur de téléchargement",
+    ),
+    (
+        "skipped",
+        "ignoré",
+    ),
+    (
+        "Checking connection...",
+        "Vérification de la connexion...",
+    ),
+    (
+        "Use \"Network diagnostics\" in the logs section to find out why",
+        "Utilisez \"Diagnostic réseau\" dans la section des journaux pour en connaître la raison",
+    ),
+    (
+        "Plugin file",
+        "Fichier du plugin",
+    ),
+    (
+        "Plugin matches the checksum published with the release",
+        "Le plugin correspond à la somme de contrôle publiée avec la version",
+    ),
+    (
+        "Release doesn't include a checksums file for the plugin",
+        "La version n'inclut pas de fichier de sommes de contrôle pour le plugin",
+    ),
+    (
+        "Interface scale: {}%",
+        "Échelle de l'interface : {}%",
+    ),
+    (
+        "Copy error",
+        "Copier l'erreur",
+    ),
+    (
+        "Release feeds for additional channels such as nightly builds (comma separated, name=URL)",
+        "Flux de versions pour des canaux supplémentaires comme les builds nocturnes (séparés par des virgules, nom=URL)",
+    ),
+    (
+        "Plugin logs",
+        "Journaux du plugin",
+    ),
+    (
+        "View plugin logs",
+        "Afficher les journaux du plugin",
+    ),
+    (
+        "Hide plugin logs",
+        "Masquer les journaux du plugin",
+    ),
+    (
+        "Copy plugin logs",
+        "Copier les journaux du plugin",
+    ),
+    (
+        "Export plugin logs",
+        "Exporter les journaux du plugin",
+    ),
+    (
+        "Copied plugin logs to clipboard",
+        "Journaux du plugin copiés dans le presse-papiers",
+    ),
+    (
+        "Exported plugin logs to {}",
+        "Journaux du plugin exportés vers {}",
+    ),
+    (
+        "failed to export plugin logs: {}",
+        "échec de l'exportation des journaux du plugin : {}",
+    ),
+    (
+        "Reading plugin logs...",
+        "Lecture des journaux du plugin...",
+    ),
+    (
+        "The plugin hasn't written any logs yet, start the game with the plugin installed",
+        "Le plugin n'a encore écrit aucun journal, lancez le jeu avec le plugin installé",
+    ),
+    (
+        "failed to read plugin logs: {}",
+        "échec de la lecture des journaux du plugin : {}",
+    ),
+    (
+        "Leftover files",
+        "Fichiers restants",
+    ),
+    (
+        "No files from previous installs were found",
+        "Aucun fichier d'une installation précédente n'a été trouvé",
+    ),
+    (
+        "These files were left behind by a previous or manual install and can stop the plugin from working",
+        "Ces fichiers ont été laissés par une installation précédente ou manuelle et peuvent empêcher le plugin de fonctionner",
+    ),
+    (
+        "plugin from an older release",
+        "plugin d'une ancienne version",
+    ),
+    (
+        "plugin outside of the ASI folder",
+        "plugin en dehors du dossier ASI",
+    ),
+    (
+        "plugin config outside of the ASI folder",
+        "configuration du plugin en dehors du dossier ASI",
+    ),
+    (
+        "not used without the patched binkw32.dll",
+        "inutilisé sans le binkw32.dll patché",
+    ),
+    (
+        "Clean up leftover files",
+        "Nettoyer les fichiers restants",
+    ),
+    (
+        "Cleaning up leftover files...",
+        "Nettoyage des fichiers restants...",
+    ),
+    (
+        "Cleaned up leftover files",
+        "Fichiers restants nettoyés",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before cleaning up.",
+        "Mass Effect 3 est en cours d'exécution, fermez le jeu avant de nettoyer.",
+    ),
+    (
+        "Failed to clean up leftover files: {}",
+        "Échec du nettoyage des fichiers restants : {}",
+    ),
+    (
+        "All installations",
+        "Toutes les installations",
+    ),
+    (
+        "Apply the patch and install the {} plugin to all {} installations",
+        "Appliquer le patch et installer le plugin {} sur les {} installations",
+    ),
+    (
+        "Install to all installations",
+        "Installer sur toutes les installations",
+    ),
+    (
+        "Waiting",
+        "En attente",
+    ),
+    (
+        "Installing...",
+        "Installation...",
+    ),
+    (
+        "Installed, checksum verified",
+        "Installé, somme de contrôle vérifiée",
+    ),
+    (
+        "Installed",
+        "Installé",
+    ),
+    (
+        "Skipped, the game is running",
+        "Ignoré, le jeu est en cours d'exécution",
+    ),
+    (
+        "Portable folder",
+        "Dossier portable",
+    ),
+    (
+        "Portable mode, the settings, cache and logs are stored in {}",
+        "Mode portable, les paramètres, le cache et les journaux sont stockés dans {}",
+    ),
+    (
+        "Game",
+        "Jeu",
+    ),
+    (
+        "The game can't be changed while changes are being made",
+        "Le jeu ne peut pas être changé pendant que des modifications sont en cours",
+    ),
+    (
+        "Set-up checklist",
+        "Liste de vérification de l'installation",
+    ),
+    (
+        "The game is installed for all users in Program Files, changes to the game folder need administrator access",
+        "Le jeu est installé pour tous les utilisateurs dans Program Files, les modifications du dossier du jeu nécessitent un accès administrateur",
+    ),
+    (
+        "The installer is running as administrator and can modify the game folder",
+        "L'installateur s'exécute en tant qu'administrateur et peut modifier le dossier du jeu",
+    ),
+    (
+        "The installer can modify the game folder",
+        "L'installateur peut modifier le dossier du jeu",
+    ),
+    (
+        "The game folder can't be modified even as administrator, check the folder permissions and that no antivirus is blocking changes",
+        "Le dossier du jeu ne peut pas être modifié même en tant qu'administrateur, vérifiez les autorisations du dossier et qu'aucun antivirus ne bloque les modifications",
+    ),
+    (
+        "The game loads your own copies of these files instead of the installed ones, remove them from {}: {}",
+        "Le jeu charge vos propres copies de ces fichiers au lieu de celles installées, supprimez-les de {} : {}",
+    ),
+    (
+        "Check again",
+        "Vérifier à nouveau",
+    ),
+    (
+        "Send anonymous usage statistics",
+        "Envoyer des statistiques d'utilisation anonymes",
+    ),
+    (
+        "Reports the installer version, operating system and whether each change to the game succeeded, so the most common problems can be fixed. Paths, errors and servers are never sent.",
+        "Indique la version de l'installateur, le système d'exploitation et si chaque modification du jeu a réussi, afin de corriger les problèmes les plus courants. Les chemins, erreurs et serveurs ne sont jamais envoyés.",
+    ),
+    (
+        "Example of a report that would be sent:",
+        "Exemple de rapport qui serait envoyé :",
+    ),
+    (
+        "Invalid statistics endpoint, expected an http:// or https:// URL",
+        "Point de terminaison des statistiques invalide, URL http:// ou https:// attendue",
+    ),
+    (
+        "Enter the endpoint to send usage statistics to",
+        "Saisissez le point de terminaison où envoyer les statistiques d'utilisation",
+    ),
+    (
+        "Help",
+        "Aide",
+    ),
+    (
+        "Close",
+        "Fermer",
+    ),
+    (
+        "Troubleshooting",
+        "Dépannage",
+    ),
+    (
+        "Loading troubleshooting guide...",
+        "Chargement du guide de dépannage...",
+    ),
+    (
+        "Couldn't fetch the latest troubleshooting guide, showing the saved copy",
+        "Impossible de récupérer le dernier guide de dépannage, affichage de la copie enregistrée",
+    ),
+    (
+        "Couldn't fetch the latest troubleshooting guide, showing the copy included with the installer",
+        "Impossible de récupérer le dernier guide de dépannage, affichage de la copie incluse avec l'installateur",
+    ),
+    (
+        "More details",
+        "Plus de détails",
+    ),
+    (
+        "Apply patch again",
+        "Appliquer à nouveau le patch",
+    ),
+    (
+        "Dismiss",
+        "Ignorer",
+    ),
+    (
+        "Patch removed",
+        "Patch supprimé",
+    ),
+    (
+        "The original binkw32.dll was restored, open the installer to apply the patch again",
+        "Le binkw32.dll d'origine a été restauré, ouvrez l'installateur pour appliquer à nouveau le patch",
+    ),
+    (
+        "The patch was removed, the original binkw32.dll was restored (Origin and the EA App do this when repairing or updating the game). Apply the patch again to keep using Pocket Relay.",
+        "Le patch a été supprimé, le binkw32.dll d'origine a été restauré (Origin et l'EA App le font lors de la réparation ou de la mise à jour du jeu). Appliquez à nouveau le patch pour continuer à utiliser Pocket Relay.",
+    ),
+    (
+        "Offer to apply the patch again when Origin or the EA App removes it",
+        "Proposer d'appliquer à nouveau le patch quand Origin ou l'EA App le supprime",
+    ),
+    (
+        "Disable Plugin",
+        "Désactiver le plugin",
+    ),
+    (
+        "Enable Plugin",
+        "Activer le plugin",
+    ),
+    (
+        "The Pocket Relay client plugin is disabled, the game will connect to the official servers until its enabled.",
+        "Le plugin client Pocket Relay est désactivé, le jeu se connectera aux serveurs officiels jusqu'à ce qu'il soit activé.",
+    ),
+    (
+        "Enabling plugin...",
+        "Activation du plugin...",
+    ),
+    (
+        "Disabling plugin...",
+        "Désactivation du plugin...",
+    ),
+    (
+        "failed to enable plugin: {}",
+        "impossible d'activer le plugin : {}",
+    ),
+    (
+        "failed to disable plugin: {}",
+        "impossible de désactiver le plugin : {}",
+    ),
+    (
+        "History",
+        "Historique",
+    ),
+    (
+        "Undo",
+        "Annuler",
+    ),
+    (
+        "Undoing...",
+        "Annulation...",
+    ),
+    (
+        "Applied the patch",
+        "Patch appliqué",
+    ),
+    (
+        "Removed the patch",
+        "Patch supprimé",
+    ),
+    (
+        "Installed the plugin",
+        "Plugin installé",
+    ),
+    (
+        "Removed the plugin",
+        "Plugin supprimé",
+    ),
+    (
+        "Enabled the plugin",
+        "Plugin activé",
+    ),
+    (
+        "Disabled the plugin",
+        "Plugin désactivé",
+    ),
+    (
+        "Applied the patch and installed the plugin",
+        "Patch appliqué et plugin installé",
+    ),
+    (
+        "failed to load history: {}",
+        "impossible de charger l'historique : {}",
+    ),
+    (
+        "Loading history...",
+        "Chargement de l'historique...",
+    ),
+    (
+        "The installer hasn't made any changes to this game",
+        "L'installateur n'a apporté aucune modification à ce jeu",
+    ),
+    (
+        "The game is currently running, close the game before undoing changes.",
+        "Le jeu est en cours d'exécution, fermez le jeu avant d'annuler des modifications.",
+    ),
+    (
+        "failed to undo change: {}",
+        "impossible d'annuler la modification : {}",
+    ),
+    (
+        "Choose folder",
+        "Choisir un dossier",
+    ),
+    (
+        "EA App",
+        "EA App",
+    ),
+    (
+        "Store: {}",
+        "Boutique : {}",
+    ),
+    (
+        "Origin may remove the patch when it updates or repairs the game, apply the patch again if Pocket Relay stops working",
+        "Origin peut supprimer le correctif lors de la mise à jour ou de la réparation du jeu, appliquez à nouveau le correctif si Pocket Relay cesse de fonctionner",
+    ),
+    (
+        "The EA App may remove the patch when it updates or repairs the game, apply the patch again if Pocket Relay stops working",
+        "L'EA App peut supprimer le correctif lors de la mise à jour ou de la réparation du jeu, appliquez à nouveau le correctif si Pocket Relay cesse de fonctionner",
+    ),
+    (
+        "the Pocket Relay patch",
+        "le correctif Pocket Relay",
+    ),
+    (
+        "another binkw32.dll loader",
+        "un autre chargeur binkw32.dll",
+    ),
+    (
+        "Ready to play, the plugin is loaded by {}",
+        "Prêt à jouer, le plugin est chargé par {}",
+    ),
+    (
+        "Not ready, the plugin is not installed",
+        "Pas prêt, le plugin n'est pas installé",
+    ),
+    (
+        "Not ready, the plugin is disabled",
+        "Pas prêt, le plugin est désactivé",
+    ),
+    (
+        "Not ready, {} doesn't load plugins from the ASI folder, apply the patch so the plugin is loaded",
+        "Pas prêt, {} ne charge pas les plugins du dossier ASI, appliquez le correctif pour que le plugin soit chargé",
+    ),
+    (
+        "Not ready, nothing will load the plugin, apply the patch so the plugin is loaded",
+        "Pas prêt, rien ne chargera le plugin, appliquez le correctif pour que le plugin soit chargé",
+    ),
+    (
+        "Keep running in the system tray when the window is closed",
+        "Continuer dans la zone de notification quand la fenêtre est fermée",
+    ),
+    (
+        "Show installer",
+        "Afficher l'installateur",
+    ),
+    (
+        "Check for plugin updates",
+        "Rechercher des mises à jour du plugin",
+    ),
+    (
+        "Open game folder",
+        "Ouvrir le dossier du jeu",
+    ),
+    (
+        "Quit",
+        "Quitter",
+    ),
+    (
+        "Open ASI folder",
+        "Ouvrir le dossier ASI",
+    ),
+    (
+        "failed to open folder: {}",
+        "impossible d'ouvrir le dossier : {}",
+    ),
+    (
+        "The plugin will be installed once the release details load...",
+        "Le plugin sera installé une fois les détails de la version chargés...",
+    ),
+    (
+        "Release pages to load (100 releases each): {}",
+        "Pages de versions à charger (100 versions chacune) : {}",
+    ),
+    (
+        "Older releases aren't listed, raise the release page limit in the settings to see them",
+        "Les anciennes versions ne sont pas listées, augmentez la limite de pages de versions dans les paramètres pour les voir",
+    ),
+    (
+        "requires installer {}",
+        "nécessite l'installateur {}",
+    ),
+    (
+        "Plugin {} requires installer version {} or newer, update the installer to install it",
+        "Le plugin {} nécessite l'installateur en version {} ou plus récente, mettez à jour l'installateur pour l'installer",
+    ),
+    (
+        "Keep downloaded plugins to reinstall them without downloading again",
+        "Conserver les plugins téléchargés pour les réinstaller sans les télécharger à nouveau",
+    ),
+    (
+        "Download cache: {} files, {}",
+        "Cache des téléchargements : {} fichiers, {}",
+    ),
+    (
+        "Checking the download cache...",
+        "Vérification du cache des téléchargements...",
+    ),
+    (
+        "Clear download cache",
+        "Vider le cache des téléchargements",
+    ),
+    (
+        "Published {}, download size {}",
+        "Publié le {}, taille du téléchargement {}",
+    ),
+    (
+        "Published {}",
+        "Publié le {}",
+    ),
+    (
+        "B",
+        "o",
+    ),
+    (
+        "KB",
+        "Ko",
+    ),
+    (
+        "MB",
+        "Mo",
+    ),
+    (
+        "GB",
+        "Go",
+    ),
+    (
+        "Checking...",
+        "Vérification...",
+    ),
+    (
+        "Downloading {}%",
+        "Téléchargement {} %",
+    ),
+    (
+        "Downloading... ({})",
+        "Téléchargement... ({})",
+    ),
+    (
+        "Verifying...",
+        "Vérification des fichiers...",
+    ),
+    (
+        "Writing files...",
+        "Écriture des fichiers...",
+    ),
+    (
+        "Download size {}",
+        "Taille du téléchargement {}",
+    ),
+    (
+        "Open the install links on Pocket Relay websites and server dashboards with this installer",
+        "Ouvrir les liens d'installation des sites et tableaux de bord de serveurs Pocket Relay avec cet installateur",
+    ),
+    (
+        "Open links with the installer",
+        "Ouvrir les liens avec l'installateur",
+    ),
+    (
+        "Links now open the installer",
+        "Les liens ouvrent maintenant l'installateur",
+    ),
+    (
+        "The link could not be opened: {}",
+        "Impossible d'ouvrir le lien : {}",
+    ),
+    (
+        "Opened from a link to the server {}, save the server connection once the plugin is installed to connect to it",
+        "Ouvert depuis un lien vers le serveur {}, enregistrez la connexion au serveur une fois le plugin installé pour vous y connecter",
+    ),
+    (
+        "Are you sure you want to delete {}? It can't be restored",
+        "Voulez-vous vraiment supprimer {} ? Il ne pourra pas être restauré",
+    ),
+];
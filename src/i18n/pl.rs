@@ -0,0 +1,1434 @@
+//! Polish translations
+
+use super::Translations;
+
+pub const TRANSLATIONS: Translations = &[
+    (
+        "Stable ({})",
+        "Stabilna ({})",
+    ),
+    (
+        "Beta ({})",
+        "Beta ({})",
+    ),
+    (
+        "Please click the button below to choose your game path. When the file picker opens navigate to the folder containing MassEffect3.exe and pick that file",
+        "Kliknij przycisk poniżej, aby wybrać ścieżkę gry. Gdy otworzy się okno wyboru pliku, przejdź do folderu zawierającego MassEffect3.exe i wybierz ten plik",
+    ),
+    (
+        "Choose game path",
+        "Wybierz ścieżkę gry",
+    ),
+    (
+        "Settings",
+        "Ustawienia",
+    ),
+    (
+        "failed to pick file: {}",
+        "nie udało się wybrać pliku: {}",
+    ),
+    (
+        "Or choose one of the following detected game installs:",
+        "Lub wybierz jedną z wykrytych instalacji gry:",
+    ),
+    (
+        "Back",
+        "Wstecz",
+    ),
+    (
+        "Preview changes",
+        "Podgląd zmian",
+    ),
+    (
+        "Installations",
+        "Instalacje",
+    ),
+    (
+        "Add installation",
+        "Dodaj instalację",
+    ),
+    (
+        "The game folder can't be modified without administrator access, restart the installer as administrator to make changes.",
+        "Folderu gry nie można zmienić bez uprawnień administratora, uruchom instalator ponownie jako administrator, aby wprowadzić zmiany.",
+    ),
+    (
+        "Restart as administrator",
+        "Uruchom ponownie jako administrator",
+    ),
+    (
+        "Retry",
+        "Spróbuj ponownie",
+    ),
+    (
+        "Apply the patch and install the {} plugin in one step",
+        "Zastosuj łatkę i zainstaluj wtyczkę {} w jednym kroku",
+    ),
+    (
+        "Install everything",
+        "Zainstaluj wszystko",
+    ),
+    (
+        "Applying patch...",
+        "Stosowanie łatki...",
+    ),
+    (
+        "Installing plugin...",
+        "Instalowanie wtyczki...",
+    ),
+    (
+        "Patch and plugin installed",
+        "Zainstalowano łatkę i wtyczkę",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before installing.",
+        "Mass Effect 3 jest uruchomiony, zamknij grę przed instalacją.",
+    ),
+    (
+        "Failed to install: {}",
+        "Instalacja nie powiodła się: {}",
+    ),
+    (
+        "Cancel",
+        "Anuluj",
+    ),
+    (
+        "Preparing preview...",
+        "Przygotowywanie podglądu...",
+    ),
+    (
+        "Failed to preview changes: {}",
+        "Nie udało się wyświetlić podglądu zmian: {}",
+    ),
+    (
+        "The following changes will be made:",
+        "Zostaną wprowadzone następujące zmiany:",
+    ),
+    (
+        "Confirm",
+        "Potwierdź",
+    ),
+    (
+        "Server Connection",
+        "Połączenie z serwerem",
+    ),
+    (
+        "Address of the Pocket Relay server the plugin should connect to",
+        "Adres serwera Pocket Relay, z którym ma się łączyć wtyczka",
+    ),
+    (
+        "Save",
+        "Zapisz",
+    ),
+    (
+        "Loading config...",
+        "Wczytywanie konfiguracji...",
+    ),
+    (
+        "Saving config...",
+        "Zapisywanie konfiguracji...",
+    ),
+    (
+        "Saved server connection",
+        "Zapisano połączenie z serwerem",
+    ),
+    (
+        "Logs",
+        "Logi",
+    ),
+    (
+        "Hide logs",
+        "Ukryj logi",
+    ),
+    (
+        "Show logs",
+        "Pokaż logi",
+    ),
+    (
+        "Copy diagnostics to clipboard",
+        "Kopiuj diagnostykę do schowka",
+    ),
+    (
+        "Copied diagnostics to clipboard",
+        "Skopiowano diagnostykę do schowka",
+    ),
+    (
+        "Uninstall",
+        "Odinstaluj",
+    ),
+    (
+        "Remove the plugin, its config and log files, and restore the original binkw32.dll",
+        "Usuń wtyczkę, jej konfigurację i pliki logów oraz przywróć oryginalny binkw32.dll",
+    ),
+    (
+        "Remove the ASI folder if its empty",
+        "Usuń folder ASI, jeśli jest pusty",
+    ),
+    (
+        "Uninstall everything",
+        "Odinstaluj wszystko",
+    ),
+    (
+        "Are you sure you want to remove everything Pocket Relay has installed?",
+        "Czy na pewno chcesz usunąć wszystko, co zainstalował Pocket Relay?",
+    ),
+    (
+        "Uninstalling...",
+        "Odinstalowywanie...",
+    ),
+    (
+        "Nothing to uninstall",
+        "Nie ma nic do odinstalowania",
+    ),
+    (
+        "Uninstalled everything",
+        "Odinstalowano wszystko",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before uninstalling.",
+        "Mass Effect 3 jest uruchomiony, zamknij grę przed odinstalowaniem.",
+    ),
+    (
+        "Failed to uninstall: {}",
+        "Odinstalowanie nie powiodło się: {}",
+    ),
+    (
+        "ASI Plugins",
+        "Wtyczki ASI",
+    ),
+    (
+        "Refresh",
+        "Odśwież",
+    ),
+    (
+        "Loading plugins...",
+        "Wczytywanie wtyczek...",
+    ),
+    (
+        "failed to load plugins: {}",
+        "nie udało się wczytać wtyczek: {}",
+    ),
+    (
+        "No ASI plugins are installed",
+        "Brak zainstalowanych wtyczek ASI",
+    ),
+    (
+        "Enabled",
+        "Włączona",
+    ),
+    (
+        "Disabled",
+        "Wyłączona",
+    ),
+    (
+        "Disable",
+        "Wyłącz",
+    ),
+    (
+        "Enable",
+        "Włącz",
+    ),
+    (
+        "Delete",
+        "Usuń",
+    ),
+    (
+        "Language",
+        "Język",
+    ),
+    (
+        "Proxy used when connecting to GitHub",
+        "Proxy używane do łączenia z GitHubem",
+    ),
+    (
+        "System proxy",
+        "Proxy systemowe",
+    ),
+    (
+        "No proxy",
+        "Bez proxy",
+    ),
+    (
+        "Manual proxy",
+        "Ręczne proxy",
+    ),
+    (
+        "Mirrors to use when GitHub is unreachable (comma separated, URL prefixes or URLs containing {url})",
+        "Serwery lustrzane używane, gdy GitHub jest niedostępny (oddzielone przecinkami, prefiksy URL lub adresy URL zawierające {url})",
+    ),
+    (
+        "GitHub personal access token (optional, raises the GitHub rate limit on shared networks)",
+        "Osobisty token dostępu GitHub (opcjonalny, zwiększa limit zapytań GitHub w sieciach współdzielonych)",
+    ),
+    (
+        "Token",
+        "Token",
+    ),
+    (
+        "A new version of the installer is available ({})",
+        "Dostępna jest nowa wersja instalatora ({})",
+    ),
+    (
+        "Update installer",
+        "Zaktualizuj instalator",
+    ),
+    (
+        "Updating installer...",
+        "Aktualizowanie instalatora...",
+    ),
+    (
+        "failed to update installer: {}",
+        "nie udało się zaktualizować instalatora: {}",
+    ),
+    (
+        "Your game is patched",
+        "Twoja gra jest załatana",
+    ),
+    (
+        "Remove Patch",
+        "Usuń łatkę",
+    ),
+    (
+        "Your game is not patched, you must apply the patch to use the client plugin.",
+        "Twoja gra nie jest załatana, musisz zastosować łatkę, aby używać wtyczki klienta.",
+    ),
+    (
+        "Apply Patch",
+        "Zastosuj łatkę",
+    ),
+    (
+        "Your game has an unrecognized binkw32.dll, it may be from another mod or corrupted. Replacing it will back up the current file to binkw32.dll.bak",
+        "Twoja gra ma nierozpoznany plik binkw32.dll, może pochodzić z innego moda lub być uszkodzony. Zastąpienie go utworzy kopię zapasową obecnego pliku jako binkw32.dll.bak",
+    ),
+    (
+        "Back up and replace",
+        "Utwórz kopię i zastąp",
+    ),
+    (
+        "Installing patch...",
+        "Instalowanie łatki...",
+    ),
+    (
+        "Uninstalling patch...",
+        "Odinstalowywanie łatki...",
+    ),
+    (
+        "Patch successfully removed.",
+        "Łatka została usunięta.",
+    ),
+    (
+        "Patch successfully installed.",
+        "Łatka została zainstalowana.",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before changing the patch.",
+        "Mass Effect 3 jest uruchomiony, zamknij grę przed zmianą łatki.",
+    ),
+    (
+        "failed to add patch: {}",
+        "nie udało się zastosować łatki: {}",
+    ),
+    (
+        "failed to remove patch: {}",
+        "nie udało się usunąć łatki: {}",
+    ),
+    (
+        "You have the Pocket Relay client plugin installed.",
+        "Wtyczka klienta Pocket Relay jest zainstalowana.",
+    ),
+    (
+        "Remove Plugin",
+        "Usuń wtyczkę",
+    ),
+    (
+        "Unknown",
+        "Nieznana",
+    ),
+    (
+        "Installed: {}",
+        "Zainstalowana: {}",
+    ),
+    (
+        "Installed: {} (Latest)",
+        "Zainstalowana: {} (Najnowsza)",
+    ),
+    (
+        "Installed: {} \u{2014} Update available: {}",
+        "Zainstalowana: {} \u{2014} Dostępna aktualizacja: {}",
+    ),
+    (
+        "Update Plugin",
+        "Zaktualizuj wtyczkę",
+    ),
+    (
+        "You do not have the Pocket Relay client plugin installed",
+        "Wtyczka klienta Pocket Relay nie jest zainstalowana",
+    ),
+    (
+        "Uninstalling plugin...",
+        "Odinstalowywanie wtyczki...",
+    ),
+    (
+        "Pocket Relay client plugin successfully installed.",
+        "Wtyczka klienta Pocket Relay została zainstalowana.",
+    ),
+    (
+        "Pocket Relay client plugin successfully removed.",
+        "Wtyczka klienta Pocket Relay została usunięta.",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before changing the plugin.",
+        "Mass Effect 3 jest uruchomiony, zamknij grę przed zmianą wtyczki.",
+    ),
+    (
+        "failed to update plugin: {}",
+        "nie udało się zaktualizować wtyczki: {}",
+    ),
+    (
+        "failed to install plugin: {}",
+        "nie udało się zainstalować wtyczki: {}",
+    ),
+    (
+        "failed to remove plugin: {}",
+        "nie udało się usunąć wtyczki: {}",
+    ),
+    (
+        "Install from file...",
+        "Zainstaluj z pliku...",
+    ),
+    (
+        "Loading latest plugin version details...",
+        "Wczytywanie informacji o najnowszej wersji wtyczki...",
+    ),
+    (
+        "Unable to load latest plugin version: {}",
+        "Nie udało się wczytać najnowszej wersji wtyczki: {}",
+    ),
+    (
+        "The latest version of the plugin client is {}",
+        "Najnowsza wersja wtyczki klienta to {}",
+    ),
+    (
+        "Add Plugin",
+        "Dodaj wtyczkę",
+    ),
+    (
+        "Select version",
+        "Wybierz wersję",
+    ),
+    (
+        "Hide notes",
+        "Ukryj informacje",
+    ),
+    (
+        "Release notes",
+        "Informacje o wydaniu",
+    ),
+    (
+        "This release has no release notes",
+        "To wydanie nie ma informacji o wydaniu",
+    ),
+    (
+        "View on GitHub",
+        "Zobacz na GitHubie",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before changing plugins.",
+        "Mass Effect 3 jest uruchomiony, zamknij grę przed zmianą wtyczek.",
+    ),
+    (
+        "failed to change plugin: {}",
+        "nie udało się zmienić wtyczki: {}",
+    ),
+    (
+        "failed to load config: {}",
+        "nie udało się wczytać konfiguracji: {}",
+    ),
+    (
+        "failed to save config: {}",
+        "nie udało się zapisać konfiguracji: {}",
+    ),
+    (
+        "Failed to switch installation: {}",
+        "Nie udało się przełączyć instalacji: {}",
+    ),
+    (
+        "{} will be created, {}",
+        "{} zostanie utworzony, {}",
+    ),
+    (
+        "{} will be overwritten (backed up to {}), {}",
+        "{} zostanie nadpisany (kopia zapasowa w {}), {}",
+    ),
+    (
+        "{} will be overwritten, {}",
+        "{} zostanie nadpisany, {}",
+    ),
+    (
+        "{} will be restored from {}",
+        "{} zostanie przywrócony z {}",
+    ),
+    (
+        "{} will be deleted",
+        "{} zostanie usunięty",
+    ),
+    (
+        "Administrator access is required to modify the game folder, use \"Restart as administrator\" or install the game outside Program Files",
+        "Do zmiany folderu gry wymagane są uprawnienia administratora, użyj \"Uruchom ponownie jako administrator\" lub zainstaluj grę poza folderem Program Files",
+    ),
+    (
+        "Theme",
+        "Motyw",
+    ),
+    (
+        "System",
+        "Systemowy",
+    ),
+    (
+        "Light",
+        "Jasny",
+    ),
+    (
+        "Dark",
+        "Ciemny",
+    ),
+    (
+        "Accent color (optional, hex color such as #5865f2)",
+        "Kolor akcentu (opcjonalny, kolor szesnastkowy, np. #5865f2)",
+    ),
+    (
+        "Invalid accent color, expected a hex color such as #5865f2",
+        "Nieprawidłowy kolor akcentu, oczekiwano koloru szesnastkowego, np. #5865f2",
+    ),
+    (
+        "Proton",
+        "Proton",
+    ),
+    (
+        "When running through Proton the game must load the patched binkw32.dll, add the following to the game launch options in Steam:",
+        "Podczas uruchamiania przez Proton gra musi wczytać załatany binkw32.dll, dodaj poniższe do opcji uruchamiania gry w Steam:",
+    ),
+    (
+        "Add to Steam launch options",
+        "Dodaj do opcji uruchamiania Steam",
+    ),
+    (
+        "Copy launch options",
+        "Kopiuj opcje uruchamiania",
+    ),
+    (
+        "Updating launch options...",
+        "Aktualizowanie opcji uruchamiania...",
+    ),
+    (
+        "Launch options are already set",
+        "Opcje uruchamiania są już ustawione",
+    ),
+    (
+        "Launch options updated, restart Steam to use them",
+        "Zaktualizowano opcje uruchamiania, uruchom ponownie Steam, aby ich użyć",
+    ),
+    (
+        "Copied launch options to clipboard",
+        "Skopiowano opcje uruchamiania do schowka",
+    ),
+    (
+        "failed to update launch options: {}",
+        "nie udało się zaktualizować opcji uruchamiania: {}",
+    ),
+    (
+        "Verified",
+        "Zweryfikowano",
+    ),
+    (
+        "Plugin update available ({})",
+        "Dostępna aktualizacja wtyczki ({})",
+    ),
+    (
+        "Update",
+        "Aktualizuj",
+    ),
+    (
+        "Cannot reach GitHub, check your internet connection",
+        "Nie można połączyć się z GitHub, sprawdź połączenie z internetem",
+    ),
+    (
+        "GitHub rate limited, wait a while and try again",
+        "Przekroczono limit zapytań GitHub, odczekaj chwilę i spróbuj ponownie",
+    ),
+    (
+        "Invalid plugin repository, expected a GitHub repository such as PocketRelay/PocketRelayClientPlugin",
+        "Nieprawidłowe repozytorium wtyczki, oczekiwano repozytorium GitHub, np. PocketRelay/PocketRelayClientPlugin",
+    ),
+    (
+        "Invalid plugin asset name, expected a file name such as pocket-relay-plugin.asi",
+        "Nieprawidłowa nazwa pliku wtyczki, oczekiwano nazwy pliku, np. pocket-relay-plugin.asi",
+    ),
+    (
+        "Plugin source (change to install a fork or test build of the plugin)",
+        "Źródło wtyczki (zmień, aby zainstalować fork lub wersję testową wtyczki)",
+    ),
+    (
+        "Use official plugin",
+        "Użyj oficjalnej wtyczki",
+    ),
+    (
+        "Using plugin releases from {} ({})",
+        "Używane są wydania wtyczki z {} ({})",
+    ),
+    (
+        "binkw32.dll has been replaced by another ASI loader, such as the ME3Tweaks binkw32 bypass",
+        "binkw32.dll został zastąpiony innym loaderem ASI, np. obejściem binkw32 od ME3Tweaks",
+    ),
+    (
+        "Apply the patch to replace it, plugins in the ASI folder will still be loaded",
+        "Zastosuj łatkę, aby go zastąpić, wtyczki z folderu ASI nadal będą ładowane",
+    ),
+    (
+        "binkw32.dll is not a known version, it may be modified or corrupted",
+        "binkw32.dll nie jest znaną wersją, może być zmodyfikowany lub uszkodzony",
+    ),
+    (
+        "Apply the patch to replace it, or verify the game files through your launcher",
+        "Zastosuj łatkę, aby go zastąpić, lub zweryfikuj pliki gry w launcherze",
+    ),
+    (
+        "{} is a wrapper loader (such as Ultimate ASI Loader or ReShade), it can load plugins twice or stop the client plugin from loading",
+        "{} to loader typu wrapper (np. Ultimate ASI Loader lub ReShade), może ładować wtyczki dwukrotnie lub blokować ładowanie wtyczki klienta",
+    ),
+    (
+        "Remove or rename the file if the client plugin doesn't load",
+        "Usuń plik lub zmień jego nazwę, jeśli wtyczka klienta się nie ładuje",
+    ),
+    (
+        "The {} folder contains ASI plugins for another ASI loader",
+        "Folder {} zawiera wtyczki ASI dla innego loadera ASI",
+    ),
+    (
+        "Move the plugins into the ASI folder so they are only loaded once",
+        "Przenieś wtyczki do folderu ASI, aby były ładowane tylko raz",
+    ),
+    (
+        "ASI plugin {} is loaded with the client plugin",
+        "Wtyczka ASI {} jest ładowana razem z wtyczką klienta",
+    ),
+    (
+        "Disable it from the ASI plugins list if the game crashes or can't connect",
+        "Wyłącz ją na liście wtyczek ASI, jeśli gra się zawiesza lub nie może się połączyć",
+    ),
+    (
+        "DLC mods are installed: {}",
+        "Zainstalowane są mody DLC: {}",
+    ),
+    (
+        "Players in the same lobby need the same multiplayer mods, disable mods that change multiplayer if you have trouble joining games",
+        "Gracze w tym samym lobby potrzebują tych samych modów trybu wieloosobowego, wyłącz mody zmieniające tryb wieloosobowy, jeśli masz problem z dołączaniem do gier",
+    ),
+    (
+        "{} is a Legendary Edition file, Legendary Edition mods don't work with the original Mass Effect 3",
+        "{} to plik z Edycji Legendarnej, mody do Edycji Legendarnej nie działają z oryginalnym Mass Effect 3",
+    ),
+    (
+        "Remove the mod or repair the game files through your launcher",
+        "Usuń moda lub napraw pliki gry w launcherze",
+    ),
+    (
+        "Compatibility",
+        "Zgodność",
+    ),
+    (
+        "Scan again",
+        "Skanuj ponownie",
+    ),
+    (
+        "Scanning game folder...",
+        "Skanowanie folderu gry...",
+    ),
+    (
+        "failed to scan game folder: {}",
+        "nie udało się przeskanować folderu gry: {}",
+    ),
+    (
+        "No known conflicts were found",
+        "Nie znaleziono znanych konfliktów",
+    ),
+    (
+        "Installation report",
+        "Raport instalacji",
+    ),
+    (
+        "Export a report of the game files, or compare against a report from an installation that works",
+        "Wyeksportuj raport plików gry lub porównaj go z raportem z działającej instalacji",
+    ),
+    (
+        "Export report",
+        "Eksportuj raport",
+    ),
+    (
+        "Compare with report",
+        "Porównaj z raportem",
+    ),
+    (
+        "Reading game files...",
+        "Odczytywanie plików gry...",
+    ),
+    (
+        "Exported report to {}",
+        "Wyeksportowano raport do {}",
+    ),
+    (
+        "failed to create report: {}",
+        "nie udało się utworzyć raportu: {}",
+    ),
+    (
+        "The installation matches the report",
+        "Instalacja jest zgodna z raportem",
+    ),
+    (
+        "Differences from the report:",
+        "Różnice względem raportu:",
+    ),
+    (
+        "Current: {}\nReport: {}",
+        "Obecnie: {}\nRaport: {}",
+    ),
+    (
+        "Installation complete",
+        "Instalacja zakończona",
+    ),
+    (
+        "Pocket Relay plugin {} installed",
+        "Zainstalowano wtyczkę Pocket Relay {}",
+    ),
+    (
+        "Pocket Relay plugin installed",
+        "Zainstalowano wtyczkę Pocket Relay",
+    ),
+    (
+        "Installation failed",
+        "Instalacja nie powiodła się",
+    ),
+    (
+        "Mass Effect 3 is running, close the game and try again",
+        "Mass Effect 3 jest uruchomiony, zamknij grę i spróbuj ponownie",
+    ),
+    (
+        "Unlimited",
+        "Bez limitu",
+    ),
+    (
+        "{} KB/s",
+        "{} KB/s",
+    ),
+    (
+        "Download speed limit (for metered or shared connections): {}",
+        "Limit prędkości pobierania (dla połączeń taryfowych lub współdzielonych): {}",
+    ),
+    (
+        "Show advanced details",
+        "Pokaż szczegóły zaawansowane",
+    ),
+    (
+        "Hide advanced details",
+        "Ukryj szczegóły zaawansowane",
+    ),
+    (
+        "missing",
+        "brak",
+    ),
+    (
+        "present",
+        "obecny",
+    ),
+    (
+        "unknown",
+        "nieznany",
+    ),
+    (
+        "binkw32.dll SHA256: {}",
+        "SHA256 binkw32.dll: {}",
+    ),
+    (
+        "binkw23.dll: {}",
+        "binkw23.dll: {}",
+    ),
+    (
+        "Plugin SHA256: {}",
+        "SHA256 wtyczki: {}",
+    ),
+    (
+        "Plugin size: {}",
+        "Rozmiar wtyczki: {}",
+    ),
+    (
+        "Plugin modified: {}",
+        "Wtyczka zmodyfikowana: {}",
+    ),
+    (
+        "Plugin file: not installed",
+        "Plik wtyczki: nie zainstalowano",
+    ),
+    (
+        "Back up the plugin config and logs, restored when reinstalling",
+        "Utwórz kopię zapasową konfiguracji i logów wtyczki, przywracaną przy ponownej instalacji",
+    ),
+    (
+        "Downloads",
+        "Pobrane",
+    ),
+    (
+        "Clear finished",
+        "Wyczyść zakończone",
+    ),
+    (
+        "Queued",
+        "W kolejce",
+    ),
+    (
+        "Downloading...",
+        "Pobieranie...",
+    ),
+    (
+        "Finished",
+        "Zakończono",
+    ),
+    (
+        "Failed: {}",
+        "Niepowodzenie: {}",
+    ),
+    (
+        "Cancelled",
+        "Anulowano",
+    ),
+    (
+        "The installer is damaged and can't safely patch your game. This is usually caused by an incomplete download or an antivirus modifying the installer, download the installer again and add an exclusion for it in your antivirus",
+        "Instalator jest uszkodzony i nie może bezpiecznie zainstalować łatki. Zwykle jest to spowodowane niepełnym pobraniem lub programem antywirusowym modyfikującym instalator, pobierz instalator ponownie i dodaj dla niego wyjątek w programie antywirusowym",
+    ),
+    (
+        "The plugin was removed right after it was installed, this is usually your antivirus falsely detecting the plugin as a threat and quarantining it.",
+        "Wtyczka została usunięta zaraz po instalacji, zwykle oznacza to, że program antywirusowy błędnie uznał ją za zagrożenie i poddał kwarantannie.",
+    ),
+    (
+        "Add an exclusion for the following folder in your antivirus (for Windows Defender: Virus & threat protection > Manage settings > Exclusions), then reinstall the plugin:",
+        "Dodaj wyjątek dla następującego folderu w programie antywirusowym (dla Windows Defender: Ochrona przed wirusami i zagrożeniami > Zarządzaj ustawieniami > Wykluczenia), a następnie zainstaluj wtyczkę ponownie:",
+    ),
+    (
+        "Reinstall",
+        "Zainstaluj ponownie",
+    ),
+    (
+        "Default plugin release channel",
+        "Domyślny kanał wydań wtyczki",
+    ),
+    (
+        "Stable",
+        "Stabilna",
+    ),
+    (
+        "Beta",
+        "Beta",
+    ),
+    (
+        "Folder for downloads and cached releases (optional, uses the system folders when empty)",
+        "Folder na pobrane pliki i zapisane wydania (opcjonalnie, gdy pusty używane są foldery systemowe)",
+    ),
+    (
+        "System folders",
+        "Foldery systemowe",
+    ),
+    (
+        "Browse...",
+        "Przeglądaj...",
+    ),
+    (
+        "Invalid cache folder, expected a full folder path",
+        "Nieprawidłowy folder pamięci podręcznej, oczekiwano pełnej ścieżki folderu",
+    ),
+    (
+        "Launch Mass Effect 3",
+        "Uruchom Mass Effect 3",
+    ),
+    (
+        "failed to launch game: {}",
+        "nie udało się uruchomić gry: {}",
+    ),
+    (
+        "Plugin {} is too new for your server (version {}), update the server or pick an older plugin version",
+        "Wtyczka {} jest zbyt nowa dla twojego serwera (wersja {}), zaktualizuj serwer lub wybierz starszą wersję wtyczki",
+    ),
+    (
+        "Plugin {} is too old for your server (version {}), pick a newer plugin version",
+        "Wtyczka {} jest zbyt stara dla twojego serwera (wersja {}), wybierz nowszą wersję wtyczki",
+    ),
+    (
+        "Network diagnostics",
+        "Diagnostyka sieci",
+    ),
+    (
+        "Resolve api.github.com",
+        "Rozwiązywanie api.github.com",
+    ),
+    (
+        "Connect to api.github.com",
+        "Łączenie z api.github.com",
+    ),
+    (
+        "Secure connection (TLS)",
+        "Bezpieczne połączenie (TLS)",
+    ),
+    (
+        "GitHub API",
+        "API GitHub",
+    ),
+    (
+        "Download server",
+        "Serwer pobierania",
+    ),
+    (
+        "skipped",
+        "pominięto",
+    ),
+    (
+        "Checking connection...",
+        "Sprawdzanie połączenia...",
+    ),
+    (
+        "Use \"Network diagnostics\" in the logs section to find out why",
+        "Użyj \"Diagnostyka sieci\" w sekcji dzienników, aby poznać przyczynę",
+    ),
+    (
+        "Plugin file",
+        "Plik wtyczki",
+    ),
+    (
+        "Plugin matches the checksum published with the release",
+        "Wtyczka zgadza się z sumą kontrolną opublikowaną z wydaniem",
+    ),
+    (
+        "Release doesn't include a checksums file for the plugin",
+        "Wydanie nie zawiera pliku sum kontrolnych dla wtyczki",
+    ),
+    (
+        "Interface scale: {}%",
+        "Skala interfejsu: {}%",
+    ),
+    (
+        "Copy error",
+        "Kopiuj błąd",
+    ),
+    (
+        "Release feeds for additional channels such as nightly builds (comma separated, name=URL)",
+        "Kanały wydań dla dodatkowych kanałów, np. nocnych kompilacji (oddzielone przecinkami, nazwa=URL)",
+    ),
+    (
+        "Plugin logs",
+        "Logi wtyczki",
+    ),
+    (
+        "View plugin logs",
+        "Pokaż logi wtyczki",
+    ),
+    (
+        "Hide plugin logs",
+        "Ukryj logi wtyczki",
+    ),
+    (
+        "Copy plugin logs",
+        "Kopiuj logi wtyczki",
+    ),
+    (
+        "Export plugin logs",
+        "Eksportuj logi wtyczki",
+    ),
+    (
+        "Copied plugin logs to clipboard",
+        "Skopiowano logi wtyczki do schowka",
+    ),
+    (
+        "Exported plugin logs to {}",
+        "Wyeksportowano logi wtyczki do {}",
+    ),
+    (
+        "failed to export plugin logs: {}",
+        "nie udało się wyeksportować logów wtyczki: {}",
+    ),
+    (
+        "Reading plugin logs...",
+        "Odczytywanie logów wtyczki...",
+    ),
+    (
+        "The plugin hasn't written any logs yet, start the game with the plugin installed",
+        "Wtyczka nie zapisała jeszcze żadnych logów, uruchom grę z zainstalowaną wtyczką",
+    ),
+    (
+        "failed to read plugin logs: {}",
+        "nie udało się odczytać logów wtyczki: {}",
+    ),
+    (
+        "Leftover files",
+        "Pozostałe pliki",
+    ),
+    (
+        "No files from previous installs were found",
+        "Nie znaleziono plików z poprzednich instalacji",
+    ),
+    (
+        "These files were left behind by a previous or manual install and can stop the plugin from working",
+        "Te pliki pozostały po poprzedniej lub ręcznej instalacji i mogą uniemożliwić działanie wtyczki",
+    ),
+    (
+        "plugin from an older release",
+        "wtyczka ze starszego wydania",
+    ),
+    (
+        "plugin outside of the ASI folder",
+        "wtyczka poza folderem ASI",
+    ),
+    (
+        "plugin config outside of the ASI folder",
+        "konfiguracja wtyczki poza folderem ASI",
+    ),
+    (
+        "not used without the patched binkw32.dll",
+        "nieużywany bez załatanego binkw32.dll",
+    ),
+    (
+        "Clean up leftover files",
+        "Usuń pozostałe pliki",
+    ),
+    (
+        "Cleaning up leftover files...",
+        "Usuwanie pozostałych plików...",
+    ),
+    (
+        "Cleaned up leftover files",
+        "Usunięto pozostałe pliki",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before cleaning up.",
+        "Mass Effect 3 jest uruchomiony, zamknij grę przed czyszczeniem.",
+    ),
+    (
+        "Failed to clean up leftover files: {}",
+        "Nie udało się usunąć pozostałych plików: {}",
+    ),
+    (
+        "All installations",
+        "Wszystkie instalacje",
+    ),
+    (
+        "Apply the patch and install the {} plugin to all {} installations",
+        "Zastosuj łatkę i zainstaluj wtyczkę {} we wszystkich instalacjach ({})",
+    ),
+    (
+        "Install to all installations",
+        "Zainstaluj we wszystkich instalacjach",
+    ),
+    (
+        "Waiting",
+        "Oczekiwanie",
+    ),
+    (
+        "Installing...",
+        "Instalowanie...",
+    ),
+    (
+        "Installed, checksum verified",
+        "Zainstalowano, suma kontrolna zweryfikowana",
+    ),
+    (
+        "Installed",
+        "Zainstalowano",
+    ),
+    (
+        "Skipped, the game is running",
+        "Pominięto, gra jest uruchomiona",
+    ),
+    (
+        "Portable folder",
+        "Folder przenośny",
+    ),
+    (
+        "Portable mode, the settings, cache and logs are stored in {}",
+        "Tryb przenośny, ustawienia, pamięć podręczna i logi są przechowywane w {}",
+    ),
+    (
+        "Game",
+        "Gra",
+    ),
+    (
+        "The game can't be changed while changes are being made",
+        "Nie można zmienić gry podczas wprowadzania zmian",
+    ),
+    (
+        "Set-up checklist",
+        "Lista kontrolna konfiguracji",
+    ),
+    (
+        "The game is installed for all users in Program Files, changes to the game folder need administrator access",
+        "Gra jest zainstalowana dla wszystkich użytkowników w Program Files, zmiany w folderze gry wymagają uprawnień administratora",
+    ),
+    (
+        "The installer is running as administrator and can modify the game folder",
+        "Instalator działa jako administrator i może modyfikować folder gry",
+    ),
+    (
+        "The installer can modify the game folder",
+        "Instalator może modyfikować folder gry",
+    ),
+    (
+        "The game folder can't be modified even as administrator, check the folder permissions and that no antivirus is blocking changes",
+        "Folderu gry nie można zmodyfikować nawet jako administrator, sprawdź uprawnienia folderu i czy żaden antywirus nie blokuje zmian",
+    ),
+    (
+        "The game loads your own copies of these files instead of the installed ones, remove them from {}: {}",
+        "Gra wczytuje twoje własne kopie tych plików zamiast zainstalowanych, usuń je z {}: {}",
+    ),
+    (
+        "Check again",
+        "Sprawdź ponownie",
+    ),
+    (
+        "Send anonymous usage statistics",
+        "Wysyłaj anonimowe statystyki użytkowania",
+    ),
+    (
+        "Reports the installer version, operating system and whether each change to the game succeeded, so the most common problems can be fixed. Paths, errors and servers are never sent.",
+        "Zgłasza wersję instalatora, system operacyjny i to, czy każda zmiana w grze się powiodła, aby można było naprawić najczęstsze problemy. Ścieżki, błędy i serwery nigdy nie są wysyłane.",
+    ),
+    (
+        "Example of a report that would be sent:",
+        "Przykład raportu, który zostałby wysłany:",
+    ),
+    (
+        "Invalid statistics endpoint, expected an http:// or https:// URL",
+        "Nieprawidłowy adres statystyk, oczekiwano adresu URL http:// lub https://",
+    ),
+    (
+        "Enter the endpoint to send usage statistics to",
+        "Wpisz adres, na który mają być wysyłane statystyki użytkowania",
+    ),
+    (
+        "Help",
+        "Pomoc",
+    ),
+    (
+        "Close",
+        "Zamknij",
+    ),
+    (
+        "Troubleshooting",
+        "Rozwiązywanie problemów",
+    ),
+    (
+        "Loading troubleshooting guide...",
+        "Wczytywanie poradnika rozwiązywania problemów...",
+    ),
+    (
+        "Couldn't fetch the latest troubleshooting guide, showing the saved copy",
+        "Nie udało się pobrać najnowszego poradnika, wyświetlana jest zapisana kopia",
+    ),
+    (
+        "Couldn't fetch the latest troubleshooting guide, showing the copy included with the installer",
+        "Nie udało się pobrać najnowszego poradnika, wyświetlana jest kopia dołączona do instalatora",
+    ),
+    (
+        "More details",
+        "Więcej szczegółów",
+    ),
+    (
+        "Apply patch again",
+        "Zastosuj łatkę ponownie",
+    ),
+    (
+        "Dismiss",
+        "Odrzuć",
+    ),
+    (
+        "Patch removed",
+        "Łatka usunięta",
+    ),
+    (
+        "The original binkw32.dll was restored, open the installer to apply the patch again",
+        "Przywrócono oryginalny plik binkw32.dll, otwórz instalator, aby ponownie zastosować łatkę",
+    ),
+    (
+        "The patch was removed, the original binkw32.dll was restored (Origin and the EA App do this when repairing or updating the game). Apply the patch again to keep using Pocket Relay.",
+        "Łatka została usunięta, przywrócono oryginalny plik binkw32.dll (Origin i EA App robią to podczas naprawy lub aktualizacji gry). Zastosuj łatkę ponownie, aby dalej korzystać z Pocket Relay.",
+    ),
+    (
+        "Offer to apply the patch again when Origin or the EA App removes it",
+        "Proponuj ponowne zastosowanie łatki, gdy Origin lub EA App ją usunie",
+    ),
+    (
+        "Disable Plugin",
+        "Wyłącz wtyczkę",
+    ),
+    (
+        "Enable Plugin",
+        "Włącz wtyczkę",
+    ),
+    (
+        "The Pocket Relay client plugin is disabled, the game will connect to the official servers until its enabled.",
+        "Wtyczka klienta Pocket Relay jest wyłączona, gra będzie łączyć się z oficjalnymi serwerami, dopóki nie zostanie włączona.",
+    ),
+    (
+        "Enabling plugin...",
+        "Włączanie wtyczki...",
+    ),
+    (
+        "Disabling plugin...",
+        "Wyłączanie wtyczki...",
+    ),
+    (
+        "failed to enable plugin: {}",
+        "nie udało się włączyć wtyczki: {}",
+    ),
+    (
+        "failed to disable plugin: {}",
+        "nie udało się wyłączyć wtyczki: {}",
+    ),
+    (
+        "History",
+        "Historia",
+    ),
+    (
+        "Undo",
+        "Cofnij",
+    ),
+    (
+        "Undoing...",
+        "Cofanie...",
+    ),
+    (
+        "Applied the patch",
+        "Zastosowano łatkę",
+    ),
+    (
+        "Removed the patch",
+        "Usunięto łatkę",
+    ),
+    (
+        "Installed the plugin",
+        "Zainstalowano wtyczkę",
+    ),
+    (
+        "Removed the plugin",
+        "Usunięto wtyczkę",
+    ),
+    (
+        "Enabled the plugin",
+        "Włączono wtyczkę",
+    ),
+    (
+        "Disabled the plugin",
+        "Wyłączono wtyczkę",
+    ),
+    (
+        "Applied the patch and installed the plugin",
+        "Zastosowano łatkę i zainstalowano wtyczkę",
+    ),
+    (
+        "failed to load history: {}",
+        "nie udało się wczytać historii: {}",
+    ),
+    (
+        "Loading history...",
+        "Wczytywanie historii...",
+    ),
+    (
+        "The installer hasn't made any changes to this game",
+        "Instalator nie wprowadził żadnych zmian w tej grze",
+    ),
+    (
+        "The game is currently running, close the game before undoing changes.",
+        "Gra jest uruchomiona, zamknij grę przed cofnięciem zmian.",
+    ),
+    (
+        "failed to undo change: {}",
+        "nie udało się cofnąć zmiany: {}",
+    ),
+    (
+        "Choose folder",
+        "Wybierz folder",
+    ),
+    (
+        "EA App",
+        "EA App",
+    ),
+    (
+        "Store: {}",
+        "Sklep: {}",
+    ),
+    (
+        "Origin may remove the patch when it updates or repairs the game, apply the patch again if Pocket Relay stops working",
+        "Origin może usunąć łatkę podczas aktualizacji lub naprawy gry, zastosuj łatkę ponownie, jeśli Pocket Relay przestanie działać",
+    ),
+    (
+        "The EA App may remove the patch when it updates or repairs the game, apply the patch again if Pocket Relay stops working",
+        "EA App może usunąć łatkę podczas aktualizacji lub naprawy gry, zastosuj łatkę ponownie, jeśli Pocket Relay przestanie działać",
+    ),
+    (
+        "the Pocket Relay patch",
+        "łatkę Pocket Relay",
+    ),
+    (
+        "another binkw32.dll loader",
+        "inny program ładujący binkw32.dll",
+    ),
+    (
+        "Ready to play, the plugin is loaded by {}",
+        "Gotowe do gry, wtyczka jest ładowana przez {}",
+    ),
+    (
+        "Not ready, the plugin is not installed",
+        "Nie gotowe, wtyczka nie jest zainstalowana",
+    ),
+    (
+        "Not ready, the plugin is disabled",
+        "Nie gotowe, wtyczka jest wyłączona",
+    ),
+    (
+        "Not ready, {} doesn't load plugins from the ASI folder, apply the patch so the plugin is loaded",
+        "Nie gotowe, {} nie ładuje wtyczek z folderu ASI, zastosuj łatkę, aby wtyczka została załadowana",
+    ),
+    (
+        "Not ready, nothing will load the plugin, apply the patch so the plugin is loaded",
+        "Nie gotowe, nic nie załaduje wtyczki, zastosuj łatkę, aby wtyczka została załadowana",
+    ),
+    (
+        "Keep running in the system tray when the window is closed",
+        "Działaj w zasobniku systemowym po zamknięciu okna",
+    ),
+    (
+        "Show installer",
+        "Pokaż instalator",
+    ),
+    (
+        "Check for plugin updates",
+        "Sprawdź aktualizacje wtyczki",
+    ),
+    (
+        "Open game folder",
+        "Otwórz folder gry",
+    ),
+    (
+        "Quit",
+        "Zakończ",
+    ),
+    (
+        "Open ASI folder",
+        "Otwórz folder ASI",
+    ),
+    (
+        "failed to open folder: {}",
+        "nie udało się otworzyć folderu: {}",
+    ),
+    (
+        "The plugin will be installed once the release details load...",
+        "Wtyczka zostanie zainstalowana po wczytaniu szczegółów wydania...",
+    ),
+    (
+        "Release pages to load (100 releases each): {}",
+        "Strony wydań do wczytania (po 100 wydań): {}",
+    ),
+    (
+        "Older releases aren't listed, raise the release page limit in the settings to see them",
+        "Starsze wydania nie są wyświetlane, zwiększ limit stron wydań w ustawieniach, aby je zobaczyć",
+    ),
+    (
+        "requires installer {}",
+        "wymaga instalatora {}",
+    ),
+    (
+        "Plugin {} requires installer version {} or newer, update the installer to install it",
+        "Wtyczka {} wymaga instalatora w wersji {} lub nowszej, zaktualizuj instalator, aby ją zainstalować",
+    ),
+    (
+        "Keep downloaded plugins to reinstall them without downloading again",
+        "Zachowuj pobrane wtyczki, aby instalować je ponownie bez ponownego pobierania",
+    ),
+    (
+        "Download cache: {} files, {}",
+        "Pamięć podręczna pobrań: {} plików, {}",
+    ),
+    (
+        "Checking the download cache...",
+        "Sprawdzanie pamięci podręcznej pobrań...",
+    ),
+    (
+        "Clear download cache",
+        "Wyczyść pamięć podręczną pobrań",
+    ),
+    (
+        "Published {}, download size {}",
+        "Opublikowano {}, rozmiar pobierania {}",
+    ),
+    (
+        "Published {}",
+        "Opublikowano {}",
+    ),
+    (
+        "B",
+        "B",
+    ),
+    (
+        "KB",
+        "KB",
+    ),
+    (
+        "MB",
+        "MB",
+    ),
+    (
+        "GB",
+        "GB",
+    ),
+    (
+        "Checking...",
+        "Sprawdzanie...",
+    ),
+    (
+        "Downloading {}%",
+        "Pobieranie {}%",
+    ),
+    (
+        "Downloading... ({})",
+        "Pobieranie... ({})",
+    ),
+    (
+        "Verifying...",
+        "Weryfikowanie...",
+    ),
+    (
+        "Writing files...",
+        "Zapisywanie plików...",
+    ),
+    (
+        "Download size {}",
+        "Rozmiar pobierania {}",
+    ),
+    (
+        "Open the install links on Pocket Relay websites and server dashboards with this installer",
+        "Otwieraj linki instalacyjne na stronach Pocket Relay i w panelach serwerów za pomocą tego instalatora",
+    ),
+    (
+        "Open links with the installer",
+        "Otwieraj linki instalatorem",
+    ),
+    (
+        "Links now open the installer",
+        "Linki otwierają teraz instalator",
+    ),
+    (
+        "The link could not be opened: {}",
+        "Nie udało się otworzyć linku: {}",
+    ),
+    (
+        "Opened from a link to the server {}, save the server connection once the plugin is installed to connect to it",
+        "Otwarto z linku do serwera {}, zapisz połączenie z serwerem po zainstalowaniu wtyczki, aby się z nim połączyć",
+    ),
+    (
+        "Are you sure you want to delete {}? It can't be restored",
+        "Czy na pewno chcesz usunąć {}? Nie będzie można go przywrócić",
+    ),
+];
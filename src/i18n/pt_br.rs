@@ -0,0 +1,1434 @@
+//! Brazilian Portuguese translations
+
+use super::Translations;
+
+pub const TRANSLATIONS: Translations = &[
+    (
+        "Stable ({})",
+        "Estável ({})",
+    ),
+    (
+        "Beta ({})",
+        "Beta ({})",
+    ),
+    (
+        "Please click the button below to choose your game path. When the file picker opens navigate to the folder containing MassEffect3.exe and pick that file",
+        "Clique no botão abaixo para escolher o caminho do jogo. Quando o seletor de arquivos abrir, navegue até a pasta que contém o MassEffect3.exe e selecione esse arquivo",
+    ),
+    (
+        "Choose game path",
+        "Escolher caminho do jogo",
+    ),
+    (
+        "Settings",
+        "Configurações",
+    ),
+    (
+        "failed to pick file: {}",
+        "falha ao selecionar o arquivo: {}",
+    ),
+    (
+        "Or choose one of the following detected game installs:",
+        "Ou escolha uma das instalações do jogo detectadas:",
+    ),
+    (
+        "Back",
+        "Voltar",
+    ),
+    (
+        "Preview changes",
+        "Pré-visualizar alterações",
+    ),
+    (
+        "Installations",
+        "Instalações",
+    ),
+    (
+        "Add installation",
+        "Adicionar instalação",
+    ),
+    (
+        "The game folder can't be modified without administrator access, restart the installer as administrator to make changes.",
+        "A pasta do jogo não pode ser modificada sem acesso de administrador, reinicie o instalador como administrador para fazer alterações.",
+    ),
+    (
+        "Restart as administrator",
+        "Reiniciar como administrador",
+    ),
+    (
+        "Retry",
+        "Tentar novamente",
+    ),
+    (
+        "Apply the patch and install the {} plugin in one step",
+        "Aplicar o patch e instalar o plugin {} em uma única etapa",
+    ),
+    (
+        "Install everything",
+        "Instalar tudo",
+    ),
+    (
+        "Applying patch...",
+        "Aplicando patch...",
+    ),
+    (
+        "Installing plugin...",
+        "Instalando plugin...",
+    ),
+    (
+        "Patch and plugin installed",
+        "Patch e plugin instalados",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before installing.",
+        "Mass Effect 3 está em execução, feche o jogo antes de instalar.",
+    ),
+    (
+        "Failed to install: {}",
+        "Falha ao instalar: {}",
+    ),
+    (
+        "Cancel",
+        "Cancelar",
+    ),
+    (
+        "Preparing preview...",
+        "Preparando pré-visualização...",
+    ),
+    (
+        "Failed to preview changes: {}",
+        "Falha ao pré-visualizar as alterações: {}",
+    ),
+    (
+        "The following changes will be made:",
+        "As seguintes alterações serão feitas:",
+    ),
+    (
+        "Confirm",
+        "Confirmar",
+    ),
+    (
+        "Server Connection",
+        "Conexão com o servidor",
+    ),
+    (
+        "Address of the Pocket Relay server the plugin should connect to",
+        "Endereço do servidor Pocket Relay ao qual o plugin deve se conectar",
+    ),
+    (
+        "Save",
+        "Salvar",
+    ),
+    (
+        "Loading config...",
+        "Carregando configuração...",
+    ),
+    (
+        "Saving config...",
+        "Salvando configuração...",
+    ),
+    (
+        "Saved server connection",
+        "Conexão com o servidor salva",
+    ),
+    (
+        "Logs",
+        "Logs",
+    ),
+    (
+        "Hide logs",
+        "Ocultar logs",
+    ),
+    (
+        "Show logs",
+        "Mostrar logs",
+    ),
+    (
+        "Copy diagnostics to clipboard",
+        "Copiar diagnóstico para a área de transferência",
+    ),
+    (
+        "Copied diagnostics to clipboard",
+        "Diagnóstico copiado para a área de transferência",
+    ),
+    (
+        "Uninstall",
+        "Desinstalar",
+    ),
+    (
+        "Remove the plugin, its config and log files, and restore the original binkw32.dll",
+        "Remover o plugin, sua configuração e arquivos de log, e restaurar o binkw32.dll original",
+    ),
+    (
+        "Remove the ASI folder if its empty",
+        "Remover a pasta ASI se estiver vazia",
+    ),
+    (
+        "Uninstall everything",
+        "Desinstalar tudo",
+    ),
+    (
+        "Are you sure you want to remove everything Pocket Relay has installed?",
+        "Tem certeza de que deseja remover tudo o que o Pocket Relay instalou?",
+    ),
+    (
+        "Uninstalling...",
+        "Desinstalando...",
+    ),
+    (
+        "Nothing to uninstall",
+        "Nada para desinstalar",
+    ),
+    (
+        "Uninstalled everything",
+        "Tudo foi desinstalado",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before uninstalling.",
+        "Mass Effect 3 está em execução, feche o jogo antes de desinstalar.",
+    ),
+    (
+        "Failed to uninstall: {}",
+        "Falha ao desinstalar: {}",
+    ),
+    (
+        "ASI Plugins",
+        "Plugins ASI",
+    ),
+    (
+        "Refresh",
+        "Atualizar",
+    ),
+    (
+        "Loading plugins...",
+        "Carregando plugins...",
+    ),
+    (
+        "failed to load plugins: {}",
+        "falha ao carregar os plugins: {}",
+    ),
+    (
+        "No ASI plugins are installed",
+        "Nenhum plugin ASI está instalado",
+    ),
+    (
+        "Enabled",
+        "Ativado",
+    ),
+    (
+        "Disabled",
+        "Desativado",
+    ),
+    (
+        "Disable",
+        "Desativar",
+    ),
+    (
+        "Enable",
+        "Ativar",
+    ),
+    (
+        "Delete",
+        "Excluir",
+    ),
+    (
+        "Language",
+        "Idioma",
+    ),
+    (
+        "Proxy used when connecting to GitHub",
+        "Proxy usado ao conectar ao GitHub",
+    ),
+    (
+        "System proxy",
+        "Proxy do sistema",
+    ),
+    (
+        "No proxy",
+        "Sem proxy",
+    ),
+    (
+        "Manual proxy",
+        "Proxy manual",
+    ),
+    (
+        "Mirrors to use when GitHub is unreachable (comma separated, URL prefixes or URLs containing {url})",
+        "Espelhos usados quando o GitHub estiver inacessível (separados por vírgula, prefixos de URL ou URLs contendo {url})",
+    ),
+    (
+        "GitHub personal access token (optional, raises the GitHub rate limit on shared networks)",
+        "Token de acesso pessoal do GitHub (opcional, aumenta o limite de requisições do GitHub em redes compartilhadas)",
+    ),
+    (
+        "Token",
+        "Token",
+    ),
+    (
+        "A new version of the installer is available ({})",
+        "Uma nova versão do instalador está disponível ({})",
+    ),
+    (
+        "Update installer",
+        "Atualizar instalador",
+    ),
+    (
+        "Updating installer...",
+        "Atualizando instalador...",
+    ),
+    (
+        "failed to update installer: {}",
+        "falha ao atualizar o instalador: {}",
+    ),
+    (
+        "Your game is patched",
+        "Seu jogo está com o patch",
+    ),
+    (
+        "Remove Patch",
+        "Remover patch",
+    ),
+    (
+        "Your game is not patched, you must apply the patch to use the client plugin.",
+        "Seu jogo não está com o patch, você deve aplicar o patch para usar o plugin do cliente.",
+    ),
+    (
+        "Apply Patch",
+        "Aplicar patch",
+    ),
+    (
+        "Your game has an unrecognized binkw32.dll, it may be from another mod or corrupted. Replacing it will back up the current file to binkw32.dll.bak",
+        "Seu jogo tem um binkw32.dll não reconhecido, ele pode ser de outro mod ou estar corrompido. Substituí-lo fará um backup do arquivo atual em binkw32.dll.bak",
+    ),
+    (
+        "Back up and replace",
+        "Fazer backup e substituir",
+    ),
+    (
+        "Installing patch...",
+        "Instalando patch...",
+    ),
+    (
+        "Uninstalling patch...",
+        "Desinstalando patch...",
+    ),
+    (
+        "Patch successfully removed.",
+        "Patch removido com sucesso.",
+    ),
+    (
+        "Patch successfully installed.",
+        "Patch instalado com sucesso.",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before changing the patch.",
+        "Mass Effect 3 está em execução, feche o jogo antes de alterar o patch.",
+    ),
+    (
+        "failed to add patch: {}",
+        "falha ao aplicar o patch: {}",
+    ),
+    (
+        "failed to remove patch: {}",
+        "falha ao remover o patch: {}",
+    ),
+    (
+        "You have the Pocket Relay client plugin installed.",
+        "O plugin do cliente Pocket Relay está instalado.",
+    ),
+    (
+        "Remove Plugin",
+        "Remover plugin",
+    ),
+    (
+        "Unknown",
+        "Desconhecida",
+    ),
+    (
+        "Installed: {}",
+        "Instalada: {}",
+    ),
+    (
+        "Installed: {} (Latest)",
+        "Instalada: {} (Mais recente)",
+    ),
+    (
+        "Installed: {} \u{2014} Update available: {}",
+        "Instalada: {} \u{2014} Atualização disponível: {}",
+    ),
+    (
+        "Update Plugin",
+        "Atualizar plugin",
+    ),
+    (
+        "You do not have the Pocket Relay client plugin installed",
+        "O plugin do cliente Pocket Relay não está instalado",
+    ),
+    (
+        "Uninstalling plugin...",
+        "Desinstalando plugin...",
+    ),
+    (
+        "Pocket Relay client plugin successfully installed.",
+        "Plugin do cliente Pocket Relay instalado com sucesso.",
+    ),
+    (
+        "Pocket Relay client plugin successfully removed.",
+        "Plugin do cliente Pocket Relay removido com sucesso.",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before changing the plugin.",
+        "Mass Effect 3 está em execução, feche o jogo antes de alterar o plugin.",
+    ),
+    (
+        "failed to update plugin: {}",
+        "falha ao atualizar o plugin: {}",
+    ),
+    (
+        "failed to install plugin: {}",
+        "falha ao instalar o plugin: {}",
+    ),
+    (
+        "failed to remove plugin: {}",
+        "falha ao remover o plugin: {}",
+    ),
+    (
+        "Install from file...",
+        "Instalar de um arquivo...",
+    ),
+    (
+        "Loading latest plugin version details...",
+        "Carregando detalhes da versão mais recente do plugin...",
+    ),
+    (
+        "Unable to load latest plugin version: {}",
+        "Não foi possível carregar a versão mais recente do plugin: {}",
+    ),
+    (
+        "The latest version of the plugin client is {}",
+        "A versão mais recente do plugin do cliente é {}",
+    ),
+    (
+        "Add Plugin",
+        "Adicionar plugin",
+    ),
+    (
+        "Select version",
+        "Selecionar versão",
+    ),
+    (
+        "Hide notes",
+        "Ocultar notas",
+    ),
+    (
+        "Release notes",
+        "Notas da versão",
+    ),
+    (
+        "This release has no release notes",
+        "Esta versão não tem notas",
+    ),
+    (
+        "View on GitHub",
+        "Ver no GitHub",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before changing plugins.",
+        "Mass Effect 3 está em execução, feche o jogo antes de alterar os plugins.",
+    ),
+    (
+        "failed to change plugin: {}",
+        "falha ao alterar o plugin: {}",
+    ),
+    (
+        "failed to load config: {}",
+        "falha ao carregar a configuração: {}",
+    ),
+    (
+        "failed to save config: {}",
+        "falha ao salvar a configuração: {}",
+    ),
+    (
+        "Failed to switch installation: {}",
+        "Falha ao trocar de instalação: {}",
+    ),
+    (
+        "{} will be created, {}",
+        "{} será criado, {}",
+    ),
+    (
+        "{} will be overwritten (backed up to {}), {}",
+        "{} será sobrescrito (backup em {}), {}",
+    ),
+    (
+        "{} will be overwritten, {}",
+        "{} será sobrescrito, {}",
+    ),
+    (
+        "{} will be restored from {}",
+        "{} será restaurado de {}",
+    ),
+    (
+        "{} will be deleted",
+        "{} será excluído",
+    ),
+    (
+        "Administrator access is required to modify the game folder, use \"Restart as administrator\" or install the game outside Program Files",
+        "É necessário acesso de administrador para modificar a pasta do jogo, use \"Reiniciar como administrador\" ou instale o jogo fora de Program Files",
+    ),
+    (
+        "Theme",
+        "Tema",
+    ),
+    (
+        "System",
+        "Sistema",
+    ),
+    (
+        "Light",
+        "Claro",
+    ),
+    (
+        "Dark",
+        "Escuro",
+    ),
+    (
+        "Accent color (optional, hex color such as #5865f2)",
+        "Cor de destaque (opcional, cor hexadecimal como #5865f2)",
+    ),
+    (
+        "Invalid accent color, expected a hex color such as #5865f2",
+        "Cor de destaque inválida, esperada uma cor hexadecimal como #5865f2",
+    ),
+    (
+        "Proton",
+        "Proton",
+    ),
+    (
+        "When running through Proton the game must load the patched binkw32.dll, add the following to the game launch options in Steam:",
+        "Ao executar pelo Proton, o jogo deve carregar o binkw32.dll com patch, adicione o seguinte às opções de inicialização do jogo na Steam:",
+    ),
+    (
+        "Add to Steam launch options",
+        "Adicionar às opções de inicialização da Steam",
+    ),
+    (
+        "Copy launch options",
+        "Copiar opções de inicialização",
+    ),
+    (
+        "Updating launch options...",
+        "Atualizando opções de inicialização...",
+    ),
+    (
+        "Launch options are already set",
+        "As opções de inicialização já estão definidas",
+    ),
+    (
+        "Launch options updated, restart Steam to use them",
+        "Opções de inicialização atualizadas, reinicie a Steam para usá-las",
+    ),
+    (
+        "Copied launch options to clipboard",
+        "Opções de inicialização copiadas para a área de transferência",
+    ),
+    (
+        "failed to update launch options: {}",
+        "falha ao atualizar as opções de inicialização: {}",
+    ),
+    (
+        "Verified",
+        "Verificado",
+    ),
+    (
+        "Plugin update available ({})",
+        "Atualização do plugin disponível ({})",
+    ),
+    (
+        "Update",
+        "Atualizar",
+    ),
+    (
+        "Cannot reach GitHub, check your internet connection",
+        "Não foi possível acessar o GitHub, verifique sua conexão com a internet",
+    ),
+    (
+        "GitHub rate limited, wait a while and try again",
+        "Limite de requisições do GitHub atingido, aguarde um pouco e tente novamente",
+    ),
+    (
+        "Invalid plugin repository, expected a GitHub repository such as PocketRelay/PocketRelayClientPlugin",
+        "Repositório do plugin inválido, esperado um repositório do GitHub como PocketRelay/PocketRelayClientPlugin",
+    ),
+    (
+        "Invalid plugin asset name, expected a file name such as pocket-relay-plugin.asi",
+        "Nome do arquivo do plugin inválido, esperado um nome de arquivo como pocket-relay-plugin.asi",
+    ),
+    (
+        "Plugin source (change to install a fork or test build of the plugin)",
+        "Origem do plugin (altere para instalar um fork ou uma versão de teste do plugin)",
+    ),
+    (
+        "Use official plugin",
+        "Usar o plugin oficial",
+    ),
+    (
+        "Using plugin releases from {} ({})",
+        "Usando versões do plugin de {} ({})",
+    ),
+    (
+        "binkw32.dll has been replaced by another ASI loader, such as the ME3Tweaks binkw32 bypass",
+        "binkw32.dll foi substituído por outro carregador ASI, como o bypass binkw32 do ME3Tweaks",
+    ),
+    (
+        "Apply the patch to replace it, plugins in the ASI folder will still be loaded",
+        "Aplique o patch para substituí-lo, os plugins da pasta ASI continuarão sendo carregados",
+    ),
+    (
+        "binkw32.dll is not a known version, it may be modified or corrupted",
+        "binkw32.dll não é uma versão conhecida, pode estar modificado ou corrompido",
+    ),
+    (
+        "Apply the patch to replace it, or verify the game files through your launcher",
+        "Aplique o patch para substituí-lo ou verifique os arquivos do jogo pelo seu launcher",
+    ),
+    (
+        "{} is a wrapper loader (such as Ultimate ASI Loader or ReShade), it can load plugins twice or stop the client plugin from loading",
+        "{} é um carregador wrapper (como Ultimate ASI Loader ou ReShade), ele pode carregar plugins duas vezes ou impedir o carregamento do plugin cliente",
+    ),
+    (
+        "Remove or rename the file if the client plugin doesn't load",
+        "Remova ou renomeie o arquivo se o plugin cliente não carregar",
+    ),
+    (
+        "The {} folder contains ASI plugins for another ASI loader",
+        "A pasta {} contém plugins ASI para outro carregador ASI",
+    ),
+    (
+        "Move the plugins into the ASI folder so they are only loaded once",
+        "Mova os plugins para a pasta ASI para que sejam carregados apenas uma vez",
+    ),
+    (
+        "ASI plugin {} is loaded with the client plugin",
+        "O plugin ASI {} é carregado junto com o plugin cliente",
+    ),
+    (
+        "Disable it from the ASI plugins list if the game crashes or can't connect",
+        "Desative-o na lista de plugins ASI se o jogo travar ou não conseguir conectar",
+    ),
+    (
+        "DLC mods are installed: {}",
+        "Mods de DLC estão instalados: {}",
+    ),
+    (
+        "Players in the same lobby need the same multiplayer mods, disable mods that change multiplayer if you have trouble joining games",
+        "Jogadores no mesmo lobby precisam dos mesmos mods de multijogador, desative mods que alteram o multijogador se tiver problemas para entrar em partidas",
+    ),
+    (
+        "{} is a Legendary Edition file, Legendary Edition mods don't work with the original Mass Effect 3",
+        "{} é um arquivo da Edição Lendária, mods da Edição Lendária não funcionam com o Mass Effect 3 original",
+    ),
+    (
+        "Remove the mod or repair the game files through your launcher",
+        "Remova o mod ou repare os arquivos do jogo pelo seu launcher",
+    ),
+    (
+        "Compatibility",
+        "Compatibilidade",
+    ),
+    (
+        "Scan again",
+        "Verificar novamente",
+    ),
+    (
+        "Scanning game folder...",
+        "Verificando a pasta do jogo...",
+    ),
+    (
+        "failed to scan game folder: {}",
+        "falha ao verificar a pasta do jogo: {}",
+    ),
+    (
+        "No known conflicts were found",
+        "Nenhum conflito conhecido foi encontrado",
+    ),
+    (
+        "Installation report",
+        "Relatório de instalação",
+    ),
+    (
+        "Export a report of the game files, or compare against a report from an installation that works",
+        "Exporte um relatório dos arquivos do jogo ou compare com o relatório de uma instalação que funciona",
+    ),
+    (
+        "Export report",
+        "Exportar relatório",
+    ),
+    (
+        "Compare with report",
+        "Comparar com relatório",
+    ),
+    (
+        "Reading game files...",
+        "Lendo os arquivos do jogo...",
+    ),
+    (
+        "Exported report to {}",
+        "Relatório exportado para {}",
+    ),
+    (
+        "failed to create report: {}",
+        "falha ao criar o relatório: {}",
+    ),
+    (
+        "The installation matches the report",
+        "A instalação corresponde ao relatório",
+    ),
+    (
+        "Differences from the report:",
+        "Diferenças em relação ao relatório:",
+    ),
+    (
+        "Current: {}\nReport: {}",
+        "Atual: {}\nRelatório: {}",
+    ),
+    (
+        "Installation complete",
+        "Instalação concluída",
+    ),
+    (
+        "Pocket Relay plugin {} installed",
+        "Plugin Pocket Relay {} instalado",
+    ),
+    (
+        "Pocket Relay plugin installed",
+        "Plugin Pocket Relay instalado",
+    ),
+    (
+        "Installation failed",
+        "Falha na instalação",
+    ),
+    (
+        "Mass Effect 3 is running, close the game and try again",
+        "Mass Effect 3 está em execução, feche o jogo e tente novamente",
+    ),
+    (
+        "Unlimited",
+        "Ilimitada",
+    ),
+    (
+        "{} KB/s",
+        "{} KB/s",
+    ),
+    (
+        "Download speed limit (for metered or shared connections): {}",
+        "Limite de velocidade de download (para conexões limitadas ou compartilhadas): {}",
+    ),
+    (
+        "Show advanced details",
+        "Mostrar detalhes avançados",
+    ),
+    (
+        "Hide advanced details",
+        "Ocultar detalhes avançados",
+    ),
+    (
+        "missing",
+        "ausente",
+    ),
+    (
+        "present",
+        "presente",
+    ),
+    (
+        "unknown",
+        "desconhecido",
+    ),
+    (
+        "binkw32.dll SHA256: {}",
+        "SHA256 do binkw32.dll: {}",
+    ),
+    (
+        "binkw23.dll: {}",
+        "binkw23.dll: {}",
+    ),
+    (
+        "Plugin SHA256: {}",
+        "SHA256 do plugin: {}",
+    ),
+    (
+        "Plugin size: {}",
+        "Tamanho do plugin: {}",
+    ),
+    (
+        "Plugin modified: {}",
+        "Plugin modificado: {}",
+    ),
+    (
+        "Plugin file: not installed",
+        "Arquivo do plugin: não instalado",
+    ),
+    (
+        "Back up the plugin config and logs, restored when reinstalling",
+        "Fazer backup da configuração e dos logs do plugin, restaurados ao reinstalar",
+    ),
+    (
+        "Downloads",
+        "Downloads",
+    ),
+    (
+        "Clear finished",
+        "Limpar concluídos",
+    ),
+    (
+        "Queued",
+        "Na fila",
+    ),
+    (
+        "Downloading...",
+        "Baixando...",
+    ),
+    (
+        "Finished",
+        "Concluído",
+    ),
+    (
+        "Failed: {}",
+        "Falhou: {}",
+    ),
+    (
+        "Cancelled",
+        "Cancelado",
+    ),
+    (
+        "The installer is damaged and can't safely patch your game. This is usually caused by an incomplete download or an antivirus modifying the installer, download the installer again and add an exclusion for it in your antivirus",
+        "O instalador está danificado e não pode aplicar o patch com segurança. Isso geralmente é causado por um download incompleto ou por um antivírus modificando o instalador, baixe o instalador novamente e adicione uma exclusão para ele no seu antivírus",
+    ),
+    (
+        "The plugin was removed right after it was installed, this is usually your antivirus falsely detecting the plugin as a threat and quarantining it.",
+        "O plugin foi removido logo após ser instalado, isso geralmente é o seu antivírus detectando o plugin como ameaça por engano e colocando-o em quarentena.",
+    ),
+    (
+        "Add an exclusion for the following folder in your antivirus (for Windows Defender: Virus & threat protection > Manage settings > Exclusions), then reinstall the plugin:",
+        "Adicione uma exclusão para a seguinte pasta no seu antivírus (no Windows Defender: Proteção contra vírus e ameaças > Gerenciar configurações > Exclusões) e reinstale o plugin:",
+    ),
+    (
+        "Reinstall",
+        "Reinstalar",
+    ),
+    (
+        "Default plugin release channel",
+        "Canal de versões padrão do plugin",
+    ),
+    (
+        "Stable",
+        "Estável",
+    ),
+    (
+        "Beta",
+        "Beta",
+    ),
+    (
+        "Folder for downloads and cached releases (optional, uses the system folders when empty)",
+        "Pasta para downloads e versões em cache (opcional, usa as pastas do sistema quando vazia)",
+    ),
+    (
+        "System folders",
+        "Pastas do sistema",
+    ),
+    (
+        "Browse...",
+        "Procurar...",
+    ),
+    (
+        "Invalid cache folder, expected a full folder path",
+        "Pasta de cache inválida, esperado um caminho completo de pasta",
+    ),
+    (
+        "Launch Mass Effect 3",
+        "Iniciar Mass Effect 3",
+    ),
+    (
+        "failed to launch game: {}",
+        "falha ao iniciar o jogo: {}",
+    ),
+    (
+        "Plugin {} is too new for your server (version {}), update the server or pick an older plugin version",
+        "O plugin {} é novo demais para o seu servidor (versão {}), atualize o servidor ou escolha uma versão mais antiga do plugin",
+    ),
+    (
+        "Plugin {} is too old for your server (version {}), pick a newer plugin version",
+        "O plugin {} é antigo demais para o seu servidor (versão {}), escolha uma versão mais nova do plugin",
+    ),
+    (
+        "Network diagnostics",
+        "Diagnóstico de rede",
+    ),
+    (
+        "Resolve api.github.com",
+        "Resolver api.github.com",
+    ),
+    (
+        "Connect to api.github.com",
+        "Conectar a api.github.com",
+    ),
+    (
+        "Secure connection (TLS)",
+        "Conexão segura (TLS)",
+    ),
+    (
+        "GitHub API",
+        "API do GitHub",
+    ),
+    (
+        "Download server",
+        "Servidor de download",
+    ),
+    (
+        "skipped",
+        "ignorado",
+    ),
+    (
+        "Checking connection...",
+        "Verificando a conexão...",
+    ),
+    (
+        "Use \"Network diagnostics\" in the logs section to find out why",
+        "Use \"Diagnóstico de rede\" na seção de logs para descobrir o motivo",
+    ),
+    (
+        "Plugin file",
+        "Arquivo do plugin",
+    ),
+    (
+        "Plugin matches the checksum published with the release",
+        "O plugin corresponde à soma de verificação publicada com a versão",
+    ),
+    (
+        "Release doesn't include a checksums file for the plugin",
+        "A versão não inclui um arquivo de somas de verificação para o plugin",
+    ),
+    (
+        "Interface scale: {}%",
+        "Escala da interface: {}%",
+    ),
+    (
+        "Copy error",
+        "Copiar erro",
+    ),
+    (
+        "Release feeds for additional channels such as nightly builds (comma separated, name=URL)",
+        "Feeds de versões para canais adicionais, como builds noturnas (separados por vírgula, nome=URL)",
+    ),
+    (
+        "Plugin logs",
+        "Logs do plugin",
+    ),
+    (
+        "View plugin logs",
+        "Ver logs do plugin",
+    ),
+    (
+        "Hide plugin logs",
+        "Ocultar logs do plugin",
+    ),
+    (
+        "Copy plugin logs",
+        "Copiar logs do plugin",
+    ),
+    (
+        "Export plugin logs",
+        "Exportar logs do plugin",
+    ),
+    (
+        "Copied plugin logs to clipboard",
+        "Logs do plugin copiados para a área de transferência",
+    ),
+    (
+        "Exported plugin logs to {}",
+        "Logs do plugin exportados para {}",
+    ),
+    (
+        "failed to export plugin logs: {}",
+        "falha ao exportar os logs do plugin: {}",
+    ),
+    (
+        "Reading plugin logs...",
+        "Lendo os logs do plugin...",
+    ),
+    (
+        "The plugin hasn't written any logs yet, start the game with the plugin installed",
+        "O plugin ainda não escreveu nenhum log, inicie o jogo com o plugin instalado",
+    ),
+    (
+        "failed to read plugin logs: {}",
+        "falha ao ler os logs do plugin: {}",
+    ),
+    (
+        "Leftover files",
+        "Arquivos restantes",
+    ),
+    (
+        "No files from previous installs were found",
+        "Nenhum arquivo de instalações anteriores foi encontrado",
+    ),
+    (
+        "These files were left behind by a previous or manual install and can stop the plugin from working",
+        "Estes arquivos foram deixados por uma instalação anterior ou manual e podem impedir o plugin de funcionar",
+    ),
+    (
+        "plugin from an older release",
+        "plugin de uma versão antiga",
+    ),
+    (
+        "plugin outside of the ASI folder",
+        "plugin fora da pasta ASI",
+    ),
+    (
+        "plugin config outside of the ASI folder",
+        "configuração do plugin fora da pasta ASI",
+    ),
+    (
+        "not used without the patched binkw32.dll",
+        "não usado sem o binkw32.dll modificado",
+    ),
+    (
+        "Clean up leftover files",
+        "Limpar arquivos restantes",
+    ),
+    (
+        "Cleaning up leftover files...",
+        "Limpando arquivos restantes...",
+    ),
+    (
+        "Cleaned up leftover files",
+        "Arquivos restantes limpos",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before cleaning up.",
+        "Mass Effect 3 está em execução, feche o jogo antes de limpar.",
+    ),
+    (
+        "Failed to clean up leftover files: {}",
+        "Falha ao limpar arquivos restantes: {}",
+    ),
+    (
+        "All installations",
+        "Todas as instalações",
+    ),
+    (
+        "Apply the patch and install the {} plugin to all {} installations",
+        "Aplicar o patch e instalar o plugin {} em todas as {} instalações",
+    ),
+    (
+        "Install to all installations",
+        "Instalar em todas as instalações",
+    ),
+    (
+        "Waiting",
+        "Aguardando",
+    ),
+    (
+        "Installing...",
+        "Instalando...",
+    ),
+    (
+        "Installed, checksum verified",
+        "Instalado, checksum verificado",
+    ),
+    (
+        "Installed",
+        "Instalado",
+    ),
+    (
+        "Skipped, the game is running",
+        "Ignorado, o jogo está em execução",
+    ),
+    (
+        "Portable folder",
+        "Pasta portátil",
+    ),
+    (
+        "Portable mode, the settings, cache and logs are stored in {}",
+        "Modo portátil, as configurações, o cache e os logs são armazenados em {}",
+    ),
+    (
+        "Game",
+        "Jogo",
+    ),
+    (
+        "The game can't be changed while changes are being made",
+        "O jogo não pode ser alterado enquanto alterações estão sendo feitas",
+    ),
+    (
+        "Set-up checklist",
+        "Lista de verificação da configuração",
+    ),
+    (
+        "The game is installed for all users in Program Files, changes to the game folder need administrator access",
+        "O jogo está instalado para todos os usuários em Program Files, alterações na pasta do jogo exigem acesso de administrador",
+    ),
+    (
+        "The installer is running as administrator and can modify the game folder",
+        "O instalador está sendo executado como administrador e pode modificar a pasta do jogo",
+    ),
+    (
+        "The installer can modify the game folder",
+        "O instalador pode modificar a pasta do jogo",
+    ),
+    (
+        "The game folder can't be modified even as administrator, check the folder permissions and that no antivirus is blocking changes",
+        "A pasta do jogo não pode ser modificada nem como administrador, verifique as permissões da pasta e se nenhum antivírus está bloqueando as alterações",
+    ),
+    (
+        "The game loads your own copies of these files instead of the installed ones, remove them from {}: {}",
+        "O jogo carrega suas próprias cópias destes arquivos em vez das instaladas, remova-as de {}: {}",
+    ),
+    (
+        "Check again",
+        "Verificar novamente",
+    ),
+    (
+        "Send anonymous usage statistics",
+        "Enviar estatísticas de uso anônimas",
+    ),
+    (
+        "Reports the installer version, operating system and whether each change to the game succeeded, so the most common problems can be fixed. Paths, errors and servers are never sent.",
+        "Informa a versão do instalador, o sistema operacional e se cada alteração no jogo foi bem-sucedida, para que os problemas mais comuns possam ser corrigidos. Caminhos, erros e servidores nunca são enviados.",
+    ),
+    (
+        "Example of a report that would be sent:",
+        "Exemplo de um relatório que seria enviado:",
+    ),
+    (
+        "Invalid statistics endpoint, expected an http:// or https:// URL",
+        "Endereço de estatísticas inválido, esperado um URL http:// ou https://",
+    ),
+    (
+        "Enter the endpoint to send usage statistics to",
+        "Informe o endereço para onde enviar as estatísticas de uso",
+    ),
+    (
+        "Help",
+        "Ajuda",
+    ),
+    (
+        "Close",
+        "Fechar",
+    ),
+    (
+        "Troubleshooting",
+        "Solução de problemas",
+    ),
+    (
+        "Loading troubleshooting guide...",
+        "Carregando o guia de solução de problemas...",
+    ),
+    (
+        "Couldn't fetch the latest troubleshooting guide, showing the saved copy",
+        "Não foi possível obter o guia de solução de problemas mais recente, mostrando a cópia salva",
+    ),
+    (
+        "Couldn't fetch the latest troubleshooting guide, showing the copy included with the installer",
+        "Não foi possível obter o guia de solução de problemas mais recente, mostrando a cópia incluída no instalador",
+    ),
+    (
+        "More details",
+        "Mais detalhes",
+    ),
+    (
+        "Apply patch again",
+        "Aplicar o patch novamente",
+    ),
+    (
+        "Dismiss",
+        "Dispensar",
+    ),
+    (
+        "Patch removed",
+        "Patch removido",
+    ),
+    (
+        "The original binkw32.dll was restored, open the installer to apply the patch again",
+        "O binkw32.dll original foi restaurado, abra o instalador para aplicar o patch novamente",
+    ),
+    (
+        "The patch was removed, the original binkw32.dll was restored (Origin and the EA App do this when repairing or updating the game). Apply the patch again to keep using Pocket Relay.",
+        "O patch foi removido, o binkw32.dll original foi restaurado (o Origin e o EA App fazem isso ao reparar ou atualizar o jogo). Aplique o patch novamente para continuar usando o Pocket Relay.",
+    ),
+    (
+        "Offer to apply the patch again when Origin or the EA App removes it",
+        "Oferecer aplicar o patch novamente quando o Origin ou o EA App o remover",
+    ),
+    (
+        "Disable Plugin",
+        "Desativar plugin",
+    ),
+    (
+        "Enable Plugin",
+        "Ativar plugin",
+    ),
+    (
+        "The Pocket Relay client plugin is disabled, the game will connect to the official servers until its enabled.",
+        "O plugin cliente do Pocket Relay está desativado, o jogo se conectará aos servidores oficiais até que ele seja ativado.",
+    ),
+    (
+        "Enabling plugin...",
+        "Ativando plugin...",
+    ),
+    (
+        "Disabling plugin...",
+        "Desativando plugin...",
+    ),
+    (
+        "failed to enable plugin: {}",
+        "falha ao ativar o plugin: {}",
+    ),
+    (
+        "failed to disable plugin: {}",
+        "falha ao desativar o plugin: {}",
+    ),
+    (
+        "History",
+        "Histórico",
+    ),
+    (
+        "Undo",
+        "Desfazer",
+    ),
+    (
+        "Undoing...",
+        "Desfazendo...",
+    ),
+    (
+        "Applied the patch",
+        "Patch aplicado",
+    ),
+    (
+        "Removed the patch",
+        "Patch removido",
+    ),
+    (
+        "Installed the plugin",
+        "Plugin instalado",
+    ),
+    (
+        "Removed the plugin",
+        "Plugin removido",
+    ),
+    (
+        "Enabled the plugin",
+        "Plugin ativado",
+    ),
+    (
+        "Disabled the plugin",
+        "Plugin desativado",
+    ),
+    (
+        "Applied the patch and installed the plugin",
+        "Patch aplicado e plugin instalado",
+    ),
+    (
+        "failed to load history: {}",
+        "falha ao carregar o histórico: {}",
+    ),
+    (
+        "Loading history...",
+        "Carregando histórico...",
+    ),
+    (
+        "The installer hasn't made any changes to this game",
+        "O instalador não fez nenhuma alteração neste jogo",
+    ),
+    (
+        "The game is currently running, close the game before undoing changes.",
+        "O jogo está em execução, feche o jogo antes de desfazer alterações.",
+    ),
+    (
+        "failed to undo change: {}",
+        "falha ao desfazer a alteração: {}",
+    ),
+    (
+        "Choose folder",
+        "Escolher pasta",
+    ),
+    (
+        "EA App",
+        "EA App",
+    ),
+    (
+        "Store: {}",
+        "Loja: {}",
+    ),
+    (
+        "Origin may remove the patch when it updates or repairs the game, apply the patch again if Pocket Relay stops working",
+        "O Origin pode remover o patch ao atualizar ou reparar o jogo, aplique o patch novamente se o Pocket Relay parar de funcionar",
+    ),
+    (
+        "The EA App may remove the patch when it updates or repairs the game, apply the patch again if Pocket Relay stops working",
+        "O EA App pode remover o patch ao atualizar ou reparar o jogo, aplique o patch novamente se o Pocket Relay parar de funcionar",
+    ),
+    (
+        "the Pocket Relay patch",
+        "o patch do Pocket Relay",
+    ),
+    (
+        "another binkw32.dll loader",
+        "outro carregador binkw32.dll",
+    ),
+    (
+        "Ready to play, the plugin is loaded by {}",
+        "Pronto para jogar, o plugin é carregado por {}",
+    ),
+    (
+        "Not ready, the plugin is not installed",
+        "Não está pronto, o plugin não está instalado",
+    ),
+    (
+        "Not ready, the plugin is disabled",
+        "Não está pronto, o plugin está desativado",
+    ),
+    (
+        "Not ready, {} doesn't load plugins from the ASI folder, apply the patch so the plugin is loaded",
+        "Não está pronto, {} não carrega plugins da pasta ASI, aplique o patch para que o plugin seja carregado",
+    ),
+    (
+        "Not ready, nothing will load the plugin, apply the patch so the plugin is loaded",
+        "Não está pronto, nada carregará o plugin, aplique o patch para que o plugin seja carregado",
+    ),
+    (
+        "Keep running in the system tray when the window is closed",
+        "Continuar na bandeja do sistema quando a janela for fechada",
+    ),
+    (
+        "Show installer",
+        "Mostrar instalador",
+    ),
+    (
+        "Check for plugin updates",
+        "Verificar atualizações do plugin",
+    ),
+    (
+        "Open game folder",
+        "Abrir pasta do jogo",
+    ),
+    (
+        "Quit",
+        "Sair",
+    ),
+    (
+        "Open ASI folder",
+        "Abrir pasta ASI",
+    ),
+    (
+        "failed to open folder: {}",
+        "falha ao abrir a pasta: {}",
+    ),
+    (
+        "The plugin will be installed once the release details load...",
+        "O plugin será instalado assim que os detalhes da versão forem carregados...",
+    ),
+    (
+        "Release pages to load (100 releases each): {}",
+        "Páginas de versões para carregar (100 versões cada): {}",
+    ),
+    (
+        "Older releases aren't listed, raise the release page limit in the settings to see them",
+        "Versões mais antigas não são listadas, aumente o limite de páginas de versões nas configurações para vê-las",
+    ),
+    (
+        "requires installer {}",
+        "requer o instalador {}",
+    ),
+    (
+        "Plugin {} requires installer version {} or newer, update the installer to install it",
+        "O plugin {} requer o instalador na versão {} ou mais recente, atualize o instalador para instalá-lo",
+    ),
+    (
+        "Keep downloaded plugins to reinstall them without downloading again",
+        "Manter os plugins baixados para reinstalá-los sem baixar novamente",
+    ),
+    (
+        "Download cache: {} files, {}",
+        "Cache de downloads: {} arquivos, {}",
+    ),
+    (
+        "Checking the download cache...",
+        "Verificando o cache de downloads...",
+    ),
+    (
+        "Clear download cache",
+        "Limpar cache de downloads",
+    ),
+    (
+        "Published {}, download size {}",
+        "Publicado em {}, tamanho do download {}",
+    ),
+    (
+        "Published {}",
+        "Publicado em {}",
+    ),
+    (
+        "B",
+        "B",
+    ),
+    (
+        "KB",
+        "KB",
+    ),
+    (
+        "MB",
+        "MB",
+    ),
+    (
+        "GB",
+        "GB",
+    ),
+    (
+        "Checking...",
+        "Verificando...",
+    ),
+    (
+        "Downloading {}%",
+        "Baixando {}%",
+    ),
+    (
+        "Downloading... ({})",
+        "Baixando... ({})",
+    ),
+    (
+        "Verifying...",
+        "Validando arquivos...",
+    ),
+    (
+        "Writing files...",
+        "Gravando arquivos...",
+    ),
+    (
+        "Download size {}",
+        "Tamanho do download {}",
+    ),
+    (
+        "Open the install links on Pocket Relay websites and server dashboards with this installer",
+        "Abrir os links de instalação de sites e painéis de servidores Pocket Relay com este instalador",
+    ),
+    (
+        "Open links with the installer",
+        "Abrir links com o instalador",
+    ),
+    (
+        "Links now open the installer",
+        "Os links agora abrem o instalador",
+    ),
+    (
+        "The link could not be opened: {}",
+        "Não foi possível abrir o link: {}",
+    ),
+    (
+        "Opened from a link to the server {}, save the server connection once the plugin is installed to connect to it",
+        "Aberto a partir de um link para o servidor {}, salve a conexão com o servidor depois de instalar o plugin para se conectar a ele",
+    ),
+    (
+        "Are you sure you want to delete {}? It can't be restored",
+        "Tem certeza de que deseja excluir {}? Não será possível restaurá-lo",
+    ),
+];
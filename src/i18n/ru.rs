@@ -0,0 +1,1434 @@
+//! Russian translations
+
+use super::Translations;
+
+pub const TRANSLATIONS: Translations = &[
+    (
+        "Stable ({})",
+        "Стабильная ({})",
+    ),
+    (
+        "Beta ({})",
+        "Бета ({})",
+    ),
+    (
+        "Please click the button below to choose your game path. When the file picker opens navigate to the folder containing MassEffect3.exe and pick that file",
+        "Нажмите кнопку ниже, чтобы выбрать путь к игре. Когда откроется окно выбора файла, перейдите в папку с MassEffect3.exe и выберите этот файл",
+    ),
+    (
+        "Choose game path",
+        "Выбрать путь к игре",
+    ),
+    (
+        "Settings",
+        "Настройки",
+    ),
+    (
+        "failed to pick file: {}",
+        "не удалось выбрать файл: {}",
+    ),
+    (
+        "Or choose one of the following detected game installs:",
+        "Или выберите одну из найденных установок игры:",
+    ),
+    (
+        "Back",
+        "Назад",
+    ),
+    (
+        "Preview changes",
+        "Предпросмотр изменений",
+    ),
+    (
+        "Installations",
+        "Установки",
+    ),
+    (
+        "Add installation",
+        "Добавить установку",
+    ),
+    (
+        "The game folder can't be modified without administrator access, restart the installer as administrator to make changes.",
+        "Папку игры нельзя изменить без прав администратора, перезапустите установщик от имени администратора, чтобы внести изменения.",
+    ),
+    (
+        "Restart as administrator",
+        "Перезапустить от имени администратора",
+    ),
+    (
+        "Retry",
+        "Повторить",
+    ),
+    (
+        "Apply the patch and install the {} plugin in one step",
+        "Применить патч и установить плагин {} за один шаг",
+    ),
+    (
+        "Install everything",
+        "Установить всё",
+    ),
+    (
+        "Applying patch...",
+        "Применение патча...",
+    ),
+    (
+        "Installing plugin...",
+        "Установка плагина...",
+    ),
+    (
+        "Patch and plugin installed",
+        "Патч и плагин установлены",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before installing.",
+        "Mass Effect 3 запущена, закройте игру перед установкой.",
+    ),
+    (
+        "Failed to install: {}",
+        "Не удалось установить: {}",
+    ),
+    (
+        "Cancel",
+        "Отмена",
+    ),
+    (
+        "Preparing preview...",
+        "Подготовка предпросмотра...",
+    ),
+    (
+        "Failed to preview changes: {}",
+        "Не удалось показать изменения: {}",
+    ),
+    (
+        "The following changes will be made:",
+        "Будут внесены следующие изменения:",
+    ),
+    (
+        "Confirm",
+        "Подтвердить",
+    ),
+    (
+        "Server Connection",
+        "Подключение к серверу",
+    ),
+    (
+        "Address of the Pocket Relay server the plugin should connect to",
+        "Адрес сервера Pocket Relay, к которому должен подключаться плагин",
+    ),
+    (
+        "Save",
+        "Сохранить",
+    ),
+    (
+        "Loading config...",
+        "Загрузка конфигурации...",
+    ),
+    (
+        "Saving config...",
+        "Сохранение конфигурации...",
+    ),
+    (
+        "Saved server connection",
+        "Подключение к серверу сохранено",
+    ),
+    (
+        "Logs",
+        "Журналы",
+    ),
+    (
+        "Hide logs",
+        "Скрыть журналы",
+    ),
+    (
+        "Show logs",
+        "Показать журналы",
+    ),
+    (
+        "Copy diagnostics to clipboard",
+        "Скопировать диагностику в буфер обмена",
+    ),
+    (
+        "Copied diagnostics to clipboard",
+        "Диагностика скопирована в буфер обмена",
+    ),
+    (
+        "Uninstall",
+        "Удаление",
+    ),
+    (
+        "Remove the plugin, its config and log files, and restore the original binkw32.dll",
+        "Удалить плагин, его конфигурацию и файлы журналов и восстановить оригинальный binkw32.dll",
+    ),
+    (
+        "Remove the ASI folder if its empty",
+        "Удалить папку ASI, если она пуста",
+    ),
+    (
+        "Uninstall everything",
+        "Удалить всё",
+    ),
+    (
+        "Are you sure you want to remove everything Pocket Relay has installed?",
+        "Вы уверены, что хотите удалить всё, что установил Pocket Relay?",
+    ),
+    (
+        "Uninstalling...",
+        "Удаление...",
+    ),
+    (
+        "Nothing to uninstall",
+        "Нечего удалять",
+    ),
+    (
+        "Uninstalled everything",
+        "Всё удалено",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before uninstalling.",
+        "Mass Effect 3 запущена, закройте игру перед удалением.",
+    ),
+    (
+        "Failed to uninstall: {}",
+        "Не удалось удалить: {}",
+    ),
+    (
+        "ASI Plugins",
+        "Плагины ASI",
+    ),
+    (
+        "Refresh",
+        "Обновить",
+    ),
+    (
+        "Loading plugins...",
+        "Загрузка плагинов...",
+    ),
+    (
+        "failed to load plugins: {}",
+        "не удалось загрузить плагины: {}",
+    ),
+    (
+        "No ASI plugins are installed",
+        "Плагины ASI не установлены",
+    ),
+    (
+        "Enabled",
+        "Включён",
+    ),
+    (
+        "Disabled",
+        "Отключён",
+    ),
+    (
+        "Disable",
+        "Отключить",
+    ),
+    (
+        "Enable",
+        "Включить",
+    ),
+    (
+        "Delete",
+        "Удалить",
+    ),
+    (
+        "Language",
+        "Язык",
+    ),
+    (
+        "Proxy used when connecting to GitHub",
+        "Прокси для подключения к GitHub",
+    ),
+    (
+        "System proxy",
+        "Системный прокси",
+    ),
+    (
+        "No proxy",
+        "Без прокси",
+    ),
+    (
+        "Manual proxy",
+        "Ручной прокси",
+    ),
+    (
+        "Mirrors to use when GitHub is unreachable (comma separated, URL prefixes or URLs containing {url})",
+        "Зеркала для использования, когда GitHub недоступен (через запятую, префиксы URL или URL, содержащие {url})",
+    ),
+    (
+        "GitHub personal access token (optional, raises the GitHub rate limit on shared networks)",
+        "Персональный токен доступа GitHub (необязательно, повышает лимит запросов GitHub в общих сетях)",
+    ),
+    (
+        "Token",
+        "Токен",
+    ),
+    (
+        "A new version of the installer is available ({})",
+        "Доступна новая версия установщика ({})",
+    ),
+    (
+        "Update installer",
+        "Обновить установщик",
+    ),
+    (
+        "Updating installer...",
+        "Обновление установщика...",
+    ),
+    (
+        "failed to update installer: {}",
+        "не удалось обновить установщик: {}",
+    ),
+    (
+        "Your game is patched",
+        "Игра пропатчена",
+    ),
+    (
+        "Remove Patch",
+        "Удалить патч",
+    ),
+    (
+        "Your game is not patched, you must apply the patch to use the client plugin.",
+        "Игра не пропатчена, для использования клиентского плагина необходимо применить патч.",
+    ),
+    (
+        "Apply Patch",
+        "Применить патч",
+    ),
+    (
+        "Your game has an unrecognized binkw32.dll, it may be from another mod or corrupted. Replacing it will back up the current file to binkw32.dll.bak",
+        "В игре найден неизвестный binkw32.dll, возможно, он от другого мода или повреждён. При замене текущий файл будет сохранён как binkw32.dll.bak",
+    ),
+    (
+        "Back up and replace",
+        "Сохранить копию и заменить",
+    ),
+    (
+        "Installing patch...",
+        "Установка патча...",
+    ),
+    (
+        "Uninstalling patch...",
+        "Удаление патча...",
+    ),
+    (
+        "Patch successfully removed.",
+        "Патч успешно удалён.",
+    ),
+    (
+        "Patch successfully installed.",
+        "Патч успешно установлен.",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before changing the patch.",
+        "Mass Effect 3 запущена, закройте игру перед изменением патча.",
+    ),
+    (
+        "failed to add patch: {}",
+        "не удалось применить патч: {}",
+    ),
+    (
+        "failed to remove patch: {}",
+        "не удалось удалить патч: {}",
+    ),
+    (
+        "You have the Pocket Relay client plugin installed.",
+        "Клиентский плагин Pocket Relay установлен.",
+    ),
+    (
+        "Remove Plugin",
+        "Удалить плагин",
+    ),
+    (
+        "Unknown",
+        "Неизвестно",
+    ),
+    (
+        "Installed: {}",
+        "Установлена: {}",
+    ),
+    (
+        "Installed: {} (Latest)",
+        "Установлена: {} (последняя)",
+    ),
+    (
+        "Installed: {} \u{2014} Update available: {}",
+        "Установлена: {} \u{2014} Доступно обновление: {}",
+    ),
+    (
+        "Update Plugin",
+        "Обновить плагин",
+    ),
+    (
+        "You do not have the Pocket Relay client plugin installed",
+        "Клиентский плагин Pocket Relay не установлен",
+    ),
+    (
+        "Uninstalling plugin...",
+        "Удаление плагина...",
+    ),
+    (
+        "Pocket Relay client plugin successfully installed.",
+        "Клиентский плагин Pocket Relay успешно установлен.",
+    ),
+    (
+        "Pocket Relay client plugin successfully removed.",
+        "Клиентский плагин Pocket Relay успешно удалён.",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before changing the plugin.",
+        "Mass Effect 3 запущена, закройте игру перед изменением плагина.",
+    ),
+    (
+        "failed to update plugin: {}",
+        "не удалось обновить плагин: {}",
+    ),
+    (
+        "failed to install plugin: {}",
+        "не удалось установить плагин: {}",
+    ),
+    (
+        "failed to remove plugin: {}",
+        "не удалось удалить плагин: {}",
+    ),
+    (
+        "Install from file...",
+        "Установить из файла...",
+    ),
+    (
+        "Loading latest plugin version details...",
+        "Загрузка сведений о последней версии плагина...",
+    ),
+    (
+        "Unable to load latest plugin version: {}",
+        "Не удалось загрузить последнюю версию плагина: {}",
+    ),
+    (
+        "The latest version of the plugin client is {}",
+        "Последняя версия клиентского плагина: {}",
+    ),
+    (
+        "Add Plugin",
+        "Добавить плагин",
+    ),
+    (
+        "Select version",
+        "Выберите версию",
+    ),
+    (
+        "Hide notes",
+        "Скрыть заметки",
+    ),
+    (
+        "Release notes",
+        "Заметки о выпуске",
+    ),
+    (
+        "This release has no release notes",
+        "У этого выпуска нет заметок",
+    ),
+    (
+        "View on GitHub",
+        "Открыть на GitHub",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before changing plugins.",
+        "Mass Effect 3 запущена, закройте игру перед изменением плагинов.",
+    ),
+    (
+        "failed to change plugin: {}",
+        "не удалось изменить плагин: {}",
+    ),
+    (
+        "failed to load config: {}",
+        "не удалось загрузить конфигурацию: {}",
+    ),
+    (
+        "failed to save config: {}",
+        "не удалось сохранить конфигурацию: {}",
+    ),
+    (
+        "Failed to switch installation: {}",
+        "Не удалось переключить установку: {}",
+    ),
+    (
+        "{} will be created, {}",
+        "{} будет создан, {}",
+    ),
+    (
+        "{} will be overwritten (backed up to {}), {}",
+        "{} будет перезаписан (копия сохранена в {}), {}",
+    ),
+    (
+        "{} will be overwritten, {}",
+        "{} будет перезаписан, {}",
+    ),
+    (
+        "{} will be restored from {}",
+        "{} будет восстановлен из {}",
+    ),
+    (
+        "{} will be deleted",
+        "{} будет удалён",
+    ),
+    (
+        "Administrator access is required to modify the game folder, use \"Restart as administrator\" or install the game outside Program Files",
+        "Для изменения папки игры требуются права администратора, используйте \"Перезапустить от имени администратора\" или установите игру вне Program Files",
+    ),
+    (
+        "Theme",
+        "Тема",
+    ),
+    (
+        "System",
+        "Системная",
+    ),
+    (
+        "Light",
+        "Светлая",
+    ),
+    (
+        "Dark",
+        "Тёмная",
+    ),
+    (
+        "Accent color (optional, hex color such as #5865f2)",
+        "Акцентный цвет (необязательно, шестнадцатеричный цвет, например #5865f2)",
+    ),
+    (
+        "Invalid accent color, expected a hex color such as #5865f2",
+        "Недопустимый акцентный цвет, ожидается шестнадцатеричный цвет, например #5865f2",
+    ),
+    (
+        "Proton",
+        "Proton",
+    ),
+    (
+        "When running through Proton the game must load the patched binkw32.dll, add the following to the game launch options in Steam:",
+        "При запуске через Proton игра должна загружать пропатченный binkw32.dll, добавьте следующее в параметры запуска игры в Steam:",
+    ),
+    (
+        "Add to Steam launch options",
+        "Добавить в параметры запуска Steam",
+    ),
+    (
+        "Copy launch options",
+        "Скопировать параметры запуска",
+    ),
+    (
+        "Updating launch options...",
+        "Обновление параметров запуска...",
+    ),
+    (
+        "Launch options are already set",
+        "Параметры запуска уже заданы",
+    ),
+    (
+        "Launch options updated, restart Steam to use them",
+        "Параметры запуска обновлены, перезапустите Steam, чтобы применить их",
+    ),
+    (
+        "Copied launch options to clipboard",
+        "Параметры запуска скопированы в буфер обмена",
+    ),
+    (
+        "failed to update launch options: {}",
+        "не удалось обновить параметры запуска: {}",
+    ),
+    (
+        "Verified",
+        "Проверено",
+    ),
+    (
+        "Plugin update available ({})",
+        "Доступно обновление плагина ({})",
+    ),
+    (
+        "Update",
+        "Обновить",
+    ),
+    (
+        "Cannot reach GitHub, check your internet connection",
+        "Не удаётся подключиться к GitHub, проверьте подключение к интернету",
+    ),
+    (
+        "GitHub rate limited, wait a while and try again",
+        "Превышен лимит запросов GitHub, подождите немного и попробуйте снова",
+    ),
+    (
+        "Invalid plugin repository, expected a GitHub repository such as PocketRelay/PocketRelayClientPlugin",
+        "Неверный репозиторий плагина, ожидается репозиторий GitHub, например PocketRelay/PocketRelayClientPlugin",
+    ),
+    (
+        "Invalid plugin asset name, expected a file name such as pocket-relay-plugin.asi",
+        "Неверное имя файла плагина, ожидается имя файла, например pocket-relay-plugin.asi",
+    ),
+    (
+        "Plugin source (change to install a fork or test build of the plugin)",
+        "Источник плагина (измените, чтобы установить форк или тестовую сборку плагина)",
+    ),
+    (
+        "Use official plugin",
+        "Использовать официальный плагин",
+    ),
+    (
+        "Using plugin releases from {} ({})",
+        "Используются версии плагина из {} ({})",
+    ),
+    (
+        "binkw32.dll has been replaced by another ASI loader, such as the ME3Tweaks binkw32 bypass",
+        "binkw32.dll заменён другим загрузчиком ASI, например обходом binkw32 от ME3Tweaks",
+    ),
+    (
+        "Apply the patch to replace it, plugins in the ASI folder will still be loaded",
+        "Примените патч, чтобы заменить его, плагины из папки ASI по-прежнему будут загружаться",
+    ),
+    (
+        "binkw32.dll is not a known version, it may be modified or corrupted",
+        "binkw32.dll неизвестной версии, возможно, он изменён или повреждён",
+    ),
+    (
+        "Apply the patch to replace it, or verify the game files through your launcher",
+        "Примените патч, чтобы заменить его, или проверьте файлы игры через лаунчер",
+    ),
+    (
+        "{} is a wrapper loader (such as Ultimate ASI Loader or ReShade), it can load plugins twice or stop the client plugin from loading",
+        "{} является загрузчиком-обёрткой (например Ultimate ASI Loader или ReShade), он может загружать плагины дважды или мешать загрузке клиентского плагина",
+    ),
+    (
+        "Remove or rename the file if the client plugin doesn't load",
+        "Удалите или переименуйте файл, если клиентский плагин не загружается",
+    ),
+    (
+        "The {} folder contains ASI plugins for another ASI loader",
+        "Папка {} содержит плагины ASI для другого загрузчика ASI",
+    ),
+    (
+        "Move the plugins into the ASI folder so they are only loaded once",
+        "Переместите плагины в папку ASI, чтобы они загружались только один раз",
+    ),
+    (
+        "ASI plugin {} is loaded with the client plugin",
+        "Плагин ASI {} загружается вместе с клиентским плагином",
+    ),
+    (
+        "Disable it from the ASI plugins list if the game crashes or can't connect",
+        "Отключите его в списке плагинов ASI, если игра вылетает или не может подключиться",
+    ),
+    (
+        "DLC mods are installed: {}",
+        "Установлены DLC-моды: {}",
+    ),
+    (
+        "Players in the same lobby need the same multiplayer mods, disable mods that change multiplayer if you have trouble joining games",
+        "Игрокам в одном лобби нужны одинаковые мультиплеерные моды, отключите моды, меняющие мультиплеер, если не удаётся присоединиться к играм",
+    ),
+    (
+        "{} is a Legendary Edition file, Legendary Edition mods don't work with the original Mass Effect 3",
+        "{} является файлом Legendary Edition, моды для Legendary Edition не работают с оригинальной Mass Effect 3",
+    ),
+    (
+        "Remove the mod or repair the game files through your launcher",
+        "Удалите мод или восстановите файлы игры через лаунчер",
+    ),
+    (
+        "Compatibility",
+        "Совместимость",
+    ),
+    (
+        "Scan again",
+        "Проверить снова",
+    ),
+    (
+        "Scanning game folder...",
+        "Проверка папки игры...",
+    ),
+    (
+        "failed to scan game folder: {}",
+        "не удалось проверить папку игры: {}",
+    ),
+    (
+        "No known conflicts were found",
+        "Известных конфликтов не найдено",
+    ),
+    (
+        "Installation report",
+        "Отчёт об установке",
+    ),
+    (
+        "Export a report of the game files, or compare against a report from an installation that works",
+        "Экспортируйте отчёт о файлах игры или сравните с отчётом рабочей установки",
+    ),
+    (
+        "Export report",
+        "Экспортировать отчёт",
+    ),
+    (
+        "Compare with report",
+        "Сравнить с отчётом",
+    ),
+    (
+        "Reading game files...",
+        "Чтение файлов игры...",
+    ),
+    (
+        "Exported report to {}",
+        "Отчёт экспортирован в {}",
+    ),
+    (
+        "failed to create report: {}",
+        "не удалось создать отчёт: {}",
+    ),
+    (
+        "The installation matches the report",
+        "Установка совпадает с отчётом",
+    ),
+    (
+        "Differences from the report:",
+        "Отличия от отчёта:",
+    ),
+    (
+        "Current: {}\nReport: {}",
+        "Сейчас: {}\nОтчёт: {}",
+    ),
+    (
+        "Installation complete",
+        "Установка завершена",
+    ),
+    (
+        "Pocket Relay plugin {} installed",
+        "Плагин Pocket Relay {} установлен",
+    ),
+    (
+        "Pocket Relay plugin installed",
+        "Плагин Pocket Relay установлен",
+    ),
+    (
+        "Installation failed",
+        "Ошибка установки",
+    ),
+    (
+        "Mass Effect 3 is running, close the game and try again",
+        "Mass Effect 3 запущена, закройте игру и попробуйте снова",
+    ),
+    (
+        "Unlimited",
+        "Без ограничений",
+    ),
+    (
+        "{} KB/s",
+        "{} КБ/с",
+    ),
+    (
+        "Download speed limit (for metered or shared connections): {}",
+        "Ограничение скорости загрузки (для лимитных или общих подключений): {}",
+    ),
+    (
+        "Show advanced details",
+        "Показать подробные сведения",
+    ),
+    (
+        "Hide advanced details",
+        "Скрыть подробные сведения",
+    ),
+    (
+        "missing",
+        "отсутствует",
+    ),
+    (
+        "present",
+        "присутствует",
+    ),
+    (
+        "unknown",
+        "неизвестно",
+    ),
+    (
+        "binkw32.dll SHA256: {}",
+        "SHA256 binkw32.dll: {}",
+    ),
+    (
+        "binkw23.dll: {}",
+        "binkw23.dll: {}",
+    ),
+    (
+        "Plugin SHA256: {}",
+        "SHA256 плагина: {}",
+    ),
+    (
+        "Plugin size: {}",
+        "Размер плагина: {}",
+    ),
+    (
+        "Plugin modified: {}",
+        "Плагин изменён: {}",
+    ),
+    (
+        "Plugin file: not installed",
+        "Файл плагина: не установлен",
+    ),
+    (
+        "Back up the plugin config and logs, restored when reinstalling",
+        "Сохранить резервную копию настроек и журналов плагина, она будет восстановлена при переустановке",
+    ),
+    (
+        "Downloads",
+        "Загрузки",
+    ),
+    (
+        "Clear finished",
+        "Очистить завершённые",
+    ),
+    (
+        "Queued",
+        "В очереди",
+    ),
+    (
+        "Downloading...",
+        "Загрузка...",
+    ),
+    (
+        "Finished",
+        "Завершено",
+    ),
+    (
+        "Failed: {}",
+        "Ошибка: {}",
+    ),
+    (
+        "Cancelled",
+        "Отменено",
+    ),
+    (
+        "The installer is damaged and can't safely patch your game. This is usually caused by an incomplete download or an antivirus modifying the installer, download the installer again and add an exclusion for it in your antivirus",
+        "Установщик повреждён и не может безопасно пропатчить игру. Обычно это вызвано неполной загрузкой или антивирусом, изменившим установщик. Загрузите установщик заново и добавьте его в исключения антивируса",
+    ),
+    (
+        "The plugin was removed right after it was installed, this is usually your antivirus falsely detecting the plugin as a threat and quarantining it.",
+        "Плагин был удалён сразу после установки. Обычно это антивирус ошибочно считает плагин угрозой и помещает его в карантин.",
+    ),
+    (
+        "Add an exclusion for the following folder in your antivirus (for Windows Defender: Virus & threat protection > Manage settings > Exclusions), then reinstall the plugin:",
+        "Добавьте следующую папку в исключения антивируса (для Защитника Windows: Защита от вирусов и угроз > Управление настройками > Исключения), затем переустановите плагин:",
+    ),
+    (
+        "Reinstall",
+        "Переустановить",
+    ),
+    (
+        "Default plugin release channel",
+        "Канал выпусков плагина по умолчанию",
+    ),
+    (
+        "Stable",
+        "Стабильная",
+    ),
+    (
+        "Beta",
+        "Бета",
+    ),
+    (
+        "Folder for downloads and cached releases (optional, uses the system folders when empty)",
+        "Папка для загрузок и кэша выпусков (необязательно, если пусто, используются системные папки)",
+    ),
+    (
+        "System folders",
+        "Системные папки",
+    ),
+    (
+        "Browse...",
+        "Обзор...",
+    ),
+    (
+        "Invalid cache folder, expected a full folder path",
+        "Недопустимая папка кэша, ожидается полный путь к папке",
+    ),
+    (
+        "Launch Mass Effect 3",
+        "Запустить Mass Effect 3",
+    ),
+    (
+        "failed to launch game: {}",
+        "не удалось запустить игру: {}",
+    ),
+    (
+        "Plugin {} is too new for your server (version {}), update the server or pick an older plugin version",
+        "Плагин {} слишком новый для вашего сервера (версия {}), обновите сервер или выберите более старую версию плагина",
+    ),
+    (
+        "Plugin {} is too old for your server (version {}), pick a newer plugin version",
+        "Плагин {} слишком старый для вашего сервера (версия {}), выберите более новую версию плагина",
+    ),
+    (
+        "Network diagnostics",
+        "Диагностика сети",
+    ),
+    (
+        "Resolve api.github.com",
+        "Разрешение api.github.com",
+    ),
+    (
+        "Connect to api.github.com",
+        "Подключение к api.github.com",
+    ),
+    (
+        "Secure connection (TLS)",
+        "Защищённое соединение (TLS)",
+    ),
+    (
+        "GitHub API",
+        "API GitHub",
+    ),
+    (
+        "Download server",
+        "Сервер загрузки",
+    ),
+    (
+        "skipped",
+        "пропущено",
+    ),
+    (
+        "Checking connection...",
+        "Проверка соединения...",
+    ),
+    (
+        "Use \"Network diagnostics\" in the logs section to find out why",
+        "Используйте \"Диагностика сети\" в разделе журналов, чтобы узнать причину",
+    ),
+    (
+        "Plugin file",
+        "Файл плагина",
+    ),
+    (
+        "Plugin matches the checksum published with the release",
+        "Плагин совпадает с контрольной суммой, опубликованной с выпуском",
+    ),
+    (
+        "Release doesn't include a checksums file for the plugin",
+        "Выпуск не содержит файла контрольных сумм для плагина",
+    ),
+    (
+        "Interface scale: {}%",
+        "Масштаб интерфейса: {}%",
+    ),
+    (
+        "Copy error",
+        "Копировать ошибку",
+    ),
+    (
+        "Release feeds for additional channels such as nightly builds (comma separated, name=URL)",
+        "Ленты выпусков для дополнительных каналов, например ночных сборок (через запятую, имя=URL)",
+    ),
+    (
+        "Plugin logs",
+        "Журналы плагина",
+    ),
+    (
+        "View plugin logs",
+        "Показать журналы плагина",
+    ),
+    (
+        "Hide plugin logs",
+        "Скрыть журналы плагина",
+    ),
+    (
+        "Copy plugin logs",
+        "Копировать журналы плагина",
+    ),
+    (
+        "Export plugin logs",
+        "Экспортировать журналы плагина",
+    ),
+    (
+        "Copied plugin logs to clipboard",
+        "Журналы плагина скопированы в буфер обмена",
+    ),
+    (
+        "Exported plugin logs to {}",
+        "Журналы плагина экспортированы в {}",
+    ),
+    (
+        "failed to export plugin logs: {}",
+        "не удалось экспортировать журналы плагина: {}",
+    ),
+    (
+        "Reading plugin logs...",
+        "Чтение журналов плагина...",
+    ),
+    (
+        "The plugin hasn't written any logs yet, start the game with the plugin installed",
+        "Плагин ещё не записал журналы, запустите игру с установленным плагином",
+    ),
+    (
+        "failed to read plugin logs: {}",
+        "не удалось прочитать журналы плагина: {}",
+    ),
+    (
+        "Leftover files",
+        "Оставшиеся файлы",
+    ),
+    (
+        "No files from previous installs were found",
+        "Файлы предыдущих установок не найдены",
+    ),
+    (
+        "These files were left behind by a previous or manual install and can stop the plugin from working",
+        "Эти файлы остались от предыдущей или ручной установки и могут мешать работе плагина",
+    ),
+    (
+        "plugin from an older release",
+        "плагин из старого выпуска",
+    ),
+    (
+        "plugin outside of the ASI folder",
+        "плагин вне папки ASI",
+    ),
+    (
+        "plugin config outside of the ASI folder",
+        "конфигурация плагина вне папки ASI",
+    ),
+    (
+        "not used without the patched binkw32.dll",
+        "не используется без пропатченного binkw32.dll",
+    ),
+    (
+        "Clean up leftover files",
+        "Очистить оставшиеся файлы",
+    ),
+    (
+        "Cleaning up leftover files...",
+        "Очистка оставшихся файлов...",
+    ),
+    (
+        "Cleaned up leftover files",
+        "Оставшиеся файлы очищены",
+    ),
+    (
+        "Mass Effect 3 is currently running, close the game before cleaning up.",
+        "Mass Effect 3 сейчас запущена, закройте игру перед очисткой.",
+    ),
+    (
+        "Failed to clean up leftover files: {}",
+        "Не удалось очистить оставшиеся файлы: {}",
+    ),
+    (
+        "All installations",
+        "Все установки",
+    ),
+    (
+        "Apply the patch and install the {} plugin to all {} installations",
+        "Применить патч и установить плагин {} во все установки ({})",
+    ),
+    (
+        "Install to all installations",
+        "Установить во все установки",
+    ),
+    (
+        "Waiting",
+        "Ожидание",
+    ),
+    (
+        "Installing...",
+        "Установка...",
+    ),
+    (
+        "Installed, checksum verified",
+        "Установлено, контрольная сумма проверена",
+    ),
+    (
+        "Installed",
+        "Установлено",
+    ),
+    (
+        "Skipped, the game is running",
+        "Пропущено, игра запущена",
+    ),
+    (
+        "Portable folder",
+        "Портативная папка",
+    ),
+    (
+        "Portable mode, the settings, cache and logs are stored in {}",
+        "Портативный режим, настройки, кэш и журналы хранятся в {}",
+    ),
+    (
+        "Game",
+        "Игра",
+    ),
+    (
+        "The game can't be changed while changes are being made",
+        "Нельзя сменить игру, пока вносятся изменения",
+    ),
+    (
+        "Set-up checklist",
+        "Контрольный список настройки",
+    ),
+    (
+        "The game is installed for all users in Program Files, changes to the game folder need administrator access",
+        "Игра установлена для всех пользователей в Program Files, для изменения папки игры нужны права администратора",
+    ),
+    (
+        "The installer is running as administrator and can modify the game folder",
+        "Установщик запущен от имени администратора и может изменять папку игры",
+    ),
+    (
+        "The installer can modify the game folder",
+        "Установщик может изменять папку игры",
+    ),
+    (
+        "The game folder can't be modified even as administrator, check the folder permissions and that no antivirus is blocking changes",
+        "Папку игры нельзя изменить даже от имени администратора, проверьте права доступа к папке и не блокирует ли изменения антивирус",
+    ),
+    (
+        "The game loads your own copies of these files instead of the installed ones, remove them from {}: {}",
+        "Игра загружает ваши собственные копии этих файлов вместо установленных, удалите их из {}: {}",
+    ),
+    (
+        "Check again",
+        "Проверить снова",
+    ),
+    (
+        "Send anonymous usage statistics",
+        "Отправлять анонимную статистику использования",
+    ),
+    (
+        "Reports the installer version, operating system and whether each change to the game succeeded, so the most common problems can be fixed. Paths, errors and servers are never sent.",
+        "Сообщает версию установщика, операционную систему и успешность каждого изменения игры, чтобы исправлять самые частые проблемы. Пути, ошибки и серверы никогда не отправляются.",
+    ),
+    (
+        "Example of a report that would be sent:",
+        "Пример отчёта, который будет отправлен:",
+    ),
+    (
+        "Invalid statistics endpoint, expected an http:// or https:// URL",
+        "Неверный адрес для статистики, ожидается URL http:// или https://",
+    ),
+    (
+        "Enter the endpoint to send usage statistics to",
+        "Укажите адрес для отправки статистики использования",
+    ),
+    (
+        "Help",
+        "Помощь",
+    ),
+    (
+        "Close",
+        "Закрыть",
+    ),
+    (
+        "Troubleshooting",
+        "Устранение неполадок",
+    ),
+    (
+        "Loading troubleshooting guide...",
+        "Загрузка руководства по устранению неполадок...",
+    ),
+    (
+        "Couldn't fetch the latest troubleshooting guide, showing the saved copy",
+        "Не удалось загрузить последнее руководство, показана сохранённая копия",
+    ),
+    (
+        "Couldn't fetch the latest troubleshooting guide, showing the copy included with the installer",
+        "Не удалось загрузить последнее руководство, показана копия из установщика",
+    ),
+    (
+        "More details",
+        "Подробнее",
+    ),
+    (
+        "Apply patch again",
+        "Применить патч снова",
+    ),
+    (
+        "Dismiss",
+        "Скрыть",
+    ),
+    (
+        "Patch removed",
+        "Патч удалён",
+    ),
+    (
+        "The original binkw32.dll was restored, open the installer to apply the patch again",
+        "Оригинальный binkw32.dll был восстановлен, откройте установщик, чтобы снова применить патч",
+    ),
+    (
+        "The patch was removed, the original binkw32.dll was restored (Origin and the EA App do this when repairing or updating the game). Apply the patch again to keep using Pocket Relay.",
+        "Патч был удалён, оригинальный binkw32.dll был восстановлен (Origin и EA App делают это при восстановлении или обновлении игры). Примените патч снова, чтобы продолжить использовать Pocket Relay.",
+    ),
+    (
+        "Offer to apply the patch again when Origin or the EA App removes it",
+        "Предлагать снова применить патч, когда Origin или EA App удаляет его",
+    ),
+    (
+        "Disable Plugin",
+        "Отключить плагин",
+    ),
+    (
+        "Enable Plugin",
+        "Включить плагин",
+    ),
+    (
+        "The Pocket Relay client plugin is disabled, the game will connect to the official servers until its enabled.",
+        "Клиентский плагин Pocket Relay отключён, игра будет подключаться к официальным серверам, пока он не будет включён.",
+    ),
+    (
+        "Enabling plugin...",
+        "Включение плагина...",
+    ),
+    (
+        "Disabling plugin...",
+        "Отключение плагина...",
+    ),
+    (
+        "failed to enable plugin: {}",
+        "не удалось включить плагин: {}",
+    ),
+    (
+        "failed to disable plugin: {}",
+        "не удалось отключить плагин: {}",
+    ),
+    (
+        "History",
+        "История",
+    ),
+    (
+        "Undo",
+        "Отменить",
+    ),
+    (
+        "Undoing...",
+        "Отмена...",
+    ),
+    (
+        "Applied the patch",
+        "Патч применён",
+    ),
+    (
+        "Removed the patch",
+        "Патч удалён",
+    ),
+    (
+        "Installed the plugin",
+        "Плагин установлен",
+    ),
+    (
+        "Removed the plugin",
+        "Плагин удалён",
+    ),
+    (
+        "Enabled the plugin",
+        "Плагин включён",
+    ),
+    (
+        "Disabled the plugin",
+        "Плагин отключён",
+    ),
+    (
+        "Applied the patch and installed the plugin",
+        "Патч применён и плагин установлен",
+    ),
+    (
+        "failed to load history: {}",
+        "не удалось загрузить историю: {}",
+    ),
+    (
+        "Loading history...",
+        "Загрузка истории...",
+    ),
+    (
+        "The installer hasn't made any changes to this game",
+        "Установщик не вносил изменений в эту игру",
+    ),
+    (
+        "The game is currently running, close the game before undoing changes.",
+        "Игра сейчас запущена, закройте игру перед отменой изменений.",
+    ),
+    (
+        "failed to undo change: {}",
+        "не удалось отменить изменение: {}",
+    ),
+    (
+        "Choose folder",
+        "Выбрать папку",
+    ),
+    (
+        "EA App",
+        "EA App",
+    ),
+    (
+        "Store: {}",
+        "Магазин: {}",
+    ),
+    (
+        "Origin may remove the patch when it updates or repairs the game, apply the patch again if Pocket Relay stops working",
+        "Origin может удалить патч при обновлении или восстановлении игры, примените патч снова, если Pocket Relay перестанет работать",
+    ),
+    (
+        "The EA App may remove the patch when it updates or repairs the game, apply the patch again if Pocket Relay stops working",
+        "EA App может удалить патч при обновлении или восстановлении игры, примените патч снова, если Pocket Relay перестанет работать",
+    ),
+    (
+        "the Pocket Relay patch",
+        "патч Pocket Relay",
+    ),
+    (
+        "another binkw32.dll loader",
+        "другой загрузчик binkw32.dll",
+    ),
+    (
+        "Ready to play, the plugin is loaded by {}",
+        "Готово к игре, плагин загружается через {}",
+    ),
+    (
+        "Not ready, the plugin is not installed",
+        "Не готово, плагин не установлен",
+    ),
+    (
+        "Not ready, the plugin is disabled",
+        "Не готово, плагин отключён",
+    ),
+    (
+        "Not ready, {} doesn't load plugins from the ASI folder, apply the patch so the plugin is loaded",
+        "Не готово, {} не загружает плагины из папки ASI, примените патч, чтобы плагин загружался",
+    ),
+    (
+        "Not ready, nothing will load the plugin, apply the patch so the plugin is loaded",
+        "Не готово, плагин ничем не загружается, примените патч, чтобы плагин загружался",
+    ),
+    (
+        "Keep running in the system tray when the window is closed",
+        "Оставаться в системном трее при закрытии окна",
+    ),
+    (
+        "Show installer",
+        "Показать установщик",
+    ),
+    (
+        "Check for plugin updates",
+        "Проверить обновления плагина",
+    ),
+    (
+        "Open game folder",
+        "Открыть папку игры",
+    ),
+    (
+        "Quit",
+        "Выход",
+    ),
+    (
+        "Open ASI folder",
+        "Открыть папку ASI",
+    ),
+    (
+        "failed to open folder: {}",
+        "не удалось открыть папку: {}",
+    ),
+    (
+        "The plugin will be installed once the release details load...",
+        "Плагин будет установлен после загрузки сведений о выпуске...",
+    ),
+    (
+        "Release pages to load (100 releases each): {}",
+        "Страниц выпусков для загрузки (по 100 выпусков): {}",
+    ),
+    (
+        "Older releases aren't listed, raise the release page limit in the settings to see them",
+        "Старые выпуски не показаны, увеличьте лимит страниц выпусков в настройках, чтобы их увидеть",
+    ),
+    (
+        "requires installer {}",
+        "требуется установщик {}",
+    ),
+    (
+        "Plugin {} requires installer version {} or newer, update the installer to install it",
+        "Плагину {} нужен установщик версии {} или новее, обновите установщик, чтобы установить его",
+    ),
+    (
+        "Keep downloaded plugins to reinstall them without downloading again",
+        "Сохранять загруженные плагины для повторной установки без повторной загрузки",
+    ),
+    (
+        "Download cache: {} files, {}",
+        "Кэш загрузок: файлов: {}, {}",
+    ),
+    (
+        "Checking the download cache...",
+        "Проверка кэша загрузок...",
+    ),
+    (
+        "Clear download cache",
+        "Очистить кэш загрузок",
+    ),
+    (
+        "Published {}, download size {}",
+        "Опубликовано {}, размер загрузки {}",
+    ),
+    (
+        "Published {}",
+        "Опубликовано {}",
+    ),
+    (
+        "B",
+        "Б",
+    ),
+    (
+        "KB",
+        "КБ",
+    ),
+    (
+        "MB",
+        "МБ",
+    ),
+    (
+        "GB",
+        "ГБ",
+    ),
+    (
+        "Checking...",
+        "Проверка...",
+    ),
+    (
+        "Downloading {}%",
+        "Загрузка {}%",
+    ),
+    (
+        "Downloading... ({})",
+        "Загрузка... ({})",
+    ),
+    (
+        "Verifying...",
+        "Проверка файлов...",
+    ),
+    (
+        "Writing files...",
+        "Запись файлов...",
+    ),
+    (
+        "Download size {}",
+        "Размер загрузки {}",
+    ),
+    (
+        "Open the install links on Pocket Relay websites and server dashboards with this installer",
+        "Открывать ссылки установки на сайтах Pocket Relay и в панелях серверов с помощью этого установщика",
+    ),
+    (
+        "Open links with the installer",
+        "Открывать ссылки установщиком",
+    ),
+    (
+        "Links now open the installer",
+        "Теперь ссылки открывают установщик",
+    ),
+    (
+        "The link could not be opened: {}",
+        "Не удалось открыть ссылку: {}",
+    ),
+    (
+        "Opened from a link to the server {}, save the server connection once the plugin is installed to connect to it",
+        "Открыто по ссылке на сервер {}, сохраните подключение к серверу после установки плагина, чтобы подключиться к нему",
+    ),
+    (
+        "Are you sure you want to delete {}? It can't be restored",
+        "Вы уверены, что хотите удалить {}? Его нельзя будет восстановить",
+    ),
+];
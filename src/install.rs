@@ -0,0 +1,192 @@
+//! Module for applying the patch and installing the plugin together
+//! as a single action
+
+use crate::{
+    bink::{apply_patch, patch_state, remove_patch, PatchState},
+    downloads::DownloadManager,
+    github::GitHubRelease,
+    plugin::{
+        apply_plugin, find_named_asset, restore_plugin, snapshot_plugin, staging_dir,
+        ChecksumStatus, PluginSnapshot,
+    },
+    preflight::{check_disk_space, check_install_paths},
+    process::ensure_game_not_running,
+    profile::game,
+    progress::Progress,
+};
+use anyhow::{anyhow, Context};
+use log::{debug, error};
+use std::{future::Future, path::PathBuf};
+
+/// Current step of installing everything, includes the progress of the
+/// step once its known
+#[derive(Debug, Clone, Copy)]
+pub enum InstallStep {
+    /// Applying the patch to the game
+    Patching(Option<Progress>),
+    /// Downloading and installing the plugin
+    Plugin(Option<Progress>),
+}
+
+/// Action that undoes a step of an [InstallPlan]
+#[derive(Debug)]
+enum Compensation {
+    /// Removes the patch, restoring the original binkw32.dll
+    RemovePatch,
+    /// Restores the plugin files from before the plugin was installed
+    RestorePlugin(PluginSnapshot),
+}
+
+impl Compensation {
+    /// Whether the compensation can safely undo a step that failed part way,
+    /// removing the patch after a failed patch would replace a binkw32.dll
+    /// that was never backed up
+    fn undoes_partial(&self) -> bool {
+        matches!(self, Compensation::RestorePlugin(_))
+    }
+
+    async fn run(self, game_path: PathBuf) -> anyhow::Result<()> {
+        match self {
+            Compensation::RemovePatch => remove_patch(game(), game_path)
+                .await
+                .context("failed to remove patch"),
+            Compensation::RestorePlugin(snapshot) => restore_plugin(&game_path, snapshot)
+                .await
+                .context("failed to restore previous plugin"),
+        }
+    }
+}
+
+/// Set of install steps that are applied together, when a step fails the
+/// completed steps are undone in reverse order so the game folder is left
+/// how it was before the install
+struct InstallPlan {
+    /// Path to the game folder
+    game_path: PathBuf,
+    /// Compensations for the completed steps, in the order they completed
+    compensations: Vec<Compensation>,
+}
+
+impl InstallPlan {
+    fn new(game_path: PathBuf) -> Self {
+        Self {
+            game_path,
+            compensations: Vec::new(),
+        }
+    }
+
+    /// Runs the `step` future registering the `compensation` to undo it. When
+    /// the step fails the plan is rolled back, including the failed step if
+    /// the compensation can undo a partially applied step
+    async fn run_step<T, F>(&mut self, compensation: Compensation, step: F) -> anyhow::Result<T>
+    where
+        F: Future<Output = anyhow::Result<T>>,
+    {
+        match step.await {
+            Ok(value) => {
+                self.compensations.push(compensation);
+                Ok(value)
+            }
+            Err(err) => {
+                if compensation.undoes_partial() {
+                    self.compensations.push(compensation);
+                }
+                Err(self.rollback(err).await)
+            }
+        }
+    }
+
+    /// Undoes the completed steps in reverse order, the `err` that caused the
+    /// rollback is combined with the outcome of the rollback into a single error
+    async fn rollback(&mut self, err: anyhow::Error) -> anyhow::Error {
+        let mut failures = Vec::new();
+
+        while let Some(compensation) = self.compensations.pop() {
+            if let Err(err) = compensation.run(self.game_path.clone()).await {
+                error!("failed to rollback install step: {err:?}");
+                failures.push(format!("{err:#}"));
+            }
+        }
+
+        if failures.is_empty() {
+            debug!("rolled back install");
+            err.context("install failed, all changes were rolled back")
+        } else {
+            err.context(anyhow!(
+                "install failed and some changes could not be rolled back ({})",
+                failures.join(", ")
+            ))
+        }
+    }
+}
+
+/// Applies the patch to the game when its not already patched then installs
+/// the plugin from the `asset_name` asset of the provided `release`, downloading
+/// through the `downloads` manager. When any step fails the changes from
+/// earlier steps are rolled back. Each step is reported to `on_step`, the
+/// result of checking the plugin against the release checksums is returned
+pub async fn install_all(
+    http_client: reqwest::Client,
+    downloads: DownloadManager,
+    mirrors: Vec<String>,
+    game_path: PathBuf,
+    release: GitHubRelease,
+    asset_name: String,
+    mut on_step: impl FnMut(InstallStep),
+) -> anyhow::Result<ChecksumStatus> {
+    // Report problems with the game folder before patching anything
+    check_install_paths(&game_path).await?;
+
+    // Patch shouldn't be applied when there isn't space for the plugin
+    let asset = find_named_asset(&release, &asset_name)?;
+    check_disk_space(
+        &game_path,
+        &staging_dir(downloads.download_dir()),
+        asset.size,
+        asset.size,
+    )
+    .await?;
+
+    // Checked up front as the rollback would also be blocked by the game
+    ensure_game_not_running(&game_path).await?;
+
+    let mut plan = InstallPlan::new(game_path.clone());
+
+    // Missing, unreadable or unknown binkw32.dll is replaced with the patch
+    let already_patched = matches!(
+        patch_state(game(), &game_path).await,
+        Ok(PatchState::Patched)
+    );
+
+    if !already_patched {
+        on_step(InstallStep::Patching(None));
+
+        plan.run_step(Compensation::RemovePatch, async {
+            apply_patch(game(), game_path.clone(), |progress| {
+                on_step(InstallStep::Patching(Some(progress)))
+            })
+            .await
+            .context("failed to apply patch")
+        })
+        .await?;
+    }
+
+    on_step(InstallStep::Plugin(None));
+
+    let snapshot = snapshot_plugin(&game_path).await;
+
+    plan.run_step(Compensation::RestorePlugin(snapshot), async {
+        apply_plugin(
+            http_client,
+            downloads,
+            mirrors,
+            game_path.clone(),
+            release,
+            asset_name,
+            |progress| on_step(InstallStep::Plugin(Some(progress))),
+        )
+        .await
+        .context("failed to install plugin")
+    })
+    .await
+}
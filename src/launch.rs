@@ -0,0 +1,45 @@
+//! Module for launching the game after installing, the game is launched
+//! through the store it was installed from so the store DRM checks pass
+
+use crate::{profile::profile, store::StoreKind};
+use anyhow::{bail, Context};
+use log::debug;
+use std::{path::Path, process::Command};
+
+/// Windows error code for starting a program that requires elevation
+const ERROR_ELEVATION_REQUIRED: i32 = 740;
+
+/// Launches the game in the `game_path` using the launch strategy for the
+/// `store` it was installed from
+pub fn launch_game(game_path: &Path, store: StoreKind) -> anyhow::Result<()> {
+    debug!("launching game from {store:?} install");
+
+    match store {
+        StoreKind::Steam => {
+            open::that_detached(format!("steam://rungameid/{}", profile().steam_app_id))
+                .context("failed to launch the game through Steam")
+        }
+        // Origin and the EA App take over launching the game when the
+        // executable is started directly
+        StoreKind::Origin | StoreKind::EaApp | StoreKind::Unknown => launch_exe(game_path),
+    }
+}
+
+/// Starts the game executable directly from the game folder
+fn launch_exe(game_path: &Path) -> anyhow::Result<()> {
+    // Non Steam installs on other platforms run within a Wine prefix
+    if cfg!(not(target_os = "windows")) {
+        bail!("the game can only be launched from Steam or your game launcher on this platform");
+    }
+
+    let exe_path = game_path.join(profile().exe_name);
+
+    match Command::new(&exe_path).current_dir(game_path).spawn() {
+        Ok(_) => Ok(()),
+        // Starting through the shell shows the elevation prompt
+        Err(err) if err.raw_os_error() == Some(ERROR_ELEVATION_REQUIRED) => {
+            open::that_detached(&exe_path).context("failed to start the game")
+        }
+        Err(err) => Err(err).context("failed to start the game"),
+    }
+}
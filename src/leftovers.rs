@@ -0,0 +1,166 @@
+//! Module for finding files left behind by older or half-finished manual
+//! installs, leftovers in the wrong place can load a second copy of the
+//! plugin or leave the game loading an unpatched binkw23.dll
+
+use crate::{
+    bink::{patch_state, PatchState},
+    fs_util::resolve_file_name,
+    plugin::{PLUGIN_DIR, PLUGIN_NAME},
+    plugin_config::PLUGIN_CONFIG_NAME,
+    process::ensure_game_not_running,
+    profile::game,
+};
+use anyhow::Context;
+use log::debug;
+use std::path::{Path, PathBuf};
+
+/// File names used by older releases of the client plugin
+const LEGACY_PLUGIN_NAMES: &[&str] = &[
+    "pocket-relay-client.asi",
+    "pocket-relay.asi",
+    "PocketRelayClientPlugin.asi",
+];
+
+/// Folders the plugin files are mistakenly copied into by manual installs,
+/// relative to the game folder ("" being the game folder itself)
+const MISPLACED_DIRS: &[&str] = &["", "scripts", "plugins"];
+
+/// Kind of leftover file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeftoverKind {
+    /// Plugin file from an older release
+    LegacyPlugin,
+    /// Plugin file outside of the ASI folder
+    MisplacedPlugin,
+    /// Plugin config outside of the ASI folder
+    MisplacedConfig,
+    /// binkw23.dll left behind without the patched binkw32.dll
+    StaleBinkw23,
+}
+
+/// File left behind by a previous install
+#[derive(Debug, Clone)]
+pub struct Leftover {
+    /// Kind of leftover
+    pub kind: LeftoverKind,
+    /// Path of the file relative to the game folder
+    pub name: String,
+    /// Full path of the file
+    pub path: PathBuf,
+}
+
+/// Scans the game folder at `game_path` for files left behind by previous
+/// installs, missing or unreadable folders are skipped
+pub async fn scan_leftovers(game_path: PathBuf) -> Vec<Leftover> {
+    let mut leftovers = Vec::new();
+
+    for dir in std::iter::once(PLUGIN_DIR).chain(MISPLACED_DIRS.iter().copied()) {
+        let dir_path = match dir.is_empty() {
+            true => game_path.clone(),
+            false => resolve_file_name(&game_path, dir).await,
+        };
+
+        let mut names: Vec<(&str, LeftoverKind)> = LEGACY_PLUGIN_NAMES
+            .iter()
+            .map(|name| (*name, LeftoverKind::LegacyPlugin))
+            .collect();
+
+        if dir != PLUGIN_DIR {
+            names.push((PLUGIN_NAME, LeftoverKind::MisplacedPlugin));
+            names.push((PLUGIN_CONFIG_NAME, LeftoverKind::MisplacedConfig));
+        }
+
+        for (name, kind) in names {
+            push_if_present(&mut leftovers, &game_path, &dir_path, name, kind).await;
+        }
+    }
+
+    // binkw23.dll is only used by a patched or third-party binkw32.dll
+    if matches!(
+        patch_state(game(), &game_path).await,
+        Ok(PatchState::Unpatched)
+    ) {
+        push_if_present(
+            &mut leftovers,
+            &game_path,
+            &game_path,
+            "binkw23.dll",
+            LeftoverKind::StaleBinkw23,
+        )
+        .await;
+    }
+
+    debug!("found {} leftover files", leftovers.len());
+
+    leftovers
+}
+
+/// Cleans up the `leftovers` from the game folder at `game_path`. Misplaced
+/// configs are moved into the ASI folder when it doesn't have a config
+/// so the server settings aren't lost, everything else is removed.
+///
+/// Returns a description of each of the changes that were made
+pub async fn remove_leftovers(
+    game_path: PathBuf,
+    leftovers: Vec<Leftover>,
+) -> anyhow::Result<Vec<String>> {
+    ensure_game_not_running(&game_path).await?;
+
+    let config_path = game_path.join(PLUGIN_DIR).join(PLUGIN_CONFIG_NAME);
+    let mut changes = Vec::new();
+
+    for leftover in leftovers {
+        // File may have been removed since the scan
+        if !leftover.path.is_file() {
+            continue;
+        }
+
+        if leftover.kind == LeftoverKind::MisplacedConfig && !config_path.exists() {
+            tokio::fs::create_dir_all(game_path.join(PLUGIN_DIR))
+                .await
+                .context("failed to create plugin directory")?;
+            tokio::fs::rename(&leftover.path, &config_path)
+                .await
+                .with_context(|| format!("failed to move {}", leftover.name))?;
+
+            changes.push(format!(
+                "Moved {} to {PLUGIN_DIR}/{PLUGIN_CONFIG_NAME}",
+                leftover.name
+            ));
+            continue;
+        }
+
+        tokio::fs::remove_file(&leftover.path)
+            .await
+            .with_context(|| format!("failed to remove {}", leftover.name))?;
+
+        changes.push(format!("Removed {}", leftover.name));
+    }
+
+    debug!("cleaned up leftovers ({} changes)", changes.len());
+
+    Ok(changes)
+}
+
+/// Adds a leftover of the `kind` when the file `name` is present within
+/// the `dir_path` folder
+async fn push_if_present(
+    leftovers: &mut Vec<Leftover>,
+    game_path: &Path,
+    dir_path: &Path,
+    name: &str,
+    kind: LeftoverKind,
+) {
+    let path = resolve_file_name(dir_path, name).await;
+    if !path.is_file() {
+        return;
+    }
+
+    let name = path
+        .strip_prefix(game_path)
+        .unwrap_or(&path)
+        .to_string_lossy()
+        .replace('\\', "/");
+
+    leftovers.push(Leftover { kind, name, path });
+}
@@ -0,0 +1,209 @@
+//! Module for detecting which ASI loaders are active in the game folder, the
+//! plugin is only loaded when a loader that loads the ASI folder is present.
+//! The patched binkw32.dll is the usual loader but some players already
+//! have another loader installed
+
+use crate::{
+    bink::PatchState,
+    compat::WRAPPER_DLLS,
+    fs_util::resolve_file_name,
+    i18n::tr,
+    pe::{contains_utf16_string, is_dll, read_machine, MACHINE_I386},
+    plugin::PluginState,
+};
+use log::debug;
+use std::{fmt::Display, path::Path};
+
+/// Extension searched for within wrapper DLLs, loaders contain the pattern
+/// used to find plugins
+const ASI_PATTERN: &str = ".asi";
+
+/// Loader that loads ASI plugins into the game
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AsiLoader {
+    /// Pocket Relay binkw32.dll patch, loads the ASI folder
+    BinkPatch,
+    /// Another binkw32.dll proxy that loads the ASI folder (e.g the
+    /// ME3Tweaks binkw32 bypass)
+    BinkProxy,
+    /// Wrapper DLL that loads ASI plugins (e.g Ultimate ASI Loader as
+    /// dsound.dll), these load the game, "scripts" and "plugins" folders
+    /// rather than the ASI folder
+    Wrapper(String),
+}
+
+impl AsiLoader {
+    /// Whether the loader loads plugins from the ASI folder
+    pub fn loads_plugin_dir(&self) -> bool {
+        matches!(self, AsiLoader::BinkPatch | AsiLoader::BinkProxy)
+    }
+}
+
+impl Display for AsiLoader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AsiLoader::BinkPatch => f.write_str(tr("the Pocket Relay patch")),
+            AsiLoader::BinkProxy => f.write_str(tr("another binkw32.dll loader")),
+            AsiLoader::Wrapper(name) => f.write_str(name),
+        }
+    }
+}
+
+/// Whether the plugin will be loaded when the game starts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Readiness {
+    /// Plugin is installed and will be loaded by the loader
+    Ready(AsiLoader),
+    /// Plugin isn't installed
+    NotInstalled,
+    /// Plugin is installed but disabled
+    Disabled,
+    /// Plugin is installed but the only loader doesn't load the ASI folder
+    WrongFolder(AsiLoader),
+    /// Plugin is installed but nothing will load it
+    NoLoader,
+}
+
+/// Detects the ASI loaders in the game folder at `game_path` other than
+/// the patch, which is known from the `patch_state`
+pub async fn detect_asi_loaders(game_path: &Path, patch_state: &PatchState) -> Vec<AsiLoader> {
+    let mut loaders = Vec::new();
+
+    // Proxies move the original binkw32.dll to binkw23.dll
+    if matches!(patch_state, PatchState::UnknownDll(_)) {
+        let binkw32_path = resolve_file_name(game_path, "binkw32.dll").await;
+        let binkw23_path = resolve_file_name(game_path, "binkw23.dll").await;
+
+        if binkw23_path.is_file() && is_loader_dll(&binkw32_path, false).await {
+            loaders.push(AsiLoader::BinkProxy);
+        }
+    }
+
+    for name in WRAPPER_DLLS {
+        let path = resolve_file_name(game_path, name).await;
+        if is_loader_dll(&path, true).await {
+            loaders.push(AsiLoader::Wrapper(name.to_string()));
+        }
+    }
+
+    debug!("detected ASI loaders: {loaders:?}");
+
+    loaders
+}
+
+/// Checks whether the file at `path` is a 32-bit DLL the game can load,
+/// `find_pattern` requires the DLL to search for ASI plugins
+async fn is_loader_dll(path: &Path, find_pattern: bool) -> bool {
+    let bytes = match tokio::fs::read(path).await {
+        Ok(value) => value,
+        Err(_) => return false,
+    };
+
+    if read_machine(&bytes) != Some(MACHINE_I386) || !is_dll(&bytes) {
+        return false;
+    }
+
+    !find_pattern
+        || contains_utf16_string(&bytes, ASI_PATTERN)
+        || bytes
+            .windows(ASI_PATTERN.len())
+            .any(|window| window == ASI_PATTERN.as_bytes())
+}
+
+/// Determines whether the plugin in the `plugin` state will be loaded by the
+/// patch in the `patch_state` or one of the other `loaders`
+pub fn check_readiness(
+    plugin: PluginState,
+    patch_state: &PatchState,
+    loaders: &[AsiLoader],
+) -> Readiness {
+    match plugin {
+        PluginState::Absent => return Readiness::NotInstalled,
+        PluginState::Disabled => return Readiness::Disabled,
+        PluginState::Installed => {}
+    }
+
+    if matches!(patch_state, PatchState::Patched) {
+        return Readiness::Ready(AsiLoader::BinkPatch);
+    }
+
+    if let Some(loader) = loaders.iter().find(|loader| loader.loads_plugin_dir()) {
+        return Readiness::Ready(loader.clone());
+    }
+
+    match loaders.first() {
+        Some(loader) => Readiness::WrongFolder(loader.clone()),
+        None => Readiness::NoLoader,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{check_readiness, detect_asi_loaders, AsiLoader, Readiness};
+    use crate::{
+        bink::PatchState, game_fs::test_util::TempDir, plugin::PluginState, profile::profile,
+    };
+
+    /// Loaders other than the patch are detected from the game folder
+    #[tokio::test]
+    async fn test_detect_asi_loaders() {
+        let game = TempDir::new();
+        // The patch is a 32-bit DLL that loads ASI plugins
+        let loader = profile().patch.patched;
+
+        std::fs::write(game.path().join("binkw32.dll"), loader).unwrap();
+        std::fs::write(game.path().join("binkw23.dll"), b"original").unwrap();
+        std::fs::write(game.path().join("dsound.dll"), loader).unwrap();
+        std::fs::write(game.path().join("d3d9.dll"), b"not a dll").unwrap();
+
+        let loaders = detect_asi_loaders(game.path(), &PatchState::UnknownDll(String::new())).await;
+
+        assert_eq!(
+            loaders,
+            vec![
+                AsiLoader::BinkProxy,
+                AsiLoader::Wrapper("dsound.dll".to_string())
+            ]
+        );
+    }
+
+    /// Plugin is only ready when a loader loads the ASI folder
+    #[test]
+    fn test_check_readiness() {
+        let unknown = PatchState::UnknownDll(String::new());
+        let wrapper = AsiLoader::Wrapper("dsound.dll".to_string());
+
+        assert_eq!(
+            check_readiness(PluginState::Absent, &PatchState::Patched, &[]),
+            Readiness::NotInstalled
+        );
+        assert_eq!(
+            check_readiness(PluginState::Disabled, &PatchState::Patched, &[]),
+            Readiness::Disabled
+        );
+        assert_eq!(
+            check_readiness(PluginState::Installed, &PatchState::Patched, &[]),
+            Readiness::Ready(AsiLoader::BinkPatch)
+        );
+        assert_eq!(
+            check_readiness(
+                PluginState::Installed,
+                &unknown,
+                &[wrapper.clone(), AsiLoader::BinkProxy]
+            ),
+            Readiness::Ready(AsiLoader::BinkProxy)
+        );
+        assert_eq!(
+            check_readiness(
+                PluginState::Installed,
+                &unknown,
+                std::slice::from_ref(&wrapper)
+            ),
+            Readiness::WrongFolder(wrapper)
+        );
+        assert_eq!(
+            check_readiness(PluginState::Installed, &PatchState::Unpatched, &[]),
+            Readiness::NoLoader
+        );
+    }
+}
@@ -0,0 +1,436 @@
+//! Module for automatically locating installations of the game from the
+//! known Steam, Origin and EA App install locations, including installs
+//! within Proton prefixes on Linux
+
+use crate::{
+    profile::profile,
+    shortcut::{is_shortcut, read_shortcut_target, resolve_shortcut_target},
+};
+use anyhow::Context;
+use log::debug;
+use std::path::{Path, PathBuf};
+
+/// Folders Origin and the EA App install games into, the game is within a
+/// folder named after the game
+const STORE_INSTALL_DIRS: &[&[&str]] = &[
+    &["Program Files (x86)", "Origin Games"],
+    &["Program Files", "Origin Games"],
+    &["Program Files", "EA Games"],
+    &["Program Files (x86)", "EA Games"],
+];
+
+/// Searches the known install locations for copies of the game executable,
+/// returns the paths to each executable that was found.
+///
+/// This function performs blocking IO and should be run on a blocking thread
+pub fn find_game_paths() -> Vec<PathBuf> {
+    let profile = profile();
+    let mut install_dirs: Vec<PathBuf> = Vec::new();
+
+    // Game folders from each of the Steam libraries
+    for library in find_steam_libraries() {
+        install_dirs.push(
+            library
+                .join("steamapps")
+                .join("common")
+                .join(profile.install_folder),
+        );
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        install_dirs.extend(windows::find_registry_install_dirs());
+        install_dirs.extend(store_install_dirs(PathBuf::from("C:\\")));
+    }
+
+    // Origin and EA App installs added to Steam as non-Steam games
+    #[cfg(not(target_os = "windows"))]
+    for drive_c in find_proton_drives() {
+        install_dirs.extend(store_install_dirs(drive_c));
+    }
+
+    let mut found: Vec<PathBuf> = Vec::new();
+    // Canonical forms of the found paths, the same install can be reached
+    // through multiple different paths (symlinks, casing)
+    let mut seen: Vec<PathBuf> = Vec::new();
+
+    for install_dir in install_dirs {
+        let exe_path = profile
+            .exe_dir
+            .iter()
+            .fold(install_dir, |path, part| path.join(part))
+            .join(profile.exe_name);
+
+        if !exe_path.is_file() {
+            continue;
+        }
+
+        let canonical = exe_path.canonicalize().unwrap_or_else(|_| exe_path.clone());
+        if seen.contains(&canonical) {
+            continue;
+        }
+
+        debug!("found game executable: {}", exe_path.display());
+        seen.push(canonical);
+        found.push(exe_path);
+    }
+
+    found
+}
+
+/// Number of folders below a picked folder searched for the game, enough
+/// to find the game when the folder containing the install is picked
+const PICKED_SEARCH_DEPTH: usize = 4;
+
+/// Resolves the `path` the user picked to the path of the game executable,
+/// a shortcut to the game or a folder within or containing the install can
+/// be picked instead of the executable. Other files are left to be
+/// validated as the game executable
+///
+/// This function performs blocking IO and should be run on a blocking thread
+pub fn resolve_picked_path(path: PathBuf) -> anyhow::Result<PathBuf> {
+    let path = match is_shortcut(&path) {
+        true => {
+            let bytes = std::fs::read(&path).context("failed to read shortcut")?;
+            let target = read_shortcut_target(&bytes).context("file is not a valid shortcut")?;
+            let target = resolve_shortcut_target(&path, &target)
+                .context("shortcut points to a file that doesn't exist")?;
+
+            debug!("resolved shortcut target: {}", target.display());
+            target
+        }
+        false => path,
+    };
+
+    if !path.is_dir() {
+        return Ok(path);
+    }
+
+    let exe_path = find_exe_near(&path).with_context(|| {
+        format!(
+            "{} could not be found within {}",
+            profile().exe_name,
+            path.display()
+        )
+    })?;
+
+    debug!(
+        "found game executable in picked folder: {}",
+        exe_path.display()
+    );
+
+    Ok(exe_path)
+}
+
+/// Finds the game executable near the `dir` folder, searching up from
+/// folders within the install (e.g "Binaries") then down through the
+/// folders below
+fn find_exe_near(dir: &Path) -> Option<PathBuf> {
+    let profile = profile();
+
+    for ancestor in dir.ancestors().take(profile.exe_dir.len() + 1) {
+        let exe_dir = profile
+            .exe_dir
+            .iter()
+            .fold(ancestor.to_path_buf(), |path, part| path.join(part));
+
+        if let Some(exe_path) = [ancestor.to_path_buf(), exe_dir]
+            .iter()
+            .find_map(|dir| find_file(dir, profile.exe_name))
+        {
+            return Some(exe_path);
+        }
+    }
+
+    let mut dirs = vec![dir.to_path_buf()];
+    for _ in 0..PICKED_SEARCH_DEPTH {
+        let mut next = Vec::new();
+
+        for dir in dirs {
+            let entries = match std::fs::read_dir(&dir) {
+                Ok(value) => value,
+                Err(_) => continue,
+            };
+
+            // Symlinks aren't followed to avoid searching in loops
+            next.extend(
+                entries
+                    .flatten()
+                    .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_dir()))
+                    .map(|entry| entry.path()),
+            );
+        }
+
+        if let Some(exe_path) = next.iter().find_map(|dir| find_file(dir, profile.exe_name)) {
+            return Some(exe_path);
+        }
+
+        dirs = next;
+    }
+
+    None
+}
+
+/// Finds the file named `name` within the `dir` ignoring the casing of
+/// the name
+fn find_file(dir: &Path, name: &str) -> Option<PathBuf> {
+    std::fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .find(|entry| {
+            entry.file_name().eq_ignore_ascii_case(name)
+                && entry.file_type().is_ok_and(|kind| kind.is_file())
+        })
+        .map(|entry| entry.path())
+}
+
+/// Determines the Origin and EA App install folders for the game within the
+/// `drive` root folder (e.g "C:\\" or the "drive_c" folder of a Proton prefix)
+fn store_install_dirs(drive: PathBuf) -> impl Iterator<Item = PathBuf> {
+    STORE_INSTALL_DIRS.iter().map(move |parts| {
+        parts
+            .iter()
+            .fold(drive.clone(), |path, part| path.join(part))
+            .join(profile().install_folder)
+    })
+}
+
+/// Finds the paths of all the Steam library folders on the system
+fn find_steam_libraries() -> Vec<PathBuf> {
+    let mut libraries = Vec::new();
+
+    for steam_dir in find_steam_dirs() {
+        let vdf_path = steam_dir.join("steamapps").join("libraryfolders.vdf");
+        let contents = match std::fs::read_to_string(&vdf_path) {
+            Ok(value) => value,
+            Err(_) => continue,
+        };
+
+        // The Steam install itself is always a library
+        libraries.push(steam_dir);
+
+        for library in parse_library_folders(&contents) {
+            if !libraries.contains(&library) {
+                libraries.push(library);
+            }
+        }
+    }
+
+    libraries
+}
+
+/// Finds the "drive_c" folders of the Proton prefixes within each of
+/// the Steam libraries
+#[cfg(not(target_os = "windows"))]
+fn find_proton_drives() -> Vec<PathBuf> {
+    let mut drives = Vec::new();
+
+    for library in find_steam_libraries() {
+        let compat_dir = library.join("steamapps").join("compatdata");
+        let entries = match std::fs::read_dir(&compat_dir) {
+            Ok(value) => value,
+            Err(_) => continue,
+        };
+
+        drives.extend(
+            entries
+                .flatten()
+                .map(|entry| entry.path().join("pfx").join("drive_c"))
+                .filter(|path| path.is_dir()),
+        );
+    }
+
+    drives
+}
+
+/// Determines the folder the game executable file picker should start in,
+/// on Linux this is the Steam game folder or the Proton prefixes folder
+/// when they exist.
+///
+/// This function performs blocking IO and should be run on a blocking thread
+pub fn default_picker_dir() -> Option<PathBuf> {
+    if cfg!(target_os = "windows") {
+        return None;
+    }
+
+    find_steam_libraries().into_iter().find_map(|library| {
+        let steamapps = library.join("steamapps");
+        [
+            steamapps.join("common").join(profile().install_folder),
+            steamapps.join("compatdata"),
+        ]
+        .into_iter()
+        .find(|path| path.is_dir())
+    })
+}
+
+/// Finds the Steam install directories that exist on the system
+pub fn find_steam_dirs() -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = Vec::new();
+
+    #[cfg(target_os = "windows")]
+    {
+        dirs.extend(windows::find_steam_registry_dirs());
+        dirs.push(PathBuf::from("C:\\Program Files (x86)\\Steam"));
+        // 32-bit Windows has no separate folder for 32-bit programs
+        dirs.push(PathBuf::from("C:\\Program Files\\Steam"));
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    if let Some(home) = std::env::var_os("HOME").map(PathBuf::from) {
+        dirs.push(home.join(".steam").join("steam"));
+        dirs.push(home.join(".local").join("share").join("Steam"));
+    }
+
+    dirs.retain(|dir| dir.is_dir());
+    dirs
+}
+
+/// Parses the library folder paths from the contents of a Steam
+/// `libraryfolders.vdf` file
+fn parse_library_folders(contents: &str) -> Vec<PathBuf> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split('"').filter(|part| !part.trim().is_empty());
+            let key = parts.next()?;
+            let value = parts.next()?;
+
+            if !key.eq_ignore_ascii_case("path") {
+                return None;
+            }
+
+            // VDF values escape backslashes
+            Some(PathBuf::from(value.replace("\\\\", "\\")))
+        })
+        .collect()
+}
+
+#[cfg(target_os = "windows")]
+mod windows {
+    //! Windows registry lookups for the game and Steam install locations
+
+    use crate::profile::profile;
+    use std::path::PathBuf;
+    use winreg::{
+        enums::{HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE},
+        RegKey,
+    };
+
+    /// Registry keys and values that store the game install folder
+    fn game_install_keys() -> [(String, &'static str); 4] {
+        let profile = profile();
+        [
+            // Origin / EA App
+            (
+                format!("SOFTWARE\\WOW6432Node\\BioWare\\{}", profile.install_folder),
+                "Install Dir",
+            ),
+            (
+                format!("SOFTWARE\\BioWare\\{}", profile.install_folder),
+                "Install Dir",
+            ),
+            // Steam uninstall entry
+            (
+                format!(
+                    "SOFTWARE\\WOW6432Node\\Microsoft\\Windows\\CurrentVersion\\Uninstall\\Steam App {}",
+                    profile.steam_app_id
+                ),
+                "InstallLocation",
+            ),
+            (
+                format!(
+                    "SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Uninstall\\Steam App {}",
+                    profile.steam_app_id
+                ),
+                "InstallLocation",
+            ),
+        ]
+    }
+
+    /// Registry keys and values that store the Steam install folder
+    const STEAM_INSTALL_KEYS: &[(&str, &str)] = &[
+        ("SOFTWARE\\WOW6432Node\\Valve\\Steam", "InstallPath"),
+        ("SOFTWARE\\Valve\\Steam", "InstallPath"),
+    ];
+
+    /// Reads a string value from the provided registry key
+    fn read_value(root: &RegKey, key: &str, value: &str) -> Option<String> {
+        root.open_subkey(key).ok()?.get_value(value).ok()
+    }
+
+    /// Finds game install folders stored in the registry
+    pub fn find_registry_install_dirs() -> Vec<PathBuf> {
+        let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
+
+        game_install_keys()
+            .iter()
+            .filter_map(|(key, value)| read_value(&hklm, key, value))
+            .map(PathBuf::from)
+            .collect()
+    }
+
+    /// Finds Steam install folders stored in the registry
+    pub fn find_steam_registry_dirs() -> Vec<PathBuf> {
+        let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
+        let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+
+        let mut dirs: Vec<PathBuf> = STEAM_INSTALL_KEYS
+            .iter()
+            .filter_map(|(key, value)| read_value(&hklm, key, value))
+            .map(PathBuf::from)
+            .collect();
+
+        if let Some(path) = read_value(&hkcu, "Software\\Valve\\Steam", "SteamPath") {
+            dirs.push(PathBuf::from(path));
+        }
+
+        dirs
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::resolve_picked_path;
+    use crate::{game_fs::test_util::TempDir, profile::profile};
+    use std::path::PathBuf;
+
+    /// Creates a game install within the `dir` returning the path to the
+    /// install and the executable
+    fn create_install(dir: &TempDir) -> (PathBuf, PathBuf) {
+        let install_path = dir.path().join("Origin Games").join("Mass Effect 3");
+        let exe_dir = install_path.join("Binaries").join("Win32");
+        std::fs::create_dir_all(&exe_dir).unwrap();
+
+        let exe_path = exe_dir.join(profile().exe_name);
+        std::fs::write(&exe_path, b"").unwrap();
+
+        (install_path, exe_path)
+    }
+
+    /// Executable is found when picking folders around the install
+    #[test]
+    fn test_resolve_picked_folder() {
+        let dir = TempDir::new();
+        let (install_path, exe_path) = create_install(&dir);
+
+        for picked in [
+            install_path.clone(),
+            install_path.join("Binaries"),
+            install_path.join("Binaries").join("Win32"),
+            dir.path().join("Origin Games"),
+            exe_path.clone(),
+        ] {
+            assert_eq!(resolve_picked_path(picked).unwrap(), exe_path);
+        }
+    }
+
+    /// Folders without the game are rejected
+    #[test]
+    fn test_resolve_picked_folder_missing() {
+        let dir = TempDir::new();
+        std::fs::create_dir_all(dir.path().join("Other Game")).unwrap();
+
+        assert!(resolve_picked_path(dir.path().to_path_buf()).is_err());
+    }
+}
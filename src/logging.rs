@@ -0,0 +1,178 @@
+//! Module for setting up logging, log output is written to env_logger,
+//! a rotating log file in the config directory and kept in an in-memory
+//! buffer so it can be viewed within the app
+
+use crate::config::config_dir;
+use log::{error, LevelFilter, Log, Metadata, Record};
+use std::{
+    collections::VecDeque,
+    fs::{File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::SystemTime,
+};
+
+/// Maximum number of log lines kept in the buffer
+const LOG_BUFFER_CAPACITY: usize = 500;
+
+/// Name of the log file
+const LOG_FILE_NAME: &str = "installer.log";
+
+/// Size in bytes the log file can reach before its rotated
+const MAX_LOG_FILE_SIZE: u64 = 1024 * 1024;
+
+/// Number of rotated log files to keep
+const MAX_ROTATED_LOG_FILES: usize = 3;
+
+/// Buffer of the most recent log lines
+static LOG_BUFFER: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+/// Logger that forwards to env_logger while keeping a copy of
+/// each line in the log buffer and log file
+struct BufferedLogger {
+    /// Underlying env_logger logger
+    inner: env_logger::Logger,
+    /// Log file to write to, none if the file couldn't be opened
+    file: Option<Mutex<LogFile>>,
+}
+
+impl Log for BufferedLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.inner.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.inner.matches(record) {
+            return;
+        }
+
+        self.inner.log(record);
+
+        let line = format!(
+            "{} [{}] {}: {}",
+            humantime::format_rfc3339_seconds(SystemTime::now()),
+            record.level(),
+            record.target(),
+            record.args()
+        );
+
+        if let Some(Ok(mut file)) = self.file.as_ref().map(Mutex::lock) {
+            file.write_line(&line);
+        }
+
+        if let Ok(mut buffer) = LOG_BUFFER.lock() {
+            if buffer.len() >= LOG_BUFFER_CAPACITY {
+                buffer.pop_front();
+            }
+            buffer.push_back(line);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+
+        if let Some(Ok(mut file)) = self.file.as_ref().map(Mutex::lock) {
+            _ = file.file.flush();
+        }
+    }
+}
+
+/// Log file that is rotated once it reaches [MAX_LOG_FILE_SIZE]
+struct LogFile {
+    /// Path to the log file
+    path: PathBuf,
+    /// The open log file
+    file: File,
+    /// Current size of the log file in bytes
+    size: u64,
+}
+
+impl LogFile {
+    /// Opens the log file at `path` for appending
+    fn open(path: PathBuf) -> std::io::Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let size = file.metadata()?.len();
+
+        Ok(Self { path, file, size })
+    }
+
+    /// Writes a line to the log file, rotating the file first if its full
+    fn write_line(&mut self, line: &str) {
+        if self.size >= MAX_LOG_FILE_SIZE {
+            if let Err(err) = self.rotate() {
+                eprintln!("failed to rotate log file: {err}");
+            }
+        }
+
+        if writeln!(self.file, "{line}").is_ok() {
+            self.size += line.len() as u64 + 1;
+        }
+    }
+
+    /// Moves the current log file to "installer.log.1" shifting the older
+    /// log files along and removing the oldest, then starts a new log file
+    fn rotate(&mut self) -> std::io::Result<()> {
+        for index in (1..MAX_ROTATED_LOG_FILES).rev() {
+            let from = rotated_path(&self.path, index);
+            if from.exists() {
+                std::fs::rename(from, rotated_path(&self.path, index + 1))?;
+            }
+        }
+
+        std::fs::rename(&self.path, rotated_path(&self.path, 1))?;
+
+        *self = LogFile::open(self.path.clone())?;
+        Ok(())
+    }
+}
+
+/// Determines the path of a rotated log file (e.g "installer.log.1")
+fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(format!(".{index}"));
+    path.with_file_name(file_name)
+}
+
+/// Initializes the logger
+pub fn init() {
+    let inner = env_logger::builder()
+        .filter_module("pocket_relay_plugin_installer", LevelFilter::Debug)
+        .build();
+
+    let max_level = inner.filter();
+
+    let file = config_dir().and_then(|path| match LogFile::open(path.join(LOG_FILE_NAME)) {
+        Ok(value) => Some(Mutex::new(value)),
+        Err(err) => {
+            eprintln!("failed to open log file: {err}");
+            None
+        }
+    });
+
+    if log::set_boxed_logger(Box::new(BufferedLogger { inner, file })).is_err() {
+        return;
+    }
+
+    log::set_max_level(max_level);
+
+    // Log panics so crashes are captured in the log file
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        error!("{info}");
+        log::logger().flush();
+        default_hook(info);
+    }));
+}
+
+/// Obtains the most recent log lines, oldest first
+pub fn recent_logs() -> Vec<String> {
+    match LOG_BUFFER.lock() {
+        Ok(buffer) => buffer.iter().cloned().collect(),
+        Err(_) => Vec::new(),
+    }
+}
@@ -5,19 +5,89 @@
 #![warn(unused_crate_dependencies)]
 
 mod app;
-mod bink;
-mod github;
-mod plugin;
+mod backup;
+mod cli;
+mod compat;
+mod config;
+mod crash;
+mod deep_link;
+mod details;
+mod diagnostics;
+mod elevation;
+mod error;
+mod explorer;
+mod format;
+mod help;
+mod history;
+mod http;
+mod i18n;
+mod install;
+mod launch;
+mod leftovers;
+mod loader;
+mod locate;
+mod logging;
+mod network;
+mod notifications;
+mod plugin_config;
+mod plugin_log;
+mod profile;
+mod proton;
+mod report;
+mod server;
+mod shortcut;
+mod state;
+mod stats;
+mod store;
+mod theme;
+mod tray;
+mod uninstall;
+mod update;
+mod validate;
+mod watch;
+
+// Install and patch logic lives in the core library, re-exported so the
+// user interface can refer to it like its own modules
+use pocket_relay_installer_core::{
+    asi, asset_cache, bink, bytes, downloads, feed, fs_util, game_fs, github, hashes, mirror, pe,
+    plan, plugin, preflight, process, progress, self_test,
+};
 
 /// Application crate version string
 pub const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 fn main() {
     // Initialize logging
-    env_logger::builder()
-        .filter_module("pocket_relay_plugin_installer", log::LevelFilter::Debug)
-        .init();
+    logging::init();
+
+    // Write crash reports when panicking
+    crash::init();
+
+    // Remove any leftovers from a previous update
+    update::cleanup_update();
+
+    // Load the saved config
+    let config = config::read_config();
+
+    // Use the saved language for the UI
+    i18n::set_language(config.language);
+
+    // Use the profile of the saved game
+    profile::set_game(config.game);
+
+    // Run the command line mode instead of the UI when given arguments
+    if let Some(exit_code) = cli::run(&config) {
+        std::process::exit(exit_code);
+    }
+
+    // Crashes close the window, show where the crash report was saved
+    crash::enable_dialog();
+
+    // Installer can be opened with the game to select
+    let launch_path = cli::launch_path();
+    // or from a link with the server to connect to
+    let launch_link = cli::launch_link();
 
     // Initialize the UI
-    app::init();
+    app::init(config, launch_path, launch_link);
 }
@@ -0,0 +1,231 @@
+//! Module for diagnosing problems connecting to GitHub, each step of making
+//! a request is checked separately so a vague request error can be traced
+//! back to the step that is failing
+
+use log::debug;
+use serde::Deserialize;
+use std::{
+    error::Error,
+    fmt::Write,
+    future::Future,
+    net::SocketAddr,
+    time::{Duration, Instant},
+};
+use tokio::net::{lookup_host, TcpStream};
+
+/// Host of the GitHub API
+const API_HOST: &str = "api.github.com";
+/// URL of the GitHub API rate limit endpoint, requests to it don't count
+/// against the rate limit
+const API_RATE_LIMIT_URL: &str = "https://api.github.com/rate_limit";
+/// URL of the CDN serving release asset downloads
+const ASSET_CDN_URL: &str = "https://objects.githubusercontent.com/";
+
+/// Time to wait for each check before it fails
+const CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Steps checked by the network diagnostics, in the order they are checked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkCheck {
+    /// Resolving the address of the GitHub API
+    Dns,
+    /// Opening a connection to the GitHub API
+    Tcp,
+    /// Establishing a secure connection to the GitHub API
+    Tls,
+    /// Making a request to the GitHub API
+    Api,
+    /// Reaching the CDN release assets are downloaded from
+    Cdn,
+}
+
+/// Outcome of a single check
+#[derive(Debug, Clone)]
+pub enum CheckOutcome {
+    /// Check passed, contains details about the result
+    Passed(String),
+    /// Check failed, contains the error message
+    Failed(String),
+    /// Check couldn't run because an earlier check failed
+    Skipped,
+}
+
+/// Result of a single check
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    /// The step that was checked
+    pub check: NetworkCheck,
+    /// Outcome of the check
+    pub outcome: CheckOutcome,
+    /// Time the check took
+    pub latency: Duration,
+}
+
+/// GitHub rate limit response, only the fields that are reported
+#[derive(Deserialize)]
+struct RateLimitResponse {
+    rate: RateLimit,
+}
+
+#[derive(Deserialize)]
+struct RateLimit {
+    limit: u32,
+    remaining: u32,
+}
+
+/// Runs each of the network checks in order using the `http_client`,
+/// each result is reported to `on_result` as soon as the check finishes
+pub async fn run_network_checks(
+    http_client: reqwest::Client,
+    mut on_result: impl FnMut(CheckResult),
+) {
+    let (result, addresses) = timed(NetworkCheck::Dns, check_dns()).await;
+    on_result(result);
+
+    // Connection can only be checked directly when the address resolved
+    let result = match addresses.and_then(|addresses| addresses.first().copied()) {
+        Some(address) => timed(NetworkCheck::Tcp, check_tcp(address)).await.0,
+        None => CheckResult {
+            check: NetworkCheck::Tcp,
+            outcome: CheckOutcome::Skipped,
+            latency: Duration::ZERO,
+        },
+    };
+    on_result(result);
+
+    // Requests may still work through a proxy when the direct checks failed
+    let (result, _) = timed(
+        NetworkCheck::Tls,
+        check_reachable(&http_client, API_RATE_LIMIT_URL),
+    )
+    .await;
+    on_result(result);
+
+    let (result, _) = timed(NetworkCheck::Api, check_api(&http_client)).await;
+    on_result(result);
+
+    let (result, _) = timed(
+        NetworkCheck::Cdn,
+        check_reachable(&http_client, ASSET_CDN_URL),
+    )
+    .await;
+    on_result(result);
+}
+
+/// Runs the `check` future timing how long it takes, the check fails if it
+/// doesn't finish within the [CHECK_TIMEOUT]. Returns the result along with
+/// the value produced by the check
+async fn timed<T, F>(check: NetworkCheck, future: F) -> (CheckResult, Option<T>)
+where
+    F: Future<Output = Result<(String, T), String>>,
+{
+    let start = Instant::now();
+    let result = tokio::time::timeout(CHECK_TIMEOUT, future).await;
+    let latency = start.elapsed();
+
+    let (outcome, value) = match result {
+        Ok(Ok((details, value))) => (CheckOutcome::Passed(details), Some(value)),
+        Ok(Err(err)) => (CheckOutcome::Failed(err), None),
+        Err(_) => (
+            CheckOutcome::Failed(format!("timed out after {}s", CHECK_TIMEOUT.as_secs())),
+            None,
+        ),
+    };
+
+    debug!("network check {check:?} finished in {latency:?}: {outcome:?}");
+
+    (
+        CheckResult {
+            check,
+            outcome,
+            latency,
+        },
+        value,
+    )
+}
+
+/// Resolves the addresses of the GitHub API
+async fn check_dns() -> Result<(String, Vec<SocketAddr>), String> {
+    let addresses: Vec<SocketAddr> = lookup_host((API_HOST, 443))
+        .await
+        .map_err(|err| format!("failed to resolve {API_HOST}: {err}"))?
+        .collect();
+
+    if addresses.is_empty() {
+        return Err(format!("{API_HOST} has no addresses"));
+    }
+
+    let details = addresses
+        .iter()
+        .map(|address| address.ip().to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Ok((details, addresses))
+}
+
+/// Opens a connection to the GitHub API `address`
+async fn check_tcp(address: SocketAddr) -> Result<(String, ()), String> {
+    TcpStream::connect(address)
+        .await
+        .map_err(|err| format!("failed to connect to {address}: {err}"))?;
+
+    Ok((format!("connected to {address}"), ()))
+}
+
+/// Makes a request to the `url`, any response means the server was reached
+/// and the secure connection was established
+async fn check_reachable(http_client: &reqwest::Client, url: &str) -> Result<(String, ()), String> {
+    let response = http_client
+        .head(url)
+        .send()
+        .await
+        .map_err(|err| describe_request_error(&err))?;
+
+    Ok((format!("HTTP {}", response.status()), ()))
+}
+
+/// Requests the rate limit from the GitHub API, checks the API responds with
+/// the expected JSON rather than a proxy or captive portal page
+async fn check_api(http_client: &reqwest::Client) -> Result<(String, ()), String> {
+    let response = http_client
+        .get(API_RATE_LIMIT_URL)
+        .send()
+        .await
+        .map_err(|err| describe_request_error(&err))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("GitHub API responded with HTTP {status}"));
+    }
+
+    let body: RateLimitResponse = response.json().await.map_err(|err| {
+        format!(
+            "GitHub API responded with an unexpected body, a proxy or captive portal \
+            may be intercepting requests: {}",
+            describe_request_error(&err)
+        )
+    })?;
+
+    Ok((
+        format!(
+            "rate limit {}/{} requests remaining",
+            body.rate.remaining, body.rate.limit
+        ),
+        (),
+    ))
+}
+
+/// Describes the request `err` along with each of its causes, the top level
+/// message alone doesn't explain what went wrong
+fn describe_request_error(err: &reqwest::Error) -> String {
+    let mut message = err.to_string();
+
+    let mut source = err.source();
+    while let Some(cause) = source {
+        _ = write!(message, ": {cause}");
+        source = cause.source();
+    }
+
+    message
+}
@@ -0,0 +1,30 @@
+//! Module for showing desktop notifications, used to let the user know
+//! when a long running operation finishes while the installer is in the
+//! background
+
+use log::{debug, error};
+use notify_rust::Notification;
+use tokio::task::spawn_blocking;
+
+/// Name of the application shown on notifications
+const APP_NAME: &str = "Pocket Relay Plugin Installer";
+
+/// Shows a desktop notification with the provided `summary` and `body`,
+/// failures are logged as notifications are best effort
+pub async fn notify(summary: String, body: String) {
+    let result = spawn_blocking(move || {
+        Notification::new()
+            .appname(APP_NAME)
+            .summary(&summary)
+            .body(&body)
+            .show()
+            .map(|_| ())
+    })
+    .await;
+
+    match result {
+        Ok(Ok(())) => debug!("showed desktop notification"),
+        Ok(Err(err)) => error!("failed to show desktop notification: {err}"),
+        Err(err) => error!("failed to join notification thread: {err}"),
+    }
+}
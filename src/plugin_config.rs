@@ -0,0 +1,66 @@
+//! Module for reading and writing the config file used by the plugin
+//! for connecting to a Pocket Relay server
+
+use crate::{fs_util::atomic_write, plugin::PLUGIN_DIR};
+use anyhow::Context;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Name of the plugin config file, stored alongside the plugin file
+pub const PLUGIN_CONFIG_NAME: &str = "pocket-relay-plugin.json";
+
+/// Config file read by the plugin
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PluginConfig {
+    /// URL of the Pocket Relay server to connect to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection_url: Option<String>,
+    /// Other fields used by the plugin, preserved when saving
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Determines the path to the plugin config file for the game
+fn plugin_config_path(game_path: &Path) -> PathBuf {
+    game_path.join(PLUGIN_DIR).join(PLUGIN_CONFIG_NAME)
+}
+
+/// Reads the plugin config for the game at the provided `game_path`, uses
+/// the default config if the file doesn't exist
+pub async fn read_plugin_config(game_path: PathBuf) -> anyhow::Result<PluginConfig> {
+    let path = plugin_config_path(&game_path);
+
+    if !path.exists() {
+        debug!("no plugin config at {}", path.display());
+        return Ok(PluginConfig::default());
+    }
+
+    let contents = tokio::fs::read(&path)
+        .await
+        .context("failed to read plugin config")?;
+
+    serde_json::from_slice(&contents).context("invalid plugin config file")
+}
+
+/// Writes the provided plugin `config` for the game at the provided `game_path`
+pub async fn write_plugin_config(game_path: PathBuf, config: PluginConfig) -> anyhow::Result<()> {
+    let path = plugin_config_path(&game_path);
+
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .context("failed to create plugin directory")?;
+    }
+
+    let contents =
+        serde_json::to_vec_pretty(&config).context("failed to serialize plugin config")?;
+
+    atomic_write(&path, contents)
+        .await
+        .context("failed to write plugin config")?;
+
+    debug!("saved plugin config to {}", path.display());
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+//! Module for reading the log file written by the plugin while the game is
+//! running, connection failures at runtime are only recorded in this log
+
+use crate::plugin::PLUGIN_DIR;
+use anyhow::Context;
+use log::debug;
+use std::{
+    io::SeekFrom,
+    path::{Path, PathBuf},
+};
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+/// Name of the log file written by the plugin
+pub const PLUGIN_LOG_NAME: &str = "pocket-relay-plugin.log";
+
+/// Maximum number of bytes read from the end of the log, the log grows
+/// with every launch of the game
+const MAX_LOG_BYTES: u64 = 256 * 1024;
+
+/// Maximum number of lines kept from the end of the log
+const MAX_LOG_LINES: usize = 500;
+
+/// Severity of a line in the plugin log
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluginLogLevel {
+    /// Line reports an error
+    Error,
+    /// Line reports a warning
+    Warn,
+    /// Any other line
+    Info,
+}
+
+/// Single line from the plugin log
+#[derive(Debug, Clone)]
+pub struct PluginLogLine {
+    /// Text of the line
+    pub text: String,
+    /// Severity of the line
+    pub level: PluginLogLevel,
+}
+
+/// Determines the path to the plugin log file for the game
+pub fn plugin_log_path(game_path: &Path) -> PathBuf {
+    game_path.join(PLUGIN_DIR).join(PLUGIN_LOG_NAME)
+}
+
+/// Reads the last lines of the plugin log for the game at the `game_path`,
+/// none when the plugin hasn't written a log yet
+pub async fn read_plugin_log(game_path: PathBuf) -> anyhow::Result<Option<Vec<PluginLogLine>>> {
+    let path = plugin_log_path(&game_path);
+
+    if !path.exists() {
+        debug!("no plugin log at {}", path.display());
+        return Ok(None);
+    }
+
+    let mut file = tokio::fs::File::open(&path)
+        .await
+        .context("failed to open plugin log")?;
+    let length = file
+        .metadata()
+        .await
+        .context("failed to read plugin log metadata")?
+        .len();
+
+    // Only the end of the log is relevant to the most recent launch
+    let start = length.saturating_sub(MAX_LOG_BYTES);
+    file.seek(SeekFrom::Start(start))
+        .await
+        .context("failed to seek plugin log")?;
+
+    let mut contents = Vec::new();
+    file.read_to_end(&mut contents)
+        .await
+        .context("failed to read plugin log")?;
+
+    let contents = String::from_utf8_lossy(&contents);
+    let mut lines: Vec<&str> = contents.lines().collect();
+
+    // First line is likely cut off when starting part way through the log
+    if start > 0 && !lines.is_empty() {
+        lines.remove(0);
+    }
+
+    let skip = lines.len().saturating_sub(MAX_LOG_LINES);
+    let lines = lines
+        .into_iter()
+        .skip(skip)
+        .map(|line| PluginLogLine {
+            text: line.to_string(),
+            level: line_level(line),
+        })
+        .collect();
+
+    Ok(Some(lines))
+}
+
+/// Copies the plugin log for the game at the `game_path` to the `target` path
+pub async fn export_plugin_log(game_path: PathBuf, target: PathBuf) -> anyhow::Result<()> {
+    tokio::fs::copy(plugin_log_path(&game_path), &target)
+        .await
+        .context("failed to copy plugin log")?;
+
+    Ok(())
+}
+
+/// Determines the severity of a log `line` from the level it was logged at
+fn line_level(line: &str) -> PluginLogLevel {
+    let line = line.to_ascii_uppercase();
+
+    if line.contains("ERROR") || line.contains("PANIC") {
+        PluginLogLevel::Error
+    } else if line.contains("WARN") {
+        PluginLogLevel::Warn
+    } else {
+        PluginLogLevel::Info
+    }
+}
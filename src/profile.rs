@@ -0,0 +1,28 @@
+//! Module for the game the installer is working with, the core library
+//! takes the game as a parameter so the selected game is kept here and
+//! passed along to it
+
+pub use pocket_relay_installer_core::profile::{Game, GameProfile};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Currently selected game, stored as the [Game] discriminant
+static GAME: AtomicU8 = AtomicU8::new(Game::MassEffect3 as u8);
+
+/// Sets the game the installer is working with
+pub fn set_game(game: Game) {
+    GAME.store(game as u8, Ordering::Relaxed);
+}
+
+/// Obtains the game the installer is working with
+pub fn game() -> Game {
+    let value = GAME.load(Ordering::Relaxed);
+    Game::ALL
+        .into_iter()
+        .find(|game| *game as u8 == value)
+        .unwrap_or_default()
+}
+
+/// Obtains the profile of the game the installer is working with
+pub fn profile() -> &'static GameProfile {
+    game().profile()
+}
@@ -0,0 +1,261 @@
+//! Module for configuring the game to run under Proton, Wine loads its own
+//! builtin binkw32.dll unless the DLL override is added to the Steam launch
+//! options of the game
+
+use crate::{fs_util::atomic_write, locate::find_steam_dirs, profile::profile};
+use anyhow::{bail, Context};
+use log::debug;
+use std::path::PathBuf;
+use sysinfo::{ProcessRefreshKind, System};
+use tokio::task::spawn_blocking;
+
+/// Environment variable assignment that makes Wine load the patched DLLs
+const DLL_OVERRIDES: &str = "WINEDLLOVERRIDES=\"binkw23=n,b;binkw32=n,b\"";
+
+/// Launch options to use when the game has no existing launch options
+pub const LAUNCH_OPTIONS: &str = "WINEDLLOVERRIDES=\"binkw23=n,b;binkw32=n,b\" %command%";
+
+/// Placeholder for the game command within Steam launch options
+const COMMAND_PLACEHOLDER: &str = "%command%";
+
+/// Name of the Steam process, Steam overwrites the launch options when
+/// it exits so it must be closed while they are changed
+const STEAM_PROCESS_NAME: &str = "steam";
+
+/// Adds the DLL overrides to the launch options of the game for every
+/// Steam user on the system. Returns the number of users updated
+pub async fn apply_launch_options() -> anyhow::Result<usize> {
+    let steam_running = spawn_blocking(is_steam_running).await.unwrap_or_default();
+    if steam_running {
+        bail!("Steam is running, close Steam before changing the launch options");
+    }
+
+    let config_paths = spawn_blocking(find_local_configs)
+        .await
+        .context("failed to join native thread")?;
+
+    if config_paths.is_empty() {
+        bail!("no Steam user configs were found");
+    }
+
+    let mut updated = 0;
+
+    for path in config_paths {
+        let contents = tokio::fs::read_to_string(&path)
+            .await
+            .with_context(|| format!("failed to read {}", path.display()))?;
+
+        let contents = match set_launch_options(&contents, profile().steam_app_id)? {
+            Some(value) => value,
+            None => {
+                debug!("launch options already set in {}", path.display());
+                continue;
+            }
+        };
+
+        atomic_write(&path, contents)
+            .await
+            .with_context(|| format!("failed to write {}", path.display()))?;
+
+        debug!("updated launch options in {}", path.display());
+        updated += 1;
+    }
+
+    Ok(updated)
+}
+
+/// Checks if Steam is currently running.
+///
+/// This function performs blocking IO and should be run on a blocking thread
+fn is_steam_running() -> bool {
+    let mut system = System::new();
+    system.refresh_processes_specifics(ProcessRefreshKind::new());
+
+    system
+        .processes()
+        .values()
+        .any(|process| process.name().eq_ignore_ascii_case(STEAM_PROCESS_NAME))
+}
+
+/// Finds the "localconfig.vdf" file of each Steam user, these store
+/// the launch options for each game.
+///
+/// This function performs blocking IO and should be run on a blocking thread
+fn find_local_configs() -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+
+    for steam_dir in find_steam_dirs() {
+        let entries = match std::fs::read_dir(steam_dir.join("userdata")) {
+            Ok(value) => value,
+            Err(_) => continue,
+        };
+
+        paths.extend(
+            entries
+                .flatten()
+                .map(|entry| entry.path().join("config").join("localconfig.vdf"))
+                .filter(|path| path.is_file()),
+        );
+    }
+
+    paths
+}
+
+/// Token within a VDF file
+enum Token<'a> {
+    /// Quoted string, contains the raw contents and the byte
+    /// range of the contents within the file
+    String(&'a str, usize, usize),
+    /// Opening brace of a section
+    Open,
+    /// Closing brace of a section, contains the byte offset of the brace
+    Close(usize),
+}
+
+/// Splits the `contents` of a VDF file into tokens
+fn tokenize(contents: &str) -> anyhow::Result<Vec<Token<'_>>> {
+    let bytes = contents.as_bytes();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'"' => {
+                let start = index + 1;
+                let mut end = start;
+                while end < bytes.len() && bytes[end] != b'"' {
+                    // Skip over escaped characters
+                    if bytes[end] == b'\\' {
+                        end += 1;
+                    }
+                    end += 1;
+                }
+
+                if end >= bytes.len() {
+                    bail!("unterminated string in Steam config");
+                }
+
+                tokens.push(Token::String(&contents[start..end], start, end));
+                index = end + 1;
+            }
+            b'{' => {
+                tokens.push(Token::Open);
+                index += 1;
+            }
+            b'}' => {
+                tokens.push(Token::Close(index));
+                index += 1;
+            }
+            // Comments run until the end of the line
+            b'/' if bytes.get(index + 1) == Some(&b'/') => {
+                while index < bytes.len() && bytes[index] != b'\n' {
+                    index += 1;
+                }
+            }
+            _ => index += 1,
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Sets the launch options of the app with the provided `app_id` within
+/// the `contents` of a "localconfig.vdf" file. Returns the updated contents
+/// or none when the launch options already contain the DLL overrides
+fn set_launch_options(contents: &str, app_id: &str) -> anyhow::Result<Option<String>> {
+    let tokens = tokenize(contents)?;
+
+    // Path of section names leading to the current token
+    let mut sections: Vec<&str> = Vec::new();
+    let mut pending_key: Option<&str> = None;
+    let mut app_depth: Option<usize> = None;
+
+    for token in &tokens {
+        match token {
+            Token::String(value, start, end) => match pending_key.take() {
+                None => pending_key = Some(value),
+                Some(key) => {
+                    if app_depth == Some(sections.len())
+                        && key.eq_ignore_ascii_case("LaunchOptions")
+                    {
+                        let options = merge_launch_options(&unescape(value))?;
+                        return Ok(options.map(|options| {
+                            let mut output = contents.to_string();
+                            output.replace_range(*start..*end, &escape(&options));
+                            output
+                        }));
+                    }
+                }
+            },
+            Token::Open => {
+                let key = pending_key.take().unwrap_or_default();
+                sections.push(key);
+
+                // The app section within the "Software/Valve/Steam/apps" section
+                let is_app = sections.len() >= 2
+                    && key == app_id
+                    && sections[sections.len() - 2].eq_ignore_ascii_case("apps");
+                if is_app && app_depth.is_none() {
+                    app_depth = Some(sections.len());
+                }
+            }
+            Token::Close(offset) => {
+                if app_depth == Some(sections.len()) {
+                    // App section has no launch options, insert them before the
+                    // closing brace using the indentation of the brace
+                    let line_start = contents[..*offset].rfind('\n').map_or(0, |i| i + 1);
+                    let indent = &contents[line_start..*offset];
+                    let line = format!(
+                        "{indent}\t\"LaunchOptions\"\t\t\"{}\"\n",
+                        escape(LAUNCH_OPTIONS)
+                    );
+
+                    let mut output = contents.to_string();
+                    output.insert_str(line_start, &line);
+                    return Ok(Some(output));
+                }
+
+                sections.pop();
+                pending_key = None;
+            }
+        }
+    }
+
+    bail!("the game was not found in the Steam config, launch the game from Steam once first")
+}
+
+/// Adds the DLL overrides to the `existing` launch options, none when
+/// the launch options already contain the overrides
+fn merge_launch_options(existing: &str) -> anyhow::Result<Option<String>> {
+    let existing = existing.trim();
+
+    if existing.contains("binkw32=n") {
+        return Ok(None);
+    }
+
+    // Combining multiple overrides is left to the user
+    if existing.contains("WINEDLLOVERRIDES") {
+        bail!("the game already has DLL overrides, add binkw23=n,b;binkw32=n,b to them manually");
+    }
+
+    let options = if existing.is_empty() {
+        LAUNCH_OPTIONS.to_string()
+    } else if existing.contains(COMMAND_PLACEHOLDER) {
+        format!("{DLL_OVERRIDES} {existing}")
+    } else {
+        // Existing options are arguments for the game
+        format!("{LAUNCH_OPTIONS} {existing}")
+    };
+
+    Ok(Some(options))
+}
+
+/// Escapes a value for use within a VDF string
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Unescapes a value from a VDF string
+fn unescape(value: &str) -> String {
+    value.replace("\\\"", "\"").replace("\\\\", "\\")
+}
@@ -0,0 +1,248 @@
+//! Module for exporting a report of the installation state and comparing
+//! it against a report from another installation, so a broken install can
+//! be compared against one that is known to work
+
+use crate::{
+    asi::list_asi_plugins,
+    bink::binkw32_hash,
+    fs_util::{atomic_write, resolve_file_name},
+    APP_VERSION,
+};
+use anyhow::Context;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use sha256::try_async_digest;
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+/// Report of the installation state of a game folder
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstallReport {
+    /// Version of the installer that created the report
+    pub installer_version: String,
+    /// Operating system and architecture the report was created on
+    pub os: String,
+    /// Path to the game folder
+    pub game_path: PathBuf,
+    /// SHA256 hash of the binkw32.dll if present
+    pub binkw32_hash: Option<String>,
+    /// SHA256 hash of the binkw23.dll if present
+    pub binkw23_hash: Option<String>,
+    /// ASI plugins within the plugin directory
+    pub plugins: Vec<ReportPlugin>,
+}
+
+/// Details about an ASI plugin within a report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportPlugin {
+    /// Plugin file name
+    pub name: String,
+    /// Whether the plugin is enabled
+    pub enabled: bool,
+    /// Size of the plugin file in bytes
+    pub size: u64,
+    /// SHA256 hash of the plugin file if it could be read
+    pub hash: Option<String>,
+    /// Version of the plugin if known
+    pub version: Option<String>,
+}
+
+/// Difference between the current installation and an imported report
+#[derive(Debug, Clone)]
+pub struct ReportDifference {
+    /// Name of the value that differs
+    pub name: String,
+    /// Value within the current installation
+    pub current: String,
+    /// Value within the imported report
+    pub imported: String,
+}
+
+/// Creates a report of the installation state of the game folder at `game_path`
+pub async fn create_report(game_path: PathBuf) -> anyhow::Result<InstallReport> {
+    let binkw23_path = resolve_file_name(&game_path, "binkw23.dll").await;
+
+    let binkw32_hash = binkw32_hash(&game_path).await.ok();
+    let binkw23_hash = try_async_digest(binkw23_path).await.ok();
+
+    let mut plugins = Vec::new();
+
+    for plugin in list_asi_plugins(game_path.clone()).await? {
+        plugins.push(ReportPlugin {
+            hash: try_async_digest(&plugin.path).await.ok(),
+            name: plugin.name,
+            enabled: plugin.enabled,
+            size: plugin.size,
+            version: plugin.version,
+        });
+    }
+
+    Ok(InstallReport {
+        installer_version: APP_VERSION.to_string(),
+        os: format!("{} ({})", std::env::consts::OS, std::env::consts::ARCH),
+        game_path,
+        binkw32_hash,
+        binkw23_hash,
+        plugins,
+    })
+}
+
+/// Writes the `report` to the file at `path`, files with a "txt" extension
+/// are written as text and everything else is written as JSON
+pub async fn write_report(report: &InstallReport, path: &Path) -> anyhow::Result<()> {
+    let is_text = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("txt"));
+
+    let contents = if is_text {
+        report.to_string().into_bytes()
+    } else {
+        serde_json::to_vec_pretty(report).context("failed to serialize report")?
+    };
+
+    atomic_write(path, contents)
+        .await
+        .context("failed to write report")?;
+
+    debug!("exported report to {}", path.display());
+
+    Ok(())
+}
+
+/// Reads a JSON report from the file at `path`
+pub async fn read_report(path: &Path) -> anyhow::Result<InstallReport> {
+    let contents = tokio::fs::read(path)
+        .await
+        .context("failed to read report")?;
+
+    serde_json::from_slice(&contents).context("report is not a valid JSON report")
+}
+
+/// Compares the `current` installation against the `imported` report,
+/// the game path is not compared as it differs between systems
+pub fn diff_reports(current: &InstallReport, imported: &InstallReport) -> Vec<ReportDifference> {
+    let mut differences = Vec::new();
+
+    let mut compare = |name: String, current: String, imported: String| {
+        if current != imported {
+            differences.push(ReportDifference {
+                name,
+                current,
+                imported,
+            });
+        }
+    };
+
+    compare(
+        "Installer version".to_string(),
+        current.installer_version.clone(),
+        imported.installer_version.clone(),
+    );
+    compare(
+        "binkw32.dll".to_string(),
+        format_optional(&current.binkw32_hash),
+        format_optional(&imported.binkw32_hash),
+    );
+    compare(
+        "binkw23.dll".to_string(),
+        format_optional(&current.binkw23_hash),
+        format_optional(&imported.binkw23_hash),
+    );
+
+    let find_plugin = |plugins: &[ReportPlugin], name: &str| -> Option<ReportPlugin> {
+        plugins
+            .iter()
+            .find(|plugin| plugin.name.eq_ignore_ascii_case(name))
+            .cloned()
+    };
+
+    // Plugins from both reports, compared by name
+    let mut names: Vec<String> = current
+        .plugins
+        .iter()
+        .chain(&imported.plugins)
+        .map(|plugin| plugin.name.to_lowercase())
+        .collect();
+    names.sort();
+    names.dedup();
+
+    for name in names {
+        let current = find_plugin(&current.plugins, &name);
+        let imported = find_plugin(&imported.plugins, &name);
+
+        let display_name = current
+            .as_ref()
+            .or(imported.as_ref())
+            .map(|plugin| plugin.name.clone())
+            .unwrap_or(name);
+
+        compare(
+            display_name,
+            current.as_ref().map(format_plugin).unwrap_or_else(missing),
+            imported.as_ref().map(format_plugin).unwrap_or_else(missing),
+        );
+    }
+
+    differences
+}
+
+/// Text used for values that are missing from a report
+fn missing() -> String {
+    "missing".to_string()
+}
+
+/// Formats an optional report value
+fn format_optional(value: &Option<String>) -> String {
+    value.clone().unwrap_or_else(missing)
+}
+
+/// Formats the state of a plugin for comparing
+fn format_plugin(plugin: &ReportPlugin) -> String {
+    format!(
+        "{}, {}, {}",
+        if plugin.enabled {
+            "enabled"
+        } else {
+            "disabled"
+        },
+        plugin.version.as_deref().unwrap_or("unknown version"),
+        format_optional(&plugin.hash)
+    )
+}
+
+impl Display for InstallReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Installer version: {}", self.installer_version)?;
+        writeln!(f, "OS: {}", self.os)?;
+        writeln!(f, "Game path: {}", self.game_path.display())?;
+        writeln!(
+            f,
+            "binkw32.dll hash: {}",
+            format_optional(&self.binkw32_hash)
+        )?;
+        writeln!(
+            f,
+            "binkw23.dll hash: {}",
+            format_optional(&self.binkw23_hash)
+        )?;
+        writeln!(f, "\nASI plugins:")?;
+
+        if self.plugins.is_empty() {
+            writeln!(f, "none")?;
+        }
+
+        for plugin in &self.plugins {
+            writeln!(
+                f,
+                "{} ({} bytes): {}",
+                plugin.name,
+                plugin.size,
+                format_plugin(plugin)
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+//! Module for checking the Pocket Relay server the plugin is configured to
+//! connect to, plugin releases can declare the server versions they support
+//! so users are warned before installing a plugin their server can't use
+
+use crate::github::{parse_tag_version, GitHubRelease};
+use anyhow::Context;
+use log::debug;
+use semver::{Op, Version, VersionReq};
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Path of the server details endpoint relative to the server URL
+const SERVER_DETAILS_PATH: &str = "api/server";
+
+/// Time to wait for the server to respond, servers are often self hosted
+/// and may not be running
+const SERVER_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Prefix of the line within the release notes declaring the server versions
+/// the release supports (e.g "Server compatibility: >=0.6.0, <0.7.0")
+const COMPATIBILITY_PREFIX: &str = "server compatibility:";
+
+/// Details reported by the server details endpoint
+#[derive(Debug, Deserialize)]
+struct ServerDetails {
+    /// Version of the server
+    version: String,
+}
+
+/// Compatibility of a plugin release with the server
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServerCompatibility {
+    /// Release supports the server version or doesn't declare the
+    /// versions it supports
+    Compatible,
+    /// Release requires a newer server
+    TooNew,
+    /// Release requires an older server
+    TooOld,
+}
+
+/// Obtains the version of the Pocket Relay server at the `connection_url`
+pub async fn get_server_version(
+    http_client: reqwest::Client,
+    connection_url: String,
+) -> anyhow::Result<Version> {
+    let base_url = match connection_url.contains("://") {
+        true => connection_url,
+        // Plugin assumes HTTP when the scheme is omitted
+        false => format!("http://{connection_url}"),
+    };
+    let url = format!("{}/{SERVER_DETAILS_PATH}", base_url.trim_end_matches('/'));
+
+    let details: ServerDetails = http_client
+        .get(&url)
+        .timeout(SERVER_REQUEST_TIMEOUT)
+        .send()
+        .await
+        .context("failed to connect to server")?
+        .error_for_status()
+        .context("server responded with an error")?
+        .json()
+        .await
+        .context("server responded with invalid details")?;
+
+    let version = parse_tag_version(&details.version)
+        .with_context(|| format!("server reported an invalid version: {}", details.version))?;
+
+    debug!("server at {base_url} is running {version}");
+
+    Ok(version)
+}
+
+/// Reads the server versions the `release` supports from its release
+/// notes, none when the release doesn't declare them
+pub fn server_requirement(release: &GitHubRelease) -> Option<VersionReq> {
+    let body = release.body.as_deref()?;
+
+    body.lines().find_map(|line| {
+        // Allow the line to be formatted as a list item or bold text
+        let line = line.trim().trim_start_matches(['-', '*', ' ']);
+        let prefix = line.get(..COMPATIBILITY_PREFIX.len())?;
+        if !prefix.eq_ignore_ascii_case(COMPATIBILITY_PREFIX) {
+            return None;
+        }
+
+        let requirement = line[COMPATIBILITY_PREFIX.len()..].trim_matches(['*', '`', ' ']);
+        VersionReq::parse(requirement).ok()
+    })
+}
+
+/// Checks whether the `release` supports the server `version`
+pub fn check_compatibility(release: &GitHubRelease, version: &Version) -> ServerCompatibility {
+    let requirement = match server_requirement(release) {
+        Some(value) => value,
+        None => return ServerCompatibility::Compatible,
+    };
+
+    if requirement.matches(version) {
+        return ServerCompatibility::Compatible;
+    }
+
+    // Failing a lower bound means the server is older than the release supports
+    let below_minimum = requirement.comparators.iter().any(|comparator| {
+        matches!(comparator.op, Op::Greater | Op::GreaterEq) && !comparator.matches(version)
+    });
+
+    match below_minimum {
+        true => ServerCompatibility::TooNew,
+        false => ServerCompatibility::TooOld,
+    }
+}
@@ -0,0 +1,244 @@
+//! Module for reading the target of Windows shortcut (.lnk) files, so a
+//! shortcut to the game can be picked instead of the game executable
+
+use crate::bytes::{read_u16, read_u32};
+use std::path::{Path, PathBuf};
+
+/// Extension of shortcut files
+pub const SHORTCUT_EXTENSION: &str = "lnk";
+
+/// Size of the shell link header
+const HEADER_SIZE: usize = 0x4C;
+
+/// Link flag set when the shortcut has a target ID list
+const HAS_TARGET_ID_LIST: u32 = 0x1;
+/// Link flag set when the shortcut has link info
+const HAS_LINK_INFO: u32 = 0x2;
+/// Link flag set when the shortcut has a name string
+const HAS_NAME: u32 = 0x4;
+/// Link flag set when the shortcut has a relative path string
+const HAS_RELATIVE_PATH: u32 = 0x8;
+/// Link flag set when the strings are UTF-16 rather than the system code page
+const IS_UNICODE: u32 = 0x80;
+
+/// Link info flag set when the link info has a local base path
+const VOLUME_ID_AND_LOCAL_BASE_PATH: u32 = 0x1;
+/// Minimum size of link info headers that include the UTF-16 paths
+const LINK_INFO_UNICODE_HEADER_SIZE: usize = 0x24;
+
+/// Reads a null terminated string at the `offset`
+fn read_c_string(bytes: &[u8], offset: usize) -> Option<String> {
+    let value = bytes.get(offset..)?;
+    let end = value.iter().position(|byte| *byte == 0)?;
+    Some(String::from_utf8_lossy(&value[..end]).to_string())
+}
+
+/// Reads a null terminated UTF-16 string at the `offset`
+fn read_utf16_c_string(bytes: &[u8], offset: usize) -> Option<String> {
+    let value: Vec<u16> = bytes
+        .get(offset..)?
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .take_while(|value| *value != 0)
+        .collect();
+    Some(String::from_utf16_lossy(&value))
+}
+
+/// Checks whether the `path` is a shortcut file
+pub fn is_shortcut(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case(SHORTCUT_EXTENSION))
+}
+
+/// Targets stored in the `bytes` of a shortcut file
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ShortcutTarget {
+    /// Absolute path to the target (e.g "C:\\Games\\MassEffect3.exe")
+    pub absolute: Option<String>,
+    /// Path to the target relative to the shortcut file
+    pub relative: Option<String>,
+}
+
+/// Reads the targets from the `bytes` of a shortcut file, none if the
+/// bytes are not a shortcut
+pub fn read_shortcut_target(bytes: &[u8]) -> Option<ShortcutTarget> {
+    if read_u32(bytes, 0)? as usize != HEADER_SIZE {
+        return None;
+    }
+
+    let flags = read_u32(bytes, 0x14)?;
+    let mut offset = HEADER_SIZE;
+    let mut target = ShortcutTarget::default();
+
+    if flags & HAS_TARGET_ID_LIST != 0 {
+        offset += 2 + read_u16(bytes, offset)? as usize;
+    }
+
+    if flags & HAS_LINK_INFO != 0 {
+        let link_info_size = read_u32(bytes, offset)? as usize;
+        let link_info_header_size = read_u32(bytes, offset + 4)? as usize;
+        let link_info_flags = read_u32(bytes, offset + 8)?;
+
+        if link_info_flags & VOLUME_ID_AND_LOCAL_BASE_PATH != 0 {
+            // UTF-16 paths are preferred over the system code page paths
+            let (base_path, suffix) = match link_info_header_size >= LINK_INFO_UNICODE_HEADER_SIZE {
+                true => (
+                    read_utf16_c_string(bytes, offset + read_u32(bytes, offset + 28)? as usize)?,
+                    read_utf16_c_string(bytes, offset + read_u32(bytes, offset + 32)? as usize)?,
+                ),
+                false => (
+                    read_c_string(bytes, offset + read_u32(bytes, offset + 16)? as usize)?,
+                    read_c_string(bytes, offset + read_u32(bytes, offset + 24)? as usize)?,
+                ),
+            };
+            target.absolute = Some(base_path + &suffix);
+        }
+
+        offset += link_info_size;
+    }
+
+    // String data is a count of characters followed by the characters
+    let char_size = match flags & IS_UNICODE != 0 {
+        true => 2,
+        false => 1,
+    };
+
+    if flags & HAS_NAME != 0 {
+        offset += 2 + read_u16(bytes, offset)? as usize * char_size;
+    }
+
+    if flags & HAS_RELATIVE_PATH != 0 {
+        let length = read_u16(bytes, offset)? as usize;
+        let value = bytes.get(offset + 2..offset + 2 + length * char_size)?;
+
+        target.relative = Some(match char_size {
+            2 => read_utf16_c_string(value, 0)?,
+            _ => String::from_utf8_lossy(value).to_string(),
+        });
+    }
+
+    Some(target)
+}
+
+/// Resolves the path the `target` of the shortcut at `shortcut_path`
+/// points to, none if the target doesn't exist.
+///
+/// Shortcuts within a Proton prefix point to paths on its C: drive, these
+/// are resolved within the "drive_c" folder of the prefix
+///
+/// This function performs blocking IO and should be run on a blocking thread
+pub fn resolve_shortcut_target(shortcut_path: &Path, target: &ShortcutTarget) -> Option<PathBuf> {
+    let mut candidates: Vec<PathBuf> = Vec::new();
+
+    if let Some(absolute) = &target.absolute {
+        candidates.push(PathBuf::from(absolute));
+
+        let drive_c = shortcut_path
+            .ancestors()
+            .find(|path| path.file_name().is_some_and(|name| name == "drive_c"));
+
+        if let (Some(drive_c), Some(rest)) = (drive_c, strip_drive_c(absolute)) {
+            candidates.push(windows_path(drive_c, rest));
+        }
+    }
+
+    if let (Some(relative), Some(parent)) = (&target.relative, shortcut_path.parent()) {
+        candidates.push(windows_path(parent, relative));
+    }
+
+    candidates.into_iter().find(|path| path.exists())
+}
+
+/// Strips the C: drive from the start of a Windows `path`
+fn strip_drive_c(path: &str) -> Option<&str> {
+    let rest = path.get(2..)?;
+    path.get(..2)
+        .is_some_and(|drive| drive.eq_ignore_ascii_case("C:"))
+        .then_some(rest)
+}
+
+/// Joins the backslash separated Windows `path` onto the `base` path
+fn windows_path(base: &Path, path: &str) -> PathBuf {
+    path.split(['\\', '/'])
+        .filter(|part| !part.is_empty() && *part != ".")
+        .fold(base.to_path_buf(), |path, part| match part {
+            ".." => path.parent().map(Path::to_path_buf).unwrap_or(path),
+            part => path.join(part),
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{read_shortcut_target, resolve_shortcut_target, ShortcutTarget, HEADER_SIZE};
+    use crate::game_fs::test_util::TempDir;
+
+    /// Creates the bytes of a shortcut with a link info `base_path` and a
+    /// UTF-16 `relative` path
+    fn create_shortcut(base_path: &str, relative: &str) -> Vec<u8> {
+        let mut bytes = vec![0u8; HEADER_SIZE];
+        bytes[0..4].copy_from_slice(&(HEADER_SIZE as u32).to_le_bytes());
+        // Has link info, relative path and unicode strings
+        bytes[0x14..0x18].copy_from_slice(&(0x2u32 | 0x8 | 0x80).to_le_bytes());
+
+        // Link info with the base path and an empty suffix
+        let header_size = 0x1C_u32;
+        let base_offset = header_size;
+        let suffix_offset = base_offset + base_path.len() as u32 + 1;
+        let size = suffix_offset + 1;
+        for value in [size, header_size, 1, 0, base_offset, 0, suffix_offset] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes.extend_from_slice(base_path.as_bytes());
+        bytes.extend_from_slice(&[0, 0]);
+
+        let relative: Vec<u16> = relative.encode_utf16().collect();
+        bytes.extend_from_slice(&(relative.len() as u16).to_le_bytes());
+        bytes.extend(relative.iter().flat_map(|value| value.to_le_bytes()));
+        bytes
+    }
+
+    /// Both targets are read from a shortcut
+    #[test]
+    fn test_read_shortcut_target() {
+        let bytes = create_shortcut(
+            "C:\\Games\\Mass Effect 3\\Binaries\\Win32\\MassEffect3.exe",
+            "..\\Mass Effect 3\\Binaries\\Win32\\MassEffect3.exe",
+        );
+
+        assert_eq!(
+            read_shortcut_target(&bytes),
+            Some(ShortcutTarget {
+                absolute: Some(
+                    "C:\\Games\\Mass Effect 3\\Binaries\\Win32\\MassEffect3.exe".to_string()
+                ),
+                relative: Some("..\\Mass Effect 3\\Binaries\\Win32\\MassEffect3.exe".to_string()),
+            })
+        );
+        assert_eq!(read_shortcut_target(b"not a shortcut"), None);
+    }
+
+    /// Targets are resolved relative to the shortcut and within Proton prefixes
+    #[test]
+    fn test_resolve_shortcut_target() {
+        let dir = TempDir::new();
+        let drive_c = dir.path().join("drive_c");
+        let exe_dir = drive_c.join("Games").join("Mass Effect 3");
+        std::fs::create_dir_all(&exe_dir).unwrap();
+        std::fs::write(exe_dir.join("MassEffect3.exe"), b"").unwrap();
+
+        let shortcut_path = drive_c.join("Desktop").join("Mass Effect 3.lnk");
+        let expected = Some(exe_dir.join("MassEffect3.exe"));
+
+        let target = ShortcutTarget {
+            absolute: Some("C:\\Games\\Mass Effect 3\\MassEffect3.exe".to_string()),
+            relative: None,
+        };
+        assert_eq!(resolve_shortcut_target(&shortcut_path, &target), expected);
+
+        let target = ShortcutTarget {
+            absolute: None,
+            relative: Some("..\\Games\\Mass Effect 3\\MassEffect3.exe".to_string()),
+        };
+        assert_eq!(resolve_shortcut_target(&shortcut_path, &target), expected);
+    }
+}
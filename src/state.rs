@@ -0,0 +1,623 @@
+//! Module for the state of the patch and plugin within the selected game and
+//! the transitions between them as they are changed. Kept separate from the
+//! user interface so the transitions can be tested on their own
+
+use crate::{bink::PatchState, plugin::PluginState, progress::Progress};
+use log::error;
+use std::fmt::Display;
+
+/// Error from an action that modifies the game files
+#[derive(Debug, Clone)]
+pub enum ActionError {
+    /// The game is running and its files can't be modified
+    GameRunning,
+    /// The action failed
+    Failed(String),
+}
+
+/// Error from a transition that isn't valid for the current state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransitionError {
+    /// Another change is already in progress
+    Busy,
+    /// Result arrived for a change that is no longer in progress
+    Stale,
+    /// Change requires the plugin to be installed
+    NotInstalled,
+}
+
+impl Display for TransitionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            TransitionError::Busy => "another change is in progress",
+            TransitionError::Stale => "change is no longer in progress",
+            TransitionError::NotInstalled => "plugin is not installed",
+        })
+    }
+}
+
+/// Current state for the plugin add process
+#[derive(Debug, Default)]
+pub enum AlterPatchState {
+    /// Initial state, patch has not been added or removed yet
+    #[default]
+    Initial,
+
+    /// Loading state, patch is being applied/removed, includes the
+    /// progress of applying the patch once its known
+    Loading(Option<Progress>),
+
+    /// Patch was added/removed successfully
+    Success,
+
+    /// Patch can't be added/removed while the game is running
+    GameRunning,
+
+    /// Failed to add/remove the patch
+    Error(String),
+}
+
+/// Current state for the plugin add process
+#[derive(Debug, Default)]
+pub enum AlterPluginState {
+    /// Initial state, plugin has not been added yet
+    #[default]
+    Initial,
+
+    /// Loading state, plugin is being installed or removed, includes the
+    /// progress of installing once its known
+    Loading(Option<Progress>),
+
+    /// Installed plugin is being replaced with the latest release, includes
+    /// the progress once its known
+    Updating(Option<Progress>),
+
+    /// Installed plugin is being enabled or disabled, contains whether
+    /// its being enabled
+    Toggling(bool),
+
+    /// Plugin was added successfully
+    Success,
+
+    /// Plugin can't be added/removed while the game is running, contains
+    /// the action to retry
+    GameRunning(PluginAction),
+
+    /// Failed to add the plugin
+    Error(String),
+
+    /// Failed to update the plugin
+    UpdateError(String),
+
+    /// Failed to enable or disable the plugin, contains whether the
+    /// plugin was being enabled
+    ToggleError(bool, String),
+
+    /// Plugin was removed shortly after being installed, most likely
+    /// quarantined by an antivirus
+    Quarantined,
+}
+
+/// Actions that modify the plugin
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluginAction {
+    /// Adding the plugin
+    Add,
+    /// Updating the installed plugin
+    Update,
+    /// Removing the plugin
+    Remove,
+    /// Enabling or disabling the plugin, contains whether its being enabled
+    SetEnabled(bool),
+}
+
+/// State of the patch within the game along with the status of the
+/// change being made to it
+#[derive(Debug)]
+pub struct PatchStatus {
+    /// State of the binkw32.dll within the game folder
+    pub state: PatchState,
+    /// Status of applying or removing the patch
+    pub alter: AlterPatchState,
+}
+
+impl PatchStatus {
+    /// Creates the status for a game with the patch `state`
+    pub fn new(state: PatchState) -> Self {
+        Self {
+            state,
+            alter: AlterPatchState::Initial,
+        }
+    }
+
+    /// Whether the patch is being applied or removed
+    pub fn is_busy(&self) -> bool {
+        matches!(self.alter, AlterPatchState::Loading(_))
+    }
+
+    /// Whether the patch is applied
+    pub fn is_patched(&self) -> bool {
+        self.state == PatchState::Patched
+    }
+
+    /// Starts applying or removing the patch
+    pub fn start(&mut self) -> Result<(), TransitionError> {
+        if self.is_busy() {
+            return Err(TransitionError::Busy);
+        }
+
+        self.alter = AlterPatchState::Loading(None);
+        Ok(())
+    }
+
+    /// Sets the `progress` of the patch being applied, ignored when the
+    /// patch isn't being changed
+    pub fn progress(&mut self, progress: Progress) {
+        if let AlterPatchState::Loading(current) = &mut self.alter {
+            *current = Some(progress);
+        }
+    }
+
+    /// Finishes applying the patch with the `result`
+    pub fn finish_apply(&mut self, result: Result<(), ActionError>) -> Result<(), TransitionError> {
+        self.finish(result, PatchState::Patched, "apply")
+    }
+
+    /// Finishes removing the patch with the `result`
+    pub fn finish_remove(
+        &mut self,
+        result: Result<(), ActionError>,
+    ) -> Result<(), TransitionError> {
+        self.finish(result, PatchState::Unpatched, "remove")
+    }
+
+    /// Finishes the change with the `result`, the patch is in the `target`
+    /// state when the change succeeds
+    fn finish(
+        &mut self,
+        result: Result<(), ActionError>,
+        target: PatchState,
+        action: &str,
+    ) -> Result<(), TransitionError> {
+        if !self.is_busy() {
+            return Err(TransitionError::Stale);
+        }
+
+        self.alter = match result {
+            Ok(()) => {
+                self.state = target;
+                AlterPatchState::Success
+            }
+            Err(ActionError::GameRunning) => AlterPatchState::GameRunning,
+            Err(ActionError::Failed(err)) => {
+                error!("failed to {action} patch: {err}");
+                AlterPatchState::Error(err)
+            }
+        };
+
+        Ok(())
+    }
+
+    /// Sets the patch `state` read from the game folder, ignored while the
+    /// patch is being changed as the change sets the state once it finishes
+    pub fn refresh(&mut self, state: PatchState) {
+        if !self.is_busy() {
+            self.state = state;
+        }
+    }
+
+    /// Sets the patch `state` after it was changed outside of the patch
+    /// section (e.g installing everything or uninstalling)
+    pub fn reset(&mut self, state: PatchState) {
+        self.state = state;
+        self.alter = AlterPatchState::Initial;
+    }
+}
+
+/// State of the plugin within the game along with the status of the
+/// change being made to it
+#[derive(Debug, Default)]
+pub struct PluginStatus {
+    /// State of the plugin within the game folder
+    pub state: PluginState,
+    /// Version of the installed plugin if known
+    pub version: Option<String>,
+    /// Status of adding, removing or toggling the plugin
+    pub alter: AlterPluginState,
+}
+
+impl PluginStatus {
+    /// Creates the status for a game with the plugin `state` and installed
+    /// `version`
+    pub fn new(state: PluginState, version: Option<String>) -> Self {
+        Self {
+            state,
+            version,
+            alter: AlterPluginState::Initial,
+        }
+    }
+
+    /// Whether the plugin is being changed
+    pub fn is_busy(&self) -> bool {
+        matches!(
+            self.alter,
+            AlterPluginState::Loading(_)
+                | AlterPluginState::Updating(_)
+                | AlterPluginState::Toggling(_)
+        )
+    }
+
+    /// Whether the plugin is installed and enabled
+    pub fn is_installed(&self) -> bool {
+        self.state == PluginState::Installed
+    }
+
+    /// Whether the plugin is installed, enabled or not
+    pub fn is_present(&self) -> bool {
+        self.state.is_present()
+    }
+
+    /// Whether the section is idle with nothing to report
+    pub fn is_idle(&self) -> bool {
+        matches!(
+            self.alter,
+            AlterPluginState::Initial | AlterPluginState::Success
+        )
+    }
+
+    /// Starts installing the plugin
+    pub fn start_install(&mut self) -> Result<(), TransitionError> {
+        self.start(AlterPluginState::Loading(None))
+    }
+
+    /// Starts replacing the installed plugin with a newer release
+    pub fn start_update(&mut self) -> Result<(), TransitionError> {
+        self.start(AlterPluginState::Updating(None))
+    }
+
+    /// Starts removing the plugin
+    pub fn start_remove(&mut self) -> Result<(), TransitionError> {
+        if !self.is_present() {
+            return Err(TransitionError::NotInstalled);
+        }
+
+        self.start(AlterPluginState::Loading(None))
+    }
+
+    /// Starts enabling or disabling the plugin
+    pub fn start_toggle(&mut self, enabled: bool) -> Result<(), TransitionError> {
+        if !self.is_present() {
+            return Err(TransitionError::NotInstalled);
+        }
+
+        self.start(AlterPluginState::Toggling(enabled))
+    }
+
+    /// Starts the change moving to the `alter` state
+    fn start(&mut self, alter: AlterPluginState) -> Result<(), TransitionError> {
+        if self.is_busy() {
+            return Err(TransitionError::Busy);
+        }
+
+        self.alter = alter;
+        Ok(())
+    }
+
+    /// Sets the `progress` of the plugin being installed, ignored when the
+    /// plugin isn't being installed
+    pub fn progress(&mut self, progress: Progress) {
+        if let AlterPluginState::Loading(current) | AlterPluginState::Updating(current) =
+            &mut self.alter
+        {
+            *current = Some(progress);
+        }
+    }
+
+    /// Finishes installing or updating the plugin with the `result`, which
+    /// contains the installed version if known
+    pub fn finish_install(
+        &mut self,
+        result: Result<Option<String>, ActionError>,
+    ) -> Result<(), TransitionError> {
+        if !matches!(
+            self.alter,
+            AlterPluginState::Loading(_) | AlterPluginState::Updating(_)
+        ) {
+            return Err(TransitionError::Stale);
+        }
+
+        self.alter = match result {
+            Ok(version) => {
+                self.state = PluginState::Installed;
+                self.version = version;
+                AlterPluginState::Success
+            }
+            // Plugin is only added while installed when updating
+            Err(ActionError::GameRunning) => {
+                AlterPluginState::GameRunning(match self.is_present() {
+                    true => PluginAction::Update,
+                    false => PluginAction::Add,
+                })
+            }
+            Err(ActionError::Failed(err)) => {
+                error!("failed to add plugin: {err:?}");
+                match self.is_present() {
+                    true => AlterPluginState::UpdateError(err),
+                    false => AlterPluginState::Error(err),
+                }
+            }
+        };
+
+        Ok(())
+    }
+
+    /// Finishes removing the plugin with the `result`
+    pub fn finish_remove(
+        &mut self,
+        result: Result<(), ActionError>,
+    ) -> Result<(), TransitionError> {
+        // Removing uses the loading state while the plugin is present
+        if !matches!(self.alter, AlterPluginState::Loading(_)) || !self.is_present() {
+            return Err(TransitionError::Stale);
+        }
+
+        self.alter = match result {
+            Ok(()) => {
+                self.state = PluginState::Absent;
+                self.version = None;
+                AlterPluginState::Success
+            }
+            Err(ActionError::GameRunning) => AlterPluginState::GameRunning(PluginAction::Remove),
+            Err(ActionError::Failed(err)) => {
+                error!("failed to remove plugin: {err:?}");
+                AlterPluginState::Error(err)
+            }
+        };
+
+        Ok(())
+    }
+
+    /// Finishes enabling or disabling the plugin with the `result`
+    pub fn finish_toggle(
+        &mut self,
+        result: Result<(), ActionError>,
+    ) -> Result<(), TransitionError> {
+        let enabled = match self.alter {
+            AlterPluginState::Toggling(enabled) => enabled,
+            _ => return Err(TransitionError::Stale),
+        };
+
+        self.alter = match result {
+            Ok(()) => {
+                self.state = match enabled {
+                    true => PluginState::Installed,
+                    false => PluginState::Disabled,
+                };
+                AlterPluginState::Initial
+            }
+            Err(ActionError::GameRunning) => {
+                AlterPluginState::GameRunning(PluginAction::SetEnabled(enabled))
+            }
+            Err(ActionError::Failed(err)) => {
+                error!("failed to set plugin enabled: {err:?}");
+                AlterPluginState::ToggleError(enabled, err)
+            }
+        };
+
+        Ok(())
+    }
+
+    /// Marks the plugin as removed by an antivirus after it was installed,
+    /// ignored while the plugin is being changed again
+    pub fn quarantined(&mut self) -> Result<(), TransitionError> {
+        if self.is_busy() {
+            return Err(TransitionError::Busy);
+        }
+
+        self.state = PluginState::Absent;
+        self.version = None;
+        self.alter = AlterPluginState::Quarantined;
+        Ok(())
+    }
+
+    /// Sets the plugin `state` and `version` read from the game folder,
+    /// ignored while the plugin is being changed as the change sets the
+    /// state once it finishes
+    pub fn refresh(&mut self, state: PluginState, version: Option<String>) {
+        if !self.is_busy() {
+            self.state = state;
+            self.version = version;
+        }
+    }
+
+    /// Sets the plugin `state` and `version` after it was changed outside
+    /// of the plugin section (e.g installing everything or uninstalling)
+    pub fn reset(&mut self, state: PluginState, version: Option<String>) {
+        self.state = state;
+        self.version = version;
+        self.alter = AlterPluginState::Initial;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{github::DownloadProgress, progress::ProgressStep};
+
+    #[test]
+    fn patch_apply_success() {
+        let mut status = PatchStatus::new(PatchState::Unpatched);
+
+        status.start().unwrap();
+        assert!(status.is_busy());
+
+        status.progress(Progress::step(ProgressStep::Writing));
+        assert!(matches!(
+            status.alter,
+            AlterPatchState::Loading(Some(Progress {
+                step: ProgressStep::Writing,
+                ..
+            }))
+        ));
+
+        status.finish_apply(Ok(())).unwrap();
+        assert!(status.is_patched());
+        assert!(matches!(status.alter, AlterPatchState::Success));
+    }
+
+    #[test]
+    fn patch_start_while_busy() {
+        let mut status = PatchStatus::new(PatchState::Unpatched);
+
+        status.start().unwrap();
+        assert_eq!(status.start(), Err(TransitionError::Busy));
+    }
+
+    #[test]
+    fn patch_remove_game_running() {
+        let mut status = PatchStatus::new(PatchState::Patched);
+
+        status.start().unwrap();
+        status.finish_remove(Err(ActionError::GameRunning)).unwrap();
+
+        // State is unchanged when the patch couldn't be removed
+        assert!(status.is_patched());
+        assert!(matches!(status.alter, AlterPatchState::GameRunning));
+    }
+
+    #[test]
+    fn patch_stale_result() {
+        let mut status = PatchStatus::new(PatchState::Unpatched);
+
+        assert_eq!(status.finish_apply(Ok(())), Err(TransitionError::Stale));
+        assert_eq!(status.state, PatchState::Unpatched);
+    }
+
+    #[test]
+    fn patch_refresh_ignored_while_busy() {
+        let mut status = PatchStatus::new(PatchState::Unpatched);
+
+        status.start().unwrap();
+        status.refresh(PatchState::Patched);
+        assert_eq!(status.state, PatchState::Unpatched);
+
+        status
+            .finish_apply(Err(ActionError::Failed("error".to_string())))
+            .unwrap();
+        status.refresh(PatchState::Patched);
+        assert!(status.is_patched());
+    }
+
+    #[test]
+    fn plugin_install_success() {
+        let mut status = PluginStatus::default();
+
+        status.start_install().unwrap();
+        status.progress(Progress::download(DownloadProgress {
+            downloaded: 10,
+            total: Some(20),
+        }));
+        assert!(matches!(status.alter, AlterPluginState::Loading(Some(_))));
+
+        status
+            .finish_install(Ok(Some("v1.0.0".to_string())))
+            .unwrap();
+        assert!(status.is_installed());
+        assert_eq!(status.version.as_deref(), Some("v1.0.0"));
+        assert!(matches!(status.alter, AlterPluginState::Success));
+    }
+
+    #[test]
+    fn plugin_install_failed() {
+        let mut status = PluginStatus::default();
+
+        status.start_install().unwrap();
+        status
+            .finish_install(Err(ActionError::Failed("error".to_string())))
+            .unwrap();
+        assert!(!status.is_present());
+        assert!(matches!(status.alter, AlterPluginState::Error(_)));
+    }
+
+    #[test]
+    fn plugin_update_retries_update() {
+        let mut status = PluginStatus::new(PluginState::Installed, Some("v1.0.0".to_string()));
+
+        status.start_update().unwrap();
+        status
+            .finish_install(Err(ActionError::GameRunning))
+            .unwrap();
+        assert!(matches!(
+            status.alter,
+            AlterPluginState::GameRunning(PluginAction::Update)
+        ));
+
+        status.start_update().unwrap();
+        status
+            .finish_install(Err(ActionError::Failed("error".to_string())))
+            .unwrap();
+        assert!(matches!(status.alter, AlterPluginState::UpdateError(_)));
+        assert_eq!(status.version.as_deref(), Some("v1.0.0"));
+    }
+
+    #[test]
+    fn plugin_remove_success() {
+        let mut status = PluginStatus::new(PluginState::Disabled, Some("v1.0.0".to_string()));
+
+        status.start_remove().unwrap();
+        status.finish_remove(Ok(())).unwrap();
+        assert_eq!(status.state, PluginState::Absent);
+        assert_eq!(status.version, None);
+    }
+
+    #[test]
+    fn plugin_remove_not_installed() {
+        let mut status = PluginStatus::default();
+
+        assert_eq!(status.start_remove(), Err(TransitionError::NotInstalled));
+        assert_eq!(
+            status.start_toggle(true),
+            Err(TransitionError::NotInstalled)
+        );
+    }
+
+    #[test]
+    fn plugin_install_result_is_not_remove_result() {
+        let mut status = PluginStatus::default();
+
+        status.start_install().unwrap();
+        assert_eq!(status.finish_remove(Ok(())), Err(TransitionError::Stale));
+        assert_eq!(status.finish_toggle(Ok(())), Err(TransitionError::Stale));
+    }
+
+    #[test]
+    fn plugin_toggle() {
+        let mut status = PluginStatus::new(PluginState::Installed, None);
+
+        status.start_toggle(false).unwrap();
+        assert!(status.is_busy());
+        status.finish_toggle(Ok(())).unwrap();
+        assert_eq!(status.state, PluginState::Disabled);
+        assert!(matches!(status.alter, AlterPluginState::Initial));
+
+        status.start_toggle(true).unwrap();
+        status.finish_toggle(Err(ActionError::GameRunning)).unwrap();
+        assert_eq!(status.state, PluginState::Disabled);
+        assert!(matches!(
+            status.alter,
+            AlterPluginState::GameRunning(PluginAction::SetEnabled(true))
+        ));
+    }
+
+    #[test]
+    fn plugin_quarantined() {
+        let mut status = PluginStatus::new(PluginState::Installed, Some("v1.0.0".to_string()));
+
+        status.quarantined().unwrap();
+        assert!(!status.is_present());
+        assert!(matches!(status.alter, AlterPluginState::Quarantined));
+
+        status.start_install().unwrap();
+        assert_eq!(status.quarantined(), Err(TransitionError::Busy));
+    }
+}
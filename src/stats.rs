@@ -0,0 +1,98 @@
+//! Module for the opt-in anonymous usage statistics, when enabled the outcome
+//! of each change to the game is reported so the most common failures can be
+//! found. Reports never include paths, error messages or server addresses
+
+use anyhow::Context;
+use log::debug;
+use reqwest::header;
+use serde::Serialize;
+use std::time::Duration;
+use sysinfo::System;
+
+/// Time to wait for the statistics endpoint to respond
+const STATS_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Change to the game a usage report is for
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageOperation {
+    /// Applying the patch
+    ApplyPatch,
+    /// Removing the patch
+    RemovePatch,
+    /// Installing or updating the plugin
+    InstallPlugin,
+    /// Removing the plugin
+    RemovePlugin,
+    /// Applying the patch and installing the plugin together
+    InstallAll,
+    /// Uninstalling everything
+    Uninstall,
+}
+
+/// Outcome of the change to the game
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageOutcome {
+    /// Change was made
+    Success,
+    /// Game files couldn't be changed as the game was running
+    GameRunning,
+    /// Change failed
+    Failed,
+}
+
+/// Anonymous report of the outcome of a change to the game
+#[derive(Debug, Clone, Serialize)]
+pub struct UsageReport {
+    /// Version of the installer
+    pub installer_version: &'static str,
+    /// Name and version of the operating system
+    pub os: String,
+    /// CPU architecture the installer was built for
+    pub arch: &'static str,
+    /// Change that was made
+    pub operation: UsageOperation,
+    /// Outcome of the change
+    pub outcome: UsageOutcome,
+}
+
+impl UsageReport {
+    /// Creates a report for the `outcome` of the `operation`
+    pub fn new(operation: UsageOperation, outcome: UsageOutcome) -> Self {
+        Self {
+            installer_version: crate::APP_VERSION,
+            os: System::long_os_version().unwrap_or_else(|| std::env::consts::OS.to_string()),
+            arch: std::env::consts::ARCH,
+            operation,
+            outcome,
+        }
+    }
+
+    /// Formats the report as the JSON body that is sent
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+/// Sends the usage `report` to the statistics `endpoint`
+pub async fn send_usage_report(
+    http_client: reqwest::Client,
+    endpoint: String,
+    report: UsageReport,
+) -> anyhow::Result<()> {
+    http_client
+        .post(&endpoint)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(report.to_json())
+        .timeout(STATS_REQUEST_TIMEOUT)
+        .send()
+        .await
+        .context("failed to send usage report")?
+        .error_for_status()
+        .context("statistics endpoint responded with an error")?;
+
+    debug!("sent usage report: {report:?}");
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+//! Module for identifying the store the game was installed from, the store
+//! determines how the game is launched and whether it may undo the patch
+
+use crate::{i18n::tr, profile::profile};
+use std::{fmt::Display, path::Path};
+
+/// Folder within Origin and EA App installs storing the installer data
+const STORE_INSTALLER_FOLDER: &str = "__Installer";
+
+/// Files Steam places next to the games it installs
+const STEAM_FILES: &[&str] = &["steam_appid.txt", "steam_api.dll"];
+
+/// Store the game was installed from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StoreKind {
+    /// Installed through Steam
+    Steam,
+    /// Installed through Origin
+    Origin,
+    /// Installed through the EA App
+    EaApp,
+    /// Unknown install location
+    Unknown,
+}
+
+impl StoreKind {
+    /// Warning about the store undoing the patch, none when the store
+    /// leaves the game files alone
+    pub fn patch_warning(&self) -> Option<&'static str> {
+        match self {
+            StoreKind::Origin => Some(tr(
+                "Origin may remove the patch when it updates or repairs the game, \
+                apply the patch again if Pocket Relay stops working",
+            )),
+            StoreKind::EaApp => Some(tr(
+                "The EA App may remove the patch when it updates or repairs the game, \
+                apply the patch again if Pocket Relay stops working",
+            )),
+            StoreKind::Steam | StoreKind::Unknown => None,
+        }
+    }
+}
+
+impl Display for StoreKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            StoreKind::Steam => "Steam",
+            StoreKind::Origin => "Origin",
+            StoreKind::EaApp => tr("EA App"),
+            StoreKind::Unknown => tr("unknown"),
+        })
+    }
+}
+
+/// Determines the store the game in the `game_path` was installed from
+/// based on the store install folders and the files the stores place
+/// within the install
+///
+/// This function performs blocking IO and should be run on a blocking thread
+pub fn detect_store(game_path: &Path) -> StoreKind {
+    let has_component = |name: &str| {
+        game_path
+            .components()
+            .any(|component| component.as_os_str().eq_ignore_ascii_case(name))
+    };
+
+    // Game folder path is the "Binaries/Win32" folder within the install
+    let install_path = game_path
+        .ancestors()
+        .nth(profile().exe_dir.len())
+        .unwrap_or(game_path);
+
+    let has_steam_file = STEAM_FILES
+        .iter()
+        .any(|name| game_path.join(name).is_file() || install_path.join(name).is_file());
+
+    if has_component("steamapps") || has_steam_file {
+        StoreKind::Steam
+    } else if has_component("EA Games") {
+        StoreKind::EaApp
+    } else if has_component("Origin Games") {
+        StoreKind::Origin
+    } else if install_path.join(STORE_INSTALLER_FOLDER).is_dir() {
+        // Both stores write the installer data, the EA App replaced Origin
+        // so installs elsewhere are most likely from the EA App
+        StoreKind::EaApp
+    } else {
+        StoreKind::Unknown
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{detect_store, StoreKind};
+    use crate::game_fs::test_util::TempDir;
+    use std::path::{Path, PathBuf};
+
+    /// Creates the game folder of an install at the `install_path`
+    fn create_game_path(install_path: &Path) -> PathBuf {
+        let game_path = install_path.join("Binaries").join("Win32");
+        std::fs::create_dir_all(&game_path).unwrap();
+        game_path
+    }
+
+    /// Stores are detected from their install folders
+    #[test]
+    fn test_detect_store_path() {
+        let dir = TempDir::new();
+
+        for (parts, expected) in [
+            (&["steamapps", "common"][..], StoreKind::Steam),
+            (&["Program Files", "EA Games"], StoreKind::EaApp),
+            (&["Program Files (x86)", "Origin Games"], StoreKind::Origin),
+            (&["Games"], StoreKind::Unknown),
+        ] {
+            let install_path = parts
+                .iter()
+                .fold(dir.path().to_path_buf(), |path, part| path.join(part))
+                .join("Mass Effect 3");
+
+            assert_eq!(detect_store(&create_game_path(&install_path)), expected);
+        }
+    }
+
+    /// Stores are detected from the files they place within the install
+    #[test]
+    fn test_detect_store_files() {
+        let dir = TempDir::new();
+
+        let steam_path = create_game_path(&dir.path().join("Steam Copy"));
+        std::fs::write(steam_path.join("steam_api.dll"), b"").unwrap();
+        assert_eq!(detect_store(&steam_path), StoreKind::Steam);
+
+        let ea_install_path = dir.path().join("EA Copy");
+        let ea_path = create_game_path(&ea_install_path);
+        std::fs::create_dir_all(ea_install_path.join("__Installer")).unwrap();
+        assert_eq!(detect_store(&ea_path), StoreKind::EaApp);
+    }
+}
@@ -0,0 +1,78 @@
+//! Module for the user interface theme and the text styles used by
+//! views so they don't hardcode colors
+
+use crate::i18n::tr;
+use iced::{widget::text, Color, Theme};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// Name of the theme created when using a custom accent color
+const CUSTOM_THEME_NAME: &str = "Pocket Relay";
+
+/// Light or dark appearance of the user interface
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeMode {
+    /// Follow the system light or dark mode
+    System,
+    /// Light theme
+    Light,
+    /// Dark theme
+    #[default]
+    Dark,
+}
+
+impl ThemeMode {
+    /// All the available theme modes
+    pub const ALL: [ThemeMode; 3] = [ThemeMode::System, ThemeMode::Light, ThemeMode::Dark];
+}
+
+impl Display for ThemeMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ThemeMode::System => tr("System"),
+            ThemeMode::Light => tr("Light"),
+            ThemeMode::Dark => tr("Dark"),
+        })
+    }
+}
+
+/// Creates the theme for the provided `mode`, using the `accent` color
+/// as the primary color when provided
+pub fn create_theme(mode: ThemeMode, accent: Option<Color>) -> Theme {
+    let theme = match mode {
+        // The default theme is detected from the system
+        ThemeMode::System => Theme::default(),
+        ThemeMode::Light => Theme::Light,
+        ThemeMode::Dark => Theme::Dark,
+    };
+
+    match accent {
+        Some(accent) => {
+            let mut palette = theme.palette();
+            palette.primary = accent;
+            Theme::custom(CUSTOM_THEME_NAME.to_string(), palette)
+        }
+        None => theme,
+    }
+}
+
+/// Parses an accent color in hex form (e.g "#5865f2")
+pub fn parse_accent_color(value: &str) -> Option<Color> {
+    Color::parse(value.trim())
+}
+
+/// Text style for less important text, such as descriptions
+pub fn muted_text(theme: &Theme) -> text::Style {
+    let palette = theme.palette();
+    let (text, background) = (palette.text, palette.background);
+
+    // Blend the text color into the background so it stands out less
+    let color = Color::from_rgb(
+        (text.r + background.r) / 2.0,
+        (text.g + background.g) / 2.0,
+        (text.b + background.b) / 2.0,
+    );
+
+    text::Style { color: Some(color) }
+}
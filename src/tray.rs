@@ -0,0 +1,297 @@
+//! Module for the system tray icon, when tray mode is enabled the installer
+//! hides to the tray instead of closing and offers quick actions from the
+//! tray menu
+
+use crate::i18n::tr;
+use futures_util::{stream, Stream};
+use log::{debug, error};
+use tokio::sync::mpsc;
+
+/// Identifier and title of the tray icon
+const TRAY_TITLE: &str = "Pocket Relay Plugin Installer";
+
+/// Action chosen from the tray icon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrayAction {
+    /// Show the installer window again
+    Show,
+    /// Check for plugin updates
+    CheckUpdates,
+    /// Open the game folder in the file manager
+    OpenGameFolder,
+    /// Launch the game
+    LaunchGame,
+    /// Close the installer
+    Quit,
+}
+
+impl TrayAction {
+    /// Actions shown in the tray menu in order
+    const MENU: [TrayAction; 5] = [
+        TrayAction::Show,
+        TrayAction::CheckUpdates,
+        TrayAction::OpenGameFolder,
+        TrayAction::LaunchGame,
+        TrayAction::Quit,
+    ];
+
+    /// Label shown for the action in the tray menu
+    fn label(&self) -> &'static str {
+        match self {
+            TrayAction::Show => tr("Show installer"),
+            TrayAction::CheckUpdates => tr("Check for plugin updates"),
+            TrayAction::OpenGameFolder => tr("Open game folder"),
+            TrayAction::LaunchGame => tr("Launch Mass Effect 3"),
+            TrayAction::Quit => tr("Quit"),
+        }
+    }
+}
+
+/// Event from the tray icon stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrayEvent {
+    /// Tray icon was created, the window can be hidden to the tray
+    Ready,
+    /// Tray icon couldn't be created
+    Unavailable,
+    /// Action was chosen from the tray icon
+    Action(TrayAction),
+}
+
+/// State of the tray icon stream
+enum TrayState {
+    /// Tray icon hasn't been created yet
+    Pending,
+    /// Tray icon along with the receiver for its actions
+    Created(platform::TrayGuard, mpsc::UnboundedReceiver<TrayAction>),
+    /// Tray icon couldn't be created
+    Failed,
+}
+
+/// Creates a stream of the events from the tray icon. The tray icon is
+/// only created once the stream is first polled and is removed when the
+/// stream is dropped
+pub fn tray_events() -> impl Stream<Item = TrayEvent> {
+    stream::unfold(TrayState::Pending, |state| async move {
+        match state {
+            TrayState::Pending => {
+                let (tx, rx) = mpsc::unbounded_channel();
+                match platform::create_tray(tx).await {
+                    Ok(tray) => Some((TrayEvent::Ready, TrayState::Created(tray, rx))),
+                    Err(err) => {
+                        error!("failed to create tray icon: {err:#}");
+                        Some((TrayEvent::Unavailable, TrayState::Failed))
+                    }
+                }
+            }
+            TrayState::Created(tray, mut rx) => {
+                let action = rx.recv().await?;
+                debug!("tray action: {action:?}");
+
+                Some((TrayEvent::Action(action), TrayState::Created(tray, rx)))
+            }
+            TrayState::Failed => None,
+        }
+    })
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    //! Tray icon through the StatusNotifierItem DBus interface
+
+    use super::{TrayAction, TRAY_TITLE};
+    use ksni::{menu::StandardItem, Handle, MenuItem, TrayMethods};
+    use tokio::sync::mpsc;
+
+    /// Icon from the desktop icon theme used for the tray
+    const ICON_NAME: &str = "applications-games";
+
+    /// Tray providing the quick actions
+    struct InstallerTray {
+        /// Sender for the chosen actions
+        tx: mpsc::UnboundedSender<TrayAction>,
+    }
+
+    impl ksni::Tray for InstallerTray {
+        fn id(&self) -> String {
+            env!("CARGO_PKG_NAME").into()
+        }
+
+        fn title(&self) -> String {
+            TRAY_TITLE.into()
+        }
+
+        fn icon_name(&self) -> String {
+            ICON_NAME.into()
+        }
+
+        fn activate(&mut self, _x: i32, _y: i32) {
+            _ = self.tx.send(TrayAction::Show);
+        }
+
+        fn menu(&self) -> Vec<MenuItem<Self>> {
+            TrayAction::MENU
+                .into_iter()
+                .map(|action| {
+                    StandardItem {
+                        label: action.label().into(),
+                        activate: Box::new(move |tray: &mut Self| _ = tray.tx.send(action)),
+                        ..Default::default()
+                    }
+                    .into()
+                })
+                .collect()
+        }
+    }
+
+    /// Removes the tray icon when dropped
+    pub struct TrayGuard(Handle<InstallerTray>);
+
+    impl Drop for TrayGuard {
+        fn drop(&mut self) {
+            _ = self.0.shutdown();
+        }
+    }
+
+    /// Creates the tray icon sending its actions through `tx`
+    pub async fn create_tray(tx: mpsc::UnboundedSender<TrayAction>) -> anyhow::Result<TrayGuard> {
+        let handle = InstallerTray { tx }.spawn().await?;
+        Ok(TrayGuard(handle))
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    //! Tray icon in the Windows notification area, the icon belongs to the
+    //! thread that creates it so it's given its own thread with a message loop
+
+    use super::{TrayAction, TRAY_TITLE};
+    use anyhow::Context;
+    use std::sync::mpsc as std_mpsc;
+    use tokio::sync::mpsc;
+    use tray_icon::{
+        menu::{Menu, MenuEvent, MenuItem},
+        Icon, MouseButton, TrayIconBuilder, TrayIconEvent,
+    };
+    use windows_sys::Win32::{
+        System::Threading::GetCurrentThreadId,
+        UI::WindowsAndMessaging::{
+            DispatchMessageW, GetMessageW, PostThreadMessageW, TranslateMessage, MSG, WM_QUIT,
+        },
+    };
+
+    /// Resource ID of the application icon embedded by the build script
+    const ICON_RESOURCE_ID: u16 = 1;
+
+    /// Ends the message loop of the tray thread when dropped, which
+    /// removes the tray icon
+    pub struct TrayGuard {
+        /// ID of the thread running the tray
+        thread_id: u32,
+    }
+
+    impl Drop for TrayGuard {
+        fn drop(&mut self) {
+            // SAFETY: Posting to a thread that already exited only fails
+            unsafe { PostThreadMessageW(self.thread_id, WM_QUIT, 0, 0) };
+        }
+    }
+
+    /// Creates the tray icon sending its actions through `tx`
+    pub async fn create_tray(tx: mpsc::UnboundedSender<TrayAction>) -> anyhow::Result<TrayGuard> {
+        let (created_tx, created_rx) = std_mpsc::sync_channel(1);
+
+        std::thread::spawn(move || {
+            let _tray = match build_tray(tx) {
+                Ok(value) => value,
+                Err(err) => {
+                    _ = created_tx.send(Err(err));
+                    return;
+                }
+            };
+
+            // SAFETY: Reads the ID of the current thread
+            let thread_id = unsafe { GetCurrentThreadId() };
+            _ = created_tx.send(Ok(thread_id));
+
+            // SAFETY: The message is only used by the calls within the loop
+            unsafe {
+                let mut msg: MSG = std::mem::zeroed();
+                while GetMessageW(&mut msg, std::ptr::null_mut(), 0, 0) > 0 {
+                    TranslateMessage(&msg);
+                    DispatchMessageW(&msg);
+                }
+            }
+        });
+
+        let thread_id = tokio::task::spawn_blocking(move || created_rx.recv())
+            .await?
+            .context("tray thread stopped")??;
+
+        Ok(TrayGuard { thread_id })
+    }
+
+    /// Builds the tray icon and its menu on the current thread
+    fn build_tray(tx: mpsc::UnboundedSender<TrayAction>) -> anyhow::Result<tray_icon::TrayIcon> {
+        let items: Vec<MenuItem> = TrayAction::MENU
+            .iter()
+            .enumerate()
+            .map(|(index, action)| MenuItem::with_id(index.to_string(), action.label(), true, None))
+            .collect();
+
+        let menu = Menu::new();
+        for item in &items {
+            menu.append(item).context("failed to add tray menu item")?;
+        }
+
+        let menu_tx = tx.clone();
+        MenuEvent::set_event_handler(Some(move |event: MenuEvent| {
+            let action = event
+                .id
+                .0
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| TrayAction::MENU.get(index));
+
+            if let Some(action) = action {
+                _ = menu_tx.send(*action);
+            }
+        }));
+
+        TrayIconEvent::set_event_handler(Some(move |event: TrayIconEvent| {
+            if let TrayIconEvent::DoubleClick {
+                button: MouseButton::Left,
+                ..
+            } = event
+            {
+                _ = tx.send(TrayAction::Show);
+            }
+        }));
+
+        let icon =
+            Icon::from_resource(ICON_RESOURCE_ID, None).context("failed to load tray icon")?;
+
+        TrayIconBuilder::new()
+            .with_menu(Box::new(menu))
+            .with_tooltip(TRAY_TITLE)
+            .with_icon(icon)
+            .build()
+            .context("failed to create tray icon")
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+mod platform {
+    //! Tray icons aren't supported on other platforms
+
+    use super::TrayAction;
+    use tokio::sync::mpsc;
+
+    /// Placeholder for the tray icon
+    pub struct TrayGuard;
+
+    /// Tray icons aren't supported on this platform
+    pub async fn create_tray(_tx: mpsc::UnboundedSender<TrayAction>) -> anyhow::Result<TrayGuard> {
+        anyhow::bail!("tray icons aren't supported on this platform")
+    }
+}
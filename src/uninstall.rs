@@ -0,0 +1,103 @@
+//! Module for removing everything the installer and plugin have
+//! added to the game
+
+use crate::{
+    asi::DISABLED_EXTENSION,
+    backup::backup_plugin_files,
+    bink::{patch_state, remove_patch, PatchState},
+    fs_util::resolve_file_name,
+    plugin::{PLUGIN_DIR, PLUGIN_NAME, PLUGIN_VERSION_NAME},
+    plugin_config::PLUGIN_CONFIG_NAME,
+    plugin_log::PLUGIN_LOG_NAME,
+    process::ensure_game_not_running,
+    profile::game,
+};
+use anyhow::Context;
+use log::debug;
+use std::path::{Path, PathBuf};
+
+/// Removes the plugin along with its version, config and log files, then
+/// restores the unpatched binkw32.dll. The config and log files are backed
+/// up first when `backup` is set. The plugin directory is removed when
+/// `remove_plugin_dir` is set and its empty.
+///
+/// Returns a description of each of the changes that were made
+pub async fn uninstall_all(
+    game_path: PathBuf,
+    remove_plugin_dir: bool,
+    backup: bool,
+) -> anyhow::Result<Vec<String>> {
+    ensure_game_not_running(&game_path).await?;
+
+    let asi_path = game_path.join(PLUGIN_DIR);
+    let disabled_plugin_name = format!("{PLUGIN_NAME}{DISABLED_EXTENSION}");
+
+    let mut removed = Vec::new();
+
+    if backup {
+        let backup_path = backup_plugin_files(&game_path)
+            .await
+            .context("failed to back up plugin files")?;
+
+        if let Some(backup_path) = backup_path {
+            removed.push(format!(
+                "Backed up the plugin config and logs to {}",
+                backup_path.display()
+            ));
+        }
+    }
+
+    for name in [
+        PLUGIN_NAME,
+        &disabled_plugin_name,
+        PLUGIN_VERSION_NAME,
+        PLUGIN_CONFIG_NAME,
+        PLUGIN_LOG_NAME,
+    ] {
+        let path = asi_path.join(name);
+        if !path.is_file() {
+            continue;
+        }
+
+        tokio::fs::remove_file(&path)
+            .await
+            .with_context(|| format!("failed to remove {name}"))?;
+
+        removed.push(format!("Removed {PLUGIN_DIR}/{name}"));
+    }
+
+    // Missing, unreadable or unknown binkw32.dll is left as is
+    let patched = matches!(
+        patch_state(game(), &game_path).await,
+        Ok(PatchState::Patched)
+    );
+    let has_binkw23 = resolve_file_name(&game_path, "binkw23.dll").await.exists();
+    if patched || has_binkw23 {
+        remove_patch(game(), game_path.clone()).await?;
+        removed.push("Restored the original binkw32.dll".to_string());
+
+        if has_binkw23 {
+            removed.push("Removed binkw23.dll".to_string());
+        }
+    }
+
+    if remove_plugin_dir && is_empty_dir(&asi_path).await {
+        tokio::fs::remove_dir(&asi_path)
+            .await
+            .context("failed to remove plugin directory")?;
+
+        removed.push(format!("Removed the empty {PLUGIN_DIR} directory"));
+    }
+
+    debug!("uninstalled everything ({} changes)", removed.len());
+
+    Ok(removed)
+}
+
+/// Checks whether the directory at `path` exists and is empty
+async fn is_empty_dir(path: &Path) -> bool {
+    match tokio::fs::read_dir(path).await {
+        Ok(mut entries) => matches!(entries.next_entry().await, Ok(None)),
+        Err(_) => false,
+    }
+}
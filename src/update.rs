@@ -0,0 +1,326 @@
+//! Module for checking for and applying updates to the installer itself
+
+use crate::{
+    config::{portable_dir, PORTABLE_ARG},
+    downloads::DownloadManager,
+    github::{
+        download_latest_release, get_latest_release, parse_tag_version, verify_asset, ApiOptions,
+        GitHubRelease, GitHubReleaseAsset,
+    },
+    process::is_file_in_use_error,
+    APP_VERSION,
+};
+use anyhow::Context;
+use log::{debug, error};
+use semver::Version;
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// The GitHub repository to use for installer releases
+pub const INSTALLER_REPOSITORY: &str = "PocketRelay/PocketRelayPluginInstaller";
+
+/// GitHub asset name for the installer executable on this platform, each
+/// Windows architecture has its own executable so updates keep the
+/// architecture that is running
+#[cfg(all(
+    target_os = "windows",
+    not(any(target_arch = "aarch64", target_arch = "x86"))
+))]
+pub const INSTALLER_ASSET_NAME: &str = "pocket-relay-plugin-installer.exe";
+#[cfg(all(target_os = "windows", target_arch = "aarch64"))]
+pub const INSTALLER_ASSET_NAME: &str = "pocket-relay-plugin-installer-arm64.exe";
+#[cfg(all(target_os = "windows", target_arch = "x86"))]
+pub const INSTALLER_ASSET_NAME: &str = "pocket-relay-plugin-installer-x86.exe";
+#[cfg(not(target_os = "windows"))]
+pub const INSTALLER_ASSET_NAME: &str = "pocket-relay-plugin-installer";
+
+/// Prefix of the line within the plugin release notes declaring the oldest
+/// installer able to install the release (e.g "installer-min-version: 0.2.0")
+const INSTALLER_MIN_VERSION_PREFIX: &str = "installer-min-version:";
+
+/// Reads the oldest installer version able to install the plugin `release`
+/// from its release notes, none when the release doesn't declare one
+pub fn installer_min_version(release: &GitHubRelease) -> Option<Version> {
+    let body = release.body.as_deref()?;
+
+    body.lines().find_map(|line| {
+        // Allow the line to be formatted as a list item or bold text
+        let line = line.trim().trim_start_matches(['-', '*', ' ']);
+        let prefix = line.get(..INSTALLER_MIN_VERSION_PREFIX.len())?;
+        if !prefix.eq_ignore_ascii_case(INSTALLER_MIN_VERSION_PREFIX) {
+            return None;
+        }
+
+        let version = line[INSTALLER_MIN_VERSION_PREFIX.len()..].trim_matches(['*', '`', ' ']);
+        parse_tag_version(version)
+    })
+}
+
+/// Obtains the newer installer version the plugin `release` requires, none
+/// when this installer can install the release
+pub fn required_installer_version(release: &GitHubRelease) -> Option<Version> {
+    let required = installer_min_version(release)?;
+    let current = Version::parse(APP_VERSION).ok()?;
+
+    (required > current).then_some(required)
+}
+
+/// Checks the installer repository for a release newer than the
+/// current version, returns the release if one is available
+pub async fn check_for_update(
+    http_client: reqwest::Client,
+    mirrors: Vec<String>,
+    options: ApiOptions,
+) -> anyhow::Result<Option<GitHubRelease>> {
+    let latest_release = get_latest_release(&http_client, INSTALLER_REPOSITORY, &mirrors, &options)
+        .await
+        .context("failed finding latest installer version")?;
+
+    let current = Version::parse(APP_VERSION).context("invalid current version")?;
+    let latest = match parse_tag_version(&latest_release.tag_name) {
+        Some(value) => value,
+        None => {
+            debug!("unknown installer version: {}", latest_release.tag_name);
+            return Ok(None);
+        }
+    };
+
+    debug!("latest installer version: {latest} (current: {current})");
+
+    // Ensure the release has an asset for this platform
+    let has_asset = latest_release
+        .assets
+        .iter()
+        .any(|asset| asset.name == INSTALLER_ASSET_NAME);
+
+    if latest <= current || !has_asset {
+        return Ok(None);
+    }
+
+    Ok(Some(latest_release))
+}
+
+/// Number of attempts at removing the previous executable on startup
+const CLEANUP_ATTEMPTS: u32 = 10;
+/// Delay between attempts at removing the previous executable
+const CLEANUP_RETRY_DELAY: Duration = Duration::from_millis(250);
+
+/// Path the previous executable is moved to while updating
+fn old_exe_path(exe_path: &Path) -> PathBuf {
+    exe_path.with_extension("old")
+}
+
+/// Downloads the installer executable from the provided `release` through the
+/// `downloads` manager, replaces the current executable with it and starts
+/// the new executable
+pub async fn apply_update(
+    http_client: reqwest::Client,
+    downloads: DownloadManager,
+    mirrors: Vec<String>,
+    release: GitHubRelease,
+) -> anyhow::Result<()> {
+    let exe_path = std::env::current_exe().context("failed to find current executable")?;
+    let new_path = exe_path.with_extension("new");
+    let old_path = old_exe_path(&exe_path);
+
+    let asset = release
+        .assets
+        .iter()
+        .find(|asset| asset.name == INSTALLER_ASSET_NAME)
+        .context("missing installer asset file")?;
+
+    let bytes = downloads
+        .run(
+            asset.name.clone(),
+            |_| {},
+            |mut reporter| async {
+                let bytes = download_latest_release(
+                    &http_client,
+                    asset,
+                    &mirrors,
+                    downloads.speed_limit(),
+                    move |progress| reporter.report(progress),
+                )
+                .await?;
+                Ok(bytes)
+            },
+        )
+        .await
+        .context("failed to download installer")?;
+
+    save_update(asset, &bytes, &new_path).await?;
+
+    // The running executable can be renamed but not overwritten
+    tokio::fs::rename(&exe_path, &old_path)
+        .await
+        .context("failed to move current installer")?;
+
+    if let Err(err) = tokio::fs::rename(&new_path, &exe_path).await {
+        // Restore the previous executable
+        _ = tokio::fs::rename(&old_path, &exe_path).await;
+        return Err(err).context("failed to replace installer");
+    }
+
+    // Portable mode must carry over when it was enabled by the argument
+    std::process::Command::new(&exe_path)
+        .args(portable_dir().map(|_| PORTABLE_ARG))
+        .spawn()
+        .context("failed to start new installer")?;
+
+    debug!("applied installer update");
+
+    Ok(())
+}
+
+/// Verifies the downloaded installer `bytes` match the `asset` then saves
+/// them as an executable at `new_path`. Downloads from a mirror that don't
+/// match are refused so the installer is never replaced by a damaged or
+/// tampered executable
+async fn save_update(
+    asset: &GitHubReleaseAsset,
+    bytes: &[u8],
+    new_path: &Path,
+) -> anyhow::Result<()> {
+    verify_asset(asset, bytes).context("downloaded installer failed verification")?;
+
+    tokio::fs::write(new_path, bytes)
+        .await
+        .context("failed to save new installer")?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        tokio::fs::set_permissions(new_path, std::fs::Permissions::from_mode(0o755))
+            .await
+            .context("failed to make new installer executable")?;
+    }
+
+    Ok(())
+}
+
+/// Removes the previous executable left behind by an update. The previous
+/// installer starts this one then exits, so removing is retried briefly on
+/// a background thread while it finishes exiting
+pub fn cleanup_update() {
+    let exe_path = match std::env::current_exe() {
+        Ok(value) => value,
+        Err(_) => return,
+    };
+
+    let old_path = old_exe_path(&exe_path);
+    if !old_path.exists() {
+        return;
+    }
+
+    std::thread::spawn(move || {
+        for attempt in 1..=CLEANUP_ATTEMPTS {
+            let err = match std::fs::remove_file(&old_path) {
+                Ok(()) => {
+                    debug!("removed previous installer");
+                    return;
+                }
+                Err(err) => err,
+            };
+
+            if !is_exe_in_use_error(&err) {
+                error!("failed to remove previous installer: {err}");
+                return;
+            }
+
+            // Still running, left for the next start after the last attempt
+            if attempt == CLEANUP_ATTEMPTS {
+                debug!("previous installer is still running: {err}");
+                return;
+            }
+
+            std::thread::sleep(CLEANUP_RETRY_DELAY);
+        }
+    });
+}
+
+/// Checks whether the `err` from removing the previous executable was caused
+/// by it still running, Windows reports removing a running executable as
+/// access denied rather than a sharing violation
+fn is_exe_in_use_error(err: &std::io::Error) -> bool {
+    is_file_in_use_error(err)
+        || (cfg!(target_os = "windows") && err.kind() == std::io::ErrorKind::PermissionDenied)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{installer_min_version, required_installer_version, save_update};
+    use crate::{
+        game_fs::test_util::TempDir,
+        github::{GitHubRelease, GitHubReleaseAsset},
+    };
+    use semver::Version;
+
+    /// Creates a release with the release notes `body`
+    fn create_release(body: &str) -> GitHubRelease {
+        GitHubRelease {
+            html_url: String::new(),
+            tag_name: "v1.0.0".to_string(),
+            name: "v1.0.0".to_string(),
+            published_at: String::new(),
+            prerelease: false,
+            body: Some(body.to_string()),
+            assets: Vec::new(),
+        }
+    }
+
+    /// Minimum installer version is read from the release notes
+    #[test]
+    fn test_installer_min_version() {
+        let release = create_release("Fixes\n\n- **Installer-Min-Version: `v0.2.0`**\n");
+        assert_eq!(installer_min_version(&release), Some(Version::new(0, 2, 0)));
+
+        assert_eq!(installer_min_version(&create_release("Fixes")), None);
+    }
+
+    /// Only releases requiring a newer installer are flagged
+    #[test]
+    fn test_required_installer_version() {
+        let release = create_release("installer-min-version: 999.0.0");
+        assert_eq!(
+            required_installer_version(&release),
+            Some(Version::new(999, 0, 0))
+        );
+
+        let release = create_release("installer-min-version: 0.0.1");
+        assert_eq!(required_installer_version(&release), None);
+    }
+
+    /// Downloads that don't match the size or digest of the asset are
+    /// never saved as the new installer
+    #[tokio::test]
+    async fn test_save_update_verifies() {
+        let dir = TempDir::new();
+        let new_path = dir.path().join("installer.new");
+
+        let bytes = b"new installer";
+        let asset = |size: u64, digest: &str| GitHubReleaseAsset {
+            name: "installer".to_string(),
+            browser_download_url: String::new(),
+            size,
+            digest: Some(format!("sha256:{digest}")),
+        };
+        let digest = sha256::digest(bytes.as_slice());
+
+        // Wrong size
+        let wrong_size = asset(bytes.len() as u64 + 1, &digest);
+        assert!(save_update(&wrong_size, bytes, &new_path).await.is_err());
+        assert!(!new_path.exists());
+
+        // Wrong digest
+        let wrong_digest = asset(bytes.len() as u64, &sha256::digest("other"));
+        assert!(save_update(&wrong_digest, bytes, &new_path).await.is_err());
+        assert!(!new_path.exists());
+
+        let valid = asset(bytes.len() as u64, &digest);
+        save_update(&valid, bytes, &new_path).await.unwrap();
+        assert_eq!(std::fs::read(&new_path).unwrap(), bytes);
+    }
+}
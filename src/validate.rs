@@ -0,0 +1,77 @@
+//! Module for validating that a picked file is the executable of the
+//! selected game before allowing changes to the game folder
+
+use crate::{
+    pe::{contains_utf16_string, read_file_version, read_machine, MACHINE_AMD64},
+    profile::profile,
+};
+use anyhow::{bail, Context};
+use log::debug;
+use std::path::Path;
+
+/// Validates that the file at `exe_path` is the executable of the selected game.
+///
+/// Files that can't be the game executable produce an error, files that
+/// don't look like the game executable produce a warning message
+pub async fn validate_game_exe(exe_path: &Path) -> anyhow::Result<Option<String>> {
+    let file_name = exe_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let profile = profile();
+
+    if file_name.eq_ignore_ascii_case(profile.launcher_exe) {
+        bail!(
+            "{} is the game launcher, pick {} instead",
+            profile.launcher_exe,
+            profile.exe_name
+        );
+    }
+
+    let bytes = tokio::fs::read(exe_path)
+        .await
+        .context("failed to read game executable")?;
+
+    match read_machine(&bytes) {
+        Some(machine) if machine == profile.machine => {}
+        // Legendary Edition uses the same executable name but is 64-bit
+        Some(MACHINE_AMD64) => {
+            bail!("{file_name} is a 64-bit executable, the Legendary Edition is not supported")
+        }
+        Some(machine) => bail!("{file_name} is not a supported executable (machine {machine:#x})"),
+        None => bail!("{file_name} is not a valid executable"),
+    }
+
+    let version = read_file_version(&bytes);
+    debug!("game executable version: {version:?}");
+
+    if !file_name.eq_ignore_ascii_case(profile.exe_name) {
+        return Ok(Some(format!(
+            "Picked {file_name} instead of {}, make sure this is the right game folder",
+            profile.exe_name
+        )));
+    }
+
+    if !contains_utf16_string(&bytes, profile.product_name) {
+        return Ok(Some(format!(
+            "{file_name} doesn't look like the {} executable, make sure \
+            this is the right game folder",
+            profile.product_name
+        )));
+    }
+
+    let is_known_version = version
+        .as_deref()
+        .and_then(|version| version.split('.').next())
+        .is_some_and(|major| major == profile.major_version);
+
+    if !is_known_version {
+        return Ok(Some(format!(
+            "Unknown {} version ({}), the plugin may not work",
+            profile.product_name,
+            version.as_deref().unwrap_or("unknown")
+        )));
+    }
+
+    Ok(None)
+}
@@ -0,0 +1,68 @@
+//! Module for watching the game folder for changes made outside the
+//! installer (e.g by ME3Tweaks Mod Manager) so the displayed state
+//! doesn't go stale
+
+use futures_util::{stream, Stream};
+use log::{debug, error};
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use tokio::sync::mpsc;
+
+/// Time to wait for further changes before reporting a change, tools
+/// usually change several files at once
+const DEBOUNCE_DELAY: Duration = Duration::from_millis(500);
+
+/// Watcher for the game folder along with the receiver for its changes
+type WatchState = (RecommendedWatcher, mpsc::UnboundedReceiver<()>);
+
+/// Creates a stream that produces an item whenever files within the game
+/// folder at `game_path` change. The watcher is only created once the
+/// stream is first polled, the stream ends if the folder can't be watched
+pub fn watch_game_folder(game_path: PathBuf) -> impl Stream<Item = ()> {
+    stream::unfold(None, move |state: Option<WatchState>| {
+        let game_path = game_path.clone();
+
+        async move {
+            let (watcher, mut rx) = match state {
+                Some(value) => value,
+                None => match create_watcher(&game_path) {
+                    Ok(value) => value,
+                    Err(err) => {
+                        error!("failed to watch game folder: {err}");
+                        return None;
+                    }
+                },
+            };
+
+            rx.recv().await?;
+
+            // Combine the changes that happen together into a single change
+            tokio::time::sleep(DEBOUNCE_DELAY).await;
+            while rx.try_recv().is_ok() {}
+
+            debug!("game folder changed: {}", game_path.display());
+
+            Some(((), Some((watcher, rx))))
+        }
+    })
+}
+
+/// Creates a watcher for the game folder at `game_path`, includes the
+/// sub folders so changes to the ASI plugins are detected
+fn create_watcher(game_path: &Path) -> notify::Result<WatchState> {
+    let (tx, rx) = mpsc::unbounded_channel();
+
+    let mut watcher = notify::recommended_watcher(move |result: notify::Result<Event>| {
+        // Reading files doesn't change the state
+        if result.is_ok_and(|event| !event.kind.is_access()) {
+            _ = tx.send(());
+        }
+    })?;
+
+    watcher.watch(game_path, RecursiveMode::Recursive)?;
+
+    Ok((watcher, rx))
+}